@@ -2,11 +2,12 @@
 //! add any custom end-point related logic.
 
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     fmt::Debug,
     num::{NonZeroU32, NonZeroU64},
+    str::FromStr,
     thread,
-    time::Duration,
+    time::{Duration, SystemTime},
 };
 
 use derive_more::{DebugCustom, Display};
@@ -36,7 +37,20 @@ use crate::{
         isi::Instruction,
         prelude::*,
         transaction::TransactionBuilder,
-        ChainId,
+        ChainId, ParseError,
+    },
+    executor_data_model::{
+        isi::{
+            multisig::{MultisigProposalValue, MultisigSpec},
+            role::{EffectivePermission, RoleHierarchy},
+            vesting::{self, VestingLockValue},
+        },
+        parameter::Parameter as _,
+        query::{
+            multisig::{FindMultisigProposals, FindMultisigSpec},
+            role::FindEffectivePermissions,
+            ExecutorQuery,
+        },
     },
     http::{Method as HttpMethod, RequestBuilder, Response, StatusCode},
     http_default::{self, DefaultRequestBuilder, WebSocketError, WebSocketMessage},
@@ -112,6 +126,50 @@ impl From<ResponseReport> for eyre::Report {
     }
 }
 
+/// Account referred to either by its identifier or by an alias registered for it,
+/// see [`Client::resolve_account`]
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
+pub enum AccountRef {
+    /// Account identifier
+    Id(AccountId),
+    /// Alias registered for the account
+    Alias(AccountAlias),
+}
+
+impl FromStr for AccountRef {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse()
+            .map(Self::Id)
+            .or_else(|_| s.parse().map(Self::Alias))
+    }
+}
+
+impl From<AccountId> for AccountRef {
+    fn from(id: AccountId) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl From<&AccountId> for AccountRef {
+    fn from(id: &AccountId) -> Self {
+        Self::Id(id.clone())
+    }
+}
+
+impl From<AccountAlias> for AccountRef {
+    fn from(alias: AccountAlias) -> Self {
+        Self::Alias(alias)
+    }
+}
+
+impl From<&AccountAlias> for AccountRef {
+    fn from(alias: &AccountAlias) -> Self {
+        Self::Alias(alias.clone())
+    }
+}
+
 /// Iroha client
 #[derive(Clone, DebugCustom, Display)]
 #[debug(
@@ -410,6 +468,55 @@ impl Client {
         ))
     }
 
+    /// Execute the transaction against the latest state of the peer without committing it.
+    /// Returns the outcome of the execution, including the rejection reason if the transaction
+    /// would be rejected.
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails, if the peer doesn't accept the transaction
+    /// or doesn't serve simulations, see `torii.simulation_enabled`
+    pub fn simulate_transaction(
+        &self,
+        request: &TransactionSimulationRequest,
+    ) -> Result<TransactionSimulation> {
+        let resp = DefaultRequestBuilder::new(
+            HttpMethod::POST,
+            join_torii_url(&self.torii_url, torii_uri::TRANSACTION_SIMULATION),
+        )
+        .headers(&self.headers)
+        .body(request.encode())
+        .build()?
+        .send()?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to simulate transaction with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        TransactionSimulation::decode_all(&mut resp.body().as_slice())
+            .wrap_err("Failed to decode body")
+    }
+
+    /// Execute instructions on behalf of the client account without committing them.
+    /// The transaction is signed by the client key only, so the signature threshold
+    /// of the account is not checked.
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails or if the peer doesn't accept the transaction
+    pub fn simulate_all<I: Instruction>(
+        &self,
+        instructions: impl IntoIterator<Item = I>,
+    ) -> Result<TransactionSimulation> {
+        let transaction = self.sign_transaction(
+            TransactionBuilder::new(self.chain.clone(), self.account.clone())
+                .with_instructions(instructions),
+        );
+
+        self.simulate_transaction(&TransactionSimulationRequest::PartiallySigned(transaction))
+    }
+
     /// Lower-level Instructions API entry point.
     ///
     /// Returns a tuple with a provided request builder, a hash of the transaction, and a response handler.
@@ -623,6 +730,158 @@ impl Client {
         DecodeAll::decode_all(&mut scaled_resp.as_slice()).map_err(|err| eyre!("{err}"))
     }
 
+    /// Execute a query defined and resolved by the executor
+    ///
+    /// # Errors
+    /// Fails if sending request fails, the executor fails to resolve the query or its output is malformed
+    pub fn query_custom<Q: ExecutorQuery>(&self, query: Q) -> Result<Q::Output> {
+        self.query_single(query.into())?
+            .try_into_any()
+            .wrap_err("Custom query output malformed")
+    }
+
+    /// Find the specification of the multisig account
+    ///
+    /// # Errors
+    /// Fails if the account is not found or is not a multisig account
+    pub fn find_multisig_spec(&self, account: impl Into<AccountRef>) -> Result<MultisigSpec> {
+        let account = self.resolve_account(account)?;
+        self.query_custom(FindMultisigSpec::new(account.clone()))
+            .wrap_err_with(|| format!("Failed to find multisig spec of account {account}"))
+    }
+
+    /// Find pending proposals of the multisig account keyed by instructions hash,
+    /// including their approvals, rejections and expiry
+    ///
+    /// # Errors
+    /// Fails if the account is not found or its metadata is malformed
+    pub fn find_multisig_proposals(
+        &self,
+        account: impl Into<AccountRef>,
+    ) -> Result<BTreeMap<HashOf<Vec<InstructionBox>>, MultisigProposalValue>> {
+        let account = self.resolve_account(account)?;
+        self.query_custom(FindMultisigProposals::new(account.clone()))
+            .wrap_err_with(|| format!("Failed to find multisig proposals of account {account}"))
+    }
+
+    /// Find amounts locked by vesting in the assets of the account, keyed by asset definition
+    ///
+    /// # Errors
+    /// Fails if the account is not found or its metadata is malformed
+    pub fn find_vesting_locks(
+        &self,
+        account: impl Into<AccountRef>,
+    ) -> Result<BTreeMap<AssetDefinitionId, VestingLockValue>> {
+        let account = self.find_account(&self.resolve_account(account)?)?;
+        vesting::locks_of(&account).map_err(|err| eyre!(err))
+    }
+
+    /// Find the amount of the asset locked by vesting, which is not spendable until claimed
+    ///
+    /// # Errors
+    /// Fails if the account is not found or its metadata is malformed
+    pub fn find_locked_amount(&self, asset: &AssetId) -> Result<Numeric> {
+        let lock = self.find_vesting_lock(asset)?;
+        Ok(lock.map_or(Numeric::ZERO, |lock| lock.locked()))
+    }
+
+    /// Find the amount of the asset released by vesting as of now and not claimed yet
+    ///
+    /// # Errors
+    /// Fails if the account or the asset definition is not found, the account metadata is malformed
+    /// or computing the amount overflows
+    pub fn find_claimable_amount(&self, asset: &AssetId) -> Result<Numeric> {
+        let Some(lock) = self.find_vesting_lock(asset)? else {
+            return Ok(Numeric::ZERO);
+        };
+        let spec = self
+            .query(FindAssetsDefinitions)
+            .filter_with(|entry| entry.id.eq(asset.definition().clone()))
+            .execute_single()
+            .wrap_err_with(|| format!("Failed to find asset definition {}", asset.definition()))?
+            .spec();
+        let now_ms = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)?
+            .as_millis()
+            .try_into()?;
+
+        lock.claimable(now_ms, spec)
+            .wrap_err("Failed to compute the claimable amount")
+    }
+
+    fn find_vesting_lock(&self, asset: &AssetId) -> Result<Option<VestingLockValue>> {
+        let mut locks = self.find_vesting_locks(asset.account())?;
+        Ok(locks.remove(asset.definition()))
+    }
+
+    /// Find the hierarchy of roles maintained by the executor
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub fn find_role_hierarchy(&self) -> Result<RoleHierarchy> {
+        let parameters = self.query_single(FindParameters)?;
+        let Some(parameter) = parameters.custom().get(&RoleHierarchy::id()) else {
+            return Ok(RoleHierarchy::default());
+        };
+
+        RoleHierarchy::try_from(parameter)
+            .map_err(|err| eyre!("Role hierarchy parameter malformed: {err:?}"))
+    }
+
+    /// Find the permissions in effect for the account along with their provenance,
+    /// including the ones of the roles inherited by the roles granted to it
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub fn find_effective_permissions(
+        &self,
+        account: impl Into<AccountRef>,
+    ) -> Result<Vec<EffectivePermission>> {
+        let account = self.resolve_account(account)?;
+        self.query_custom(FindEffectivePermissions::new(account.clone()))
+            .wrap_err_with(|| format!("Failed to find effective permissions of account {account}"))
+    }
+
+    /// Resolve the alias into the account it is registered for
+    ///
+    /// # Errors
+    /// Fails if the alias is not registered
+    pub fn find_account_by_alias(&self, alias: &AccountAlias) -> Result<AccountId> {
+        self.query_single(FindAccountIdByAlias::new(alias.clone()))
+            .wrap_err_with(|| format!("Failed to resolve account alias {alias}"))
+    }
+
+    /// Resolve the account referred to either by its identifier or by an alias.
+    ///
+    /// Every method of the client accepting an [`AccountRef`] resolves it with this method.
+    ///
+    /// # Errors
+    /// Fails if the alias is not registered
+    pub fn resolve_account(&self, account: impl Into<AccountRef>) -> Result<AccountId> {
+        match account.into() {
+            AccountRef::Id(id) => Ok(id),
+            AccountRef::Alias(alias) => self.find_account_by_alias(&alias),
+        }
+    }
+
+    /// Find names of all aliases registered for the account
+    ///
+    /// # Errors
+    /// Fails if the alias is not registered or sending request or decoding fails
+    pub fn find_account_aliases(&self, account: impl Into<AccountRef>) -> Result<Vec<Name>> {
+        let account = self.resolve_account(account)?;
+        self.query(FindAliasesByAccountId::new(account.clone()))
+            .execute_all()
+            .wrap_err_with(|| format!("Failed to find aliases of account {account}"))
+    }
+
+    fn find_account(&self, id: &AccountId) -> Result<Account> {
+        self.query(FindAccounts)
+            .filter_with(|account| account.id.eq(id.clone()))
+            .execute_single()
+            .wrap_err_with(|| format!("Failed to find account {id}"))
+    }
+
     /// Prepares http-request to implement [`Self::get_status`] on your own.
     ///
     /// For general usage example see [`Client::prepare_query_request`].
@@ -0,0 +1,86 @@
+use eyre::Result;
+use iroha::{client::Client, data_model::prelude::*};
+use iroha_test_network::*;
+use iroha_test_samples::{gen_account_in, ALICE_ID};
+
+#[test]
+fn account_alias_lifecycle() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let (bob_id, _bob_key) = gen_account_in("wonderland");
+    test_client.submit_blocking(Register::account(Account::new(bob_id.clone())))?;
+
+    let alias: AccountAlias = "alice@wonderland".parse()?;
+    test_client.submit_blocking(RegisterAccountAlias::new(alias.clone(), ALICE_ID.clone()))?;
+    assert_eq!(test_client.find_account_by_alias(&alias)?, ALICE_ID.clone());
+    assert_eq!(
+        test_client.find_account_aliases(ALICE_ID.clone())?,
+        vec!["alice".parse::<Name>()?]
+    );
+
+    // Aliases are unique within a domain
+    let _err = test_client
+        .submit_blocking(RegisterAccountAlias::new(alias.clone(), bob_id.clone()))
+        .unwrap_err();
+
+    test_client.submit_blocking(TransferAccountAlias::new(alias.clone(), bob_id.clone()))?;
+    assert_eq!(test_client.find_account_by_alias(&alias)?, bob_id);
+    assert!(test_client
+        .find_account_aliases(ALICE_ID.clone())?
+        .is_empty());
+    // Client methods accepting an account resolve aliases as well
+    assert_eq!(test_client.resolve_account(&alias)?, bob_id);
+    assert_eq!(
+        test_client.find_account_aliases(&alias)?,
+        vec!["alice".parse::<Name>()?]
+    );
+
+    test_client.submit_blocking(UnregisterAccountAlias::new(alias.clone()))?;
+    let _err = test_client.find_account_by_alias(&alias).unwrap_err();
+    assert!(test_client.find_account_aliases(&bob_id)?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn account_alias_belongs_to_account_domain() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let alias: AccountAlias = "alice@garden_of_live_flowers".parse()?;
+    test_client.submit_blocking(Register::domain(Domain::new(alias.domain().clone())))?;
+
+    let _err = test_client
+        .submit_blocking(RegisterAccountAlias::new(alias, ALICE_ID.clone()))
+        .unwrap_err();
+
+    Ok(())
+}
+
+#[test]
+fn account_alias_of_another_account_is_not_permitted() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let (bob_id, bob_key) = gen_account_in("wonderland");
+    test_client.submit_blocking(Register::account(Account::new(bob_id.clone())))?;
+    let bob_client = Client {
+        account: bob_id.clone(),
+        key_pair: bob_key,
+        ..test_client.clone()
+    };
+
+    let alias: AccountAlias = "alice@wonderland".parse()?;
+    let _err = bob_client
+        .submit_blocking(RegisterAccountAlias::new(alias.clone(), ALICE_ID.clone()))
+        .unwrap_err();
+
+    test_client.submit_blocking(RegisterAccountAlias::new(alias.clone(), ALICE_ID.clone()))?;
+    let _err = bob_client
+        .submit_blocking(TransferAccountAlias::new(alias.clone(), bob_id))
+        .unwrap_err();
+    assert_eq!(test_client.find_account_by_alias(&alias)?, ALICE_ID.clone());
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use std::num::NonZeroU16;
+
+use eyre::Result;
+use iroha::{client::Client, crypto::KeyPair, data_model::prelude::*};
+use iroha_test_network::*;
+use iroha_test_samples::gen_account_in;
+
+#[test]
+fn account_keys_threshold_and_rotation() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let (account_id, first_key) = gen_account_in("wonderland");
+    let second_key = KeyPair::random();
+    let third_key = KeyPair::random();
+    test_client.submit_blocking(Register::account(Account::new(account_id.clone())))?;
+
+    // A single key is enough to authorize transactions by default
+    let client = Client {
+        account: account_id.clone(),
+        key_pair: first_key.clone(),
+        ..test_client.clone()
+    };
+    client.submit_all_blocking::<InstructionBox>([
+        AddAccountKey::new(account_id.clone(), second_key.public_key().clone(), 1).into(),
+        SetAccountKeyThreshold::new(account_id.clone(), NonZeroU16::new(2).unwrap()).into(),
+    ])?;
+
+    let keys = account_keys(&account_id, &test_client)?;
+    assert_eq!(keys.total_weight(), 2);
+    assert_eq!(keys.threshold().get(), 2);
+
+    let set_metadata =
+        |value: &str| SetKeyValue::account(account_id.clone(), "key".parse().unwrap(), value);
+    let build = |value: &str| {
+        TransactionBuilder::new(network.chain_id(), account_id.clone())
+            .with_instructions([set_metadata(value)])
+    };
+
+    // A single key doesn't reach the threshold anymore
+    let tx = build("single").sign(first_key.private_key());
+    let _err = test_client.submit_transaction_blocking(&tx).unwrap_err();
+
+    // Both keys together do
+    let tx = build("cosigned")
+        .sign(first_key.private_key())
+        .cosign(second_key.private_key());
+    test_client.submit_transaction_blocking(&tx)?;
+
+    // The initial key is rotated out while the account keeps its identifier
+    let tx = TransactionBuilder::new(network.chain_id(), account_id.clone())
+        .with_instructions([RotateAccountKey::new(
+            account_id.clone(),
+            first_key.public_key().clone(),
+            third_key.public_key().clone(),
+        )])
+        .sign(first_key.private_key())
+        .cosign(second_key.private_key());
+    test_client.submit_transaction_blocking(&tx)?;
+
+    let keys = account_keys(&account_id, &test_client)?;
+    assert!(!keys.contains(first_key.public_key()));
+    assert!(keys.contains(third_key.public_key()));
+
+    let tx = build("rotated out")
+        .sign(first_key.private_key())
+        .cosign(second_key.private_key());
+    let _err = test_client.submit_transaction_blocking(&tx).unwrap_err();
+
+    let tx = build("rotated in")
+        .sign(third_key.private_key())
+        .cosign(second_key.private_key());
+    test_client.submit_transaction_blocking(&tx)?;
+
+    // Queries are authorized by any registered key alone
+    let query_signed_by = |key_pair: &KeyPair| {
+        Client {
+            account: account_id.clone(),
+            key_pair: key_pair.clone(),
+            ..test_client.clone()
+        }
+        .query(FindAccounts)
+        .execute_all()
+    };
+    let _err = query_signed_by(&first_key).unwrap_err();
+    query_signed_by(&second_key)?;
+    query_signed_by(&third_key)?;
+
+    Ok(())
+}
+
+#[test]
+fn account_keys_stay_operable() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let (account_id, key) = gen_account_in("wonderland");
+    test_client.submit_blocking(Register::account(Account::new(account_id.clone())))?;
+    let client = Client {
+        account: account_id.clone(),
+        key_pair: key.clone(),
+        ..test_client.clone()
+    };
+
+    // The last key can't be removed
+    let _err = client
+        .submit_blocking(RemoveAccountKey::new(
+            account_id.clone(),
+            key.public_key().clone(),
+        ))
+        .unwrap_err();
+
+    // The threshold can't exceed the total weight of the keys
+    let _err = client
+        .submit_blocking(SetAccountKeyThreshold::new(
+            account_id.clone(),
+            NonZeroU16::new(2).unwrap(),
+        ))
+        .unwrap_err();
+
+    // Keys of another account can't be managed
+    let _err = test_client
+        .submit_blocking(AddAccountKey::new(
+            account_id.clone(),
+            KeyPair::random().public_key().clone(),
+            1,
+        ))
+        .unwrap_err();
+
+    assert_eq!(
+        account_keys(&account_id, &test_client)?,
+        AccountKeys::single(key.public_key().clone())
+    );
+
+    Ok(())
+}
+
+fn account_keys(account_id: &AccountId, client: &Client) -> Result<AccountKeys> {
+    let account = client
+        .query(FindAccounts)
+        .filter_with(|account| account.id.eq(account_id.clone()))
+        .execute_single()?;
+
+    Ok(account.keys().clone())
+}
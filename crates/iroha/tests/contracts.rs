@@ -0,0 +1,178 @@
+use eyre::Result;
+use futures_util::TryStreamExt as _;
+use iroha::{
+    client::Client,
+    data_model::{
+        isi::error::{InstructionExecutionError, InvalidParameterError},
+        parameter::{ContractParameter, Parameter, SmartContractParameter},
+        prelude::*,
+        transaction::error::TransactionRejectionReason,
+    },
+};
+use iroha_executor_data_model::permission::contract::{CanCallContract, CanDeployContract};
+use iroha_test_network::*;
+use iroha_test_samples::{gen_account_in, load_sample_wasm, ALICE_ID};
+use nonzero_ext::nonzero;
+
+fn start_network() -> Result<(Network, tokio::runtime::Runtime)> {
+    NetworkBuilder::new()
+        .with_genesis_instruction(Grant::account_permission(
+            CanDeployContract,
+            ALICE_ID.clone(),
+        ))
+        .start_blocking()
+}
+
+#[test]
+fn contract_is_called_recursively_up_to_depth_limit() -> Result<()> {
+    let (network, rt) = start_network()?;
+    let test_client = network.client();
+
+    let contract_id: ContractId = "recursive".parse()?;
+    test_client.submit_blocking(DeployContract::new(
+        contract_id.clone(),
+        load_sample_wasm("recursive_contract"),
+    ))?;
+    let contract = test_client
+        .query(FindContracts)
+        .filter_with(|contract| contract.id.eq(contract_id.clone()))
+        .execute_single()?;
+    assert_eq!(contract.owner(), &*ALICE_ID);
+
+    // Same module is deduplicated, but ids are unique
+    let _err = test_client
+        .submit_blocking(DeployContract::new(
+            contract_id.clone(),
+            load_sample_wasm("recursive_contract"),
+        ))
+        .unwrap_err();
+
+    let events_client = test_client.clone();
+    let filter = ContractEventFilter::new()
+        .for_contract(contract_id.clone())
+        .for_events(ContractEventSet::Called);
+    let task = rt.spawn(async move {
+        let mut stream = events_client
+            .listen_for_events_async([filter])
+            .await
+            .unwrap();
+        // Nested calls complete first, so the outermost call is the last one
+        while let Some(event) = stream.try_next().await.unwrap() {
+            if let EventBox::Data(DataEvent::Contract(ContractEvent::Called(called))) = event {
+                if called.output() == &Json::new(3_u32) {
+                    assert_eq!(called.caller(), &*ALICE_ID);
+                    break;
+                }
+            }
+        }
+    });
+
+    test_client.submit_blocking(CallContract::new(contract_id.clone()).with_args(&3_u32))?;
+    rt.block_on(async {
+        tokio::time::timeout(core::time::Duration::from_secs(60), task)
+            .await
+            .unwrap()
+    })
+    .expect("should receive output of the outermost call");
+
+    // 1 outermost call and 8 nested ones exceed the depth limit
+    let err = test_client
+        .submit_blocking(CallContract::new(contract_id.clone()).with_args(&8_u32))
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<TransactionRejectionReason>(),
+        Some(TransactionRejectionReason::Validation(
+            ValidationFail::InstructionFailed(InstructionExecutionError::InvariantViolation(_))
+        ))
+    ));
+
+    // The depth limit is set on chain
+    test_client.submit_blocking(SetParameter::new(Parameter::Contract(
+        ContractParameter::MaxCallDepth(nonzero!(3_u64)),
+    )))?;
+    test_client.submit_blocking(CallContract::new(contract_id.clone()).with_args(&2_u32))?;
+    let _err = test_client
+        .submit_blocking(CallContract::new(contract_id).with_args(&3_u32))
+        .unwrap_err();
+
+    Ok(())
+}
+
+#[test]
+fn nested_contract_calls_are_limited_by_fuel_of_the_caller() -> Result<()> {
+    let (network, _rt) = start_network()?;
+    let test_client = network.client();
+
+    let contract_id: ContractId = "recursive".parse()?;
+    test_client.submit_blocking(DeployContract::new(
+        contract_id.clone(),
+        load_sample_wasm("recursive_contract"),
+    ))?;
+    test_client.submit_blocking(SetParameter::new(Parameter::Contract(
+        ContractParameter::MaxCallDepth(nonzero!(64_u64)),
+    )))?;
+    test_client.submit_blocking(SetParameter::new(Parameter::SmartContract(
+        SmartContractParameter::Fuel(nonzero!(3_000_000_u64)),
+    )))?;
+
+    // Every nested call consumes at least 100_000 fuel of the outermost call on its own,
+    // so the calls run out of fuel long before reaching the depth limit
+    let err = test_client
+        .submit_blocking(CallContract::new(contract_id).with_args(&40_u32))
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<TransactionRejectionReason>(),
+        Some(TransactionRejectionReason::Validation(
+            ValidationFail::InstructionFailed(InstructionExecutionError::InvalidParameter(
+                InvalidParameterError::Wasm(_)
+            ))
+        ))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn contract_deploy_and_call_require_permission() -> Result<()> {
+    let (network, _rt) = start_network()?;
+    let test_client = network.client();
+
+    let (bob_id, bob_key) = gen_account_in("wonderland");
+    test_client.submit_blocking(Register::account(Account::new(bob_id.clone())))?;
+    let bob_client = Client {
+        account: bob_id.clone(),
+        key_pair: bob_key,
+        ..test_client.clone()
+    };
+
+    let contract_id: ContractId = "recursive".parse()?;
+    let _err = bob_client
+        .submit_blocking(DeployContract::new(
+            contract_id.clone(),
+            load_sample_wasm("recursive_contract"),
+        ))
+        .unwrap_err();
+
+    test_client.submit_blocking(DeployContract::new(
+        contract_id.clone(),
+        load_sample_wasm("recursive_contract"),
+    ))?;
+    let call = CallContract::new(contract_id.clone()).with_args(&1_u32);
+    let _err = bob_client.submit_blocking(call.clone()).unwrap_err();
+
+    test_client.submit_blocking(Grant::account_permission(
+        CanCallContract {
+            contract: contract_id.clone(),
+        },
+        bob_id,
+    ))?;
+    bob_client.submit_blocking(call)?;
+
+    let _err = bob_client
+        .submit_blocking(RemoveContract::new(contract_id.clone()))
+        .unwrap_err();
+    test_client.submit_blocking(RemoveContract::new(contract_id))?;
+    assert!(test_client.query(FindContracts).execute_all()?.is_empty());
+
+    Ok(())
+}
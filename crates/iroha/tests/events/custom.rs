@@ -0,0 +1,125 @@
+use std::time::Duration;
+
+use executor_custom_data_model::events::Announcement;
+use eyre::Result;
+use futures_util::TryStreamExt as _;
+use iroha::{
+    client::Client,
+    data_model::{
+        parameter::{Parameter, SmartContractParameter},
+        prelude::*,
+    },
+};
+use iroha_test_network::*;
+use iroha_test_samples::{load_sample_wasm, load_wasm_build_profile, ALICE_ID};
+use nonzero_ext::nonzero;
+
+fn announcement() -> Announcement {
+    Announcement {
+        message: "The white rabbit is late".to_owned(),
+    }
+}
+
+fn rose_value(client: &Client, rose_id: &AssetId) -> Result<Numeric> {
+    Ok(*client
+        .query(FindAssets::new())
+        .filter_with(|asset| asset.id.eq(rose_id.clone()))
+        .execute_single()?
+        .value())
+}
+
+fn upgrade_executor(client: &Client) -> Result<()> {
+    if !load_wasm_build_profile().is_optimized() {
+        client.submit_blocking(SetParameter::new(Parameter::Executor(
+            SmartContractParameter::Fuel(nonzero!(90_000_000_u64)),
+        )))?;
+    }
+    client.submit_blocking(Upgrade::new(Executor::new(load_sample_wasm(
+        "executor_with_custom_event",
+    ))))?;
+
+    Ok(())
+}
+
+#[test]
+fn custom_event_must_be_defined_in_executor_data_model() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+
+    let _err = client
+        .submit_blocking(EmitEvent::from(announcement()))
+        .unwrap_err();
+
+    upgrade_executor(&client)?;
+    let data_model = client.query_single(FindExecutorDataModel)?;
+    assert!(data_model.events().contains("Announcement"));
+    client.submit_blocking(EmitEvent::from(announcement()))?;
+
+    Ok(())
+}
+
+#[test]
+fn custom_event_payload_must_match_schema() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+    upgrade_executor(&client)?;
+
+    let event_name: Name = "Announcement".parse()?;
+    for payload in [r#"{"text":"The white rabbit is late"}"#, r#"{"message":42}"#] {
+        let _err = client
+            .submit_blocking(EmitEvent::new(event_name.clone(), payload.parse::<Json>()?))
+            .unwrap_err();
+    }
+    client.submit_blocking(EmitEvent::from(announcement()))?;
+
+    Ok(())
+}
+
+#[test]
+fn custom_event_is_delivered_to_subscribers_and_triggers() -> Result<()> {
+    let (network, rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+    upgrade_executor(&client)?;
+
+    let event_name: Name = "Announcement".parse()?;
+    let rose_id = AssetId::new("rose#wonderland".parse()?, ALICE_ID.clone());
+    client.submit_blocking(Register::trigger(Trigger::new(
+        "mint_rose_on_announcement".parse()?,
+        Action::new(
+            [Mint::asset_numeric(1u32, rose_id.clone())],
+            Repeats::Indefinitely,
+            ALICE_ID.clone(),
+            CustomEventFilter::new().for_event(event_name.clone()),
+        ),
+    )))?;
+    let prev_value = rose_value(&client, &rose_id)?;
+
+    let events_client = client.clone();
+    let filter = CustomEventFilter::new()
+        .for_event(event_name)
+        .from_emitter(ALICE_ID.clone());
+    let task = rt.spawn(async move {
+        let mut stream = events_client
+            .listen_for_events_async([filter])
+            .await
+            .unwrap();
+        let Some(EventBox::Custom(event)) = stream.try_next().await.unwrap() else {
+            panic!("should receive custom event");
+        };
+        event
+    });
+
+    client.submit_blocking(EmitEvent::from(announcement()))?;
+    let event = rt.block_on(async {
+        tokio::time::timeout(Duration::from_secs(60), task)
+            .await
+            .unwrap()
+    })?;
+    assert_eq!(event.emitter(), &*ALICE_ID);
+    assert_eq!(Announcement::try_from(event.payload())?, announcement());
+
+    let new_value = rose_value(&client, &rose_id)?;
+    assert_eq!(new_value, prev_value.checked_add(numeric!(1)).unwrap());
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+mod custom;
 mod data;
 mod notification;
 mod pipeline;
@@ -0,0 +1,23 @@
+use eyre::Result;
+use iroha::data_model::prelude::*;
+use iroha_test_network::*;
+use iroha_test_samples::ALICE_ID;
+
+#[test]
+fn honest_network_records_no_equivocations() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().with_peers(4).start_blocking()?;
+    let client = network.client();
+
+    let rose_id = AssetId::new("rose#wonderland".parse()?, ALICE_ID.clone());
+    for _ in 0..3 {
+        client.submit_blocking(Mint::asset_numeric(1u32, rose_id.clone()))?;
+    }
+
+    assert!(client.query(FindEquivocations).execute_all()?.is_empty());
+    for block in client.query(FindBlocks).execute_all()? {
+        assert!(block.header().equivocations_hash().is_none());
+        assert_eq!(block.equivocations().count(), 0);
+    }
+
+    Ok(())
+}
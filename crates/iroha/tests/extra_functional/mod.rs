@@ -1,7 +1,9 @@
 mod connected_peers;
+mod equivocation;
 mod genesis;
 mod multiple_blocks_created;
 mod normal;
 mod offline_peers;
+mod replica;
 mod restart_peer;
 mod unregister_peer;
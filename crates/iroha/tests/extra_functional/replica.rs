@@ -0,0 +1,43 @@
+use eyre::Result;
+use iroha::data_model::prelude::*;
+use iroha_config_base::toml::WriteExt;
+use iroha_test_network::*;
+use iroha_test_samples::ALICE_ID;
+use tokio::{task::spawn_blocking, time::timeout};
+
+#[tokio::test]
+async fn replica_follows_validators_and_forwards_transactions() -> Result<()> {
+    let replica = NetworkPeer::generate();
+    let replica_id = replica.peer_id();
+    let network = NetworkBuilder::new()
+        .with_peers(4)
+        .with_config(|config| {
+            config.write(["network", "replicas"], [&replica_id]);
+        })
+        .start()
+        .await?;
+
+    replica
+        .start(
+            network
+                .config()
+                .write(["sumeragi", "replica"], true)
+                .write(["network", "block_gossip_period_ms"], 500),
+            None,
+        )
+        .await;
+    timeout(network.sync_timeout(), replica.once_block(1)).await?;
+
+    let rose_id = AssetId::new("rose#wonderland".parse()?, ALICE_ID.clone());
+    let client = replica.client();
+    spawn_blocking(move || client.submit_blocking(Mint::asset_numeric(1u32, rose_id))).await??;
+    network.ensure_blocks(2).await?;
+    timeout(network.sync_timeout(), replica.once_block(2)).await?;
+
+    let client = replica.client();
+    let peers = spawn_blocking(move || client.query(FindPeers).execute_all()).await??;
+    assert_eq!(peers.len(), 4);
+    assert!(!peers.contains(&replica_id));
+
+    Ok(())
+}
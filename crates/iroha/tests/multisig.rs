@@ -533,6 +533,163 @@ fn multisig_recursion_base(suite: TestSuite) -> Result<()> {
     Ok(())
 }
 
+/// # Scenario
+///
+/// 1. Three signatories of weight 1 share a multisig account with quorum 2
+/// 2. A proposal is discarded once rejections make the quorum unreachable
+/// 3. A proposal can be canceled only by its proposer
+/// 4. The multisig spec can be updated only through an approved proposal and only to a reachable quorum
+#[test]
+#[expect(clippy::too_many_lines)]
+fn multisig_reject_cancel_and_update_spec() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let domain: DomainId = "kingdom".parse().unwrap();
+    let register_and_transfer_kingdom: [InstructionBox; 2] = [
+        Register::domain(Domain::new(domain.clone())).into(),
+        Transfer::domain(ALICE_ID.clone(), domain.clone(), BOB_ID.clone()).into(),
+    ];
+    test_client.submit_all_blocking(register_and_transfer_kingdom)?;
+
+    let signatories = core::iter::repeat_with(|| gen_account_in(&domain))
+        .take(3)
+        .collect::<Vec<(AccountId, KeyPair)>>();
+    let domain_owner = alt_client((BOB_ID.clone(), BOB_KEYPAIR.clone()), &test_client);
+    domain_owner.submit_all_blocking(
+        signatories
+            .iter()
+            .map(|(id, _)| Register::account(Account::new(id.clone()))),
+    )?;
+
+    let multisig_account_id = gen_account_in(&domain).0;
+    let spec_with_quorum = |quorum| {
+        MultisigSpec::new(
+            signatories.iter().map(|(id, _)| (id.clone(), 1)).collect(),
+            NonZeroU16::new(quorum).unwrap(),
+            NonZeroU64::MAX,
+        )
+    };
+    let _err = domain_owner
+        .submit_blocking(MultisigRegister::new(
+            multisig_account_id.clone(),
+            spec_with_quorum(4),
+        ))
+        .expect_err("multisig account shouldn't be registered with an unreachable quorum");
+    domain_owner.submit_blocking(MultisigRegister::new(
+        multisig_account_id.clone(),
+        spec_with_quorum(2),
+    ))?;
+
+    let clients = signatories
+        .iter()
+        .cloned()
+        .map(|signatory| alt_client(signatory, &test_client))
+        .collect::<Vec<_>>();
+    let instructions = |marker: &str| -> Vec<InstructionBox> {
+        vec![SetKeyValue::account(
+            multisig_account_id.clone(),
+            "marker".parse().unwrap(),
+            marker.parse::<Json>().unwrap(),
+        )
+        .into()]
+    };
+
+    // Rejections by two out of three signatories leave the quorum unreachable
+    let rejected = instructions("\"rejected\"");
+    let rejected_hash = HashOf::new(&rejected);
+    clients[0].submit_blocking(MultisigPropose::new(
+        multisig_account_id.clone(),
+        rejected,
+        None,
+    ))?;
+    let reject = MultisigReject::new(multisig_account_id.clone(), rejected_hash);
+    clients[1].submit_blocking(reject.clone())?;
+    let proposals = test_client.find_multisig_proposals(&multisig_account_id)?;
+    assert!(proposals[&rejected_hash]
+        .rejections
+        .contains(&signatories[1].0));
+    clients[2].submit_blocking(reject)?;
+    assert!(test_client
+        .find_multisig_proposals(&multisig_account_id)?
+        .is_empty());
+
+    // Only the proposer can cancel the proposal
+    let canceled = instructions("\"canceled\"");
+    let canceled_hash = HashOf::new(&canceled);
+    clients[0].submit_blocking(MultisigPropose::new(
+        multisig_account_id.clone(),
+        canceled,
+        None,
+    ))?;
+    let cancel = MultisigCancel::new(multisig_account_id.clone(), canceled_hash);
+    let _err = clients[1]
+        .submit_blocking(cancel.clone())
+        .expect_err("proposal shouldn't be canceled by anyone other than the proposer");
+    clients[0].submit_blocking(cancel)?;
+    assert!(test_client
+        .find_multisig_proposals(&multisig_account_id)?
+        .is_empty());
+
+    // The spec cannot be updated to an unreachable quorum even through an approved proposal
+    let unreachable_spec: Vec<InstructionBox> =
+        vec![MultisigUpdateSpec::new(multisig_account_id.clone(), spec_with_quorum(4)).into()];
+    let unreachable_spec_hash = HashOf::new(&unreachable_spec);
+    clients[0].submit_blocking(MultisigPropose::new(
+        multisig_account_id.clone(),
+        unreachable_spec,
+        None,
+    ))?;
+    let _err = clients[1]
+        .submit_blocking(MultisigApprove::new(
+            multisig_account_id.clone(),
+            unreachable_spec_hash,
+        ))
+        .expect_err("spec shouldn't be updated to an unreachable quorum");
+
+    // The spec cannot be updated bypassing the multisig approval
+    let new_spec = MultisigSpec::new(
+        signatories
+            .iter()
+            .take(2)
+            .map(|(id, _)| (id.clone(), 1))
+            .collect(),
+        NonZeroU16::new(1).unwrap(),
+        NonZeroU64::MAX,
+    );
+    let update_spec = MultisigUpdateSpec::new(multisig_account_id.clone(), new_spec);
+    let _err = clients[0]
+        .submit_blocking(update_spec.clone())
+        .expect_err("spec shouldn't be updated without multisig approval");
+
+    let update_spec: Vec<InstructionBox> = vec![update_spec.into()];
+    let update_spec_hash = HashOf::new(&update_spec);
+    clients[0].submit_blocking(MultisigPropose::new(
+        multisig_account_id.clone(),
+        update_spec,
+        None,
+    ))?;
+    clients[1].submit_blocking(MultisigApprove::new(
+        multisig_account_id.clone(),
+        update_spec_hash,
+    ))?;
+
+    let spec = test_client.find_multisig_spec(&multisig_account_id)?;
+    assert_eq!(spec.signatories.len(), 2);
+    assert_eq!(u16::from(spec.quorum), 1);
+
+    // The removed signatory is no longer qualified to propose
+    let _err = clients[2]
+        .submit_blocking(MultisigPropose::new(
+            multisig_account_id.clone(),
+            instructions("\"removed\""),
+            None,
+        ))
+        .expect_err("removed signatory shouldn't propose");
+
+    Ok(())
+}
+
 #[test]
 fn reserved_roles() {
     let (network, _rt) = NetworkBuilder::new().start_blocking().unwrap();
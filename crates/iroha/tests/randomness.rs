@@ -0,0 +1,45 @@
+use eyre::Result;
+use iroha::{crypto::Hash, data_model::prelude::*};
+use iroha_test_network::*;
+use iroha_test_samples::ALICE_ID;
+
+#[test]
+fn every_block_has_verifiable_randomness() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+
+    let rose_id = AssetId::new("rose#wonderland".parse()?, ALICE_ID.clone());
+    for _ in 0..2 {
+        client.submit_blocking(Mint::asset_numeric(1u32, rose_id.clone()))?;
+    }
+
+    // Blocks are sorted by height in descending order
+    let blocks = client.query(FindBlocks).execute_all()?;
+    let latest_block = blocks.first().expect("blocks are committed");
+    assert_eq!(
+        Some(client.query_single(FindRandomness)?),
+        latest_block.header().randomness()
+    );
+
+    let leader = network.peer().peer_id();
+    for (block, prev_block) in blocks.iter().zip(blocks.iter().skip(1)) {
+        let proof = block
+            .randomness_proof()
+            .expect("leader with a BLS key proves the randomness");
+        proof.verify(leader.public_key(), &prev_block.header().randomness_seed())?;
+        assert_eq!(
+            block.header().randomness(),
+            Some(Hash::new(proof.payload()))
+        );
+        assert_ne!(
+            block.header().randomness(),
+            prev_block.header().randomness()
+        );
+    }
+
+    let genesis = blocks.last().expect("genesis is committed");
+    assert!(genesis.header().randomness().is_none());
+    assert!(genesis.randomness_proof().is_none());
+
+    Ok(())
+}
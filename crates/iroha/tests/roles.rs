@@ -1,7 +1,15 @@
+use std::collections::BTreeSet;
+
 use executor_custom_data_model::permissions::CanControlDomainLives;
 use eyre::Result;
-use iroha::data_model::{prelude::*, transaction::error::TransactionRejectionReason};
-use iroha_executor_data_model::permission::account::CanModifyAccountMetadata;
+use iroha::{
+    client::Client,
+    data_model::{prelude::*, transaction::error::TransactionRejectionReason},
+};
+use iroha_executor_data_model::{
+    isi::role::{RoleRegisterScoped, RoleSetAdmins, RoleSetParents},
+    permission::account::CanModifyAccountMetadata,
+};
 use iroha_test_network::*;
 use iroha_test_samples::{gen_account_in, ALICE_ID};
 use serde_json::json;
@@ -276,3 +284,142 @@ fn grant_unexisting_role_in_genesis_fail() {
         .with_genesis_instruction(grant_genesis_role)
         .start_blocking();
 }
+
+#[test]
+fn inherited_role_permissions_are_effective() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let (mouse_id, mouse_keypair) = gen_account_in("wonderland");
+    test_client.submit_blocking(Register::account(Account::new(mouse_id.clone())))?;
+    let mouse_client = Client {
+        account: mouse_id.clone(),
+        key_pair: mouse_keypair,
+        ..test_client.clone()
+    };
+
+    // Only the parent role holds the permission
+    let can_modify_alice_metadata = CanModifyAccountMetadata {
+        account: ALICE_ID.clone(),
+    };
+    let editor_id: RoleId = "EDITOR".parse()?;
+    let member_id: RoleId = "MEMBER".parse()?;
+    test_client.submit_all_blocking::<InstructionBox>([
+        Register::role(
+            Role::new(editor_id.clone(), ALICE_ID.clone())
+                .add_permission(can_modify_alice_metadata.clone()),
+        )
+        .into(),
+        Register::role(Role::new(member_id.clone(), ALICE_ID.clone())).into(),
+        RoleSetParents::new(member_id.clone(), BTreeSet::from([editor_id.clone()])).into(),
+        Grant::account_role(member_id.clone(), mouse_id.clone()).into(),
+    ])?;
+
+    let set_key_value =
+        SetKeyValue::account(ALICE_ID.clone(), "key".parse()?, "value".parse::<Json>()?);
+    mouse_client.submit_blocking(set_key_value.clone())?;
+
+    // The permission comes from the granted role through the inherited one
+    let effective = test_client.find_effective_permissions(&mouse_id)?;
+    assert_eq!(effective.len(), 1);
+    assert_eq!(effective[0].via, vec![member_id.clone(), editor_id.clone()]);
+    assert!(CanModifyAccountMetadata::try_from(&effective[0].permission)
+        .is_ok_and(|permission| permission == can_modify_alice_metadata));
+
+    // Inheritance cycles are rejected
+    let _err = test_client
+        .submit_blocking(RoleSetParents::new(
+            editor_id.clone(),
+            BTreeSet::from([member_id.clone()]),
+        ))
+        .unwrap_err();
+
+    // The permission is lost along with the inheritance
+    test_client.submit_blocking(RoleSetParents::new(member_id, BTreeSet::new()))?;
+    let _err = mouse_client.submit_blocking(set_key_value).unwrap_err();
+    assert!(test_client
+        .find_effective_permissions(&mouse_id)?
+        .is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn domain_owner_manages_scoped_roles() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    // Mouse owns the garden without the permission to manage roles
+    let domain_id: DomainId = "garden".parse()?;
+    let (mouse_id, mouse_keypair) = gen_account_in("wonderland");
+    let (rabbit_id, rabbit_keypair) = gen_account_in("garden");
+    test_client.submit_all_blocking::<InstructionBox>([
+        Register::domain(Domain::new(domain_id.clone())).into(),
+        Register::account(Account::new(mouse_id.clone())).into(),
+        Register::account(Account::new(rabbit_id.clone())).into(),
+        Transfer::domain(ALICE_ID.clone(), domain_id.clone(), mouse_id.clone()).into(),
+    ])?;
+    let mouse_client = Client {
+        account: mouse_id.clone(),
+        key_pair: mouse_keypair,
+        ..test_client.clone()
+    };
+    let rabbit_client = Client {
+        account: rabbit_id.clone(),
+        key_pair: rabbit_keypair,
+        ..test_client.clone()
+    };
+
+    let gardener_id: RoleId = "GARDENER".parse()?;
+    let _err = mouse_client
+        .submit_blocking(Register::role(Role::new(
+            gardener_id.clone(),
+            mouse_id.clone(),
+        )))
+        .unwrap_err();
+
+    // Scoped roles only accept permissions inside the domain
+    let outside_of_scope =
+        Role::new(gardener_id.clone(), mouse_id.clone()).add_permission(CanModifyAccountMetadata {
+            account: mouse_id.clone(),
+        });
+    let _err = mouse_client
+        .submit_blocking(RoleRegisterScoped::new(outside_of_scope, domain_id.clone()))
+        .unwrap_err();
+
+    let gardener =
+        Role::new(gardener_id.clone(), mouse_id.clone()).add_permission(CanModifyAccountMetadata {
+            account: rabbit_id.clone(),
+        });
+    let head_gardener_id: RoleId = "HEAD_GARDENER".parse()?;
+    let head_gardener = Role::new(head_gardener_id.clone(), rabbit_id.clone());
+    mouse_client.submit_all_blocking::<InstructionBox>([
+        RoleRegisterScoped::new(gardener, domain_id.clone()).into(),
+        RoleRegisterScoped::new(head_gardener, domain_id.clone()).into(),
+        RoleSetAdmins::new(
+            gardener_id.clone(),
+            BTreeSet::from([head_gardener_id.clone()]),
+        )
+        .into(),
+    ])?;
+
+    // Administration of the role is delegated to the holders of the admin role
+    rabbit_client.submit_blocking(Grant::account_role(gardener_id.clone(), ALICE_ID.clone()))?;
+    test_client.submit_blocking(SetKeyValue::account(
+        rabbit_id.clone(),
+        "key".parse()?,
+        "value".parse::<Json>()?,
+    ))?;
+    let _err = test_client
+        .submit_blocking(Grant::account_role(gardener_id.clone(), mouse_id.clone()))
+        .unwrap_err();
+
+    // Scoped roles go away along with the domain
+    mouse_client.submit_blocking(Unregister::domain(domain_id))?;
+    let role_ids = test_client.query(FindRoleIds).execute_all()?;
+    assert!(!role_ids.contains(&gardener_id));
+    assert!(!role_ids.contains(&head_gardener_id));
+    assert!(test_client.find_role_hierarchy()?.roles.is_empty());
+
+    Ok(())
+}
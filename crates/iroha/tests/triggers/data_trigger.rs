@@ -1,7 +1,13 @@
 use eyre::Result;
-use iroha::{client, data_model::prelude::*};
+use iroha::{
+    client,
+    data_model::{block::BlockHeader, parameter::TriggerParameter, prelude::*},
+};
 use iroha_test_network::*;
 use iroha_test_samples::{gen_account_in, ALICE_ID};
+use nonzero_ext::nonzero;
+
+use super::get_asset_value;
 
 #[test]
 fn must_execute_both_triggers() -> Result<()> {
@@ -60,3 +66,141 @@ fn must_execute_both_triggers() -> Result<()> {
 
     Ok(())
 }
+
+fn register_chained_triggers(client: &client::Client, asset_id: &AssetId) -> Result<()> {
+    let register_account = Register::trigger(Trigger::new(
+        "register_account_on_domain".parse()?,
+        Action::new(
+            [Register::account(Account::new(
+                gen_account_in("wonderland").0,
+            ))],
+            Repeats::Exactly(1),
+            ALICE_ID.clone(),
+            DomainEventFilter::new().for_events(DomainEventSet::Created),
+        ),
+    ));
+    let mint_rose = Register::trigger(Trigger::new(
+        "mint_rose_on_account".parse()?,
+        Action::new(
+            [Mint::asset_numeric(1u32, asset_id.clone())],
+            Repeats::Indefinitely,
+            ALICE_ID.clone(),
+            AccountEventFilter::new().for_events(AccountEventSet::Created),
+        ),
+    ));
+    client.submit_all_blocking::<InstructionBox>([register_account.into(), mint_rose.into()])?;
+
+    Ok(())
+}
+
+fn trigger_outcomes(client: &client::Client, id: &str) -> Result<Vec<TriggerCompletedOutcome>> {
+    let trigger_id: TriggerId = id.parse()?;
+    Ok(client
+        .query(FindTriggerReceipts)
+        .filter_with(|receipt| receipt.value.trigger_id.eq(trigger_id))
+        .execute_all()?
+        .into_iter()
+        .map(|receipt| receipt.value().outcome().clone())
+        .collect())
+}
+
+#[test]
+fn chained_triggers_are_executed_in_the_same_block() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let asset_id = AssetId::new("rose#wonderland".parse()?, ALICE_ID.clone());
+    let prev_value = get_asset_value(&test_client, asset_id.clone());
+
+    register_chained_triggers(&test_client, &asset_id)?;
+    test_client.submit_blocking(Register::domain(Domain::new("neverland".parse()?)))?;
+
+    let new_value = get_asset_value(&test_client, asset_id);
+    assert_eq!(new_value, prev_value.checked_add(numeric!(1)).unwrap());
+
+    let block_hash_of = |id: &str| -> Result<HashOf<BlockHeader>> {
+        let trigger_id: TriggerId = id.parse()?;
+        let receipt = test_client
+            .query(FindTriggerReceipts)
+            .filter_with(|receipt| receipt.value.trigger_id.eq(trigger_id))
+            .execute_single()?;
+        Ok(*receipt.block_hash())
+    };
+    assert_eq!(
+        block_hash_of("register_account_on_domain")?,
+        block_hash_of("mint_rose_on_account")?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn trigger_chain_depth_is_limited() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new()
+        .with_genesis_instruction(SetParameter::new(Parameter::Trigger(
+            TriggerParameter::MaxDepth(nonzero!(1_u64)),
+        )))
+        .start_blocking()?;
+    let test_client = network.client();
+
+    let asset_id = AssetId::new("rose#wonderland".parse()?, ALICE_ID.clone());
+    let prev_value = get_asset_value(&test_client, asset_id.clone());
+
+    register_chained_triggers(&test_client, &asset_id)?;
+    test_client.submit_blocking(Register::domain(Domain::new("neverland".parse()?)))?;
+
+    assert_eq!(get_asset_value(&test_client, asset_id), prev_value);
+    assert_eq!(
+        trigger_outcomes(&test_client, "register_account_on_domain")?,
+        [TriggerCompletedOutcome::Success]
+    );
+    let outcomes = trigger_outcomes(&test_client, "mint_rose_on_account")?;
+    let [TriggerCompletedOutcome::Failure(error)] = outcomes.as_slice() else {
+        panic!("chained trigger must fail");
+    };
+    assert!(error.contains("depth"));
+
+    Ok(())
+}
+
+#[test]
+fn trigger_cycle_is_deferred_to_next_block() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let asset_id = AssetId::new("rose#wonderland".parse()?, ALICE_ID.clone());
+    let trigger_id: TriggerId = "mint_rose_on_rose_added".parse()?;
+
+    let register_trigger = Register::trigger(Trigger::new(
+        trigger_id.clone(),
+        Action::new(
+            [Mint::asset_numeric(1u32, asset_id.clone())],
+            Repeats::Indefinitely,
+            ALICE_ID.clone(),
+            AssetEventFilter::new()
+                .for_asset(asset_id.clone())
+                .for_events(AssetEventSet::Added),
+        ),
+    ));
+    test_client.submit_blocking(register_trigger)?;
+    test_client.submit_blocking(Mint::asset_numeric(1u32, asset_id))?;
+    // Waiting for empty block to be committed
+    std::thread::sleep(network.pipeline_time());
+
+    let receipts = test_client
+        .query(FindTriggerReceipts)
+        .filter_with(|receipt| receipt.value.trigger_id.eq(trigger_id))
+        .execute_all()?;
+    assert!(!receipts.is_empty());
+    assert!(receipts
+        .iter()
+        .all(|receipt| *receipt.value().outcome() == TriggerCompletedOutcome::Success));
+    // Trigger is executed at most once per block
+    let blocks = receipts
+        .iter()
+        .map(CommittedTriggerReceipt::block_hash)
+        .collect::<std::collections::BTreeSet<_>>();
+    assert_eq!(blocks.len(), receipts.len());
+
+    Ok(())
+}
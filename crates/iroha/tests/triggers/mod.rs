@@ -10,9 +10,12 @@ mod by_call_trigger;
 mod data_trigger;
 mod event_trigger;
 mod orphans;
+mod receipts;
+mod storage;
 // FIXME: rewrite all in async and with shorter timings
 mod time_trigger;
 mod trigger_rollback;
+mod upgrade;
 
 fn get_asset_value(client: &Client, asset_id: AssetId) -> Numeric {
     let asset = client
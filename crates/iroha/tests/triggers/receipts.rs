@@ -0,0 +1,96 @@
+use eyre::Result;
+use iroha::data_model::prelude::*;
+use iroha_test_network::*;
+use iroha_test_samples::{gen_account_in, ALICE_ID};
+
+#[test]
+fn trigger_receipts_are_recorded_and_committed_by_next_block() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let asset_definition_id = "rose#wonderland".parse()?;
+    let asset_id = AssetId::new(asset_definition_id, ALICE_ID.clone());
+    let trigger_id: TriggerId = "mint_rose_on_account".parse()?;
+
+    let register_trigger = Register::trigger(Trigger::new(
+        trigger_id.clone(),
+        Action::new(
+            [Mint::asset_numeric(1u32, asset_id)],
+            Repeats::Indefinitely,
+            ALICE_ID.clone(),
+            AccountEventFilter::new().for_events(AccountEventSet::Created),
+        ),
+    ));
+    test_client.submit_blocking(register_trigger)?;
+
+    test_client.submit_blocking(Register::account(Account::new(
+        gen_account_in("wonderland").0,
+    )))?;
+
+    let receipt = test_client
+        .query(FindTriggerReceipts)
+        .filter_with(|receipt| receipt.value.trigger_id.eq(trigger_id.clone()))
+        .execute_single()?;
+    assert_eq!(*receipt.value().outcome(), TriggerCompletedOutcome::Success);
+    assert_eq!(*receipt.value().fuel(), 0);
+    assert!(matches!(
+        receipt.value().events().as_slice(),
+        [DataEvent::Domain(DomainEvent::Account(
+            AccountEvent::Asset(AssetEvent::Added(_))
+        ))]
+    ));
+
+    // Commit one more block to cover the receipts
+    test_client.submit_blocking(Register::domain(Domain::new("neverland".parse()?)))?;
+
+    let blocks = test_client.query(FindBlocks).execute_all()?;
+    let position = blocks
+        .iter()
+        .position(|block| block.hash() == *receipt.block_hash())
+        .expect("block with trigger receipts must be committed");
+    let receipts_block = &blocks[position];
+    let next_block = &blocks[position - 1];
+    assert_eq!(receipts_block.trigger_receipts().len(), 1);
+    assert_eq!(
+        next_block.header().prev_trigger_receipts_hash(),
+        receipts_block.trigger_receipts_hash()
+    );
+    assert!(receipts_block.trigger_receipts_hash().is_some());
+
+    Ok(())
+}
+
+#[test]
+fn failed_trigger_receipt_is_recorded() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let trigger_id: TriggerId = "burn_missing_rose".parse()?;
+    let missing_asset_id = AssetId::new("rose#wonderland".parse()?, gen_account_in("wonderland").0);
+
+    let register_trigger = Register::trigger(Trigger::new(
+        trigger_id.clone(),
+        Action::new(
+            [Burn::asset_numeric(1u32, missing_asset_id)],
+            Repeats::Indefinitely,
+            ALICE_ID.clone(),
+            DomainEventFilter::new().for_events(DomainEventSet::Created),
+        ),
+    ));
+    test_client.submit_blocking(register_trigger)?;
+
+    test_client.submit_blocking(Register::domain(Domain::new("neverland".parse()?)))?;
+
+    let receipt = test_client
+        .query(FindTriggerReceipts)
+        .filter_with(|receipt| receipt.value.is_failure())
+        .execute_single()?;
+    assert_eq!(receipt.value().trigger_id(), &trigger_id);
+    assert!(matches!(
+        receipt.value().outcome(),
+        TriggerCompletedOutcome::Failure(_)
+    ));
+    assert!(receipt.value().events().is_empty());
+
+    Ok(())
+}
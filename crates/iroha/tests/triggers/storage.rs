@@ -0,0 +1,43 @@
+use eyre::Result;
+use iroha::data_model::prelude::*;
+use iroha_test_network::*;
+use iroha_test_samples::{load_sample_wasm, ALICE_ID};
+use parity_scale_codec::Encode as _;
+
+#[test]
+fn trigger_keeps_state_in_storage_until_unregistered() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let trigger_id: TriggerId = "count_calls".parse()?;
+    let register_trigger = Register::trigger(Trigger::new(
+        trigger_id.clone(),
+        Action::new(
+            load_sample_wasm("count_calls_trigger"),
+            Repeats::Indefinitely,
+            ALICE_ID.clone(),
+            ExecuteTriggerEventFilter::new()
+                .for_trigger(trigger_id.clone())
+                .under_authority(ALICE_ID.clone()),
+        ),
+    ));
+    test_client.submit_blocking(register_trigger)?;
+
+    test_client.submit_blocking(ExecuteTrigger::new(trigger_id.clone()))?;
+    test_client.submit_blocking(ExecuteTrigger::new(trigger_id.clone()))?;
+
+    let owner = StorageOwner::Trigger(trigger_id.clone());
+    let entry = test_client
+        .query(FindStorageEntries::new(owner.clone()))
+        .filter_with(|entry| entry.key_starts_with(b"calls".to_vec()))
+        .execute_single()?;
+    assert_eq!(entry, StorageEntry::new(b"calls".to_vec(), 2_u32.encode()));
+
+    test_client.submit_blocking(Unregister::trigger(trigger_id))?;
+    let entries = test_client
+        .query(FindStorageEntries::new(owner))
+        .execute_all()?;
+    assert!(entries.is_empty());
+
+    Ok(())
+}
@@ -91,6 +91,49 @@ fn mint_asset_after_3_sec() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn periodic_trigger_stops_after_max_occurrences() -> Result<()> {
+    const PERIOD: Duration = Duration::from_millis(100);
+    const MAX_OCCURRENCES: u32 = 3;
+
+    let (network, _rt) = NetworkBuilder::new()
+        .with_default_pipeline_time()
+        .start_blocking()?;
+    let test_client = network.client();
+
+    let asset_definition_id = "rose#wonderland".parse::<AssetDefinitionId>()?;
+    let account_id = ALICE_ID.clone();
+    let asset_id = AssetId::new(asset_definition_id, account_id.clone());
+    let init_quantity = get_asset_value(&test_client, asset_id.clone());
+
+    let schedule = TimeSchedule::starting_at(curr_time())
+        .with_period(PERIOD)
+        .with_max_occurrences(MAX_OCCURRENCES.try_into()?);
+    let register_trigger = Register::trigger(Trigger::new(
+        "mint_rose_3_times".parse()?,
+        Action::new(
+            vec![Mint::asset_numeric(1_u32, asset_id.clone())],
+            Repeats::Indefinitely,
+            account_id,
+            TimeEventFilter::new(ExecutionTime::Schedule(schedule)),
+        ),
+    ));
+    test_client.submit_blocking(register_trigger)?;
+
+    // Create blocks long after all the occurrences have passed
+    for _ in 0..2 {
+        std::thread::sleep(network.pipeline_time());
+        test_client.submit_blocking(Log::new(Level::DEBUG, "Just to create block".to_string()))?;
+    }
+
+    assert_eq!(
+        get_asset_value(&test_client, asset_id),
+        init_quantity.checked_add(MAX_OCCURRENCES.into()).unwrap()
+    );
+
+    Ok(())
+}
+
 #[test]
 fn pre_commit_trigger_should_be_executed() -> Result<()> {
     const CHECKS_COUNT: usize = 5;
@@ -0,0 +1,127 @@
+use eyre::Result;
+use futures_util::TryStreamExt as _;
+use iroha::{
+    client::Client,
+    data_model::{prelude::*, query::trigger::FindTriggers, transaction::Executable},
+};
+use iroha_executor_data_model::permission::trigger::CanModifyTrigger;
+use iroha_test_network::*;
+use iroha_test_samples::{ALICE_ID, BOB_ID, BOB_KEYPAIR};
+
+use crate::triggers::get_asset_value;
+
+fn register_mint_rose_trigger(client: &Client, trigger_id: &TriggerId) -> Result<AssetId> {
+    let rose_id = AssetId::new("rose#wonderland".parse()?, ALICE_ID.clone());
+    let mut metadata = Metadata::default();
+    metadata.insert("version".parse()?, 1_u32);
+
+    client.submit_blocking(Register::trigger(Trigger::new(
+        trigger_id.clone(),
+        Action::new(
+            vec![Mint::asset_numeric(1u32, rose_id.clone())],
+            Repeats::Exactly(5),
+            ALICE_ID.clone(),
+            ExecuteTriggerEventFilter::new().for_trigger(trigger_id.clone()),
+        )
+        .with_metadata(metadata),
+    )))?;
+
+    Ok(rose_id)
+}
+
+#[test]
+fn upgraded_trigger_keeps_its_state() -> Result<()> {
+    let (network, rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let trigger_id: TriggerId = "mint_rose".parse()?;
+    let rose_id = register_mint_rose_trigger(&test_client, &trigger_id)?;
+    test_client.submit_blocking(ExecuteTrigger::new(trigger_id.clone()))?;
+
+    let events_client = test_client.clone();
+    let filter = TriggerEventFilter::new()
+        .for_trigger(trigger_id.clone())
+        .for_events(TriggerEventSet::Upgraded);
+    let task = rt.spawn(async move {
+        let mut stream = events_client
+            .listen_for_events_async([filter])
+            .await
+            .unwrap();
+        let Some(EventBox::Data(DataEvent::Trigger(TriggerEvent::Upgraded(upgraded)))) =
+            stream.try_next().await.unwrap()
+        else {
+            panic!("Expected trigger upgrade event");
+        };
+        assert_eq!(upgraded.prev_module_hash(), &None);
+        assert_eq!(upgraded.new_module_hash(), &None);
+    });
+
+    let new_instructions: Vec<InstructionBox> =
+        vec![Mint::asset_numeric(2u32, rose_id.clone()).into()];
+    test_client.submit_blocking(UpgradeTrigger::new(
+        trigger_id.clone(),
+        new_instructions.clone().into(),
+    ))?;
+    rt.block_on(async {
+        tokio::time::timeout(core::time::Duration::from_secs(60), task)
+            .await
+            .unwrap()
+    })
+    .expect("should receive trigger upgrade event");
+
+    let prev_value = get_asset_value(&test_client, rose_id.clone());
+    test_client.submit_blocking(ExecuteTrigger::new(trigger_id.clone()))?;
+    let new_value = get_asset_value(&test_client, rose_id);
+    assert_eq!(
+        new_value,
+        prev_value.checked_add(Numeric::new(2, 0)).unwrap()
+    );
+
+    let trigger = test_client
+        .query(FindTriggers::new())
+        .filter_with(|trigger| trigger.id.eq(trigger_id))
+        .execute_single()?;
+    let Executable::Instructions(instructions) = trigger.action().executable() else {
+        panic!("Expected instructions");
+    };
+    assert_eq!(instructions.to_vec(), new_instructions);
+    assert_eq!(trigger.action().repeats(), Repeats::Exactly(3));
+    assert_eq!(
+        trigger.action().metadata().get("version"),
+        Some(&Json::new(1_u32))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn only_permitted_account_can_upgrade_trigger() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+    let bob_client = Client {
+        account: BOB_ID.clone(),
+        key_pair: BOB_KEYPAIR.clone(),
+        ..test_client.clone()
+    };
+
+    let trigger_id: TriggerId = "mint_rose".parse()?;
+    let rose_id = register_mint_rose_trigger(&test_client, &trigger_id)?;
+    let upgrade = UpgradeTrigger::new(
+        trigger_id.clone(),
+        vec![Mint::asset_numeric(2u32, rose_id)].into(),
+    );
+
+    let _err = bob_client
+        .submit_blocking(upgrade.clone())
+        .expect_err("Bob doesn't own the trigger");
+
+    test_client.submit_blocking(Grant::account_permission(
+        CanModifyTrigger {
+            trigger: trigger_id,
+        },
+        BOB_ID.clone(),
+    ))?;
+    bob_client.submit_blocking(upgrade)?;
+
+    Ok(())
+}
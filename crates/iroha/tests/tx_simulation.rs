@@ -0,0 +1,113 @@
+use eyre::Result;
+use iroha::{
+    client::Client,
+    data_model::{prelude::*, Level},
+};
+use iroha_test_network::*;
+use iroha_test_samples::{gen_account_in, ALICE_ID};
+
+fn rose_id() -> AssetId {
+    AssetId::new("rose#wonderland".parse().unwrap(), ALICE_ID.clone())
+}
+
+fn rose_value(client: &Client) -> Result<Numeric> {
+    Ok(*client
+        .query(FindAssets::new())
+        .filter_with(|asset| asset.id.eq(rose_id()))
+        .execute_single()?
+        .value())
+}
+
+#[test]
+fn simulated_transaction_is_not_committed() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new()
+        .with_config(|config| {
+            config.write(["torii", "simulation_enabled"], true);
+        })
+        .start_blocking()?;
+    let client = network.client();
+
+    let trigger_id: TriggerId = "log_rose_mint".parse()?;
+    client.submit_blocking(Register::trigger(Trigger::new(
+        trigger_id.clone(),
+        Action::new(
+            [Log::new(Level::INFO, "Rose is minted".to_owned())],
+            Repeats::Indefinitely,
+            ALICE_ID.clone(),
+            AssetEventFilter::new()
+                .for_asset(rose_id())
+                .for_events(AssetEventSet::Added),
+        ),
+    )))?;
+    let prev_value = rose_value(&client)?;
+
+    let simulation = client.simulate_all([Mint::asset_numeric(1u32, rose_id())])?;
+    assert!(simulation.is_accepted(), "{simulation:?}");
+    assert!(simulation.events().iter().any(|event| matches!(
+        event,
+        DataEvent::Domain(DomainEvent::Account(AccountEvent::Asset(
+            AssetEvent::Added(_)
+        )))
+    )));
+    assert_eq!(simulation.triggers(), &[trigger_id]);
+    // Transactions are validated by the WASM executor
+    assert!(simulation.fuel_consumed() > 0);
+    assert!(simulation.memory_consumed() > 0);
+
+    let signed =
+        client.build_transaction([Mint::asset_numeric(1u32, rose_id())], Metadata::default());
+    assert!(client
+        .simulate_transaction(&TransactionSimulationRequest::Signed(signed))?
+        .is_accepted());
+
+    assert_eq!(rose_value(&client)?, prev_value);
+
+    Ok(())
+}
+
+#[test]
+fn simulated_transaction_reports_rejection_reason() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new()
+        .with_config(|config| {
+            config.write(["torii", "simulation_enabled"], true);
+        })
+        .start_blocking()?;
+    let client = network.client();
+
+    let missing_asset = AssetId::new("ksor#wonderland".parse()?, ALICE_ID.clone());
+    let simulation = client.simulate_all([Mint::asset_numeric(1u32, missing_asset)])?;
+    assert!(simulation.events().is_empty());
+    assert!(matches!(
+        simulation.rejection_reason(),
+        Some(TransactionRejectionReason::Validation(_))
+    ));
+
+    let (bob_id, bob_key) = gen_account_in("wonderland");
+    client.submit_blocking(Register::account(Account::new(bob_id.clone())))?;
+
+    // Only keys of the authority can simulate transactions on its behalf
+    let forged = TransactionBuilder::new(client.chain.clone(), ALICE_ID.clone())
+        .with_instructions([Mint::asset_numeric(1u32, rose_id())])
+        .sign(bob_key.private_key());
+    let simulation =
+        client.simulate_transaction(&TransactionSimulationRequest::PartiallySigned(forged))?;
+    assert!(matches!(
+        simulation.rejection_reason(),
+        Some(TransactionRejectionReason::InsufficientSignatures)
+    ));
+
+    let bob_client = Client {
+        account: bob_id,
+        key_pair: bob_key,
+        ..client.clone()
+    };
+    let simulation = bob_client.simulate_all([Mint::asset_numeric(1u32, rose_id())])?;
+    assert!(matches!(
+        simulation.rejection_reason(),
+        Some(TransactionRejectionReason::Validation(
+            ValidationFail::NotPermitted(_)
+        ))
+    ));
+
+    Ok(())
+}
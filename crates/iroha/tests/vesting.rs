@@ -0,0 +1,163 @@
+use std::{num::NonZeroU64, time::SystemTime};
+
+use eyre::Result;
+use iroha::{client::Client, data_model::prelude::*, executor_data_model::isi::vesting::*};
+use iroha_test_network::*;
+use iroha_test_samples::{ALICE_ID, BOB_ID, BOB_KEYPAIR};
+
+const DAY_MS: u64 = 24 * 60 * 60 * 1_000;
+
+/// Schedule that has released exactly a half by now, in two of four steps
+fn half_released_schedule() -> VestingSchedule {
+    let now_ms: u64 = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_millis()
+        .try_into()
+        .unwrap();
+
+    VestingSchedule::new(
+        now_ms - 10 * DAY_MS,
+        DAY_MS,
+        NonZeroU64::new(20 * DAY_MS).unwrap(),
+        NonZeroU64::new(5 * DAY_MS),
+    )
+}
+
+fn setup(test_client: &Client) -> Result<(AssetId, AssetId)> {
+    let definition: AssetDefinitionId = "vest#wonderland".parse()?;
+    let grantor_asset = AssetId::new(definition.clone(), ALICE_ID.clone());
+    let beneficiary_asset = AssetId::new(definition.clone(), BOB_ID.clone());
+    test_client.submit_all_blocking::<InstructionBox>([
+        Register::asset_definition(AssetDefinition::numeric(definition)).into(),
+        Mint::asset_numeric(100_u32, grantor_asset.clone()).into(),
+    ])?;
+
+    Ok((grantor_asset, beneficiary_asset))
+}
+
+/// Balance of the asset, which is removed once it reaches zero
+fn balance(asset: &AssetId, client: &Client) -> Result<Numeric> {
+    let balance = client
+        .query(FindAssets)
+        .filter_with(|entry| entry.id.eq(asset.clone()))
+        .select_with(|entry| entry.value)
+        .execute_single_opt()?;
+
+    Ok(balance.unwrap_or(Numeric::ZERO))
+}
+
+#[test]
+fn vesting_claim_and_revoke() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+    let bob_client = Client {
+        account: BOB_ID.clone(),
+        key_pair: BOB_KEYPAIR.clone(),
+        ..test_client.clone()
+    };
+    let (grantor_asset, beneficiary_asset) = setup(&test_client)?;
+
+    test_client.submit_blocking(VestingLock::new(
+        beneficiary_asset.clone(),
+        numeric!(100),
+        half_released_schedule(),
+        Some(ALICE_ID.clone()),
+    ))?;
+    assert_eq!(balance(&grantor_asset, &test_client)?, numeric!(0));
+    assert_eq!(balance(&beneficiary_asset, &test_client)?, numeric!(100));
+    assert_eq!(
+        test_client.find_locked_amount(&beneficiary_asset)?,
+        numeric!(100)
+    );
+    assert_eq!(
+        test_client.find_claimable_amount(&beneficiary_asset)?,
+        numeric!(50)
+    );
+
+    // Locked amounts are not spendable
+    let transfer = |quantity: u32| {
+        Transfer::asset_numeric(beneficiary_asset.clone(), quantity, ALICE_ID.clone())
+    };
+    let _err = bob_client.submit_blocking(transfer(1)).unwrap_err();
+    let _err = bob_client
+        .submit_blocking(Burn::asset_numeric(1_u32, beneficiary_asset.clone()))
+        .unwrap_err();
+
+    // Only the beneficiary can claim
+    let _err = test_client
+        .submit_blocking(VestingClaim::new(beneficiary_asset.clone()))
+        .unwrap_err();
+    bob_client.submit_blocking(VestingClaim::new(beneficiary_asset.clone()))?;
+    assert_eq!(
+        test_client.find_locked_amount(&beneficiary_asset)?,
+        numeric!(50)
+    );
+    assert_eq!(
+        test_client.find_claimable_amount(&beneficiary_asset)?,
+        numeric!(0)
+    );
+
+    // Only the claimed portion is spendable
+    let _err = bob_client.submit_blocking(transfer(51)).unwrap_err();
+    bob_client.submit_blocking(transfer(10))?;
+
+    // Only the revoker can revoke the unvested portion, which returns to the grantor
+    let _err = bob_client
+        .submit_blocking(VestingRevoke::new(beneficiary_asset.clone()))
+        .unwrap_err();
+    test_client.submit_blocking(VestingRevoke::new(beneficiary_asset.clone()))?;
+    assert_eq!(balance(&grantor_asset, &test_client)?, numeric!(60));
+    assert_eq!(balance(&beneficiary_asset, &test_client)?, numeric!(40));
+    assert_eq!(
+        test_client.find_locked_amount(&beneficiary_asset)?,
+        numeric!(0)
+    );
+    assert!(test_client.find_vesting_locks(BOB_ID.clone())?.is_empty());
+
+    bob_client.submit_blocking(transfer(40))?;
+
+    Ok(())
+}
+
+#[test]
+fn vesting_lock_is_protected() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+    let bob_client = Client {
+        account: BOB_ID.clone(),
+        key_pair: BOB_KEYPAIR.clone(),
+        ..test_client.clone()
+    };
+    let (_grantor_asset, beneficiary_asset) = setup(&test_client)?;
+
+    // Irrevocable vesting
+    let lock = VestingLock::new(
+        beneficiary_asset.clone(),
+        numeric!(60),
+        half_released_schedule(),
+        None,
+    );
+    test_client.submit_blocking(lock.clone())?;
+
+    // A second lock can't be placed on top of a pending one
+    let _err = test_client.submit_blocking(lock).unwrap_err();
+
+    let _err = test_client
+        .submit_blocking(VestingRevoke::new(beneficiary_asset.clone()))
+        .unwrap_err();
+
+    // The beneficiary can't unlock the amount by discarding the vesting entry
+    let _err = bob_client
+        .submit_blocking(RemoveKeyValue::account(
+            BOB_ID.clone(),
+            lock_key(beneficiary_asset.definition()),
+        ))
+        .unwrap_err();
+    assert_eq!(
+        test_client.find_locked_amount(&beneficiary_asset)?,
+        numeric!(60)
+    );
+
+    Ok(())
+}
@@ -14,7 +14,11 @@ use erased_serde::Serialize;
 use error_stack::{fmt::ColorMode, IntoReportCompat, ResultExt};
 use eyre::{eyre, Result, WrapErr};
 use futures::TryStreamExt;
-use iroha::{client::Client, config::Config, data_model::prelude::*};
+use iroha::{
+    client::{AccountRef, Client},
+    config::Config,
+    data_model::prelude::*,
+};
 use thiserror::Error;
 use tokio::runtime::Runtime;
 
@@ -76,6 +80,9 @@ enum Command {
     /// See the [usage guide](./docs/multisig.md) for details
     #[command(subcommand)]
     Multisig(multisig::Command),
+    /// Lock assets under vesting schedules and release them
+    #[command(subcommand)]
+    Vesting(vesting::Command),
     /// Read various data
     #[command(subcommand)]
     Query(query::Command),
@@ -233,7 +240,7 @@ macro_rules! match_all {
 impl Run for Command {
     fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
         use Command::*;
-        match_all!((self, context), { Domain, Account, Asset, Nft, Peer, Events, Blocks, Multisig, Query, Transaction, Role, Parameter, Trigger, Executor, MarkdownHelp })
+        match_all!((self, context), { Domain, Account, Asset, Nft, Peer, Events, Blocks, Multisig, Vesting, Query, Transaction, Role, Parameter, Trigger, Executor, MarkdownHelp })
     }
 }
 
@@ -386,6 +393,8 @@ mod events {
         TriggerExecute,
         /// Notify when a trigger execution is completed
         TriggerComplete,
+        /// Notify when a custom event is emitted
+        Custom,
     }
 
     impl Run for Args {
@@ -399,6 +408,7 @@ mod events {
                 Block => listen(BlockEventFilter::default(), context, timeout),
                 TriggerExecute => listen(ExecuteTriggerEventFilter::new(), context, timeout),
                 TriggerComplete => listen(TriggerCompletedEventFilter::new(), context, timeout),
+                Custom => listen(CustomEventFilter::new(), context, timeout),
             }
         }
     }
@@ -578,8 +588,11 @@ mod domain {
                         .wrap_err("Failed to unregister domain")
                 }
                 Transfer(args) => {
-                    let instruction =
-                        iroha::data_model::isi::Transfer::domain(args.from, args.id, args.to);
+                    let instruction = iroha::data_model::isi::Transfer::domain(
+                        args.from.resolve(context)?,
+                        args.id,
+                        args.to.resolve(context)?,
+                    );
                     context
                         .finish([instruction])
                         .wrap_err("Failed to transfer domain")
@@ -594,12 +607,12 @@ mod domain {
         /// Domain name
         #[arg(short, long)]
         pub id: DomainId,
-        /// Source account, in the format "multihash@domain"
+        /// Source account, in the format "multihash@domain" or "alias@domain"
         #[arg(short, long)]
-        pub from: AccountId,
-        /// Destination account, in the format "multihash@domain"
+        pub from: AccountRef,
+        /// Destination account, in the format "multihash@domain" or "alias@domain"
         #[arg(short, long)]
-        pub to: AccountId,
+        pub to: AccountRef,
     }
 
     #[derive(clap::Args, Debug)]
@@ -613,7 +626,7 @@ mod domain {
 }
 
 mod account {
-    use std::fmt::Debug;
+    use std::{fmt::Debug, num::NonZeroU16};
 
     use super::*;
 
@@ -625,13 +638,19 @@ mod account {
         /// Read and write account permissions
         #[command(subcommand)]
         Permission(PermissionCommand),
+        /// Read and write keys authorized to sign on behalf of an account
+        #[command(subcommand)]
+        Key(KeyCommand),
+        /// Read and write human-readable aliases of an account
+        #[command(subcommand)]
+        Alias(AliasCommand),
         /// List accounts
         #[command(subcommand)]
         List(List),
         /// Retrieve details of a specific account
         Get(Id),
         /// Register an account
-        Register(Id),
+        Register(NewId),
         /// Unregister an account
         Unregister(Id),
         /// Read and write metadata
@@ -645,12 +664,15 @@ mod account {
             match self {
                 Role(cmd) => cmd.run(context),
                 Permission(cmd) => cmd.run(context),
+                Key(cmd) => cmd.run(context),
+                Alias(cmd) => cmd.run(context),
                 List(cmd) => cmd.run(context),
                 Get(args) => {
+                    let id = args.id.resolve(context)?;
                     let client = context.client_from_config();
                     let entry = client
                         .query(FindAccounts)
-                        .filter_with(|entry| entry.id.eq(args.id))
+                        .filter_with(|entry| entry.id.eq(id))
                         .execute_single()
                         .wrap_err("Failed to get account")?;
                     context.print_data(&entry)
@@ -663,7 +685,8 @@ mod account {
                         .wrap_err("Failed to register account")
                 }
                 Unregister(args) => {
-                    let instruction = iroha::data_model::isi::Unregister::account(args.id);
+                    let instruction =
+                        iroha::data_model::isi::Unregister::account(args.id.resolve(context)?);
                     context
                         .finish([instruction])
                         .wrap_err("Failed to unregister account")
@@ -688,22 +711,25 @@ mod account {
             use self::RoleCommand::*;
             match self {
                 List(args) => {
+                    let id = args.id.resolve(context)?;
                     let client = context.client_from_config();
-                    let roles = client
-                        .query(FindRolesByAccountId::new(args.id))
-                        .execute_all()?;
+                    let roles = client.query(FindRolesByAccountId::new(id)).execute_all()?;
                     context.print_data(&roles)
                 }
                 Grant(args) => {
-                    let instruction =
-                        iroha::data_model::isi::Grant::account_role(args.role, args.id);
+                    let instruction = iroha::data_model::isi::Grant::account_role(
+                        args.role,
+                        args.id.resolve(context)?,
+                    );
                     context
                         .finish([instruction])
                         .wrap_err("Failed to grant the role to the account")
                 }
                 Revoke(args) => {
-                    let instruction =
-                        iroha::data_model::isi::Revoke::account_role(args.role, args.id);
+                    let instruction = iroha::data_model::isi::Revoke::account_role(
+                        args.role,
+                        args.id.resolve(context)?,
+                    );
                     context
                         .finish([instruction])
                         .wrap_err("Failed to revoke the role from the account")
@@ -716,6 +742,8 @@ mod account {
     pub enum PermissionCommand {
         /// List account permissions
         List(Id),
+        /// List permissions in effect for an account, along with the roles they come from
+        Effective(Id),
         /// Grant an account permission using JSON5 input from stdin
         Grant(Id),
         /// Revoke an account permission using JSON5 input from stdin
@@ -727,24 +755,35 @@ mod account {
             use self::PermissionCommand::*;
             match self {
                 List(args) => {
+                    let id = args.id.resolve(context)?;
                     let client = context.client_from_config();
                     let permissions = client
-                        .query(FindPermissionsByAccountId::new(args.id))
+                        .query(FindPermissionsByAccountId::new(id))
                         .execute_all()?;
                     context.print_data(&permissions)
                 }
+                Effective(args) => {
+                    let id = args.id.resolve(context)?;
+                    let client = context.client_from_config();
+                    let permissions = client.find_effective_permissions(&id)?;
+                    context.print_data(&permissions)
+                }
                 Grant(args) => {
                     let permission: Permission = parse_json5_stdin(context)?;
-                    let instruction =
-                        iroha::data_model::isi::Grant::account_permission(permission, args.id);
+                    let instruction = iroha::data_model::isi::Grant::account_permission(
+                        permission,
+                        args.id.resolve(context)?,
+                    );
                     context
                         .finish([instruction])
                         .wrap_err("Failed to grant the permission to the account")
                 }
                 Revoke(args) => {
                     let permission: Permission = parse_json5_stdin(context)?;
-                    let instruction =
-                        iroha::data_model::isi::Revoke::account_permission(permission, args.id);
+                    let instruction = iroha::data_model::isi::Revoke::account_permission(
+                        permission,
+                        args.id.resolve(context)?,
+                    );
                     context
                         .finish([instruction])
                         .wrap_err("Failed to revoke the permission from the account")
@@ -753,18 +792,192 @@ mod account {
         }
     }
 
+    #[derive(clap::Subcommand, Debug)]
+    pub enum KeyCommand {
+        /// List account keys along with their weights and the signature threshold
+        List(Id),
+        /// Add a key to an account
+        Add(IdKeyWeight),
+        /// Remove a key from an account
+        Remove(IdKey),
+        /// Replace a key of an account, keeping its weight
+        Rotate(IdKeyRotation),
+        /// Set the total weight of signing keys required to authorize a transaction
+        Threshold(IdThreshold),
+    }
+
+    impl Run for KeyCommand {
+        fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
+            use self::KeyCommand::*;
+            match self {
+                List(args) => {
+                    let id = args.id.resolve(context)?;
+                    let client = context.client_from_config();
+                    let entry = client
+                        .query(FindAccounts)
+                        .filter_with(|entry| entry.id.eq(id))
+                        .execute_single()
+                        .wrap_err("Failed to get account")?;
+                    context.print_data(entry.keys())
+                }
+                Add(args) => {
+                    let instruction =
+                        AddAccountKey::new(args.id.resolve(context)?, args.key, args.weight);
+                    context
+                        .finish([instruction])
+                        .wrap_err("Failed to add the key to the account")
+                }
+                Remove(args) => {
+                    let instruction = RemoveAccountKey::new(args.id.resolve(context)?, args.key);
+                    context
+                        .finish([instruction])
+                        .wrap_err("Failed to remove the key from the account")
+                }
+                Rotate(args) => {
+                    let instruction =
+                        RotateAccountKey::new(args.id.resolve(context)?, args.old, args.new);
+                    context
+                        .finish([instruction])
+                        .wrap_err("Failed to rotate the key of the account")
+                }
+                Threshold(args) => {
+                    let instruction =
+                        SetAccountKeyThreshold::new(args.id.resolve(context)?, args.threshold);
+                    context
+                        .finish([instruction])
+                        .wrap_err("Failed to set the signature threshold of the account")
+                }
+            }
+        }
+    }
+
+    #[derive(clap::Subcommand, Debug)]
+    pub enum AliasCommand {
+        /// List aliases registered for an account
+        List(Id),
+        /// Register an alias for an account
+        Register(AliasId),
+        /// Make an alias resolve to another account
+        Transfer(AliasId),
+        /// Unregister an alias
+        Unregister(Alias),
+    }
+
+    impl Run for AliasCommand {
+        fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
+            use self::AliasCommand::*;
+            match self {
+                List(args) => {
+                    let id = args.id.resolve(context)?;
+                    let aliases = context.client_from_config().find_account_aliases(&id)?;
+                    context.print_data(&aliases)
+                }
+                Register(args) => {
+                    let instruction =
+                        RegisterAccountAlias::new(args.alias, args.id.resolve(context)?);
+                    context
+                        .finish([instruction])
+                        .wrap_err("Failed to register the alias")
+                }
+                Transfer(args) => {
+                    let instruction =
+                        TransferAccountAlias::new(args.alias, args.id.resolve(context)?);
+                    context
+                        .finish([instruction])
+                        .wrap_err("Failed to transfer the alias")
+                }
+                Unregister(args) => {
+                    let instruction = UnregisterAccountAlias::new(args.alias);
+                    context
+                        .finish([instruction])
+                        .wrap_err("Failed to unregister the alias")
+                }
+            }
+        }
+    }
+
     #[derive(clap::Args, Debug)]
     pub struct Id {
-        /// Account in the format "multihash@domain"
+        /// Account in the format "multihash@domain" or "alias@domain"
+        #[arg(short, long)]
+        id: AccountRef,
+    }
+
+    #[derive(clap::Args, Debug)]
+    pub struct NewId {
+        /// Account in the format "multihash@domain", aliases can only be registered afterwards
         #[arg(short, long)]
         id: AccountId,
     }
 
+    #[derive(clap::Args, Debug)]
+    pub struct IdKey {
+        /// Account in the format "multihash@domain" or "alias@domain"
+        #[arg(short, long)]
+        pub id: AccountRef,
+        /// Public key in multihash format
+        #[arg(short, long)]
+        pub key: PublicKey,
+    }
+
+    #[derive(clap::Args, Debug)]
+    pub struct IdKeyWeight {
+        /// Account in the format "multihash@domain" or "alias@domain"
+        #[arg(short, long)]
+        pub id: AccountRef,
+        /// Public key in multihash format
+        #[arg(short, long)]
+        pub key: PublicKey,
+        /// Relative weight of the key
+        #[arg(short, long, default_value_t = 1)]
+        pub weight: u8,
+    }
+
+    #[derive(clap::Args, Debug)]
+    pub struct IdKeyRotation {
+        /// Account in the format "multihash@domain" or "alias@domain"
+        #[arg(short, long)]
+        pub id: AccountRef,
+        /// Public key to be replaced in multihash format
+        #[arg(short, long)]
+        pub old: PublicKey,
+        /// Public key to replace with in multihash format
+        #[arg(short, long)]
+        pub new: PublicKey,
+    }
+
+    #[derive(clap::Args, Debug)]
+    pub struct IdThreshold {
+        /// Account in the format "multihash@domain" or "alias@domain"
+        #[arg(short, long)]
+        pub id: AccountRef,
+        /// Total weight of signing keys required to authorize a transaction
+        #[arg(short, long)]
+        pub threshold: NonZeroU16,
+    }
+
+    #[derive(clap::Args, Debug)]
+    pub struct Alias {
+        /// Alias in the format "alias@domain"
+        #[arg(short, long)]
+        pub alias: AccountAlias,
+    }
+
+    #[derive(clap::Args, Debug)]
+    pub struct AliasId {
+        /// Alias in the format "alias@domain"
+        #[arg(short, long)]
+        pub alias: AccountAlias,
+        /// Account the alias resolves to, in the format "multihash@domain" or "alias@domain"
+        #[arg(short, long)]
+        pub id: AccountRef,
+    }
+
     #[derive(clap::Args, Debug)]
     pub struct IdRole {
-        /// Account in the format "multihash@domain"
+        /// Account in the format "multihash@domain" or "alias@domain"
         #[arg(short, long)]
-        pub id: AccountId,
+        pub id: AccountRef,
         /// Role name
         #[arg(short, long)]
         pub role: RoleId,
@@ -827,7 +1040,7 @@ mod asset {
                     let instruction = iroha::data_model::isi::Transfer::asset_numeric(
                         args.id,
                         args.quantity,
-                        args.to,
+                        args.to.resolve(context)?,
                     );
                     context
                         .finish([instruction])
@@ -893,7 +1106,9 @@ mod asset {
                     }
                     Transfer(args) => {
                         let instruction = iroha::data_model::isi::Transfer::asset_definition(
-                            args.from, args.id, args.to,
+                            args.from.resolve(context)?,
+                            args.id,
+                            args.to.resolve(context)?,
                         );
                         context
                             .finish([instruction])
@@ -922,12 +1137,12 @@ mod asset {
             /// Asset definition in the format "asset#domain"
             #[arg(short, long)]
             pub id: AssetDefinitionId,
-            /// Source account, in the format "multihash@domain"
+            /// Source account, in the format "multihash@domain" or "alias@domain"
             #[arg(short, long)]
-            pub from: AccountId,
-            /// Destination account, in the format "multihash@domain"
+            pub from: AccountRef,
+            /// Destination account, in the format "multihash@domain" or "alias@domain"
             #[arg(short, long)]
-            pub to: AccountId,
+            pub to: AccountRef,
         }
 
         #[derive(clap::Args, Debug)]
@@ -945,9 +1160,9 @@ mod asset {
         /// Asset in the format "asset##account@domain" or "asset#another_domain#account@domain"
         #[arg(short, long)]
         pub id: AssetId,
-        /// Destination account, in the format "multihash@domain"
+        /// Destination account, in the format "multihash@domain" or "alias@domain"
         #[arg(short, long)]
-        pub to: AccountId,
+        pub to: AccountRef,
         /// Transfer amount (integer or decimal)
         #[arg(short, long)]
         pub quantity: Numeric,
@@ -1029,8 +1244,11 @@ mod nft {
                         .wrap_err("Failed to unregister NFT")
                 }
                 Transfer(args) => {
-                    let instruction =
-                        iroha::data_model::isi::Transfer::nft(args.from, args.id, args.to);
+                    let instruction = iroha::data_model::isi::Transfer::nft(
+                        args.from.resolve(context)?,
+                        args.id,
+                        args.to.resolve(context)?,
+                    );
                     context
                         .finish([instruction])
                         .wrap_err("Failed to transfer NFT")
@@ -1065,12 +1283,12 @@ mod nft {
         /// NFT in the format "name$domain"
         #[arg(short, long)]
         pub id: NftId,
-        /// Source account, in the format "multihash@domain"
+        /// Source account, in the format "multihash@domain" or "alias@domain"
         #[arg(short, long)]
-        pub from: AccountId,
-        /// Destination account, in the format "multihash@domain"
+        pub from: AccountRef,
+        /// Destination account, in the format "multihash@domain" or "alias@domain"
         #[arg(short, long)]
-        pub to: AccountId,
+        pub to: AccountRef,
     }
 
     #[derive(clap::Args, Debug)]
@@ -1174,12 +1392,16 @@ mod multisig {
         Propose(Propose),
         /// Approve a multisig transaction
         Approve(Approve),
+        /// Reject a multisig transaction
+        Reject(Reject),
+        /// Cancel a multisig transaction you proposed
+        Cancel(Cancel),
     }
 
     impl Run for Command {
         fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
             use self::Command::*;
-            match_all!((self, context), { List, Register, Propose, Approve })
+            match_all!((self, context), { List, Register, Propose, Approve, Reject, Cancel })
         }
     }
     #[derive(clap::Args, Debug)]
@@ -1189,7 +1411,7 @@ mod multisig {
         pub account: AccountId,
         /// List of signatories for the multisig account
         #[arg(short, long, num_args(2..))]
-        pub signatories: Vec<AccountId>,
+        pub signatories: Vec<AccountRef>,
         /// Relative weights of signatories' responsibilities
         #[arg(short, long, num_args(2..))]
         pub weights: Vec<u8>,
@@ -1211,10 +1433,15 @@ mod multisig {
             if self.signatories.len() != self.weights.len() {
                 return Err(eyre!("signatories and weights must be equal in length"));
             }
+            let signatories = self
+                .signatories
+                .into_iter()
+                .map(|signatory| signatory.resolve(context))
+                .collect::<Result<Vec<_>>>()?;
             let instruction = MultisigRegister::new(
                 self.account,
                 MultisigSpec::new(
-                    self.signatories.into_iter().zip(self.weights).collect(),
+                    signatories.into_iter().zip(self.weights).collect(),
                     NonZeroU16::new(self.quorum).expect("quorum should not be 0"),
                     self.transaction_ttl
                         .as_millis()
@@ -1235,7 +1462,7 @@ mod multisig {
     pub struct Propose {
         /// Multisig authority managing the proposed transaction
         #[arg(short, long)]
-        pub account: AccountId,
+        pub account: AccountRef,
         /// Overrides the default time-to-live for this transaction.
         /// Example: "1y 6M 2w 3d 12h 30m 30s"
         #[arg(short, long)]
@@ -1257,8 +1484,11 @@ mod multisig {
             let instructions_hash = HashOf::new(&instructions);
             println!("{instructions_hash}");
 
-            let propose_multisig_transaction =
-                MultisigPropose::new(self.account, instructions, transaction_ttl_ms);
+            let propose_multisig_transaction = MultisigPropose::new(
+                self.account.resolve(context)?,
+                instructions,
+                transaction_ttl_ms,
+            );
 
             context
                 .finish([propose_multisig_transaction])
@@ -1270,7 +1500,7 @@ mod multisig {
     pub struct Approve {
         /// Multisig authority of the transaction
         #[arg(short, long)]
-        pub account: AccountId,
+        pub account: AccountRef,
         /// Hash of the instructions to approve
         #[arg(short, long)]
         pub instructions_hash: ProposalKey,
@@ -1279,7 +1509,7 @@ mod multisig {
     impl Run for Approve {
         fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
             let approve_multisig_transaction =
-                MultisigApprove::new(self.account, self.instructions_hash);
+                MultisigApprove::new(self.account.resolve(context)?, self.instructions_hash);
 
             context
                 .finish([approve_multisig_transaction])
@@ -1287,6 +1517,48 @@ mod multisig {
         }
     }
 
+    #[derive(clap::Args, Debug)]
+    pub struct Reject {
+        /// Multisig authority of the transaction
+        #[arg(short, long)]
+        pub account: AccountRef,
+        /// Hash of the instructions to reject
+        #[arg(short, long)]
+        pub instructions_hash: ProposalKey,
+    }
+
+    impl Run for Reject {
+        fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
+            let reject_multisig_transaction =
+                MultisigReject::new(self.account.resolve(context)?, self.instructions_hash);
+
+            context
+                .finish([reject_multisig_transaction])
+                .wrap_err("Failed to reject transaction")
+        }
+    }
+
+    #[derive(clap::Args, Debug)]
+    pub struct Cancel {
+        /// Multisig authority of the transaction
+        #[arg(short, long)]
+        pub account: AccountRef,
+        /// Hash of the instructions to cancel
+        #[arg(short, long)]
+        pub instructions_hash: ProposalKey,
+    }
+
+    impl Run for Cancel {
+        fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
+            let cancel_multisig_transaction =
+                MultisigCancel::new(self.account.resolve(context)?, self.instructions_hash);
+
+            context
+                .finish([cancel_multisig_transaction])
+                .wrap_err("Failed to cancel transaction")
+        }
+    }
+
     #[derive(clap::Subcommand, Debug)]
     pub enum List {
         /// List all pending multisig transactions relevant to you
@@ -1316,30 +1588,6 @@ mod multisig {
         }
     }
 
-    const DELIMITER: char = '/';
-    const MULTISIG: &str = "multisig";
-    const MULTISIG_SIGNATORY: &str = "MULTISIG_SIGNATORY";
-
-    fn spec_key() -> Name {
-        format!("{MULTISIG}{DELIMITER}spec").parse().unwrap()
-    }
-
-    fn proposal_key_prefix() -> String {
-        format!("{MULTISIG}{DELIMITER}proposals{DELIMITER}")
-    }
-
-    fn multisig_account_from(role: &RoleId) -> Option<AccountId> {
-        role.name()
-            .as_ref()
-            .strip_prefix(MULTISIG_SIGNATORY)?
-            .rsplit_once(DELIMITER)
-            .and_then(|(init, last)| {
-                format!("{last}@{}", init.trim_matches(DELIMITER))
-                    .parse()
-                    .ok()
-            })
-    }
-
     type PendingProposals = BTreeMap<ProposalKey, ProposalStatus>;
 
     type ProposalKey = HashOf<Vec<InstructionBox>>;
@@ -1401,26 +1649,10 @@ mod multisig {
         let Some(context) = stack.pop() else {
             return Ok(());
         };
-        let account = client
-            .query(FindAccounts)
-            .filter_with(|account| account.id.eq(context.this.clone()))
-            .execute_single()?;
-        let spec: MultisigSpec = account
-            .metadata()
-            .get(&spec_key())
-            .unwrap()
-            .try_into_any()?;
-        for (proposal_key, proposal_value) in account
-            .metadata()
-            .iter()
-            .filter_map(|(k, v)| {
-                k.as_ref().strip_prefix(&proposal_key_prefix()).map(|k| {
-                    (
-                        k.parse::<ProposalKey>().unwrap(),
-                        v.try_into_any::<MultisigProposalValue>().unwrap(),
-                    )
-                })
-            })
+        let spec = client.find_multisig_spec(&context.this)?;
+        for (proposal_key, proposal_value) in client
+            .find_multisig_proposals(&context.this)?
+            .into_iter()
             .filter(|(k, _v)| context.key_span.map_or(true, |(_, top)| *k == top))
         {
             let mut is_root_proposal = true;
@@ -1448,11 +1680,7 @@ mod multisig {
             let edge = ApprovalEdge::new(
                 *spec.signatories.get(&context.child).unwrap(),
                 proposal_value.approvals.contains(&context.child),
-                spec.signatories
-                    .iter()
-                    .filter(|(id, _)| proposal_value.approvals.contains(id))
-                    .map(|(_, weight)| u16::from(*weight))
-                    .sum(),
+                spec.weight_of(&proposal_value.approvals),
                 spec.quorum.into(),
                 context.this.clone(),
             );
@@ -1481,6 +1709,169 @@ mod multisig {
     }
 }
 
+mod vesting {
+    use std::{
+        num::NonZeroU64,
+        time::{Duration, SystemTime},
+    };
+
+    use iroha::executor_data_model::isi::vesting::*;
+    use serde::Serialize;
+
+    use super::*;
+
+    #[derive(clap::Subcommand, Debug)]
+    pub enum Command {
+        /// List amounts locked by vesting in the assets of an account
+        List(List),
+        /// Get the amounts of an asset locked by vesting and claimable as of now
+        Get(Get),
+        /// Transfer an amount of your asset to the beneficiary, locking it under a vesting schedule
+        Lock(Lock),
+        /// Unlock the portion of your asset released so far
+        Claim(Claim),
+        /// Return the unvested portion to the grantor, releasing the rest immediately
+        Revoke(Revoke),
+    }
+
+    impl Run for Command {
+        fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
+            use self::Command::*;
+            match_all!((self, context), { List, Get, Lock, Claim, Revoke })
+        }
+    }
+
+    #[derive(clap::Args, Debug)]
+    pub struct List {
+        /// Beneficiary account, in the format "multihash@domain" or "alias@domain"
+        #[arg(short, long)]
+        pub account: AccountRef,
+    }
+
+    impl Run for List {
+        fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
+            let account = self.account.resolve(context)?;
+            let locks = context.client_from_config().find_vesting_locks(&account)?;
+            context.print_data(&locks)
+        }
+    }
+
+    #[derive(clap::Args, Debug)]
+    pub struct Get {
+        /// Asset of the beneficiary in the format "asset##account@domain" or "asset#another_domain#account@domain"
+        #[arg(short, long)]
+        pub asset: AssetId,
+    }
+
+    #[derive(Debug, Serialize)]
+    struct Amounts {
+        locked: Numeric,
+        claimable: Numeric,
+    }
+
+    impl Run for Get {
+        fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
+            let client = context.client_from_config();
+            let amounts = Amounts {
+                locked: client.find_locked_amount(&self.asset)?,
+                claimable: client.find_claimable_amount(&self.asset)?,
+            };
+            context.print_data(&amounts)
+        }
+    }
+
+    #[derive(clap::Args, Debug)]
+    pub struct Lock {
+        /// Asset of the beneficiary in the format "asset##account@domain" or "asset#another_domain#account@domain"
+        #[arg(short, long)]
+        pub asset: AssetId,
+        /// Amount to be transferred from your asset of the same definition and locked (integer or decimal)
+        #[arg(short, long)]
+        pub quantity: Numeric,
+        /// Start of the vesting. Defaults to now.
+        /// Example: "2025-01-01T00:00:00Z"
+        #[arg(long)]
+        pub start: Option<humantime::Timestamp>,
+        /// Time after the start before which nothing is released.
+        /// Example: "1y 6M 2w 3d 12h 30m 30s"
+        #[arg(short, long, default_value = "0s")]
+        pub cliff: humantime::Duration,
+        /// Time after the start at which the whole amount is released
+        #[arg(short, long)]
+        pub duration: humantime::Duration,
+        /// Interval between releases. Releases linearly if omitted
+        #[arg(short, long)]
+        pub step: Option<humantime::Duration>,
+        /// Account allowed to revoke the unvested portion, in the format "multihash@domain" or "alias@domain". Irrevocable if omitted
+        #[arg(short, long)]
+        pub revoker: Option<AccountRef>,
+    }
+
+    impl Run for Lock {
+        fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
+            let start: SystemTime = self.start.map_or_else(SystemTime::now, Into::into);
+            let schedule = VestingSchedule::new(
+                millis(start.duration_since(SystemTime::UNIX_EPOCH)?)?,
+                millis(*self.cliff)?,
+                NonZeroU64::new(millis(*self.duration)?)
+                    .ok_or_else(|| eyre!("duration should be at least 1 ms"))?,
+                self.step
+                    .map(|step| {
+                        NonZeroU64::new(millis(*step)?)
+                            .ok_or_else(|| eyre!("step should be at least 1 ms"))
+                    })
+                    .transpose()?,
+            );
+            let revoker = self
+                .revoker
+                .map(|revoker| revoker.resolve(context))
+                .transpose()?;
+            let instruction = VestingLock::new(self.asset, self.quantity, schedule, revoker);
+
+            context
+                .finish([instruction])
+                .wrap_err("Failed to lock the asset under vesting")
+        }
+    }
+
+    fn millis(duration: Duration) -> Result<u64> {
+        duration
+            .as_millis()
+            .try_into()
+            .wrap_err("time should be within 584942417 years")
+    }
+
+    #[derive(clap::Args, Debug)]
+    pub struct Claim {
+        /// Asset of yours in the format "asset##account@domain" or "asset#another_domain#account@domain"
+        #[arg(short, long)]
+        pub asset: AssetId,
+    }
+
+    impl Run for Claim {
+        fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
+            context
+                .finish([VestingClaim::new(self.asset)])
+                .wrap_err("Failed to claim the vested asset")
+        }
+    }
+
+    #[derive(clap::Args, Debug)]
+    pub struct Revoke {
+        /// Asset of the beneficiary in the format "asset##account@domain" or "asset#another_domain#account@domain"
+        #[arg(short, long)]
+        pub asset: AssetId,
+    }
+
+    impl Run for Revoke {
+        fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
+            context
+                .finish([VestingRevoke::new(self.asset)])
+                .wrap_err("Failed to revoke the vesting")
+        }
+    }
+}
+
 mod query {
     use iroha::data_model::query::AnyQueryBox;
 
@@ -1653,6 +2044,8 @@ mod transaction {
 }
 
 mod role {
+    use iroha::executor_data_model::isi::role::*;
+
     use super::*;
 
     #[derive(clap::Subcommand, Debug)]
@@ -1664,9 +2057,13 @@ mod role {
         #[command(subcommand)]
         List(List),
         /// Register a role and grant it to the registrant
-        Register(Id),
+        Register(Register),
         /// Unregister a role
         Unregister(Id),
+        /// Replace the roles whose permissions a role inherits
+        Inherit(Inherit),
+        /// Replace the roles whose holders can grant and revoke a role and its permissions
+        Delegate(Delegate),
     }
 
     impl Run for Command {
@@ -1676,10 +2073,11 @@ mod role {
                 Permission(cmd) => cmd.run(context),
                 List(cmd) => cmd.run(context),
                 Register(args) => {
-                    let instruction = iroha::data_model::isi::Register::role(Role::new(
-                        args.id,
-                        context.config().account.clone(),
-                    ));
+                    let role = Role::new(args.id, context.config().account.clone());
+                    let instruction: InstructionBox = match args.domain {
+                        Some(domain) => RoleRegisterScoped::new(role, domain).into(),
+                        None => iroha::data_model::isi::Register::role(role).into(),
+                    };
                     context
                         .finish([instruction])
                         .wrap_err("Failed to register role")
@@ -1690,10 +2088,55 @@ mod role {
                         .finish([instruction])
                         .wrap_err("Failed to unregister role")
                 }
+                Inherit(args) => {
+                    let instruction =
+                        RoleSetParents::new(args.id, args.parents.into_iter().collect());
+                    context
+                        .finish([instruction])
+                        .wrap_err("Failed to set the roles inherited by the role")
+                }
+                Delegate(args) => {
+                    let instruction =
+                        RoleSetAdmins::new(args.id, args.admins.into_iter().collect());
+                    context
+                        .finish([instruction])
+                        .wrap_err("Failed to set the roles administering the role")
+                }
             }
         }
     }
 
+    #[derive(clap::Args, Debug)]
+    pub struct Register {
+        /// Role name
+        #[arg(short, long)]
+        id: RoleId,
+        /// Domain outside of which the role grants no permissions.
+        /// Registering such a role requires owning the domain rather than the permission to manage roles
+        #[arg(short, long)]
+        domain: Option<DomainId>,
+    }
+
+    #[derive(clap::Args, Debug)]
+    pub struct Inherit {
+        /// Role name
+        #[arg(short, long)]
+        id: RoleId,
+        /// Roles whose permissions are inherited. No value means none
+        #[arg(short, long = "parent")]
+        parents: Vec<RoleId>,
+    }
+
+    #[derive(clap::Args, Debug)]
+    pub struct Delegate {
+        /// Role name
+        #[arg(short, long)]
+        id: RoleId,
+        /// Roles whose holders administer the role. No value means the holders of the role itself
+        #[arg(short, long = "admin")]
+        admins: Vec<RoleId>,
+    }
+
     #[derive(clap::Subcommand, Debug)]
     pub enum PermissionCommand {
         /// List role permissions
@@ -1807,6 +2250,8 @@ mod parameter {
 }
 
 mod trigger {
+    use std::{num::NonZeroU32, time::SystemTime};
+
     use super::*;
 
     #[derive(clap::Subcommand, Debug)]
@@ -1817,7 +2262,7 @@ mod trigger {
         /// Retrieve details of a specific trigger
         // TODO: For better readability and reusability, triggers should reference a Wasm executable instead of storing the blob itself.
         Get(Id),
-        /// TODO: Register a trigger
+        /// Register a time trigger executing a Wasm from the file or JSON5 `[Instruction]` from stdin
         Register(Register),
         /// Unregister a trigger
         Unregister(Id),
@@ -1906,11 +2351,79 @@ mod trigger {
     }
 
     #[derive(clap::Args, Debug)]
-    pub struct Register;
+    pub struct Register {
+        /// Trigger name
+        #[arg(short, long)]
+        pub id: TriggerId,
+        /// Path to the compiled Wasm file. Instructions are read from stdin if omitted
+        #[arg(short, long)]
+        pub path: Option<PathBuf>,
+        /// First execution time. Defaults to now.
+        /// Example: "2025-01-01T00:00:00Z"
+        #[arg(short, long)]
+        pub start: Option<humantime::Timestamp>,
+        /// Interval between executions.
+        /// Example: "1d 12h"
+        #[arg(long, conflicts_with = "cron")]
+        pub period: Option<humantime::Duration>,
+        /// Cron expression in UTC with fields "minute hour day-of-month month day-of-week".
+        /// Example: "0 9 * * MON-FRI"
+        #[arg(short, long)]
+        pub cron: Option<CronExpression>,
+        /// Time from which on the trigger is no longer executed
+        #[arg(short, long)]
+        pub end: Option<humantime::Timestamp>,
+        /// Maximal number of executions
+        #[arg(short, long)]
+        pub max_occurrences: Option<NonZeroU32>,
+    }
 
     impl Run for Register {
-        fn run<C: RunContext>(self, _context: &mut C) -> Result<()> {
-            todo!()
+        fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
+            let executable: Executable = match self.path {
+                Some(path) => fs::read(path)
+                    .map(WasmSmartContract::from_compiled)
+                    .wrap_err("Failed to read a Wasm from the file")?
+                    .into(),
+                None => parse_json5_stdin::<Vec<InstructionBox>>(context)?.into(),
+            };
+
+            let since_epoch = |time: SystemTime| time.duration_since(SystemTime::UNIX_EPOCH);
+            let start = self.start.map_or_else(SystemTime::now, Into::into);
+            let mut schedule = TimeSchedule::starting_at(since_epoch(start)?);
+            if let Some(period) = self.period {
+                if period.is_zero() {
+                    return Err(eyre!("period should be at least 1 ms"));
+                }
+                schedule = schedule.with_period(*period);
+            }
+            if let Some(cron) = self.cron {
+                schedule = schedule.with_cron(cron);
+            }
+            if let Some(end) = self.end {
+                schedule = schedule.until(since_epoch(end.into())?);
+            }
+            if let Some(max_occurrences) = self.max_occurrences {
+                schedule = schedule.with_max_occurrences(max_occurrences);
+            }
+
+            let repeats = if schedule.period_ms.is_none() && schedule.cron.is_none() {
+                Repeats::Exactly(1)
+            } else {
+                Repeats::Indefinitely
+            };
+            let action = Action::new(
+                executable,
+                repeats,
+                context.config().account.clone(),
+                TimeEventFilter::new(ExecutionTime::Schedule(schedule)),
+            );
+            let instruction =
+                iroha::data_model::isi::Register::trigger(Trigger::new(self.id, action));
+
+            context
+                .finish([instruction])
+                .wrap_err("Failed to register trigger")
         }
     }
 }
@@ -2073,6 +2586,17 @@ mod metadata {
     }
 }
 
+/// Resolve accounts referred to by aliases with [`Client::resolve_account`]
+trait ResolveAccount {
+    fn resolve(self, context: &impl RunContext) -> Result<AccountId>;
+}
+
+impl ResolveAccount for AccountRef {
+    fn resolve(self, context: &impl RunContext) -> Result<AccountId> {
+        context.client_from_config().resolve_account(self)
+    }
+}
+
 fn dump_json5_stdout<T>(value: &T) -> Result<()>
 where
     T: serde::Serialize,
@@ -2,7 +2,7 @@
 //! structures in a way that is efficient for Iroha internally.
 
 use std::{
-    num::{NonZeroU32, NonZeroUsize},
+    num::{NonZeroU32, NonZeroU64, NonZeroUsize},
     path::PathBuf,
     time::Duration,
 };
@@ -80,6 +80,13 @@ pub struct Network {
     pub address: WithOrigin<SocketAddr>,
     pub public_address: WithOrigin<SocketAddr>,
     pub idle_timeout: Duration,
+    pub replicas: UniqueVec<PeerId>,
+    pub inbound_bytes_per_sec: Option<NonZeroU64>,
+    pub outbound_bytes_per_sec: Option<NonZeroU64>,
+    pub ban_duration: Duration,
+    pub compression: bool,
+    pub compression_threshold: Bytes<u64>,
+    pub max_decompressed_len: Bytes<u64>,
 }
 
 /// Parsed genesis configuration
@@ -123,6 +130,8 @@ impl Default for Queue {
 #[derive(Debug, Copy, Clone)]
 #[allow(missing_docs)]
 pub struct Sumeragi {
+    pub replica: bool,
+    pub execution_workers: NonZeroUsize,
     pub debug_force_soft_fork: bool,
 }
 
@@ -186,6 +195,10 @@ pub struct TransactionGossiper {
 pub struct Torii {
     pub address: WithOrigin<SocketAddr>,
     pub max_content_len: Bytes<u64>,
+    pub simulation_enabled: bool,
+    pub simulation_max_concurrent: NonZeroUsize,
+    pub simulation_max_per_sec: NonZeroU32,
+    pub simulation_max_fuel: NonZeroU64,
 }
 
 /// Complete configuration needed to start regular telemetry.
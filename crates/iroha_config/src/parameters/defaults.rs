@@ -28,7 +28,15 @@ pub mod kura {
     pub const BLOCKS_IN_MEMORY: NonZeroUsize = nonzero!(128_usize);
 }
 
+pub mod sumeragi {
+    use super::*;
+
+    pub const EXECUTION_WORKERS: NonZeroUsize = nonzero!(1_usize);
+}
+
 pub mod network {
+    use iroha_config_base::util::Bytes;
+
     use super::*;
 
     pub const TRANSACTION_GOSSIP_PERIOD: Duration = Duration::from_secs(1);
@@ -38,6 +46,11 @@ pub mod network {
     pub const BLOCK_GOSSIP_SIZE: NonZeroU32 = nonzero!(4u32);
 
     pub const IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+
+    pub const BAN_DURATION: Duration = Duration::from_secs(60);
+
+    pub const COMPRESSION_THRESHOLD: Bytes<u64> = Bytes(1024);
+    pub const MAX_DECOMPRESSED_LEN: Bytes<u64> = Bytes(2_u64.pow(20) * 64);
 }
 
 pub mod snapshot {
@@ -49,7 +62,10 @@ pub mod snapshot {
 }
 
 pub mod torii {
-    use std::{num::NonZeroUsize, time::Duration};
+    use std::{
+        num::{NonZeroU32, NonZeroU64, NonZeroUsize},
+        time::Duration,
+    };
 
     use iroha_config_base::util::Bytes;
     use nonzero_ext::nonzero;
@@ -58,6 +74,9 @@ pub mod torii {
     pub const QUERY_IDLE_TIME: Duration = Duration::from_secs(10);
     pub const QUERY_STORE_CAPACITY: NonZeroUsize = nonzero!(128usize);
     pub const QUERY_STORE_CAPACITY_PER_USER: NonZeroUsize = nonzero!(128usize);
+    pub const SIMULATION_MAX_CONCURRENT: NonZeroUsize = nonzero!(4usize);
+    pub const SIMULATION_MAX_PER_SEC: NonZeroU32 = nonzero!(16u32);
+    pub const SIMULATION_MAX_FUEL: NonZeroU64 = nonzero!(55_000_000_u64);
 }
 
 pub mod telemetry {
@@ -14,7 +14,7 @@ use std::{
     borrow::Cow,
     convert::Infallible,
     fmt::Debug,
-    num::{NonZeroU32, NonZeroUsize},
+    num::{NonZeroU32, NonZeroU64, NonZeroUsize},
     path::PathBuf,
 };
 
@@ -26,7 +26,10 @@ use iroha_config_base::{
     ReadConfig, WithOrigin,
 };
 use iroha_crypto::{PrivateKey, PublicKey};
-use iroha_data_model::{peer::Peer, ChainId};
+use iroha_data_model::{
+    peer::{Peer, PeerId},
+    ChainId,
+};
 use iroha_primitives::{addr::SocketAddr, unique_vec::UniqueVec};
 use serde::Deserialize;
 use url::Url;
@@ -230,6 +233,16 @@ pub struct KuraDebug {
 
 #[derive(Debug, Clone, Copy, ReadConfig)]
 pub struct Sumeragi {
+    /// Run the peer as a replica: a non-voting follower which syncs committed blocks
+    /// from the validators and forwards submitted transactions to them.
+    #[config(default)]
+    pub replica: bool,
+    /// Number of threads executing the transactions of a block speculatively in parallel.
+    /// With a single worker the transactions are executed one by one.
+    /// Measure the `execute_transactions` benchmark of `iroha_core` on the target hardware
+    /// before enabling more workers.
+    #[config(default = "defaults::sumeragi::EXECUTION_WORKERS")]
+    pub execution_workers: NonZeroUsize,
     #[config(nested)]
     pub debug: SumeragiDebug,
 }
@@ -257,10 +270,14 @@ impl Default for TrustedPeers {
 impl Sumeragi {
     fn parse(self) -> actual::Sumeragi {
         let Self {
+            replica,
+            execution_workers,
             debug: SumeragiDebug { force_soft_fork },
         } = self;
 
         actual::Sumeragi {
+            replica,
+            execution_workers,
             debug_force_soft_fork: force_soft_fork,
         }
     }
@@ -292,6 +309,28 @@ pub struct Network {
     /// Duration of time after which connection with peer is terminated if peer is idle
     #[config(default = "defaults::network::IDLE_TIMEOUT.into()")]
     pub idle_timeout_ms: DurationMs,
+    /// Replicas which are allowed to connect to this peer without being part of the topology.
+    #[config(default)]
+    pub replicas: UniqueVec<PeerId>,
+    /// Max number of bytes per second received from a single peer. Unlimited if not set.
+    /// Messages exceeding the limit are dropped and the peer is penalized.
+    pub inbound_bytes_per_sec: Option<NonZeroU64>,
+    /// Max number of bytes per second sent to a single peer. Unlimited if not set.
+    pub outbound_bytes_per_sec: Option<NonZeroU64>,
+    /// Duration of the first ban of a misbehaving peer, doubled for every following ban.
+    #[config(default = "defaults::network::BAN_DURATION.into()")]
+    pub ban_duration_ms: DurationMs,
+    /// Offer compression of the messages to peers once connected.
+    /// It is used with the peers that offer it as well, other peers are sent uncompressed messages.
+    /// Peers of earlier versions ignore the offer.
+    #[config(default)]
+    pub compression: bool,
+    /// Messages smaller than this are sent uncompressed.
+    #[config(default = "defaults::network::COMPRESSION_THRESHOLD")]
+    pub compression_threshold: Bytes<u64>,
+    /// Max size of a decompressed message. Peers sending larger messages are penalized.
+    #[config(default = "defaults::network::MAX_DECOMPRESSED_LEN")]
+    pub max_decompressed_len: Bytes<u64>,
 }
 
 impl Network {
@@ -310,6 +349,13 @@ impl Network {
             transaction_gossip_size,
             transaction_gossip_period_ms: transaction_gossip_period,
             idle_timeout_ms: idle_timeout,
+            replicas,
+            inbound_bytes_per_sec,
+            outbound_bytes_per_sec,
+            ban_duration_ms: ban_duration,
+            compression,
+            compression_threshold,
+            max_decompressed_len,
         } = self;
 
         (
@@ -317,6 +363,13 @@ impl Network {
                 address,
                 public_address,
                 idle_timeout: idle_timeout.get(),
+                replicas,
+                inbound_bytes_per_sec,
+                outbound_bytes_per_sec,
+                ban_duration: ban_duration.get(),
+                compression,
+                compression_threshold,
+                max_decompressed_len,
             },
             actual::BlockSync {
                 gossip_period: block_gossip_period.get(),
@@ -453,6 +506,21 @@ pub struct Torii {
     /// The upper limit of the number of live queries for a single user.
     #[config(default = "defaults::torii::QUERY_STORE_CAPACITY_PER_USER")]
     pub query_store_capacity_per_user: NonZeroUsize,
+    /// Serve the transaction simulation endpoint.
+    ///
+    /// Every simulation copies the whole latest world state before executing the transaction,
+    /// so it costs the peer time and memory proportional to the size of the state.
+    #[config(default)]
+    pub simulation_enabled: bool,
+    /// The upper limit of the number of transactions simulated at the same time.
+    #[config(default = "defaults::torii::SIMULATION_MAX_CONCURRENT")]
+    pub simulation_max_concurrent: NonZeroUsize,
+    /// The upper limit of the number of transaction simulations started per second.
+    #[config(default = "defaults::torii::SIMULATION_MAX_PER_SEC")]
+    pub simulation_max_per_sec: NonZeroU32,
+    /// The upper limit of fuel available to a simulated transaction, regardless of the on-chain parameters.
+    #[config(default = "defaults::torii::SIMULATION_MAX_FUEL")]
+    pub simulation_max_fuel: NonZeroU64,
 }
 
 impl Torii {
@@ -460,6 +528,10 @@ impl Torii {
         let torii = actual::Torii {
             address: self.address,
             max_content_len: self.max_content_len,
+            simulation_enabled: self.simulation_enabled,
+            simulation_max_concurrent: self.simulation_max_concurrent,
+            simulation_max_per_sec: self.simulation_max_per_sec,
+            simulation_max_fuel: self.simulation_max_fuel,
         };
 
         let query = actual::LiveQueryStore {
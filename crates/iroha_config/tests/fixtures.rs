@@ -122,6 +122,19 @@ fn minimal_config_snapshot() {
                     },
                 },
                 idle_timeout: 60s,
+                replicas: UniqueVec(
+                    [],
+                ),
+                inbound_bytes_per_sec: None,
+                outbound_bytes_per_sec: None,
+                ban_duration: 60s,
+                compression: false,
+                compression_threshold: Bytes(
+                    1024,
+                ),
+                max_decompressed_len: Bytes(
+                    67108864,
+                ),
             },
             genesis: Genesis {
                 public_key: PublicKey(
@@ -142,6 +155,10 @@ fn minimal_config_snapshot() {
                 max_content_len: Bytes(
                     16777216,
                 ),
+                simulation_enabled: false,
+                simulation_max_concurrent: 4,
+                simulation_max_per_sec: 16,
+                simulation_max_fuel: 55000000,
             },
             kura: Kura {
                 init_mode: Strict,
@@ -155,6 +172,8 @@ fn minimal_config_snapshot() {
                 debug_output_new_blocks: false,
             },
             sumeragi: Sumeragi {
+                replica: false,
+                execution_workers: 1,
                 debug_force_soft_fork: false,
             },
             block_sync: BlockSync {
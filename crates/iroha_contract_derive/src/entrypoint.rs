@@ -0,0 +1,62 @@
+//! Module wht [`main`](super::main) macro implementation
+
+use iroha_macro_utils::Emitter;
+use manyhow::emit;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+mod export {
+    pub const CONTRACT_MAIN: &str = "_iroha_contract_main";
+}
+
+/// [`main`](super::main()) macro implementation
+#[allow(clippy::needless_pass_by_value)]
+pub fn impl_entrypoint(emitter: &mut Emitter, item: syn::ItemFn) -> TokenStream {
+    let syn::ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = item;
+
+    if sig.output == syn::ReturnType::Default {
+        emit!(
+            emitter,
+            sig.ident,
+            "Contract `main()` function must return `Result<Json, ValidationFail>`"
+        )
+    }
+
+    let fn_name = &sig.ident;
+    let main_fn_name = syn::Ident::new(export::CONTRACT_MAIN, proc_macro2::Span::call_site());
+
+    quote! {
+        iroha_contract::utils::register_getrandom_err_callback!();
+
+        /// Contract entrypoint
+        ///
+        /// # Memory safety
+        ///
+        /// This function transfers the ownership of allocated `Result<Json, ValidationFail>`
+        #[no_mangle]
+        #[doc(hidden)]
+        unsafe extern "C" fn #main_fn_name(context: *const u8) -> *const u8 {
+            let host = ::iroha_contract::Iroha;
+            let context = ::iroha_contract::utils::__decode_contract_context(context);
+            let output = #fn_name(host, context);
+
+            let bytes_box = ::core::mem::ManuallyDrop::new(
+                ::iroha_contract::utils::encode_with_length_prefix(&output)
+            );
+
+            bytes_box.as_ptr()
+        }
+
+        // NOTE: Host objects are always passed by value to wasm
+        #[allow(clippy::needless_pass_by_value)]
+        #(#attrs)*
+        #[inline]
+        #vis #sig
+        #block
+    }
+}
@@ -0,0 +1,47 @@
+//! Crate with contract procedural macros.
+
+use iroha_macro_utils::Emitter;
+use manyhow::{emit, manyhow};
+use proc_macro2::TokenStream;
+
+mod entrypoint;
+
+/// Annotate the user-defined function that starts the execution of the contract.
+///
+/// Requires function to accept two arguments of types:
+/// 1. `host: Iroha` - handle to the host system (use it to execute instructions and queries)
+/// 2. `context: Context` - context of the execution (caller, arguments, etc)
+///
+/// and to return `Result<Json, ValidationFail>` which is the output of the call.
+///
+/// # Panics
+///
+/// - If function has no return type
+///
+/// # Examples
+///
+/// ```ignore
+/// use iroha_contract::prelude::*;
+///
+/// #[main]
+/// fn main(host: Iroha, context: Context) -> Result<Json, ValidationFail> {
+///     todo!()
+/// }
+/// ```
+#[manyhow]
+#[proc_macro_attribute]
+pub fn main(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut emitter = Emitter::new();
+
+    if !attr.is_empty() {
+        emit!(emitter, "#[main] attribute does not accept arguments");
+    }
+
+    let Some(item) = emitter.handle(syn::parse2(item)) else {
+        return emitter.finish_token_stream();
+    };
+
+    let result = entrypoint::impl_entrypoint(&mut emitter, item);
+
+    emitter.finish_token_stream_with(result)
+}
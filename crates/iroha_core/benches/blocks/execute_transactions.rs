@@ -0,0 +1,103 @@
+#[allow(dead_code)]
+#[path = "./common.rs"]
+mod common;
+
+use std::num::NonZeroUsize;
+
+use common::*;
+use iroha_core::{
+    block::{BlockBuilder, NewBlock},
+    prelude::*,
+    state::State,
+    sumeragi::network_topology::Topology,
+};
+use iroha_data_model::{isi::InstructionBox, prelude::*};
+use iroha_test_samples::gen_account_in;
+
+pub struct StateExecuteTransactions {
+    state: State,
+    block: NewBlock,
+}
+
+impl StateExecuteTransactions {
+    /// Create [`State`] with funded accounts and a block of independent transfers between them
+    ///
+    /// # Panics
+    ///
+    /// - Failed to parse [`AssetDefinitionId`]
+    /// - Failed to create block populating the state
+    pub fn setup(rt: &tokio::runtime::Handle, execution_workers: NonZeroUsize) -> Self {
+        let transfers = 100;
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+        let (peer_public_key, peer_private_key) = KeyPair::random().into_parts();
+        let topology = Topology::new(vec![PeerId::new(peer_public_key)]);
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let state = build_state(rt, &alice_id).with_execution_workers(execution_workers);
+
+        let rose: AssetDefinitionId = "rose#wonderland".parse().unwrap();
+        let accounts = (0..2 * transfers)
+            .map(|_| gen_account_in("wonderland"))
+            .collect::<Vec<_>>();
+        let instructions =
+            [Register::asset_definition(AssetDefinition::numeric(rose.clone())).into()]
+                .into_iter()
+                .chain(accounts.iter().flat_map(|(account_id, _)| {
+                    [
+                        InstructionBox::from(Register::account(Account::new(account_id.clone()))),
+                        Mint::asset_numeric(100u32, AssetId::new(rose.clone(), account_id.clone()))
+                            .into(),
+                    ]
+                }))
+                .collect::<Vec<InstructionBox>>();
+        let (block, mut state_block) = create_block(
+            &state,
+            instructions,
+            alice_id,
+            alice_keypair.private_key(),
+            &topology,
+            &peer_private_key,
+        );
+        let _events = state_block.apply_without_execution(&block, topology.as_ref().to_owned());
+        state_block.commit();
+
+        let (max_clock_drift, tx_limits) = {
+            let state_view = state.view();
+            let params = state_view.world.parameters();
+            (params.sumeragi().max_clock_drift(), params.transaction)
+        };
+        // Pairs of accounts are disjoint, so no transfer conflicts with another one
+        let transactions = accounts
+            .chunks_exact(2)
+            .map(|pair| {
+                let [(from, key_pair), (to, _)] = pair else {
+                    unreachable!()
+                };
+                let transaction = TransactionBuilder::new(chain_id.clone(), from.clone())
+                    .with_instructions([Transfer::asset_numeric(
+                        AssetId::new(rose.clone(), from.clone()),
+                        10u32,
+                        to.clone(),
+                    )])
+                    .sign(key_pair.private_key());
+                AcceptedTransaction::accept(transaction, &chain_id, max_clock_drift, tx_limits)
+                    .unwrap()
+            })
+            .collect();
+        let block = BlockBuilder::new(transactions)
+            .chain(0, state.view().latest_block().as_deref())
+            .sign(&peer_private_key)
+            .unpack(|_| {});
+
+        Self { state, block }
+    }
+
+    /// Run benchmark body.
+    ///
+    /// # Panics
+    /// If any of the transfers is rejected
+    pub fn measure(Self { state, block }: Self) {
+        let mut state_block = state.block(block.header());
+        let block = block.categorize(&mut state_block).unpack(|_| {});
+        assert_eq!(block.as_ref().errors().count(), 0);
+    }
+}
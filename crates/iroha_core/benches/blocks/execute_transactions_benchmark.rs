@@ -0,0 +1,33 @@
+#![allow(missing_docs)]
+
+mod execute_transactions;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use execute_transactions::StateExecuteTransactions;
+use nonzero_ext::nonzero;
+
+fn execute_transactions(c: &mut Criterion) {
+    let rt = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .expect("Failed building the Runtime");
+
+    let mut group = c.benchmark_group("execute_transactions");
+    group.significance_level(0.1).sample_size(10);
+    for (name, execution_workers) in [
+        ("sequential", nonzero!(1_usize)),
+        ("parallel", nonzero!(4_usize)),
+    ] {
+        group.bench_function(name, |b| {
+            b.iter_batched(
+                || StateExecuteTransactions::setup(rt.handle(), execution_workers),
+                StateExecuteTransactions::measure,
+                criterion::BatchSize::SmallInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(state, execute_transactions);
+criterion_main!(state);
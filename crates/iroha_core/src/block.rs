@@ -6,7 +6,7 @@
 //!    [`Block`]s are organised into a linear sequence over time (also known as the block chain).
 use std::time::Duration;
 
-use iroha_crypto::{HashOf, KeyPair, MerkleTree};
+use iroha_crypto::{Algorithm, HashOf, KeyPair, MerkleTree};
 use iroha_data_model::{
     block::*,
     events::prelude::*,
@@ -56,6 +56,13 @@ pub enum BlockValidationError {
     },
     /// The transaction hash stored in the block header does not match the actual transaction hash
     TransactionHashMismatch,
+    /// Mismatch between the actual and expected hashes of the previous block trigger receipts. Expected: {expected:?}, actual: {actual:?}
+    PrevTriggerReceiptsHashMismatch {
+        /// Expected value
+        expected: Option<HashOf<MerkleTree<TriggerReceipt>>>,
+        /// Actual value
+        actual: Option<HashOf<MerkleTree<TriggerReceipt>>>,
+    },
     /// Error during transaction validation
     TransactionValidation(#[from] TransactionValidationError),
     /// Mismatch between the actual and expected topology. Expected: {expected:?}, actual: {actual:?}
@@ -75,6 +82,38 @@ pub enum BlockValidationError {
     BlockInThePast,
     /// Block's creation time is later than the current node local time
     BlockInTheFuture,
+    /// Block's randomness isn't derived by the leader from the randomness seed of the previous block
+    InvalidRandomness,
+    /// Block records equivocation of an unknown peer, expired or duplicate equivocation
+    InvalidEquivocation,
+    /// Block activates a validator set other than the staged one or before its epoch is over
+    InvalidValidatorSet,
+}
+
+impl BlockValidationError {
+    /// Whether the block is invalid in itself rather than out of line with the state
+    /// of this peer, e.g. because the block is stale or this peer lags behind.
+    ///
+    /// Only relaying a provably invalid block is a misbehavior of the peer.
+    pub fn proves_invalid_block(&self) -> bool {
+        match self {
+            Self::TransactionHashMismatch
+            | Self::PrevTriggerReceiptsHashMismatch { .. }
+            | Self::SignatureVerification(_)
+            | Self::InvalidGenesis(_)
+            | Self::BlockInThePast
+            | Self::InvalidRandomness
+            | Self::InvalidEquivocation
+            | Self::InvalidValidatorSet => true,
+            Self::HasCommittedTransactions
+            | Self::PrevBlockHashMismatch { .. }
+            | Self::PrevBlockHeightMismatch { .. }
+            | Self::TransactionValidation(_)
+            | Self::TopologyMismatch { .. }
+            | Self::ViewChangeIndexTooLarge
+            | Self::BlockInTheFuture => false,
+        }
+    }
 }
 
 /// Error during signature verification
@@ -112,9 +151,16 @@ pub enum InvalidGenesisError {
 #[derive(Debug, Clone)]
 pub struct BlockBuilder<B>(B);
 
-mod pending {
-    use std::time::SystemTime;
+/// Whether a key of the `algorithm` has exactly one valid signature of any message.
+///
+/// BLS signatures are computed without a nonce, while a signer can pick any nonce
+/// for an Ed25519 or ECDSA signature and get another valid signature.
+fn has_unique_signatures(algorithm: Algorithm) -> bool {
+    matches!(algorithm, Algorithm::BlsNormal | Algorithm::BlsSmall)
+}
 
+mod pending {
+    use iroha_primitives::time::TimeSource;
     use nonzero_ext::nonzero;
 
     use super::*;
@@ -127,6 +173,12 @@ mod pending {
     pub struct Pending {
         /// Collection of transactions which have been accepted.
         transactions: Vec<AcceptedTransaction>,
+        /// Evidence of peers' equivocation to be recorded in the block.
+        equivocations: Vec<EquivocationEvidence>,
+        /// Staged validator set to be activated by the block.
+        validator_set: Option<Vec<PeerId>>,
+        /// Clock which the creation time of the block is taken from.
+        time_source: TimeSource,
     }
 
     impl BlockBuilder<Pending> {
@@ -135,15 +187,44 @@ mod pending {
         /// Create [`Self`]
         #[inline]
         pub fn new(transactions: Vec<AcceptedTransaction>) -> Self {
+            Self::new_with_time_source(transactions, TimeSource::new_system())
+        }
+
+        /// Create [`Self`] whose creation time is taken from the `time_source`
+        #[inline]
+        pub fn new_with_time_source(
+            transactions: Vec<AcceptedTransaction>,
+            time_source: TimeSource,
+        ) -> Self {
             // Note that empty block is allowed
 
-            Self(Pending { transactions })
+            Self(Pending {
+                transactions,
+                equivocations: Vec::new(),
+                validator_set: None,
+                time_source,
+            })
+        }
+
+        /// Record evidence of peers' equivocation in the block
+        #[must_use]
+        pub fn with_equivocations(mut self, equivocations: Vec<EquivocationEvidence>) -> Self {
+            self.0.equivocations = equivocations;
+            self
+        }
+
+        /// Activate the staged validator set before the transactions of the block are executed
+        #[must_use]
+        pub fn with_validator_set(mut self, validator_set: Option<Vec<PeerId>>) -> Self {
+            self.0.validator_set = validator_set;
+            self
         }
 
         fn make_header(
             prev_block: Option<&SignedBlock>,
             view_change_index: usize,
             transactions: &[AcceptedTransaction],
+            time_source: &TimeSource,
         ) -> BlockHeader {
             let prev_block_time =
                 prev_block.map_or(Duration::ZERO, |block| block.header().creation_time());
@@ -156,9 +237,7 @@ mod pending {
                 // Empty block is allowed
                 .unwrap_or(Duration::ZERO);
 
-            let now = SystemTime::now()
-                .duration_since(SystemTime::UNIX_EPOCH)
-                .unwrap();
+            let now = time_source.get_unix_time();
 
             // NOTE: Lower time bound must always be upheld for a valid block
             // If the clock has drifted too far this block will be rejected
@@ -187,6 +266,7 @@ mod pending {
                     .map(SignedTransaction::hash)
                     .collect::<MerkleTree<_>>()
                     .hash(),
+                prev_trigger_receipts_hash: prev_block.and_then(SignedBlock::trigger_receipts_hash),
                 creation_time_ms: creation_time
                     .as_millis()
                     .try_into()
@@ -194,6 +274,11 @@ mod pending {
                 view_change_index: view_change_index
                     .try_into()
                     .expect("View change index should fit into u32"),
+                // Derived from the randomness seed when the block is signed
+                randomness: None,
+                // Set when the block is chained
+                equivocations_hash: None,
+                validator_set_hash: None,
             }
         }
 
@@ -205,9 +290,21 @@ mod pending {
             view_change_index: usize,
             latest_block: Option<&SignedBlock>,
         ) -> BlockBuilder<Chained> {
+            let mut header = Self::make_header(
+                latest_block,
+                view_change_index,
+                &self.0.transactions,
+                &self.0.time_source,
+            );
+            header.equivocations_hash =
+                (!self.0.equivocations.is_empty()).then(|| HashOf::new(&self.0.equivocations));
+            header.validator_set_hash = self.0.validator_set.as_ref().map(HashOf::new);
+
             BlockBuilder(Chained {
-                header: Self::make_header(latest_block, view_change_index, &self.0.transactions),
+                header,
                 transactions: self.0.transactions,
+                randomness_seed: latest_block.map(|block| block.header().randomness_seed()),
+                equivocations: self.0.equivocations,
             })
         }
     }
@@ -224,17 +321,39 @@ mod chained {
     pub struct Chained {
         pub(super) header: BlockHeader,
         pub(super) transactions: Vec<AcceptedTransaction>,
+        /// None for the genesis block
+        pub(super) randomness_seed: Option<Hash>,
+        pub(super) equivocations: Vec<EquivocationEvidence>,
     }
 
     impl BlockBuilder<Chained> {
         /// Sign this block and get [`NewBlock`].
+        ///
+        /// A leader with a BLS key derives the randomness of the block from its signature over
+        /// the randomness seed of the previous block. The key has only one valid signature of
+        /// the seed, so the leader can't choose the randomness, only withhold the block.
+        /// Signatures of other algorithms aren't unique, so a leader with a key of another
+        /// algorithm carries the seed over as the randomness of the block.
         pub fn sign(self, private_key: &PrivateKey) -> WithEvents<NewBlock> {
-            let signature = BlockSignature(0, SignatureOf::new(private_key, &self.0.header));
+            let mut header = self.0.header;
+            let randomness_proof = self
+                .0
+                .randomness_seed
+                .filter(|_| has_unique_signatures(private_key.algorithm()))
+                .map(|seed| SignatureOf::new(private_key, &seed));
+            header.randomness = randomness_proof
+                .as_ref()
+                .map_or(self.0.randomness_seed, |proof| {
+                    Some(Hash::new(proof.payload()))
+                });
+            let signature = BlockSignature(0, SignatureOf::new(private_key, &header));
 
             WithEvents::new(NewBlock {
                 signature,
-                header: self.0.header,
+                header,
                 transactions: self.0.transactions,
+                randomness_proof,
+                equivocations: self.0.equivocations,
             })
         }
     }
@@ -243,8 +362,10 @@ mod chained {
 mod new {
     use std::collections::BTreeMap;
 
+    use iroha_crypto::SignatureOf;
+
     use super::*;
-    use crate::{smartcontracts::wasm::cache::WasmCache, state::StateBlock};
+    use crate::state::StateBlock;
 
     /// First stage in the life-cycle of a [`Block`].
     ///
@@ -254,23 +375,27 @@ mod new {
         pub(super) signature: BlockSignature,
         pub(super) header: BlockHeader,
         pub(super) transactions: Vec<AcceptedTransaction>,
+        pub(super) randomness_proof: Option<SignatureOf<Hash>>,
+        pub(super) equivocations: Vec<EquivocationEvidence>,
     }
 
     impl NewBlock {
         /// Categorize transactions of this block to produce a [`ValidBlock`]
         pub fn categorize(self, state_block: &mut StateBlock<'_>) -> WithEvents<ValidBlock> {
-            let mut wasm_cache = WasmCache::new();
-            let errors = self
-                .transactions
-                .iter()
-                // FIXME: Redundant clone
-                .cloned()
+            if self.header.validator_set_hash.is_some() {
+                state_block.activate_validators();
+            }
+
+            let errors = state_block
+                .validate_transactions(&self.transactions)
+                .into_iter()
+                .zip(&self.transactions)
                 .enumerate()
-                .fold(BTreeMap::new(), |mut acc, (idx, tx)| {
-                    if let Err((rejected_tx, error)) = state_block.validate(tx, &mut wasm_cache) {
+                .fold(BTreeMap::new(), |mut acc, (idx, (result, tx))| {
+                    if let Err(error) = result {
                         iroha_logger::debug!(
                             block=%self.header.hash(),
-                            tx=%rejected_tx.hash(),
+                            tx=%tx.as_ref().hash(),
                             reason=?error,
                             "Transaction rejected"
                         );
@@ -309,6 +434,8 @@ mod new {
                 signature,
                 header,
                 transactions: self.transactions,
+                randomness_proof: self.randomness_proof,
+                equivocations: self.equivocations,
             }
         }
     }
@@ -319,21 +446,26 @@ mod new {
                 block.signature,
                 block.header,
                 block.transactions.into_iter().map(Into::into),
+                block.randomness_proof,
+                block.equivocations,
             )
         }
     }
 }
 
 mod valid {
-    use std::time::SystemTime;
+    use std::collections::BTreeSet;
 
     use commit::CommittedBlock;
+    use iroha_crypto::{Algorithm, SignatureOf};
     use iroha_data_model::{account::AccountId, events::pipeline::PipelineEventBox, ChainId};
+    use iroha_primitives::time::TimeSource;
     use mv::storage::StorageReadOnly;
 
     use super::*;
     use crate::{
-        smartcontracts::wasm::cache::WasmCache, state::StateBlock, sumeragi::network_topology::Role,
+        state::StateBlock,
+        sumeragi::{equivocation, network_topology::Role},
     };
 
     /// Block that was validated and accepted
@@ -341,6 +473,9 @@ mod valid {
     #[repr(transparent)]
     pub struct ValidBlock(pub(super) SignedBlock);
 
+    /// Separate signatures of a block along with its aggregated signature
+    pub type BlockSignatures = (Vec<BlockSignature>, Option<AggregateBlockSignature>);
+
     impl ValidBlock {
         fn verify_leader_signature(
             block: &SignedBlock,
@@ -349,6 +484,14 @@ mod valid {
             use SignatureVerificationError::LeaderMissing;
             let leader_idx = topology.leader_index();
 
+            if block
+                .aggregate_signature()
+                .is_some_and(|aggregate| aggregate.is_signed_by(leader_idx as u64))
+            {
+                return Self::verify_aggregate_signature(block, topology)
+                    .map_err(|_err| LeaderMissing);
+            }
+
             let signature = block.signatures().next().ok_or(LeaderMissing)?;
             if leader_idx != usize::try_from(signature.0).map_err(|_err| LeaderMissing)? {
                 return Err(LeaderMissing);
@@ -362,6 +505,75 @@ mod valid {
             Ok(())
         }
 
+        /// Randomness must be derived from the unique signature of a leader with a BLS key
+        /// or carried over by any other leader, see [`BlockBuilder::<Chained>::sign`]
+        fn verify_randomness(
+            block: &SignedBlock,
+            prev_block: &SignedBlock,
+            topology: &Topology,
+        ) -> Result<(), BlockValidationError> {
+            let leader_key = topology.leader().public_key();
+            let seed = prev_block.header().randomness_seed();
+
+            let randomness = match block.randomness_proof() {
+                Some(proof) if has_unique_signatures(leader_key.algorithm()) => {
+                    proof
+                        .verify(leader_key, &seed)
+                        .map_err(|_err| BlockValidationError::InvalidRandomness)?;
+                    Hash::new(proof.payload())
+                }
+                None if !has_unique_signatures(leader_key.algorithm()) => seed,
+                _ => return Err(BlockValidationError::InvalidRandomness),
+            };
+            if block.header().randomness != Some(randomness) {
+                return Err(BlockValidationError::InvalidRandomness);
+            }
+
+            Ok(())
+        }
+
+        /// Evidence itself is verified when the block is decoded
+        fn verify_equivocations(
+            block: &SignedBlock,
+            state: &impl StateReadOnly,
+        ) -> Result<(), BlockValidationError> {
+            let mut equivocations = BTreeSet::new();
+
+            for evidence in block.equivocations() {
+                if equivocation::is_expired(evidence, block.header().height().get()) {
+                    return Err(BlockValidationError::InvalidEquivocation);
+                }
+                if !state.world().peers().contains(evidence.peer()) {
+                    return Err(BlockValidationError::InvalidEquivocation);
+                }
+                if !equivocations.insert(evidence) {
+                    return Err(BlockValidationError::InvalidEquivocation);
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Block may only activate the staged validator set once the epoch it was staged in is over
+        fn verify_validator_set(
+            block: &SignedBlock,
+            state: &impl StateReadOnly,
+        ) -> Result<(), BlockValidationError> {
+            let Some(hash) = block.header().validator_set_hash else {
+                return Ok(());
+            };
+
+            let pending = state
+                .world()
+                .activatable_validators(block.header().height())
+                .ok_or(BlockValidationError::InvalidValidatorSet)?;
+            if HashOf::new(pending.peers()) != hash {
+                return Err(BlockValidationError::InvalidValidatorSet);
+            }
+
+            Ok(())
+        }
+
         fn verify_validator_signatures(
             block: &SignedBlock,
             topology: &Topology,
@@ -390,7 +602,45 @@ mod valid {
                     Ok(())
                 })?;
 
-            Ok(())
+            Self::verify_aggregate_signature(block, topology)
+        }
+
+        fn verify_aggregate_signature(
+            block: &SignedBlock,
+            topology: &Topology,
+        ) -> Result<(), SignatureVerificationError> {
+            use SignatureVerificationError::{UnknownSignatory, UnknownSignature};
+
+            let Some(aggregate_signature) = block.aggregate_signature() else {
+                return Ok(());
+            };
+
+            let mut valid_roles = vec![Role::Leader, Role::ValidatingPeer, Role::ProxyTail];
+            if topology.view_change_index() >= 1 {
+                valid_roles.push(Role::ObservingPeer);
+            }
+            let valid_signatories = topology
+                .filter_signatories_by_roles(&valid_roles, aggregate_signature.signatories())
+                .count();
+            if valid_signatories != aggregate_signature.signatories().count() {
+                return Err(UnknownSignatory);
+            }
+
+            let public_keys = aggregate_signature
+                .signatories()
+                .map(|signatory| {
+                    usize::try_from(signatory)
+                        .ok()
+                        .and_then(|signatory| topology.as_ref().get(signatory))
+                        .map(PeerId::public_key)
+                        .ok_or(UnknownSignatory)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            aggregate_signature
+                .payload()
+                .verify_aggregate(public_keys, &block.payload().header)
+                .map_err(|_err| UnknownSignature)
         }
 
         fn verify_no_undefined_signatures(
@@ -415,6 +665,14 @@ mod valid {
             use SignatureVerificationError::ProxyTailMissing;
             let proxy_tail_idx = topology.proxy_tail_index();
 
+            if block
+                .aggregate_signature()
+                .is_some_and(|aggregate| aggregate.is_signed_by(proxy_tail_idx as u64))
+            {
+                return Self::verify_aggregate_signature(block, topology)
+                    .map_err(|_err| ProxyTailMissing);
+            }
+
             let signature = block.signatures().next_back().ok_or(ProxyTailMissing)?;
             if proxy_tail_idx != usize::try_from(signature.0).map_err(|_err| ProxyTailMissing)? {
                 return Err(ProxyTailMissing);
@@ -447,18 +705,24 @@ mod valid {
             topology: &Topology,
             expected_chain_id: &ChainId,
             genesis_account: &AccountId,
+            time_source: &TimeSource,
             state_block: &mut StateBlock<'_>,
         ) -> WithEvents<Result<ValidBlock, (SignedBlock, BlockValidationError)>> {
-            if let Err(error) =
-                Self::validate_header(&block, topology, genesis_account, state_block, false)
-            {
+            if let Err(error) = Self::validate_header(
+                &block,
+                topology,
+                genesis_account,
+                time_source,
+                state_block,
+                false,
+            ) {
                 return WithEvents::new(Err((block, error)));
             }
 
             if let Err(error) =
                 Self::categorize(&mut block, expected_chain_id, genesis_account, state_block)
             {
-                return WithEvents::new(Err((block, error.into())));
+                return WithEvents::new(Err((block, error)));
             }
 
             WithEvents::new(Ok(ValidBlock(block)))
@@ -468,19 +732,26 @@ mod valid {
         /// * Block header will be validated with read-only state
         /// * If block header is valid, `voting_block` will be released,
         ///   and transactions will be validated with write state
+        #[allow(clippy::too_many_arguments)]
         pub fn validate_keep_voting_block<'state>(
             mut block: SignedBlock,
             topology: &Topology,
             expected_chain_id: &ChainId,
             genesis_account: &AccountId,
+            time_source: &TimeSource,
             state: &'state State,
             voting_block: &mut Option<VotingBlock>,
             soft_fork: bool,
         ) -> WithEvents<Result<(ValidBlock, StateBlock<'state>), (SignedBlock, BlockValidationError)>>
         {
-            if let Err(error) =
-                Self::validate_header(&block, topology, genesis_account, &state.view(), soft_fork)
-            {
+            if let Err(error) = Self::validate_header(
+                &block,
+                topology,
+                genesis_account,
+                time_source,
+                &state.view(),
+                soft_fork,
+            ) {
                 return WithEvents::new(Err((block, error)));
             }
 
@@ -498,7 +769,7 @@ mod valid {
                 genesis_account,
                 &mut state_block,
             ) {
-                return WithEvents::new(Err((block, error.into())));
+                return WithEvents::new(Err((block, error)));
             }
 
             WithEvents::new(Ok((ValidBlock(block), state_block)))
@@ -508,6 +779,7 @@ mod valid {
             block: &SignedBlock,
             topology: &Topology,
             genesis_account: &AccountId,
+            time_source: &TimeSource,
             state: &impl StateReadOnly,
             soft_fork: bool,
         ) -> Result<(), BlockValidationError> {
@@ -533,9 +805,7 @@ mod valid {
                 });
             }
 
-            let now = SystemTime::now()
-                .duration_since(SystemTime::UNIX_EPOCH)
-                .unwrap();
+            let now = time_source.get_unix_time();
             let max_clock_drift = state.world().parameters().sumeragi.max_clock_drift();
             if block.header().creation_time().saturating_sub(now) > max_clock_drift {
                 return Err(BlockValidationError::BlockInTheFuture);
@@ -558,22 +828,33 @@ mod valid {
             if block.header().is_genesis() {
                 check_genesis_block(block, genesis_account)?;
             } else {
-                let prev_block_time = if soft_fork {
+                let prev_block = if soft_fork {
                     state.prev_block()
                 } else {
                     state.latest_block()
                 }
-                .expect("INTERNAL BUG: Genesis not committed")
-                .header()
-                .creation_time();
+                .expect("INTERNAL BUG: Genesis not committed");
 
-                if block.header().creation_time() <= prev_block_time {
+                if block.header().creation_time() <= prev_block.header().creation_time() {
                     return Err(BlockValidationError::BlockInThePast);
                 }
 
+                // Receipts are produced by this peer when applying the previous block
+                let expected_trigger_receipts_hash = prev_block.trigger_receipts_hash();
+                let actual_trigger_receipts_hash = block.header().prev_trigger_receipts_hash;
+
+                if expected_trigger_receipts_hash != actual_trigger_receipts_hash {
+                    return Err(BlockValidationError::PrevTriggerReceiptsHashMismatch {
+                        expected: expected_trigger_receipts_hash,
+                        actual: actual_trigger_receipts_hash,
+                    });
+                }
+
                 Self::verify_leader_signature(block, topology)?;
+                Self::verify_randomness(block, &prev_block, topology)?;
                 Self::verify_validator_signatures(block, topology)?;
                 Self::verify_no_undefined_signatures(block, topology)?;
+                Self::verify_equivocations(block, state)?;
             }
 
             if block.transactions().any(|tx| {
@@ -594,20 +875,24 @@ mod valid {
             expected_chain_id: &ChainId,
             genesis_account: &AccountId,
             state_block: &mut StateBlock<'_>,
-        ) -> Result<(), TransactionValidationError> {
+        ) -> Result<(), BlockValidationError> {
+            // Validator set is changed before the transactions, so that they see the new one
+            if block.header().validator_set_hash.is_some() {
+                Self::verify_validator_set(block, &*state_block)?;
+                state_block.activate_validators();
+            }
+
             let (max_clock_drift, tx_limits) = {
                 let params = state_block.world().parameters();
                 (params.sumeragi().max_clock_drift(), params.transaction)
             };
 
-            let mut wasm_cache = WasmCache::new();
-            let errors = block
+            let transactions = block
                 .transactions()
                 // FIXME: Redundant clone
                 .cloned()
-                .enumerate()
-                .try_fold(Vec::new(), |mut acc, (idx, tx)| {
-                    let accepted_tx = if block.header().is_genesis() {
+                .map(|tx| {
+                    if block.header().is_genesis() {
                         AcceptedTransaction::accept_genesis(
                             tx,
                             expected_chain_id,
@@ -621,23 +906,28 @@ mod valid {
                             max_clock_drift,
                             tx_limits,
                         )
-                    }?;
-
-                    if let Err((rejected_tx, error)) =
-                        state_block.validate(accepted_tx, &mut wasm_cache)
-                    {
-                        iroha_logger::debug!(
-                            tx=%rejected_tx.hash(),
-                            block=%block.hash(),
-                            reason=?error,
-                            "Transaction rejected"
-                        );
-
-                        acc.push((idx, error));
                     }
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(TransactionValidationError::from)?;
 
-                    Ok::<_, TransactionValidationError>(acc)
-                })?;
+            let errors = state_block
+                .validate_transactions(&transactions)
+                .into_iter()
+                .zip(&transactions)
+                .enumerate()
+                .filter_map(|(idx, (result, tx))| {
+                    let error = result.err()?;
+                    iroha_logger::debug!(
+                        tx=%tx.as_ref().hash(),
+                        block=%block.hash(),
+                        reason=?error,
+                        "Transaction rejected"
+                    );
+
+                    Some((idx, error))
+                })
+                .collect::<Vec<_>>();
 
             block.set_transaction_errors(errors);
 
@@ -675,7 +965,8 @@ mod valid {
             self.0.add_signature(signature).map_err(|_err| Other)
         }
 
-        /// Replace block's signatures. Returns previous block signatures
+        /// Replace block's signatures and aggregated signature.
+        /// Returns previous block signatures and aggregated signature
         ///
         /// # Errors
         ///
@@ -686,9 +977,12 @@ mod valid {
         pub fn replace_signatures(
             &mut self,
             signatures: Vec<BlockSignature>,
+            aggregate_signature: Option<AggregateBlockSignature>,
             topology: &Topology,
-        ) -> WithEvents<Result<Vec<BlockSignature>, SignatureVerificationError>> {
-            let Ok(prev_signatures) = self.0.replace_signatures(signatures) else {
+        ) -> WithEvents<Result<BlockSignatures, SignatureVerificationError>> {
+            let Ok((prev_signatures, prev_aggregate_signature)) =
+                self.0.replace_signatures(signatures, aggregate_signature)
+            else {
                 return WithEvents::new(Err(SignatureVerificationError::Other));
             };
 
@@ -697,16 +991,70 @@ mod valid {
                 .and_then(|()| Self::verify_no_undefined_signatures(self.as_ref(), topology))
             {
                 self.0
-                    .replace_signatures(prev_signatures)
+                    .replace_signatures(prev_signatures, prev_aggregate_signature)
                     .expect("INTERNAL BUG: invalid signatures in block");
                 Err(err)
             } else {
-                Ok(prev_signatures)
+                Ok((prev_signatures, prev_aggregate_signature))
             };
 
             WithEvents::new(result)
         }
 
+        /// Aggregate signatures of peers with BLS keys into one signature.
+        ///
+        /// Only signatures of the BLS algorithm used by most of the signatories are aggregated.
+        /// Does nothing if there are less than two of them.
+        pub fn aggregate_signatures(&mut self, topology: &Topology) {
+            if self.0.aggregate_signature().is_some() {
+                return;
+            }
+
+            let algorithm_of = |signature: &BlockSignature| {
+                usize::try_from(signature.0)
+                    .ok()
+                    .and_then(|signatory| topology.as_ref().get(signatory))
+                    .map(|peer| peer.public_key().algorithm())
+            };
+            let count = |algorithm| {
+                self.0
+                    .signatures()
+                    .filter(|signature| algorithm_of(signature) == Some(algorithm))
+                    .count()
+            };
+            let algorithm = if count(Algorithm::BlsSmall) > count(Algorithm::BlsNormal) {
+                Algorithm::BlsSmall
+            } else {
+                Algorithm::BlsNormal
+            };
+
+            let (aggregated, separate): (Vec<_>, Vec<_>) = self
+                .0
+                .signatures()
+                .cloned()
+                .partition(|signature| algorithm_of(signature) == Some(algorithm));
+            if aggregated.len() < 2 {
+                return;
+            }
+
+            let signature = SignatureOf::aggregate(aggregated.iter().map(|signature| {
+                let signatory = usize::try_from(signature.0)
+                    .ok()
+                    .and_then(|signatory| topology.as_ref().get(signatory))
+                    .expect("INTERNAL BUG: Signatory of the aggregated signature not in topology");
+                (signatory.public_key(), &signature.1)
+            }))
+            .expect("INTERNAL BUG: Signatures of the same algorithm failed to aggregate");
+            let aggregate_signature = AggregateBlockSignature::new(
+                aggregated.iter().map(BlockSignature::index),
+                signature,
+            );
+
+            self.0
+                .replace_signatures(separate, Some(aggregate_signature))
+                .expect("INTERNAL BUG: Aggregated signatures are duplicated");
+        }
+
         /// commit block to the store.
         ///
         /// # Errors
@@ -736,6 +1084,7 @@ mod valid {
             topology: &Topology,
             expected_chain_id: &ChainId,
             genesis_account: &AccountId,
+            time_source: &TimeSource,
             state: &'state State,
             voting_block: &mut Option<VotingBlock>,
             soft_fork: bool,
@@ -753,6 +1102,7 @@ mod valid {
                     topology,
                     expected_chain_id,
                     genesis_account,
+                    time_source,
                     state,
                     voting_block,
                     soft_fork,
@@ -772,7 +1122,7 @@ mod valid {
             if !block.header().is_genesis() {
                 Self::verify_proxy_tail_signature(block, topology)?;
 
-                let votes_count = block.signatures().len();
+                let votes_count = block.signatories().count();
                 if votes_count < topology.min_votes_for_commit() {
                     return Err(SignatureVerificationError::NotEnoughSignatures {
                         votes_count,
@@ -810,13 +1160,19 @@ mod valid {
                 height: nonzero_ext::nonzero!(2_u64),
                 prev_block_hash: None,
                 transactions_hash: Some(transactions_hash),
+                prev_trigger_receipts_hash: None,
                 creation_time_ms: 0,
                 view_change_index: 0,
+                randomness: None,
+                equivocations_hash: None,
+                validator_set_hash: None,
             };
             f(&mut header);
             let unverified_block = BlockBuilder(Chained {
                 header,
                 transactions: Vec::new(),
+                randomness_seed: None,
+                equivocations: Vec::new(),
             })
             .sign(leader_private_key)
             .unpack(|_| {});
@@ -825,6 +1181,8 @@ mod valid {
                 unverified_block.signature,
                 unverified_block.header,
                 unverified_block.transactions.into_iter().map(Into::into),
+                unverified_block.randomness_proof,
+                unverified_block.equivocations,
             ))
         }
     }
@@ -850,6 +1208,9 @@ mod valid {
         let [signature] = signatures.as_slice() else {
             return Err(InvalidGenesisError::InvalidSignature);
         };
+        if block.aggregate_signature().is_some() {
+            return Err(InvalidGenesisError::InvalidSignature);
+        }
         signature
             .1
             .verify(&genesis_account.signatory, &block.payload().header)
@@ -866,8 +1227,6 @@ mod valid {
 
     #[cfg(test)]
     mod tests {
-        use iroha_crypto::SignatureOf;
-
         use super::*;
         use crate::sumeragi::network_topology::test_topology_with_keys;
 
@@ -902,6 +1261,164 @@ mod valid {
             let _ = block.commit(&topology).unpack(|_| {}).unwrap();
         }
 
+        #[test]
+        fn aggregated_signature_verification_ok() {
+            let key_pairs =
+                core::iter::repeat_with(|| KeyPair::random_with_algorithm(Algorithm::BlsNormal))
+                    .take(7)
+                    .collect::<Vec<_>>();
+            let topology = test_topology_with_keys(&key_pairs);
+
+            let mut block = ValidBlock::new_dummy(key_pairs[0].private_key());
+            let payload = block.0.payload().clone();
+            key_pairs
+                .iter()
+                .enumerate()
+                .take(topology.min_votes_for_commit())
+                .skip(1)
+                .filter(|(i, _)| *i != 4)
+                .map(|(i, key_pair)| {
+                    BlockSignature(
+                        i as u64,
+                        SignatureOf::new(key_pair.private_key(), &payload.header),
+                    )
+                })
+                .try_for_each(|signature| block.add_signature(signature, &topology))
+                .expect("Failed to add signatures");
+
+            block.sign(&key_pairs[4], &topology);
+            block.aggregate_signatures(&topology);
+
+            assert_eq!(block.as_ref().signatures().len(), 0);
+            let aggregate_signature = block.as_ref().aggregate_signature().unwrap().clone();
+            assert!(aggregate_signature.signatories().eq(0..5));
+
+            // Proxy tail didn't sign the aggregated signature
+            let forged = AggregateBlockSignature::new(0..4, aggregate_signature.payload().clone());
+            assert_eq!(
+                block
+                    .replace_signatures(Vec::new(), Some(forged), &topology)
+                    .unpack(|_| {}),
+                Err(SignatureVerificationError::LeaderMissing)
+            );
+
+            let _ = block.commit(&topology).unpack(|_| {}).unwrap();
+        }
+
+        #[test]
+        fn forged_aggregated_signature_rejected() {
+            let key_pairs =
+                core::iter::repeat_with(|| KeyPair::random_with_algorithm(Algorithm::BlsNormal))
+                    .take(7)
+                    .collect::<Vec<_>>();
+            let topology = test_topology_with_keys(&key_pairs);
+
+            let mut block = ValidBlock::new_dummy(key_pairs[0].private_key());
+            let payload = block.0.payload().clone();
+
+            // Leader claims that the other validators signed the block along with it
+            let forged = AggregateBlockSignature::new(
+                0..5,
+                SignatureOf::new(key_pairs[0].private_key(), &payload.header),
+            );
+            assert_eq!(
+                block
+                    .replace_signatures(Vec::new(), Some(forged.clone()), &topology)
+                    .unpack(|_| {}),
+                Err(SignatureVerificationError::LeaderMissing)
+            );
+
+            block
+                .0
+                .replace_signatures(Vec::new(), Some(forged))
+                .expect("Signatories are unique");
+            assert_eq!(
+                ValidBlock::verify_leader_signature(block.as_ref(), &topology),
+                Err(SignatureVerificationError::LeaderMissing)
+            );
+            assert_eq!(
+                ValidBlock::verify_proxy_tail_signature(block.as_ref(), &topology),
+                Err(SignatureVerificationError::ProxyTailMissing)
+            );
+            assert!(block.commit(&topology).unpack(|_| {}).is_err());
+        }
+
+        fn new_block_after(
+            prev_block: &SignedBlock,
+            leader_private_key: &PrivateKey,
+        ) -> SignedBlock {
+            let header = BlockHeader {
+                height: nonzero_ext::nonzero!(3_u64),
+                ..prev_block.header()
+            };
+
+            BlockBuilder(Chained {
+                header,
+                transactions: Vec::new(),
+                randomness_seed: Some(prev_block.header().randomness_seed()),
+                equivocations: Vec::new(),
+            })
+            .sign(leader_private_key)
+            .unpack(|_| {})
+            .into()
+        }
+
+        #[test]
+        fn randomness_is_derived_from_unique_signature_of_leader() {
+            let key_pairs =
+                core::iter::repeat_with(|| KeyPair::random_with_algorithm(Algorithm::BlsNormal))
+                    .take(4)
+                    .collect::<Vec<_>>();
+            let topology = test_topology_with_keys(&key_pairs);
+            let prev_block: SignedBlock = ValidBlock::new_dummy(key_pairs[0].private_key()).into();
+            let seed = prev_block.header().randomness_seed();
+
+            let block = new_block_after(&prev_block, key_pairs[0].private_key());
+            assert!(block.randomness_proof().is_some());
+            assert_ne!(block.header().randomness(), Some(seed));
+            ValidBlock::verify_randomness(&block, &prev_block, &topology).unwrap();
+
+            // Randomness of a peer other than the leader
+            let block = new_block_after(&prev_block, key_pairs[1].private_key());
+            assert_eq!(
+                ValidBlock::verify_randomness(&block, &prev_block, &topology),
+                Err(BlockValidationError::InvalidRandomness)
+            );
+
+            // Leader with a BLS key mustn't carry the seed over
+            let block = new_block_after(&prev_block, KeyPair::random().private_key());
+            assert!(block.randomness_proof().is_none());
+            assert_eq!(
+                ValidBlock::verify_randomness(&block, &prev_block, &topology),
+                Err(BlockValidationError::InvalidRandomness)
+            );
+        }
+
+        #[test]
+        fn randomness_is_carried_over_by_leader_without_unique_signatures() {
+            let key_pairs = core::iter::repeat_with(KeyPair::random)
+                .take(4)
+                .collect::<Vec<_>>();
+            let topology = test_topology_with_keys(&key_pairs);
+            let prev_block: SignedBlock = ValidBlock::new_dummy(key_pairs[0].private_key()).into();
+            let seed = prev_block.header().randomness_seed();
+
+            let block = new_block_after(&prev_block, key_pairs[0].private_key());
+            assert!(block.randomness_proof().is_none());
+            assert_eq!(block.header().randomness(), Some(seed));
+            ValidBlock::verify_randomness(&block, &prev_block, &topology).unwrap();
+
+            // The leader could choose among many valid signatures
+            let block = new_block_after(
+                &prev_block,
+                KeyPair::random_with_algorithm(Algorithm::BlsNormal).private_key(),
+            );
+            assert_eq!(
+                ValidBlock::verify_randomness(&block, &prev_block, &topology),
+                Err(BlockValidationError::InvalidRandomness)
+            );
+        }
+
         #[test]
         fn signature_verification_consensus_not_required_ok() {
             let key_pairs = core::iter::repeat_with(KeyPair::random)
@@ -978,6 +1495,13 @@ mod commit {
     #[derive(Debug, Clone)]
     pub struct CommittedBlock(pub(super) ValidBlock);
 
+    impl CommittedBlock {
+        /// Store receipts of the triggers executed after the block was applied
+        pub(crate) fn set_trigger_receipts(&mut self, receipts: Vec<TriggerReceipt>) {
+            self.0 .0.set_trigger_receipts(receipts);
+        }
+    }
+
     impl From<CommittedBlock> for ValidBlock {
         fn from(source: CommittedBlock) -> Self {
             ValidBlock(source.0.into())
@@ -1045,11 +1569,21 @@ mod event {
             }
         }
     }
-    impl WithEvents<Result<Vec<BlockSignature>, SignatureVerificationError>> {
+    impl
+        WithEvents<
+            Result<
+                (Vec<BlockSignature>, Option<AggregateBlockSignature>),
+                SignatureVerificationError,
+            >,
+        >
+    {
         pub fn unpack<F: Fn(PipelineEventBox)>(
             self,
             f: F,
-        ) -> Result<Vec<BlockSignature>, SignatureVerificationError> {
+        ) -> Result<
+            (Vec<BlockSignature>, Option<AggregateBlockSignature>),
+            SignatureVerificationError,
+        > {
             match self.0 {
                 Ok(ok) => Ok(ok),
                 Err(err) => Err(WithEvents(err).unpack(f)),
@@ -1140,6 +1674,7 @@ mod event {
 mod tests {
     use iroha_data_model::prelude::*;
     use iroha_genesis::GENESIS_DOMAIN_ID;
+    use iroha_primitives::time::TimeSource;
     use iroha_test_samples::gen_account_in;
 
     use super::*;
@@ -1397,6 +1932,7 @@ mod tests {
             &topology,
             &chain_id,
             &genesis_correct_account_id,
+            &TimeSource::new_system(),
             &mut state_block,
         )
         .unpack(|_| {})
@@ -30,16 +30,16 @@ use crate::{
 /// [`BlockSynchronizer`] actor handle.
 #[derive(Clone)]
 pub struct BlockSynchronizerHandle {
-    message_sender: mpsc::Sender<message::Message>,
+    message_sender: mpsc::Sender<(message::Message, PeerId)>,
 }
 
 impl BlockSynchronizerHandle {
-    /// Send [`message::Message`] to [`BlockSynchronizer`] actor.
+    /// Send [`message::Message`] received from `peer_id` to [`BlockSynchronizer`] actor.
     ///
     /// # Errors
     /// Fail if [`BlockSynchronizer`] actor is shutdown.
-    pub async fn message(&self, message: message::Message) {
-        self.message_sender.send(message).await.expect(
+    pub async fn message(&self, message: message::Message, peer_id: PeerId) {
+        self.message_sender.send((message, peer_id)).await.expect(
             "BlockSynchronizer must handle messages until there is at least one handle to it",
         )
     }
@@ -74,15 +74,15 @@ impl BlockSynchronizer {
     /// [`Self`] task.
     async fn run(
         mut self,
-        mut message_receiver: mpsc::Receiver<message::Message>,
+        mut message_receiver: mpsc::Receiver<(message::Message, PeerId)>,
         shutdown_signal: ShutdownSignal,
     ) {
         let mut gossip_period = tokio::time::interval(self.gossip_period);
         loop {
             tokio::select! {
                 _ = gossip_period.tick() => self.request_block().await,
-                Some(msg) = message_receiver.recv() => {
-                    msg.handle_message(&mut self).await;
+                Some((msg, peer_id)) = message_receiver.recv() => {
+                    msg.handle_message(&mut self, &peer_id).await;
                 }
                 () = shutdown_signal.receive() => {
                     debug!("Shutting down block sync");
@@ -135,6 +135,7 @@ impl BlockSynchronizer {
                 .iter()
                 .map(|(_height, hash)| *hash)
                 .collect(),
+            self.gossip_size,
         ))
         .send_to(&self.network, peer_id)
         .await;
@@ -179,6 +180,8 @@ pub mod message {
         pub latest_hash: Option<HashOf<BlockHeader>>,
         /// The block hashes already seen
         pub seen_blocks: BTreeSet<HashOf<BlockHeader>>,
+        /// Maximum number of blocks to share in response
+        pub max_blocks: NonZeroU32,
     }
 
     impl GetBlocksAfter {
@@ -188,12 +191,14 @@ pub mod message {
             prev_hash: Option<HashOf<BlockHeader>>,
             latest_hash: Option<HashOf<BlockHeader>>,
             seen_blocks: BTreeSet<HashOf<BlockHeader>>,
+            max_blocks: NonZeroU32,
         ) -> Self {
             Self {
                 peer_id,
                 prev_hash,
                 latest_hash,
                 seen_blocks,
+                max_blocks,
             }
         }
     }
@@ -224,16 +229,26 @@ pub mod message {
     }
 
     impl Message {
-        /// Handles the incoming message.
+        /// Handles the incoming message received from `sender`.
         #[iroha_futures::telemetry_future]
-        pub(super) async fn handle_message(&self, block_sync: &mut BlockSynchronizer) {
+        pub(super) async fn handle_message(
+            &self,
+            block_sync: &mut BlockSynchronizer,
+            sender: &PeerId,
+        ) {
             match self {
                 Message::GetBlocksAfter(GetBlocksAfter {
                     peer_id,
                     prev_hash,
                     latest_hash,
                     seen_blocks,
+                    max_blocks,
                 }) => {
+                    if let Some(hash) = *latest_hash {
+                        // Lets the leader tell if the peer is synced before it joins the validators
+                        block_sync.sumeragi.peer_synced(sender.clone(), hash);
+                    }
+
                     let local_latest_block_hash = block_sync.state.view().latest_block_hash();
 
                     if *latest_hash == local_latest_block_hash
@@ -266,7 +281,7 @@ pub mod message {
                         .all_blocks(start_height)
                         .skip_while(|block| Some(block.hash()) == *latest_hash)
                         .skip_while(|block| seen_blocks.contains(&block.hash()))
-                        .take(block_sync.gossip_size.get() as usize)
+                        .take(block_sync.gossip_size.min(*max_blocks).get() as usize)
                         .map(|block| (*block).clone())
                         .collect::<Vec<_>>();
 
@@ -279,8 +294,23 @@ pub mod message {
                     }
                 }
                 Message::ShareBlocks(ShareBlocks { blocks, .. }) => {
+                    use iroha_p2p::scoring::Misbehavior;
+
                     use crate::sumeragi::message::BlockSyncUpdate;
 
+                    // Every request of this peer asks for at most `gossip_size` blocks
+                    if blocks.len() > block_sync.gossip_size.get() as usize {
+                        warn!(
+                            peer=%sender,
+                            blocks=blocks.len(),
+                            "Peer shared more blocks than requested"
+                        );
+                        block_sync
+                            .network
+                            .penalize(sender.clone(), Misbehavior::OversizedMessage);
+                        return;
+                    }
+
                     for block in blocks.clone() {
                         let height = block
                             .header()
@@ -289,7 +319,7 @@ pub mod message {
                             .expect("INTERNAL BUG: block height exceeds usize::MAX");
 
                         block_sync.seen_blocks.insert((height, block.hash()));
-                        let msg = BlockSyncUpdate::from(&block);
+                        let msg = BlockSyncUpdate::from(&block).with_peer(sender.clone());
                         block_sync.sumeragi.incoming_block_message(msg);
                     }
                 }
@@ -320,6 +350,7 @@ pub mod message {
             prev_hash: Option<HashOf<BlockHeader>>,
             latest_hash: Option<HashOf<BlockHeader>>,
             seen_blocks: BTreeSet<HashOf<BlockHeader>>,
+            max_blocks: NonZeroU32,
         }
 
         #[derive(Decode)]
@@ -347,6 +378,7 @@ pub mod message {
                     prev_hash: self.prev_hash,
                     latest_hash: self.latest_hash,
                     seen_blocks: self.seen_blocks,
+                    max_blocks: self.max_blocks,
                 })
             }
         }
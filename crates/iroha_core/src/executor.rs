@@ -7,6 +7,7 @@ use iroha_data_model::{
     account::AccountId,
     executor as data_model_executor,
     isi::InstructionBox,
+    prelude::{CustomQuery, Json},
     query::{AnyQueryBox, QueryRequest},
     transaction::{Executable, SignedTransaction},
     ValidationFail,
@@ -234,6 +235,43 @@ impl Executor {
         }
     }
 
+    /// Resolve a custom query defined by the executor.
+    ///
+    /// # Errors
+    ///
+    /// - Initial executor doesn't define custom queries
+    /// - Failed to prepare runtime for WASM execution;
+    /// - Failed to execute the entrypoint of the WASM blob;
+    /// - Executor failed to resolve the query.
+    pub fn execute_custom_query<S: StateReadOnly>(
+        &self,
+        state_ro: &S,
+        authority: &AccountId,
+        query: CustomQuery,
+    ) -> Result<Json, ValidationFail> {
+        trace!("Running custom query execution");
+
+        match self {
+            Self::Initial => Err(ValidationFail::NotPermitted(
+                "Initial executor doesn't define custom queries".to_owned(),
+            )),
+            Self::UserProvided(loaded_executor) => {
+                let runtime =
+                    wasm::RuntimeBuilder::<wasm::state::executor::ExecuteCustomQuery<S>>::new()
+                        .with_engine(state_ro.engine().clone()) // Cloning engine is cheap, see [`wasmtime::Engine`] docs
+                        .with_config(state_ro.world().parameters().executor)
+                        .build()?;
+
+                runtime.execute_executor_custom_query(
+                    state_ro,
+                    authority,
+                    &loaded_executor.module,
+                    query,
+                )?
+            }
+        }
+    }
+
     /// Migrate executor to a new user-provided one.
     ///
     /// Execute `migrate()` entrypoint of the `raw_executor` and set `self` to
@@ -823,6 +823,7 @@ mod tests {
         ChainId, Level,
     };
     use iroha_genesis::GenesisBuilder;
+    use iroha_primitives::time::TimeSource;
     use iroha_test_samples::gen_account_in;
     use nonzero_ext::nonzero;
     use tempfile::TempDir;
@@ -1124,6 +1125,7 @@ mod tests {
                 &topology,
                 &chain_id,
                 &genesis_id,
+                &TimeSource::new_system(),
                 &mut state_block,
             )
             .unpack(|_| {})
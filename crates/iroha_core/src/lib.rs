@@ -7,6 +7,7 @@ pub mod gossiper;
 pub mod kiso;
 pub mod kura;
 pub mod metrics;
+mod parallel;
 pub mod peers_gossiper;
 pub mod query;
 pub mod queue;
@@ -20,6 +21,7 @@ use core::time::Duration;
 
 use gossiper::TransactionGossip;
 use iroha_data_model::{events::EventBox, prelude::*};
+use iroha_p2p::lane::{Lane, Prioritized};
 use iroha_primitives::unique_vec::UniqueVec;
 use parity_scale_codec::{Decode, Encode};
 use tokio::sync::broadcast;
@@ -60,6 +62,17 @@ pub enum NetworkMessage {
     Health,
 }
 
+impl Prioritized for NetworkMessage {
+    fn lane(&self) -> Lane {
+        match self {
+            Self::SumeragiBlock(_) | Self::SumeragiControlFlow(_) => Lane::Consensus,
+            Self::BlockSync(_) => Lane::BlockSync,
+            Self::TransactionGossiper(_) => Lane::TransactionGossip,
+            Self::PeersGossiper(_) | Self::Health => Lane::PeerGossip,
+        }
+    }
+}
+
 pub mod role {
     //! Module with extension for [`RoleId`] to be stored inside state.
 
@@ -116,6 +129,25 @@ pub mod role {
     }
 }
 
+pub mod storage {
+    //! Module with the key of the persistent storage of smart contracts and triggers.
+
+    use derive_more::Constructor;
+    use iroha_data_model::storage::StorageOwner;
+    use serde::{Deserialize, Serialize};
+
+    /// Key of an entry in the persistent storage with the [`StorageOwner`] attached to it.
+    #[derive(
+        Debug, Clone, Constructor, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize,
+    )]
+    pub struct StorageKey {
+        /// Owner of the storage the entry belongs to.
+        pub owner: StorageOwner,
+        /// Key of the entry within the storage of the owner.
+        pub key: Vec<u8>,
+    }
+}
+
 pub mod prelude {
     //! Re-exports important traits and types. Meant to be glob imported when using `Iroha`.
 
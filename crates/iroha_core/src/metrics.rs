@@ -4,6 +4,7 @@ use std::{num::NonZeroUsize, sync::Arc, time::SystemTime};
 
 use eyre::{Result, WrapErr as _};
 use iroha_data_model::peer::Peer;
+use iroha_p2p::lane::Lane;
 use iroha_telemetry::metrics::Metrics;
 use mv::storage::StorageReadOnly;
 use parking_lot::Mutex;
@@ -52,7 +53,7 @@ impl MetricsReporter {
     ///
     /// # Panics
     /// - If either mutex is poisoned
-    #[allow(clippy::cast_precision_loss)]
+    #[allow(clippy::cast_precision_loss, clippy::too_many_lines)]
     pub fn update_metrics(&self) -> Result<()> {
         let online_peers_count: usize = self.network.online_peers(
             #[allow(clippy::disallowed_types)]
@@ -126,6 +127,32 @@ impl MetricsReporter {
         };
 
         self.metrics.connected_peers.set(online_peers_count as u64);
+        let traffic = self.network.statistics();
+        self.metrics
+            .p2p_dropped_messages
+            .set(traffic.dropped_messages());
+        self.metrics.p2p_dropped_bytes.set(traffic.dropped_bytes());
+        self.metrics.p2p_penalties.set(traffic.penalties());
+        self.metrics.p2p_banned_peers.set(traffic.banned_peers());
+        for lane in Lane::ALL {
+            self.metrics
+                .p2p_queue_depth
+                .with_label_values(&[lane.as_str()])
+                .set(traffic.queue_depth(lane));
+        }
+        let compression_input_bytes = traffic.compression_input_bytes();
+        let compression_output_bytes = traffic.compression_output_bytes();
+        self.metrics
+            .p2p_compression_input_bytes
+            .set(compression_input_bytes);
+        self.metrics
+            .p2p_compression_output_bytes
+            .set(compression_output_bytes);
+        if compression_input_bytes != 0 {
+            self.metrics
+                .p2p_compression_ratio
+                .set(compression_output_bytes as f64 / compression_input_bytes as f64);
+        }
 
         self.metrics
             .domains
@@ -0,0 +1,926 @@
+//! Optimistic parallel execution of the transactions of a block.
+//!
+//! The transactions are first executed speculatively by several workers.
+//! The workers share the state as it was before the transactions and every transaction
+//! writes to its own buffer on top of it, recording the keys which it reads and writes.
+//! Then the transactions are committed one by one in the order of the block.
+//! A transaction whose reads intersect with the writes of the transactions committed
+//! before it is executed again, otherwise the values it wrote are applied as they are.
+//! Therefore, the committed result is identical to sequential execution.
+//!
+//! Transactions which iterate over a storage after writing to it don't see their own writes
+//! in the buffer, so they are always executed again.
+
+use std::{
+    borrow::Borrow,
+    cell::{Cell, RefCell},
+    collections::{BTreeMap, BTreeSet},
+    ops::{Bound, Deref, DerefMut, RangeBounds},
+    thread,
+};
+
+use iroha_crypto::HashOf;
+use iroha_data_model::{
+    executor::ExecutorDataModel, parameter::Parameters, permission::Permissions, prelude::*,
+    role::RoleId, transaction::WasmSmartContract,
+};
+use mv::{
+    cell::{Block as CellBlock, Transaction as CellTransaction},
+    storage::{
+        Block as StorageBlock, Iter, RangeIter, StorageReadOnly, Transaction as StorageTransaction,
+    },
+};
+use parking_lot::Mutex;
+
+use crate::{
+    executor::Executor,
+    role::RoleIdWithOwner,
+    smartcontracts::{
+        triggers::{
+            set::{SetReadOnly, SetTransaction as TriggerSetTransaction, WasmSmartContractEntry},
+            specialized::LoadedAction,
+        },
+        wasm::cache::WasmCache,
+    },
+    state::{StateBlock, WorldTransaction},
+    storage::StorageKey,
+    tx::AcceptedTransaction,
+    Peers,
+};
+
+/// Keys of a storage read and written by a transaction
+struct StorageAccesses<K, V> {
+    /// Keys read, including the ones which weren't found
+    read: BTreeSet<K>,
+    /// Whether the transaction iterated over the storage or missed a key it couldn't record
+    read_all: bool,
+    /// Keys written with their values after the transaction, `None` if removed
+    written: BTreeMap<K, Option<V>>,
+    /// Whether the transaction iterated over the storage after writing to its buffer
+    /// and therefore didn't see its own writes
+    missed_writes: bool,
+}
+
+impl<K, V> Default for StorageAccesses<K, V> {
+    fn default() -> Self {
+        Self {
+            read: BTreeSet::new(),
+            read_all: false,
+            written: BTreeMap::new(),
+            missed_writes: false,
+        }
+    }
+}
+
+impl<K: mv::Key, V> StorageAccesses<K, V> {
+    /// Whether the transaction read any of the `written` keys or has to be executed again anyway
+    fn conflicts_with(&self, written: &BTreeSet<K>) -> bool {
+        if self.missed_writes {
+            return true;
+        }
+        if self.read_all {
+            return !written.is_empty();
+        }
+        self.read.intersection(written).next().is_some()
+    }
+}
+
+/// Where a transaction writes the changes to
+enum StorageBackend<'block, 'world, K: mv::Key, V: mv::Value> {
+    /// Directly to the block, reverted unless the transaction is applied
+    Block(StorageTransaction<'block, 'world, K, V>),
+    /// To its own buffer on top of the block shared with other transactions
+    Buffer {
+        block: &'block StorageBlock<'world, K, V>,
+        /// Values written by the transaction, `None` if removed
+        written: BTreeMap<K, Option<V>>,
+    },
+}
+
+/// Storage of a transaction which records the keys read and written through it
+/// while tracking is enabled
+pub struct TrackedStorage<'block, 'world, K: mv::Key, V: mv::Value> {
+    storage: StorageBackend<'block, 'world, K, V>,
+    accesses: Option<RefCell<StorageAccesses<K, V>>>,
+}
+
+impl<'block, 'world, K: mv::Key, V: mv::Value> From<StorageTransaction<'block, 'world, K, V>>
+    for TrackedStorage<'block, 'world, K, V>
+{
+    fn from(storage: StorageTransaction<'block, 'world, K, V>) -> Self {
+        Self {
+            storage: StorageBackend::Block(storage),
+            accesses: None,
+        }
+    }
+}
+
+impl<'block, 'world, K: mv::Key, V: mv::Value> From<&'block StorageBlock<'world, K, V>>
+    for TrackedStorage<'block, 'world, K, V>
+{
+    fn from(block: &'block StorageBlock<'world, K, V>) -> Self {
+        Self {
+            storage: StorageBackend::Buffer {
+                block,
+                written: BTreeMap::new(),
+            },
+            accesses: None,
+        }
+    }
+}
+
+impl<K: mv::Key, V: mv::Value> TrackedStorage<'_, '_, K, V> {
+    /// Read entry from the storage
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.record(|accesses| {
+            accesses.read.insert(key.clone());
+        });
+        self.storage.get(key)
+    }
+
+    /// Get mutable access to the value stored in
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.record_write(key);
+        match &mut self.storage {
+            StorageBackend::Block(storage) => storage.get_mut(key),
+            StorageBackend::Buffer { block, written } => {
+                if !written.contains_key(key) {
+                    let value = block.get(key)?.clone();
+                    written.insert(key.clone(), Some(value));
+                }
+                written.get_mut(key).and_then(Option::as_mut)
+            }
+        }
+    }
+
+    /// Insert key value into the transaction temporary map
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.record_write(&key);
+        match &mut self.storage {
+            StorageBackend::Block(storage) => storage.insert(key, value),
+            StorageBackend::Buffer { block, written } => written
+                .insert(key.clone(), Some(value))
+                .unwrap_or_else(|| block.get(&key).cloned()),
+        }
+    }
+
+    /// Remove key value from storage
+    pub fn remove(&mut self, key: K) -> Option<V> {
+        self.record_write(&key);
+        match &mut self.storage {
+            StorageBackend::Block(storage) => storage.remove(key),
+            StorageBackend::Buffer { block, written } => written
+                .insert(key.clone(), None)
+                .unwrap_or_else(|| block.get(&key).cloned()),
+        }
+    }
+
+    /// Apply aggregated changes of the transaction to the block.
+    ///
+    /// Changes written to a buffer are discarded, they are collected with the accesses instead.
+    pub fn apply(self) {
+        if let StorageBackend::Block(storage) = self.storage {
+            storage.apply();
+        }
+    }
+
+    fn record(&self, record: impl FnOnce(&mut StorageAccesses<K, V>)) {
+        if let Some(accesses) = &self.accesses {
+            record(&mut accesses.borrow_mut());
+        }
+    }
+
+    /// Written keys are also read, since the previous value is observable, e.g. on insertion
+    fn record_write(&self, key: &K) {
+        self.record(|accesses| {
+            accesses.read.insert(key.clone());
+            accesses.written.insert(key.clone(), None);
+        });
+    }
+
+    /// Record the iteration over the storage, which doesn't see the values of the buffer
+    fn record_iteration(&self) {
+        let missed_writes = matches!(
+            &self.storage,
+            StorageBackend::Buffer { written, .. } if !written.is_empty()
+        );
+        self.record(|accesses| {
+            accesses.read_all = true;
+            accesses.missed_writes |= missed_writes;
+        });
+    }
+
+    fn track(&mut self) {
+        self.accesses = Some(RefCell::default());
+    }
+
+    /// Stop tracking and collect the accesses together with the values of the written keys
+    fn take_accesses(&mut self) -> StorageAccesses<K, V> {
+        let mut accesses = self
+            .accesses
+            .take()
+            .map(RefCell::into_inner)
+            .unwrap_or_default();
+        for (key, value) in &mut accesses.written {
+            *value = self.storage.get(key).cloned();
+        }
+        accesses
+    }
+
+    fn replay(&mut self, written: BTreeMap<K, Option<V>>) {
+        for (key, value) in written {
+            match value {
+                Some(value) => self.insert(key, value),
+                None => self.remove(key),
+            };
+        }
+    }
+}
+
+impl<K: mv::Key, V: mv::Value> StorageReadOnly<K, V> for StorageBackend<'_, '_, K, V> {
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        match self {
+            Self::Block(storage) => storage.get(key),
+            Self::Buffer { block, written } => written
+                .get(key)
+                .map_or_else(|| block.get(key), Option::as_ref),
+        }
+    }
+
+    /// Iterate over the block, without the values of the buffer
+    fn iter(&self) -> Iter<'_, K, V> {
+        match self {
+            Self::Block(storage) => storage.iter(),
+            Self::Buffer { block, .. } => block.iter(),
+        }
+    }
+
+    /// Iterate over the range of the block, without the values of the buffer
+    fn range<Q>(&self, bounds: impl RangeBounds<Q>) -> RangeIter<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        match self {
+            Self::Block(storage) => storage.range(bounds),
+            Self::Buffer { block, .. } => block.range(bounds),
+        }
+    }
+
+    /// Number of entries in the block, without the values of the buffer
+    fn len(&self) -> usize {
+        match self {
+            Self::Block(storage) => storage.len(),
+            Self::Buffer { block, .. } => block.len(),
+        }
+    }
+}
+
+impl<K: mv::Key, V: mv::Value> StorageReadOnly<K, V> for TrackedStorage<'_, '_, K, V> {
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let Some(accesses) = &self.accesses else {
+            return self.storage.get(key);
+        };
+        // Only the stored key can be recorded, since the borrowed one can't be converted back
+        let buffered = match &self.storage {
+            StorageBackend::Buffer { written, .. } => written.get_key_value(key),
+            StorageBackend::Block(_) => None,
+        };
+        let entry = buffered
+            .map(|(key, value)| (key, value.as_ref()))
+            .or_else(|| {
+                self.storage
+                    .range((Bound::Included(key), Bound::Included(key)))
+                    .next()
+                    .map(|(key, value)| (key, Some(value)))
+            });
+        let mut accesses = accesses.borrow_mut();
+        if let Some((key, value)) = entry {
+            accesses.read.insert(key.clone());
+            value
+        } else {
+            accesses.read_all = true;
+            None
+        }
+    }
+
+    fn iter(&self) -> Iter<'_, K, V> {
+        self.record_iteration();
+        self.storage.iter()
+    }
+
+    fn range<Q>(&self, bounds: impl RangeBounds<Q>) -> RangeIter<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.record_iteration();
+        self.storage.range(bounds)
+    }
+
+    fn len(&self) -> usize {
+        self.record_iteration();
+        self.storage.len()
+    }
+}
+
+/// Whether a cell or the set of triggers was read or written by a transaction
+#[derive(Default)]
+struct Tracker {
+    read: Cell<bool>,
+    written: bool,
+}
+
+/// Access of a transaction to a cell
+struct CellAccesses<V> {
+    read: bool,
+    /// Value after the transaction if it was written
+    written: Option<V>,
+}
+
+/// Where a transaction writes the value of a cell to
+enum CellBackend<'block, 'world, V: mv::Value> {
+    /// Directly to the block, reverted unless the transaction is applied
+    Block(CellTransaction<'block, 'world, V>),
+    /// To its own buffer on top of the block shared with other transactions
+    Buffer {
+        block: &'block CellBlock<'world, V>,
+        written: Option<V>,
+    },
+}
+
+impl<V: mv::Value> CellBackend<'_, '_, V> {
+    fn get(&self) -> &V {
+        match self {
+            Self::Block(cell) => cell.get(),
+            Self::Buffer { block, written } => written.as_ref().unwrap_or_else(|| block.get()),
+        }
+    }
+
+    fn get_mut(&mut self) -> &mut V {
+        match self {
+            Self::Block(cell) => cell.get_mut(),
+            Self::Buffer { block, written } => written.get_or_insert_with(|| block.get().clone()),
+        }
+    }
+}
+
+/// Cell of a transaction which records whether it was read or written while tracking is enabled
+pub struct TrackedCell<'block, 'world, V: mv::Value> {
+    cell: CellBackend<'block, 'world, V>,
+    tracker: Option<Tracker>,
+}
+
+impl<'block, 'world, V: mv::Value> From<CellTransaction<'block, 'world, V>>
+    for TrackedCell<'block, 'world, V>
+{
+    fn from(cell: CellTransaction<'block, 'world, V>) -> Self {
+        Self {
+            cell: CellBackend::Block(cell),
+            tracker: None,
+        }
+    }
+}
+
+impl<'block, 'world, V: mv::Value> From<&'block CellBlock<'world, V>>
+    for TrackedCell<'block, 'world, V>
+{
+    fn from(block: &'block CellBlock<'world, V>) -> Self {
+        Self {
+            cell: CellBackend::Buffer {
+                block,
+                written: None,
+            },
+            tracker: None,
+        }
+    }
+}
+
+impl<V: mv::Value> TrackedCell<'_, '_, V> {
+    /// Get mutable access to the value stored in the cell
+    pub fn get_mut(&mut self) -> &mut V {
+        self
+    }
+
+    /// Apply aggregated changes of the transaction to the block.
+    ///
+    /// Changes written to a buffer are discarded, they are collected with the accesses instead.
+    pub fn apply(self) {
+        if let CellBackend::Block(cell) = self.cell {
+            cell.apply();
+        }
+    }
+
+    fn track(&mut self) {
+        self.tracker = Some(Tracker::default());
+    }
+
+    fn take_accesses(&mut self) -> CellAccesses<V> {
+        let tracker = self.tracker.take().unwrap_or_default();
+        CellAccesses {
+            read: tracker.read.get(),
+            written: tracker.written.then(|| self.cell.get().clone()),
+        }
+    }
+}
+
+impl<V: mv::Value> Deref for TrackedCell<'_, '_, V> {
+    type Target = V;
+
+    fn deref(&self) -> &Self::Target {
+        if let Some(tracker) = &self.tracker {
+            tracker.read.set(true);
+        }
+        self.cell.get()
+    }
+}
+
+impl<V: mv::Value> DerefMut for TrackedCell<'_, '_, V> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        if let Some(tracker) = &mut self.tracker {
+            *tracker.read.get_mut() = true;
+            tracker.written = true;
+        }
+        self.cell.get_mut()
+    }
+}
+
+/// [`TriggerSetTransaction`] which records whether the triggers were read or changed
+/// while tracking is enabled
+pub struct TrackedTriggers<'block, 'world> {
+    triggers: TriggerSetTransaction<'block, 'world>,
+    tracker: Option<Tracker>,
+}
+
+impl<'block, 'world> From<TriggerSetTransaction<'block, 'world>>
+    for TrackedTriggers<'block, 'world>
+{
+    fn from(triggers: TriggerSetTransaction<'block, 'world>) -> Self {
+        Self {
+            triggers,
+            tracker: None,
+        }
+    }
+}
+
+impl<'block, 'world> TrackedTriggers<'block, 'world> {
+    /// Access the triggers to match them against events.
+    ///
+    /// Matching only reads the triggers, so that it doesn't count as a change of the set.
+    pub fn matching(&mut self) -> &mut TriggerSetTransaction<'block, 'world> {
+        if let Some(tracker) = &mut self.tracker {
+            *tracker.read.get_mut() = true;
+        }
+        &mut self.triggers
+    }
+
+    /// Apply aggregated changes of the transaction to the block
+    pub fn apply(self) {
+        self.triggers.apply()
+    }
+
+    fn track(&mut self) {
+        self.tracker = Some(Tracker::default());
+    }
+
+    fn take_accesses(&mut self) -> Tracker {
+        self.tracker.take().unwrap_or_default()
+    }
+}
+
+impl<'block, 'world> Deref for TrackedTriggers<'block, 'world> {
+    type Target = TriggerSetTransaction<'block, 'world>;
+
+    fn deref(&self) -> &Self::Target {
+        if let Some(tracker) = &self.tracker {
+            tracker.read.set(true);
+        }
+        &self.triggers
+    }
+}
+
+impl DerefMut for TrackedTriggers<'_, '_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        if let Some(tracker) = &mut self.tracker {
+            *tracker.read.get_mut() = true;
+            tracker.written = true;
+        }
+        &mut self.triggers
+    }
+}
+
+impl SetReadOnly for TrackedTriggers<'_, '_> {
+    fn data_triggers(&self) -> &impl StorageReadOnly<TriggerId, LoadedAction<DataEventFilter>> {
+        self.deref().data_triggers()
+    }
+    fn pipeline_triggers(
+        &self,
+    ) -> &impl StorageReadOnly<TriggerId, LoadedAction<PipelineEventFilterBox>> {
+        self.deref().pipeline_triggers()
+    }
+    fn time_triggers(&self) -> &impl StorageReadOnly<TriggerId, LoadedAction<TimeEventFilter>> {
+        self.deref().time_triggers()
+    }
+    fn by_call_triggers(
+        &self,
+    ) -> &impl StorageReadOnly<TriggerId, LoadedAction<ExecuteTriggerEventFilter>> {
+        self.deref().by_call_triggers()
+    }
+    fn custom_triggers(&self) -> &impl StorageReadOnly<TriggerId, LoadedAction<CustomEventFilter>> {
+        self.deref().custom_triggers()
+    }
+    fn ids(&self) -> &impl StorageReadOnly<TriggerId, TriggeringEventType> {
+        self.deref().ids()
+    }
+    fn contracts(
+        &self,
+    ) -> &impl StorageReadOnly<HashOf<WasmSmartContract>, WasmSmartContractEntry> {
+        self.deref().contracts()
+    }
+    fn matched_ids(&self) -> &[(EventBox, TriggerId)] {
+        self.deref().matched_ids()
+    }
+}
+
+macro_rules! world_accesses {
+    (
+        storages { $($storage:ident: $key:ty => $value:ty),* $(,)? }
+        cells { $($cell:ident: $cell_value:ty),* $(,)? }
+    ) => {
+        /// Accesses of a transaction to the [`World`](crate::state::World)
+        struct WorldAccesses {
+            $($storage: StorageAccesses<$key, $value>,)*
+            $($cell: CellAccesses<$cell_value>,)*
+            triggers: Tracker,
+        }
+
+        /// Keys and cells written by the transactions committed to the block so far
+        #[derive(Default)]
+        struct BlockWrites {
+            $($storage: BTreeSet<$key>,)*
+            $($cell: bool,)*
+            triggers: bool,
+        }
+
+        impl WorldAccesses {
+            /// Whether the transaction read anything written by the committed transactions
+            fn conflicts_with(&self, writes: &BlockWrites) -> bool {
+                $(self.$storage.conflicts_with(&writes.$storage) ||)*
+                $(self.$cell.read && writes.$cell ||)*
+                self.triggers.read.get() && writes.triggers
+            }
+        }
+
+        impl BlockWrites {
+            fn add(&mut self, accesses: &WorldAccesses) {
+                $(self.$storage.extend(accesses.$storage.written.keys().cloned());)*
+                $(self.$cell |= accesses.$cell.written.is_some();)*
+                self.triggers |= accesses.triggers.written;
+            }
+        }
+
+        impl WorldTransaction<'_, '_> {
+            /// Start recording accesses to the world
+            fn track_accesses(&mut self) {
+                $(self.$storage.track();)*
+                $(self.$cell.track();)*
+                self.triggers.track();
+            }
+
+            /// Stop recording accesses to the world and collect them
+            fn take_accesses(&mut self) -> WorldAccesses {
+                WorldAccesses {
+                    $($storage: self.$storage.take_accesses(),)*
+                    $($cell: self.$cell.take_accesses(),)*
+                    triggers: self.triggers.take_accesses(),
+                }
+            }
+
+            /// Write the values written by a speculative execution of a transaction
+            /// which didn't change the triggers
+            fn replay(&mut self, accesses: WorldAccesses) {
+                $(self.$storage.replay(accesses.$storage.written);)*
+                $(if let Some(value) = accesses.$cell.written {
+                    *self.$cell.get_mut() = value;
+                })*
+            }
+        }
+    };
+}
+
+world_accesses! {
+    storages {
+        domains: DomainId => Domain,
+        accounts: AccountId => Account,
+        asset_definitions: AssetDefinitionId => AssetDefinition,
+        assets: AssetId => Asset,
+        nfts: NftId => Nft,
+        roles: RoleId => Role,
+        account_permissions: AccountId => Permissions,
+        account_roles: RoleIdWithOwner => (),
+        account_aliases: AccountAlias => AccountId,
+        smart_contract_storage: StorageKey => Vec<u8>,
+        smart_contract_storage_sizes: StorageOwner => u64,
+        contracts: ContractId => Contract,
+    }
+    cells {
+        parameters: Parameters,
+        peers: Peers,
+        pending_validators: Option<PendingValidatorSet>,
+        executor: Executor,
+        executor_data_model: ExecutorDataModel,
+    }
+}
+
+/// Outcome of a transaction executed on the state before the block
+struct Speculation {
+    result: Result<(), TransactionRejectionReason>,
+    accesses: WorldAccesses,
+    events: Vec<EventBox>,
+    matched_ids: Vec<(EventBox, TriggerId)>,
+    tx_amounts: Vec<f64>,
+}
+
+impl Speculation {
+    fn execute(
+        block: &StateBlock<'_>,
+        tx: &AcceptedTransaction,
+        wasm_cache: &mut WasmCache<'_, '_, '_>,
+    ) -> Self {
+        let mut events_buffer = Vec::new();
+        let new_tx_amounts = Mutex::default();
+
+        let mut transaction = block.buffered_transaction(&mut events_buffer, &new_tx_amounts);
+        let matched_before = transaction.world.triggers.matched_ids().len();
+        transaction.world.track_accesses();
+        let result = StateBlock::validate_internal(tx.clone(), &mut transaction, true, wasm_cache);
+        let accesses = transaction.world.take_accesses();
+        let events = transaction.world.transaction_events().to_vec();
+        let matched_ids = transaction.world.triggers.matched_ids()[matched_before..].to_vec();
+        // Changes are buffered, so that every transaction is executed on the state before the block
+        drop(transaction);
+
+        Self {
+            result,
+            accesses,
+            events,
+            matched_ids,
+            tx_amounts: new_tx_amounts.into_inner(),
+        }
+    }
+}
+
+/// Validate `transactions` of the block with the given number of `workers`,
+/// see the [module docs](self).
+pub fn validate_transactions(
+    block: &mut StateBlock<'_>,
+    transactions: &[AcceptedTransaction],
+    workers: usize,
+) -> Vec<Result<(), TransactionRejectionReason>> {
+    let mut speculations = Vec::with_capacity(transactions.len());
+    thread::scope(|scope| {
+        let block = &*block;
+        let handles = (0..workers)
+            .map(|worker| scope.spawn(move || speculate(block, transactions, (worker, workers))))
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            speculations.extend(
+                handle
+                    .join()
+                    .expect("INTERNAL BUG: Speculative execution panicked"),
+            );
+        }
+    });
+    speculations.sort_unstable_by_key(|(idx, _)| *idx);
+
+    let mut wasm_cache = WasmCache::new();
+    let mut writes = BlockWrites::default();
+    speculations
+        .into_iter()
+        .zip(transactions)
+        .map(|((_, speculation), tx)| {
+            let mut transaction = block.transaction();
+
+            if !speculation.accesses.triggers.written
+                && !speculation.accesses.conflicts_with(&writes)
+            {
+                transaction
+                    .new_tx_amounts
+                    .lock()
+                    .extend(speculation.tx_amounts);
+                if speculation.result.is_ok() {
+                    writes.add(&speculation.accesses);
+                    transaction.world.replay(speculation.accesses);
+                    transaction
+                        .world
+                        .triggers
+                        .matching()
+                        .extend_matched_ids(speculation.matched_ids);
+                    transaction.world.emit_matched_events(speculation.events);
+                    transaction.apply();
+                }
+                return speculation.result;
+            }
+
+            transaction.world.track_accesses();
+            let result =
+                StateBlock::validate_internal(tx.clone(), &mut transaction, true, &mut wasm_cache);
+            let accesses = transaction.world.take_accesses();
+            if result.is_ok() {
+                writes.add(&accesses);
+                transaction.apply();
+            }
+            result
+        })
+        .collect()
+}
+
+/// Execute the share of the `worker` in the `transactions` on top of the `block`
+/// without changing it
+fn speculate(
+    block: &StateBlock<'_>,
+    transactions: &[AcceptedTransaction],
+    (worker, workers): (usize, usize),
+) -> Vec<(usize, Speculation)> {
+    let mut wasm_cache = WasmCache::new();
+    transactions
+        .iter()
+        .enumerate()
+        .skip(worker)
+        .step_by(workers)
+        .map(|(idx, tx)| (idx, Speculation::execute(block, tx, &mut wasm_cache)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use iroha_crypto::KeyPair;
+    use iroha_data_model::{isi::InstructionBox, transaction::error::TransactionRejectionReason};
+    use iroha_test_samples::gen_account_in;
+    use nonzero_ext::nonzero;
+
+    use super::*;
+    use crate::{
+        block::{BlockBuilder, ValidBlock},
+        kura::Kura,
+        query::store::LiveQueryStore,
+        smartcontracts::Registrable as _,
+        state::{State, StateReadOnly, World, WorldReadOnly},
+    };
+
+    /// Rejected transactions, events, matched triggers and assets after the block
+    type Outcome = (
+        Vec<(u64, TransactionRejectionReason)>,
+        Vec<EventBox>,
+        Vec<(EventBox, TriggerId)>,
+        Vec<Asset>,
+    );
+
+    fn execute(accounts: &[(AccountId, KeyPair)], execution_workers: NonZeroUsize) -> Outcome {
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+        let (owner_id, _) = &accounts[0];
+        let domain = Domain::new("wonderland".parse().unwrap()).build(owner_id);
+        let rose_id: AssetDefinitionId = "rose#wonderland".parse().unwrap();
+        let rose = AssetDefinition::numeric(rose_id.clone()).build(owner_id);
+        let rose_of = |idx: usize| AssetId::new(rose_id.clone(), accounts[idx].0.clone());
+        let world = World::with_assets(
+            [domain],
+            accounts
+                .iter()
+                .map(|(id, _)| Account::new(id.clone()).build(id)),
+            [rose],
+            (0..accounts.len()).map(|idx| Asset::new(rose_of(idx), 100_u32)),
+        );
+        let state = State::new(
+            world,
+            Kura::blank_kura_for_testing(),
+            LiveQueryStore::start_test(),
+        )
+        .with_execution_workers(execution_workers);
+
+        let (max_clock_drift, tx_limits) = {
+            let state_view = state.view();
+            let params = state_view.world().parameters();
+            (params.sumeragi().max_clock_drift(), params.transaction)
+        };
+        let tx = |from: usize, instruction: InstructionBox| {
+            let (authority, key_pair) = &accounts[from];
+            let tx = TransactionBuilder::new(chain_id.clone(), authority.clone())
+                .with_instructions([instruction])
+                .sign(key_pair.private_key());
+            AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits).unwrap()
+        };
+        let transfer = |from: usize, to: usize, amount: u32| {
+            tx(
+                from,
+                Transfer::asset_numeric(rose_of(from), amount, accounts[to].0.clone()).into(),
+            )
+        };
+        let register_trigger = tx(
+            5,
+            Register::trigger(Trigger::new(
+                "rose_watcher".parse().unwrap(),
+                Action::new(
+                    Vec::<InstructionBox>::new(),
+                    Repeats::Indefinitely,
+                    accounts[5].0.clone(),
+                    AssetEventFilter::new().for_asset(rose_of(0)),
+                ),
+            ))
+            .into(),
+        );
+        let transactions = vec![
+            transfer(0, 1, 10),
+            transfer(2, 3, 10),
+            // Reads the asset written by the first transaction
+            transfer(1, 4, 50),
+            // Rejected in any case
+            transfer(3, 5, 200),
+            // Rejected on the state before the block, but accepted after the third transaction
+            transfer(4, 0, 120),
+            register_trigger,
+            // Matches the trigger registered by the previous transaction
+            transfer(0, 2, 5),
+            transfer(5, 3, 1),
+        ];
+
+        let leader_private_key = &accounts[0].1.private_key();
+        let latest_block = ValidBlock::new_dummy(leader_private_key);
+        let block = BlockBuilder::new(transactions)
+            .chain(0, Some(latest_block.as_ref()))
+            .sign(leader_private_key)
+            .unpack(|_| {});
+        let mut state_block = state.block(block.header());
+        let block = block.categorize(&mut state_block).unpack(|_| {});
+
+        let errors = block
+            .as_ref()
+            .errors()
+            .map(|(idx, error)| (*idx, error.clone()))
+            .collect();
+        let matched_ids = state_block.world.triggers.matched_ids().to_vec();
+        let assets = state_block
+            .world
+            .assets
+            .iter()
+            .map(|(_, asset)| asset.clone())
+            .collect();
+        (errors, state_block.world.take_events(), matched_ids, assets)
+    }
+
+    #[test]
+    fn buffered_storage_leaves_block_unchanged() {
+        let storage = mv::storage::Storage::<u32, u32>::from_iter([(1, 10), (2, 20)]);
+        let block = storage.block();
+        let mut buffered = TrackedStorage::from(&block);
+        buffered.track();
+
+        *buffered.get_mut(&1).unwrap() += 1;
+        assert_eq!(buffered.insert(3, 30), None);
+        assert_eq!(buffered.remove(2), Some(20));
+        assert_eq!(buffered.get(&1), Some(&11));
+        assert_eq!(buffered.get(&2), None);
+        assert_eq!(StorageReadOnly::get(&buffered, &3), Some(&30));
+        // Iteration only sees the block, so the transaction has to be executed again
+        assert_eq!(buffered.iter().count(), 2);
+
+        let accesses = buffered.take_accesses();
+        assert!(accesses.missed_writes);
+        assert_eq!(
+            accesses.written,
+            BTreeMap::from([(1, Some(11)), (2, None), (3, Some(30))])
+        );
+        buffered.apply();
+        assert_eq!(
+            block.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(),
+            [(1, 10), (2, 20)]
+        );
+    }
+
+    #[tokio::test]
+    async fn parallel_execution_is_identical_to_sequential() {
+        let accounts = (0..6)
+            .map(|_| gen_account_in("wonderland"))
+            .collect::<Vec<_>>();
+        let sequential = execute(&accounts, nonzero!(1_usize));
+        let parallel = execute(&accounts, nonzero!(3_usize));
+
+        assert_eq!(
+            sequential.0.iter().map(|(idx, _)| *idx).collect::<Vec<_>>(),
+            [3]
+        );
+        assert_eq!(sequential.2.len(), 1);
+        assert_eq!(sequential, parallel);
+    }
+}
@@ -43,6 +43,25 @@ impl PeersGossiperHandle {
     }
 }
 
+#[cfg(test)]
+impl PeersGossiperHandle {
+    /// Create handle which isn't connected to any actor.
+    ///
+    /// Messages sent to the handle are discarded once the returned receivers are dropped
+    pub(crate) fn test() -> (Self, impl Sized) {
+        let (message_sender, message_receiver) = mpsc::channel(1);
+        let (update_topology_sender, update_topology_receiver) = mpsc::unbounded_channel();
+
+        (
+            Self {
+                message_sender,
+                update_topology_sender,
+            },
+            (message_receiver, update_topology_receiver),
+        )
+    }
+}
+
 /// Actor which gossips peers addresses.
 pub struct PeersGossiper {
     /// Peers provided at startup
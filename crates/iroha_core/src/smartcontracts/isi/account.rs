@@ -275,6 +275,234 @@ pub mod isi {
         }
     }
 
+    impl Execute for AddAccountKey {
+        #[metrics(+"add_account_key")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let AddAccountKey {
+                account,
+                public_key,
+                weight,
+            } = self;
+
+            update_account_keys(&account, state_transaction, |keys| {
+                if keys.contains(&public_key) {
+                    return Err(Error::InvariantViolation(format!(
+                        "Key {public_key} is already registered for account {account}"
+                    )));
+                }
+                keys.weights.insert(public_key, weight);
+                Ok(())
+            })
+        }
+    }
+
+    impl Execute for RemoveAccountKey {
+        #[metrics(+"remove_account_key")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let RemoveAccountKey {
+                account,
+                public_key,
+            } = self;
+
+            update_account_keys(&account, state_transaction, |keys| {
+                keys.weights.remove(&public_key).map(|_| ()).ok_or_else(|| {
+                    Error::InvariantViolation(format!(
+                        "Key {public_key} is not registered for account {account}"
+                    ))
+                })
+            })
+        }
+    }
+
+    impl Execute for RotateAccountKey {
+        #[metrics(+"rotate_account_key")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let RotateAccountKey {
+                account,
+                old_key,
+                new_key,
+            } = self;
+
+            update_account_keys(&account, state_transaction, |keys| {
+                if keys.contains(&new_key) {
+                    return Err(Error::InvariantViolation(format!(
+                        "Key {new_key} is already registered for account {account}"
+                    )));
+                }
+                let weight = keys.weights.remove(&old_key).ok_or_else(|| {
+                    Error::InvariantViolation(format!(
+                        "Key {old_key} is not registered for account {account}"
+                    ))
+                })?;
+                keys.weights.insert(new_key, weight);
+                Ok(())
+            })
+        }
+    }
+
+    impl Execute for SetAccountKeyThreshold {
+        #[metrics(+"set_account_key_threshold")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let SetAccountKeyThreshold { account, threshold } = self;
+
+            update_account_keys(&account, state_transaction, |keys| {
+                keys.threshold = threshold;
+                Ok(())
+            })
+        }
+    }
+
+    impl Execute for RegisterAccountAlias {
+        #[metrics(+"register_account_alias")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let RegisterAccountAlias { alias, account } = self;
+
+            state_transaction.world.account(&account)?;
+            check_alias_domain(&alias, &account)?;
+
+            if let Some(holder) = state_transaction.world.account_aliases.get(&alias) {
+                return Err(Error::InvariantViolation(format!(
+                    "Alias {alias} is already registered for account {holder}"
+                )));
+            }
+            state_transaction
+                .world
+                .account_aliases
+                .insert(alias.clone(), account.clone());
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::AliasAdded(AccountAliasChanged {
+                    account,
+                    alias,
+                })));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for TransferAccountAlias {
+        #[metrics(+"transfer_account_alias")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let TransferAccountAlias { alias, destination } = self;
+
+            state_transaction.world.account(&destination)?;
+            check_alias_domain(&alias, &destination)?;
+
+            let source = state_transaction
+                .world
+                .account_aliases
+                .get(&alias)
+                .cloned()
+                .ok_or_else(|| FindError::AccountAlias(alias.clone()))?;
+            state_transaction
+                .world
+                .account_aliases
+                .insert(alias.clone(), destination.clone());
+
+            state_transaction.world.emit_events([
+                AccountEvent::AliasRemoved(AccountAliasChanged {
+                    account: source,
+                    alias: alias.clone(),
+                }),
+                AccountEvent::AliasAdded(AccountAliasChanged {
+                    account: destination,
+                    alias,
+                }),
+            ]);
+
+            Ok(())
+        }
+    }
+
+    impl Execute for UnregisterAccountAlias {
+        #[metrics(+"unregister_account_alias")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let UnregisterAccountAlias { alias } = self;
+
+            let account = state_transaction
+                .world
+                .account_aliases
+                .remove(alias.clone())
+                .ok_or_else(|| FindError::AccountAlias(alias.clone()))?;
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::AliasRemoved(AccountAliasChanged {
+                    account,
+                    alias,
+                })));
+
+            Ok(())
+        }
+    }
+
+    /// Aliases are unique within a domain, so they may only resolve to accounts of that domain.
+    fn check_alias_domain(alias: &AccountAlias, account: &AccountId) -> Result<(), Error> {
+        if alias.domain != account.domain {
+            return Err(Error::InvariantViolation(format!(
+                "Alias {alias} can't resolve to account {account} of another domain"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Apply `update` to the keys of the account, making sure the account stays operable.
+    fn update_account_keys(
+        account_id: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+        update: impl FnOnce(&mut AccountKeys) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let account = state_transaction.world.account_mut(account_id)?;
+        let mut keys = account.keys.clone();
+        update(&mut keys)?;
+
+        if !keys.is_reachable() {
+            return Err(Error::InvariantViolation(format!(
+                "Keys of account {account_id} can't reach the signature threshold"
+            )));
+        }
+        account.keys = keys.clone();
+
+        state_transaction
+            .world
+            .emit_events(Some(AccountEvent::KeysChanged(AccountKeysChanged {
+                account: account_id.clone(),
+                keys,
+            })));
+
+        Ok(())
+    }
+
     /// Stop minting on the [`AssetDefinition`] globally.
     ///
     /// # Errors
@@ -317,7 +545,10 @@ pub mod query {
     };
 
     use super::*;
-    use crate::{smartcontracts::ValidQuery, state::StateReadOnly};
+    use crate::{
+        smartcontracts::{ValidQuery, ValidSingularQuery},
+        state::StateReadOnly,
+    };
 
     impl ValidQuery for FindRolesByAccountId {
         #[metrics(+"find_roles_by_account_id")]
@@ -336,6 +567,38 @@ pub mod query {
         }
     }
 
+    impl ValidQuery for FindAliasesByAccountId {
+        #[metrics(+"find_aliases_by_account_id")]
+        fn execute(
+            self,
+            filter: CompoundPredicate<Name>,
+            state_ro: &impl StateReadOnly,
+        ) -> Result<impl Iterator<Item = Name>, Error> {
+            let account_id = &self.id;
+            state_ro.world().account(account_id)?;
+            Ok(state_ro
+                .world()
+                .account_aliases_iter(account_id)
+                .map(|alias| &alias.name)
+                .filter(move |&name| filter.applies(name))
+                .cloned()
+                .collect::<Vec<_>>()
+                .into_iter())
+        }
+    }
+
+    impl ValidSingularQuery for FindAccountIdByAlias {
+        #[metrics(+"find_account_id_by_alias")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<AccountId, Error> {
+            state_ro
+                .world()
+                .account_aliases()
+                .get(&self.alias)
+                .cloned()
+                .ok_or_else(|| FindError::AccountAlias(self.alias.clone()).into())
+        }
+    }
+
     impl ValidQuery for FindPermissionsByAccountId {
         #[metrics(+"find_permissions_by_account_id")]
         fn execute(
@@ -1,14 +1,18 @@
 //! This module contains trait implementations related to block queries
 use eyre::Result;
+use iroha_crypto::Hash;
 use iroha_data_model::{
-    block::{BlockHeader, SignedBlock},
-    query::{dsl::CompoundPredicate, error::QueryExecutionFail},
+    block::{BlockHeader, EquivocationEvidence, SignedBlock},
+    query::{dsl::CompoundPredicate, error::QueryExecutionFail, CommittedTriggerReceipt},
 };
 use iroha_telemetry::metrics;
 use nonzero_ext::nonzero;
 
 use super::*;
-use crate::{smartcontracts::ValidQuery, state::StateReadOnly};
+use crate::{
+    smartcontracts::{ValidQuery, ValidSingularQuery},
+    state::StateReadOnly,
+};
 
 impl ValidQuery for FindBlocks {
     #[metrics(+"find_blocks")]
@@ -39,3 +43,53 @@ impl ValidQuery for FindBlockHeaders {
             .map(|block| block.header()))
     }
 }
+
+impl ValidQuery for FindTriggerReceipts {
+    #[metrics(+"find_trigger_receipts")]
+    fn execute(
+        self,
+        filter: CompoundPredicate<CommittedTriggerReceipt>,
+        state_ro: &impl StateReadOnly,
+    ) -> Result<impl Iterator<Item = Self::Item>, QueryExecutionFail> {
+        Ok(state_ro
+            .all_blocks(nonzero!(1_usize))
+            .rev()
+            .flat_map(|block| {
+                let block_hash = block.hash();
+
+                block
+                    .trigger_receipts()
+                    .cloned()
+                    .map(|value| CommittedTriggerReceipt { block_hash, value })
+                    .collect::<Vec<_>>()
+            })
+            .filter(move |receipt| filter.applies(receipt)))
+    }
+}
+
+impl ValidQuery for FindEquivocations {
+    #[metrics(+"find_equivocations")]
+    fn execute(
+        self,
+        filter: CompoundPredicate<EquivocationEvidence>,
+        state_ro: &impl StateReadOnly,
+    ) -> Result<impl Iterator<Item = Self::Item>, QueryExecutionFail> {
+        Ok(state_ro
+            .all_blocks(nonzero!(1_usize))
+            .rev()
+            .flat_map(|block| block.equivocations().cloned().collect::<Vec<_>>())
+            .filter(move |evidence| filter.applies(evidence)))
+    }
+}
+
+impl ValidSingularQuery for FindRandomness {
+    #[metrics(+"find_randomness")]
+    fn execute(&self, state_ro: &impl StateReadOnly) -> Result<Hash, QueryExecutionFail> {
+        // Authority of the query is registered in the genesis block at the earliest
+        let latest_block = state_ro
+            .latest_block()
+            .expect("INTERNAL BUG: Genesis not committed");
+
+        Ok(latest_block.header().randomness_seed())
+    }
+}
@@ -0,0 +1,193 @@
+//! This module contains implementations of instructions and queries
+//! related to the contract registry.
+
+use iroha_data_model::{prelude::*, query::error::FindError};
+use iroha_telemetry::metrics;
+
+use super::super::isi::prelude::*;
+
+/// All instructions related to the contract registry:
+/// - deploying a contract
+/// - removing a contract
+/// - calling a contract
+pub mod isi {
+    use std::num::NonZeroU64;
+
+    use iroha_data_model::isi::error::{InvalidParameterError, RepetitionError};
+
+    use super::*;
+    use crate::smartcontracts::wasm;
+
+    impl Execute for DeployContract {
+        #[metrics(+"deploy_contract")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let DeployContract {
+                contract: contract_id,
+                wasm,
+            } = self;
+
+            if state_transaction
+                .world
+                .contracts
+                .get(&contract_id)
+                .is_some()
+            {
+                return Err(RepetitionError {
+                    instruction: InstructionType::Contract,
+                    id: contract_id.into(),
+                }
+                .into());
+            }
+
+            let engine = state_transaction.engine.clone(); // Cloning engine is cheap
+            let code_hash = state_transaction
+                .world
+                .triggers
+                .add_contract_module(&engine, wasm)
+                .map_err(|error| InvalidParameterError::Wasm(error.to_string()))?;
+
+            let contract = Contract::new(contract_id.clone(), authority.clone(), code_hash);
+            state_transaction
+                .world
+                .contracts
+                .insert(contract_id, contract.clone());
+            state_transaction
+                .world
+                .emit_events(Some(ContractEvent::Deployed(contract)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for RemoveContract {
+        #[metrics(+"remove_contract")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let contract_id = self.contract;
+
+            if !state_transaction.world.remove_contract(&contract_id) {
+                return Err(FindError::Contract(contract_id).into());
+            }
+
+            state_transaction
+                .world
+                .emit_events(Some(ContractEvent::Removed(contract_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for CallContract {
+        #[metrics(+"call_contract")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let CallContract {
+                contract: contract_id,
+                args,
+            } = self;
+
+            let contract = state_transaction.world.contract(&contract_id)?.clone();
+            let mut config = state_transaction.world.parameters().smart_contract;
+            // Budget of a nested call is the fuel remaining to the calling contract
+            let budget = state_transaction.contract_fuel.take();
+            let max_call_depth = state_transaction.world.parameters().contract.max_call_depth;
+            if state_transaction.contract_call_depth >= max_call_depth.get() {
+                return Err(Error::InvariantViolation(format!(
+                    "Contract call depth exceeds the limit of {max_call_depth}"
+                )));
+            }
+            if let Some(budget) = budget {
+                config.fuel = NonZeroU64::new(budget).ok_or_else(|| {
+                    Error::InvariantViolation("No fuel left for the contract call".to_owned())
+                })?;
+            }
+
+            let module = state_transaction
+                .world
+                .triggers
+                .get_compiled_contract(contract.code_hash())
+                .expect("INTERNAL BUG: Module of the deployed contract is missing")
+                .clone(); // Cloning module is cheap, under Arc inside
+            let engine = state_transaction.engine.clone(); // Cloning engine is cheap
+
+            state_transaction.contract_call_depth += 1;
+            let result = wasm::RuntimeBuilder::<wasm::state::Contract>::new()
+                .with_engine(engine)
+                .with_config(config)
+                .build()
+                .and_then(|mut runtime| {
+                    runtime.execute_contract_module(
+                        state_transaction,
+                        &contract,
+                        authority.clone(),
+                        &module,
+                        args,
+                    )
+                });
+            state_transaction.contract_call_depth -= 1;
+            if result.is_err() {
+                // Contract trapped, e.g. ran out of fuel, so all of its fuel is spent
+                state_transaction.record_wasm_consumption(config.fuel.get(), 0);
+            }
+
+            let output = result
+                .map_err(|error| {
+                    InvalidParameterError::Wasm(format!(
+                        "{:?}",
+                        eyre::eyre!(error).wrap_err("Contract execution failed"),
+                    ))
+                })?
+                .map_err(|fail| match fail {
+                    ValidationFail::InstructionFailed(error) => error,
+                    fail => Error::InvariantViolation(fail.to_string()),
+                })?;
+
+            state_transaction
+                .world
+                .emit_events(Some(ContractEvent::Called(ContractCalled {
+                    contract: contract_id,
+                    caller: authority.clone(),
+                    output: output.clone(),
+                })));
+            state_transaction.contract_output = Some(output);
+
+            Ok(())
+        }
+    }
+}
+
+pub mod query {
+    //! Queries associated to the contract registry.
+
+    use iroha_data_model::query::{dsl::CompoundPredicate, error::QueryExecutionFail as Error};
+
+    use super::*;
+    use crate::{smartcontracts::ValidQuery, state::StateReadOnly};
+
+    impl ValidQuery for FindContracts {
+        #[metrics(+"find_contracts")]
+        fn execute(
+            self,
+            filter: CompoundPredicate<Contract>,
+            state_ro: &impl StateReadOnly,
+        ) -> Result<impl Iterator<Item = Self::Item>, Error> {
+            Ok(state_ro
+                .world()
+                .contracts()
+                .iter()
+                .map(|(_, contract)| contract)
+                .filter(move |&contract| filter.applies(contract))
+                .cloned())
+        }
+    }
+}
@@ -88,6 +88,9 @@ pub mod isi {
                 .collect::<Vec<_>>()
                 .into_iter()
                 .for_each(|trigger_id| {
+                    state_transaction
+                        .world
+                        .remove_smart_contract_storage(&StorageOwner::Trigger(trigger_id.clone()));
                     state_transaction
                         .world
                         .triggers
@@ -102,6 +105,13 @@ pub mod isi {
                 .remove(account_id.clone());
 
             state_transaction.world.remove_account_roles(&account_id);
+            state_transaction.world.remove_account_aliases(&account_id);
+            state_transaction
+                .world
+                .remove_smart_contract_storage(&StorageOwner::Account(account_id.clone()));
+            state_transaction
+                .world
+                .remove_account_contracts(&account_id);
 
             let remove_assets: Vec<AssetId> = state_transaction
                 .world
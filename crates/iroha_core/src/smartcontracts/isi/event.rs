@@ -0,0 +1,278 @@
+//! Validation of custom event payloads against the schema of the executor data model.
+//!
+//! The schema is the serialized [`iroha_schema::MetaMap`] of the types registered by the executor
+//! and the payload is expected to be in the form `serde` serializes these types to.
+
+use iroha_data_model::prelude::Json;
+use serde_json::{Map, Value};
+
+/// Check that the `payload` of the event of the `ty` type matches the `schema`.
+///
+/// # Errors
+/// Fails if the schema is malformed, the type is missing from it or the payload doesn't match it
+pub fn validate_payload(schema: &Json, ty: &str, payload: &Json) -> Result<(), String> {
+    let schema = schema
+        .try_into_any::<Map<String, Value>>()
+        .map_err(|err| format!("Executor data model schema is malformed: {err}"))?;
+    let payload = payload
+        .try_into_any::<Value>()
+        .map_err(|err| format!("Event payload is malformed: {err}"))?;
+
+    Validator { schema: &schema }.validate(ty, &payload)
+}
+
+struct Validator<'schema> {
+    schema: &'schema Map<String, Value>,
+}
+
+impl Validator<'_> {
+    fn validate(&self, ty: &str, value: &Value) -> Result<(), String> {
+        let mismatch = || Err(format!("Expected `{ty}`, got `{value}`"));
+        let Some(meta) = self.schema.get(ty) else {
+            return Err(format!("Type `{ty}` is missing from the schema"));
+        };
+
+        let (kind, meta) = match meta {
+            // Unit struct
+            Value::Null => return check(value.is_null(), mismatch),
+            Value::String(name) if name == "String" => return check(value.is_string(), mismatch),
+            Value::String(name) if name == "bool" => return check(value.is_boolean(), mismatch),
+            // Newtype struct, transparent in JSON
+            Value::String(inner) => return self.validate(inner, value),
+            Value::Object(meta) if meta.len() == 1 => meta.iter().next().expect("Checked above"),
+            _ => return Err(format!("Schema of `{ty}` is malformed")),
+        };
+
+        // Types with custom serialization, e.g. identifiers or numerics, are serialized
+        // as strings which can't be validated without knowing how to parse them
+        if value.is_string() && matches!(kind.as_str(), "Struct" | "Tuple" | "FixedPoint") {
+            return Ok(());
+        }
+
+        match (kind.as_str(), meta) {
+            ("Struct", Value::Array(fields)) => self.validate_struct(ty, fields, value),
+            ("Tuple", Value::Array(types)) => match value {
+                Value::Array(items) if items.len() == types.len() => types
+                    .iter()
+                    .zip(items)
+                    .try_for_each(|(item_ty, item)| self.validate(type_name(item_ty)?, item)),
+                _ => mismatch(),
+            },
+            ("Enum", Value::Array(variants)) => self.validate_enum(ty, variants, value),
+            ("Int" | "Bitmap", _) if value.is_i64() || value.is_u64() => Ok(()),
+            ("FixedPoint", _) if value.is_number() => Ok(()),
+            ("Option", _) if value.is_null() => Ok(()),
+            ("Option", item_ty) => self.validate(type_name(item_ty)?, value),
+            ("Vec", item_ty) => {
+                let Value::Array(items) = value else {
+                    return mismatch();
+                };
+                let item_ty = type_name(item_ty)?;
+                items
+                    .iter()
+                    .try_for_each(|item| self.validate(item_ty, item))
+            }
+            ("Array", Value::Object(array)) => {
+                let (Some(item_ty), Some(len)) = (
+                    array.get("type").and_then(Value::as_str),
+                    array.get("len").and_then(Value::as_u64),
+                ) else {
+                    return Err(format!("Schema of `{ty}` is malformed"));
+                };
+                match value {
+                    Value::Array(items) if items.len() as u64 == len => items
+                        .iter()
+                        .try_for_each(|item| self.validate(item_ty, item)),
+                    _ => mismatch(),
+                }
+            }
+            ("Map", Value::Object(map)) => {
+                let Some(value_ty) = map.get("value").and_then(Value::as_str) else {
+                    return Err(format!("Schema of `{ty}` is malformed"));
+                };
+                // Keys are serialized as strings regardless of their type
+                let Value::Object(object) = value else {
+                    return mismatch();
+                };
+                object
+                    .values()
+                    .try_for_each(|item| self.validate(value_ty, item))
+            }
+            ("Result", Value::Object(result)) => self.validate_result(ty, result, value),
+            ("Int" | "Bitmap" | "FixedPoint", _) => mismatch(),
+            _ => Err(format!("Schema of `{ty}` is malformed")),
+        }
+    }
+
+    fn validate_struct(&self, ty: &str, fields: &[Value], value: &Value) -> Result<(), String> {
+        let Value::Object(object) = value else {
+            return Err(format!("Expected `{ty}`, got `{value}`"));
+        };
+
+        for field in fields {
+            let (Some(name), Some(field_ty)) = (
+                field.get("name").and_then(Value::as_str),
+                field.get("type").and_then(Value::as_str),
+            ) else {
+                return Err(format!("Schema of `{ty}` is malformed"));
+            };
+            match object.get(name) {
+                Some(field_value) => self.validate(field_ty, field_value)?,
+                // Missing optional fields are deserialized as `None`
+                None if self.is_option(field_ty) => {}
+                None => return Err(format!("Field `{name}` of `{ty}` is missing")),
+            }
+        }
+        if let Some(name) = object.keys().find(|name| {
+            !fields
+                .iter()
+                .any(|field| field.get("name").and_then(Value::as_str) == Some(name))
+        }) {
+            return Err(format!("`{ty}` has no field `{name}`"));
+        }
+
+        Ok(())
+    }
+
+    fn validate_enum(&self, ty: &str, variants: &[Value], value: &Value) -> Result<(), String> {
+        let (tag, variant_value) = match value {
+            Value::String(tag) => (tag, None),
+            Value::Object(object) if object.len() == 1 => {
+                let (tag, variant_value) = object.iter().next().expect("Checked above");
+                (tag, Some(variant_value))
+            }
+            _ => return Err(format!("Expected `{ty}`, got `{value}`")),
+        };
+        let Some(variant) = variants
+            .iter()
+            .find(|variant| variant.get("tag").and_then(Value::as_str) == Some(tag))
+        else {
+            return Err(format!("`{ty}` has no variant `{tag}`"));
+        };
+
+        match (variant.get("type"), variant_value) {
+            (None, None) => Ok(()),
+            (Some(variant_ty), Some(variant_value)) => {
+                self.validate(type_name(variant_ty)?, variant_value)
+            }
+            _ => Err(format!("Expected `{ty}`, got `{value}`")),
+        }
+    }
+
+    fn validate_result(
+        &self,
+        ty: &str,
+        result: &Map<String, Value>,
+        value: &Value,
+    ) -> Result<(), String> {
+        let (tag, variant_value) = match value {
+            Value::Object(object) if object.len() == 1 => {
+                object.iter().next().expect("Checked above")
+            }
+            _ => return Err(format!("Expected `{ty}`, got `{value}`")),
+        };
+        let variant_ty = match tag.as_str() {
+            "Ok" => result.get("ok"),
+            "Err" => result.get("err"),
+            _ => return Err(format!("Expected `{ty}`, got `{value}`")),
+        };
+
+        variant_ty.map_or_else(
+            || Err(format!("Schema of `{ty}` is malformed")),
+            |variant_ty| self.validate(type_name(variant_ty)?, variant_value),
+        )
+    }
+
+    fn is_option(&self, ty: &str) -> bool {
+        matches!(self.schema.get(ty), Some(Value::Object(meta)) if meta.contains_key("Option"))
+    }
+}
+
+fn check(matches: bool, mismatch: impl FnOnce() -> Result<(), String>) -> Result<(), String> {
+    if matches {
+        Ok(())
+    } else {
+        mismatch()
+    }
+}
+
+fn type_name(ty: &Value) -> Result<&str, String> {
+    ty.as_str()
+        .ok_or_else(|| format!("Expected type name in the schema, got `{ty}`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_schema::IntoSchema;
+    use serde::Serialize;
+
+    use super::*;
+
+    #[derive(Serialize, IntoSchema)]
+    struct Announcement {
+        message: String,
+        priority: u8,
+        topic: Option<Topic>,
+        tags: Vec<String>,
+    }
+
+    #[derive(Serialize, IntoSchema)]
+    enum Topic {
+        Weather,
+        Sport(u32),
+    }
+
+    fn schema() -> Json {
+        Json::new(Announcement::schema())
+    }
+
+    fn validate(payload: Value) -> Result<(), String> {
+        validate_payload(&schema(), "Announcement", &Json::from(payload))
+    }
+
+    #[test]
+    fn payload_matching_schema_is_accepted() {
+        for topic in [None, Some(Topic::Weather), Some(Topic::Sport(2))] {
+            let announcement = Announcement {
+                message: "The white rabbit is late".to_owned(),
+                priority: 1,
+                topic,
+                tags: vec!["rabbit".to_owned()],
+            };
+            validate_payload(&schema(), "Announcement", &Json::new(&announcement)).unwrap();
+        }
+
+        validate(serde_json::json!({
+            "message": "",
+            "priority": 0,
+            "topic": "Weather",
+            "tags": [],
+        }))
+        .unwrap();
+        // Missing optional fields are deserialized as `None`
+        validate(serde_json::json!({ "message": "", "priority": 0, "tags": [] })).unwrap();
+    }
+
+    #[test]
+    fn payload_not_matching_schema_is_rejected() {
+        for payload in [
+            serde_json::json!(42),
+            serde_json::json!(["The white rabbit is late", 0, null, []]),
+            serde_json::json!({ "priority": 0, "tags": [] }),
+            serde_json::json!({ "message": 1, "priority": 0, "tags": [] }),
+            serde_json::json!({ "message": "", "priority": "high", "tags": [] }),
+            serde_json::json!({ "message": "", "priority": 0, "tags": [1] }),
+            serde_json::json!({ "message": "", "priority": 0, "tags": [], "topic": "Music" }),
+            serde_json::json!({ "message": "", "priority": 0, "tags": [], "topic": { "Sport": "" } }),
+            serde_json::json!({ "message": "", "priority": 0, "tags": [], "author": "alice" }),
+        ] {
+            assert!(
+                validate(payload.clone()).is_err(),
+                "{payload} must be rejected"
+            );
+        }
+
+        assert!(validate_payload(&schema(), "Topic", &Json::from("Weather")).is_ok());
+        assert!(validate_payload(&schema(), "Unknown", &Json::from("Weather")).is_err());
+    }
+}
@@ -4,7 +4,9 @@
 pub mod account;
 pub mod asset;
 pub mod block;
+pub mod contract;
 pub mod domain;
+pub mod event;
 pub mod nft;
 pub mod query;
 pub mod triggers;
@@ -56,6 +58,11 @@ impl Execute for InstructionBox {
             Self::SetParameter(isi) => isi.execute(authority, state_transaction),
             Self::Upgrade(isi) => isi.execute(authority, state_transaction),
             Self::Log(isi) => isi.execute(authority, state_transaction),
+            Self::AccountKey(isi) => isi.execute(authority, state_transaction),
+            Self::AccountAlias(isi) => isi.execute(authority, state_transaction),
+            Self::Contract(isi) => isi.execute(authority, state_transaction),
+            Self::EmitEvent(isi) => isi.execute(authority, state_transaction),
+            Self::UpgradeTrigger(isi) => isi.execute(authority, state_transaction),
             Self::Custom(_) => {
                 panic!("Custom instructions should be handled in custom executor");
             }
@@ -207,6 +214,52 @@ impl Execute for RevokeBox {
     }
 }
 
+impl Execute for AccountKeyBox {
+    #[iroha_logger::log(name = "account_key", skip_all, fields(account))]
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        match self {
+            Self::Add(sub_isi) => sub_isi.execute(authority, state_transaction),
+            Self::Remove(sub_isi) => sub_isi.execute(authority, state_transaction),
+            Self::Rotate(sub_isi) => sub_isi.execute(authority, state_transaction),
+            Self::SetThreshold(sub_isi) => sub_isi.execute(authority, state_transaction),
+        }
+    }
+}
+
+impl Execute for AccountAliasBox {
+    #[iroha_logger::log(name = "account_alias", skip_all, fields(alias))]
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        match self {
+            Self::Register(sub_isi) => sub_isi.execute(authority, state_transaction),
+            Self::Transfer(sub_isi) => sub_isi.execute(authority, state_transaction),
+            Self::Unregister(sub_isi) => sub_isi.execute(authority, state_transaction),
+        }
+    }
+}
+
+impl Execute for ContractBox {
+    #[iroha_logger::log(name = "contract", skip_all, fields(contract))]
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        match self {
+            Self::Deploy(sub_isi) => sub_isi.execute(authority, state_transaction),
+            Self::Remove(sub_isi) => sub_isi.execute(authority, state_transaction),
+            Self::Call(sub_isi) => sub_isi.execute(authority, state_transaction),
+        }
+    }
+}
+
 pub mod prelude {
     //! Re-export important traits and types for glob import `(::*)`
     pub use super::*;
@@ -480,4 +533,64 @@ mod tests {
         ));
         Ok(())
     }
+
+    #[test]
+    async fn validator_set_changes_are_staged_until_epoch_boundary() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        state_transaction.world.parameters.sumeragi.epoch_length = 10;
+        state_transaction
+            .world
+            .parameters
+            .sumeragi
+            .max_validator_changes = 1;
+        let account_id = ALICE_ID.clone();
+        let peer_id = PeerId::new(KeyPair::random().into_parts().0);
+
+        Register::peer(peer_id.clone()).execute(&account_id, &mut state_transaction)?;
+        assert!(state_transaction.world.peers().is_empty());
+        let pending = state_transaction
+            .world
+            .pending_validators()
+            .expect("Validator set change should be staged");
+        assert_eq!(pending.peers(), &vec![peer_id.clone()]);
+        assert_eq!(pending.staged_in_epoch(), 0);
+
+        let another_peer_id = PeerId::new(KeyPair::random().into_parts().0);
+        assert!(matches!(
+            Register::peer(another_peer_id.clone())
+                .execute(&account_id, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::InvariantViolation(_)
+        ));
+
+        // Changes appended in a later epoch don't postpone the activation
+        state_transaction.world.parameters.sumeragi.epoch_length = 1;
+        state_transaction
+            .world
+            .parameters
+            .sumeragi
+            .max_validator_changes = 2;
+        Register::peer(another_peer_id.clone()).execute(&account_id, &mut state_transaction)?;
+        let pending = state_transaction
+            .world
+            .pending_validators()
+            .expect("Validator set change should be staged");
+        assert_eq!(pending.peers().len(), 2);
+        assert_eq!(pending.staged_in_epoch(), 0);
+
+        Unregister::peer(peer_id).execute(&account_id, &mut state_transaction)?;
+        Unregister::peer(another_peer_id).execute(&account_id, &mut state_transaction)?;
+        assert!(state_transaction.world.pending_validators().is_none());
+
+        state_transaction.apply();
+        state_block.commit();
+
+        Ok(())
+    }
 }
@@ -9,8 +9,9 @@ use iroha_data_model::{
         dsl::{EvaluateSelector, HasProjection, SelectorMarker},
         error::QueryExecutionFail as Error,
         parameters::QueryParams,
-        CommittedTransaction, QueryBox, QueryOutputBatchBox, QueryRequest,
-        QueryRequestWithAuthority, QueryResponse, SingularQueryBox, SingularQueryOutputBox,
+        CommittedTransaction, CommittedTriggerReceipt, QueryBox, QueryOutputBatchBox, QueryRequest,
+        QueryRequestWithAuthority, QueryResponse, SignedQuery, SingularQueryBox,
+        SingularQueryOutputBox,
     },
 };
 
@@ -71,6 +72,12 @@ impl SortableQueryOutput for RoleId {
     }
 }
 
+impl SortableQueryOutput for Name {
+    fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
+        None
+    }
+}
+
 impl SortableQueryOutput for CommittedTransaction {
     fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
         None
@@ -113,6 +120,30 @@ impl SortableQueryOutput for iroha_data_model::block::BlockHeader {
     }
 }
 
+impl SortableQueryOutput for CommittedTriggerReceipt {
+    fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
+        None
+    }
+}
+
+impl SortableQueryOutput for StorageEntry {
+    fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
+        None
+    }
+}
+
+impl SortableQueryOutput for Contract {
+    fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
+        None
+    }
+}
+
+impl SortableQueryOutput for iroha_data_model::block::EquivocationEvidence {
+    fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
+        None
+    }
+}
+
 /// Applies sorting and pagination to the query output and wraps it into a type-erasing batching iterator.
 ///
 /// # Errors
@@ -187,20 +218,33 @@ where
 pub struct ValidQueryRequest(QueryRequest);
 
 impl ValidQueryRequest {
-    /// Validate a query for an API client by calling the executor.
+    /// Validate a query for an API client by checking that it is signed by one of
+    /// the keys of the authority and calling the executor.
     ///
     /// # Errors
     ///
-    /// Returns an error if the query validation fails.
+    /// - If the authority doesn't exist
+    /// - If the query isn't signed by a key of the authority
+    /// - If the query validation fails
     pub fn validate_for_client(
-        query: QueryRequestWithAuthority,
+        query: SignedQuery,
         state_ro: &impl StateReadOnly,
     ) -> Result<Self, ValidationFail> {
+        let SignedQuery::V1(query) = query;
+        let QueryRequestWithAuthority { authority, request } = query.payload;
+
+        let account = state_ro.world().account(&authority).map_err(Error::from)?;
+        if !account.keys.contains(&query.signature.public_key) {
+            return Err(ValidationFail::NotPermitted(
+                "Query is not signed by a key of the authority".to_owned(),
+            ));
+        }
+
         state_ro
             .world()
             .executor()
-            .validate_query(state_ro, &query.authority, &query.request)?;
-        Ok(Self(query.request))
+            .validate_query(state_ro, &authority, &request)?;
+        Ok(Self(request))
     }
 
     /// Validate a query for a wasm program.
@@ -245,6 +289,25 @@ impl ValidQueryRequest {
                     SingularQueryBox::FindParameters(q) => {
                         SingularQueryOutputBox::from(q.execute(state)?)
                     }
+                    SingularQueryBox::FindAccountIdByAlias(q) => {
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
+                    SingularQueryBox::FindRandomness(q) => {
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
+                    SingularQueryBox::FindValidatorSets(q) => {
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
+                    SingularQueryBox::CustomQuery(q) => SingularQueryOutputBox::from(
+                        state
+                            .world()
+                            .executor()
+                            .execute_custom_query(state, authority, q)
+                            .map_err(|err| match err {
+                                ValidationFail::QueryFailed(err) => err,
+                                err => Error::Custom(err.to_string()),
+                            })?,
+                    ),
                 };
 
                 Ok(QueryResponse::Singular(output))
@@ -302,6 +365,11 @@ impl ValidQueryRequest {
                         q.selector,
                         &iter_query.params,
                     )?,
+                    QueryBox::FindAliasesByAccountId(q) => apply_query_postprocessing(
+                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        q.selector,
+                        &iter_query.params,
+                    )?,
                     QueryBox::FindPeers(q) => apply_query_postprocessing(
                         ValidQuery::execute(q.query, q.predicate, state)?,
                         q.selector,
@@ -332,6 +400,26 @@ impl ValidQueryRequest {
                         q.selector,
                         &iter_query.params,
                     )?,
+                    QueryBox::FindTriggerReceipts(q) => apply_query_postprocessing(
+                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        q.selector,
+                        &iter_query.params,
+                    )?,
+                    QueryBox::FindStorageEntries(q) => apply_query_postprocessing(
+                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        q.selector,
+                        &iter_query.params,
+                    )?,
+                    QueryBox::FindContracts(q) => apply_query_postprocessing(
+                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        q.selector,
+                        &iter_query.params,
+                    )?,
+                    QueryBox::FindEquivocations(q) => apply_query_postprocessing(
+                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        q.selector,
+                        &iter_query.params,
+                    )?,
                 };
 
                 Ok(QueryResponse::Iterable(
@@ -19,7 +19,8 @@ pub mod isi {
         trigger::prelude::*,
     };
 
-    use super::{super::prelude::*, *};
+    use super::{super::prelude::*, set::ExecutableRef, *};
+    use crate::smartcontracts::wasm;
 
     impl Execute for Register<Trigger> {
         #[metrics(+"register_trigger")]
@@ -88,6 +89,12 @@ pub mod isi {
                 EventFilterBox::TriggerCompleted(_) => {
                     unreachable!("Disallowed during deserialization");
                 }
+                EventFilterBox::Custom(_) => triggers.add_custom_trigger(
+                    &engine,
+                    new_trigger
+                        .try_into()
+                        .map_err(|e: &str| Error::Conversion(e.to_owned()))?,
+                ),
             }
             .map_err(|e| InvalidParameterError::Wasm(e.to_string()))?;
 
@@ -118,6 +125,9 @@ pub mod isi {
 
             let triggers = &mut state_transaction.world.triggers;
             if triggers.remove(trigger_id.clone()) {
+                state_transaction
+                    .world
+                    .remove_smart_contract_storage(&StorageOwner::Trigger(trigger_id.clone()));
                 state_transaction
                     .world
                     .emit_events(Some(TriggerEvent::Deleted(trigger_id)));
@@ -305,6 +315,92 @@ pub mod isi {
             Ok(())
         }
     }
+
+    impl Execute for UpgradeTrigger {
+        #[metrics(+"upgrade_trigger")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let UpgradeTrigger {
+                trigger: trigger_id,
+                executable,
+            } = self;
+
+            let trigger_authority = state_transaction
+                .world
+                .triggers
+                .inspect_by_id(&trigger_id, |action| action.authority().clone())
+                .ok_or_else(|| Error::Find(FindError::Trigger(trigger_id.clone())))?;
+
+            let engine = state_transaction.engine.clone(); // Cloning engine is cheap
+
+            let triggers = &mut state_transaction.world.triggers;
+            let new_executable = match executable {
+                Executable::Wasm(bytes) => ExecutableRef::Wasm(
+                    triggers
+                        .add_contract_module(&engine, bytes)
+                        .map_err(|e| InvalidParameterError::Wasm(e.to_string()))?,
+                ),
+                Executable::Instructions(instructions) => ExecutableRef::Instructions(instructions),
+            };
+            let new_module_hash = match &new_executable {
+                ExecutableRef::Wasm(hash) => Some(*hash),
+                ExecutableRef::Instructions(_) => None,
+            };
+            let prev_executable = triggers
+                .inspect_by_id_mut(&trigger_id, |action| action.set_executable(new_executable))
+                .expect("trigger existence is checked above");
+            let prev_module_hash = match prev_executable {
+                ExecutableRef::Wasm(hash) => {
+                    triggers.remove_contract_module(hash);
+                    Some(hash)
+                }
+                ExecutableRef::Instructions(_) => None,
+            };
+
+            let upgraded = TriggerUpgraded {
+                trigger: trigger_id.clone(),
+                prev_module_hash,
+                new_module_hash,
+            };
+
+            if let Some(hash) = new_module_hash {
+                let module = triggers
+                    .get_compiled_contract(&hash)
+                    .expect("INTERNAL BUG: module of the upgraded trigger is missing")
+                    .clone(); // Cloning module is cheap, under Arc inside
+                let config = state_transaction.world.parameters().smart_contract;
+
+                wasm::RuntimeBuilder::<wasm::state::Trigger>::new()
+                    .with_engine(engine)
+                    .with_config(config)
+                    .build()
+                    .and_then(|mut runtime| {
+                        runtime.execute_trigger_migration(
+                            state_transaction,
+                            &trigger_id,
+                            trigger_authority,
+                            &module,
+                            DataEvent::from(TriggerEvent::Upgraded(upgraded.clone())).into(),
+                        )
+                    })
+                    .map_err(|error| {
+                        InvalidParameterError::Wasm(format!(
+                            "{:?}",
+                            eyre::eyre!(error).wrap_err("Trigger migration failed"),
+                        ))
+                    })?;
+            }
+
+            state_transaction
+                .world
+                .emit_events(Some(TriggerEvent::Upgraded(upgraded)));
+
+            Ok(())
+        }
+    }
 }
 
 pub mod query {
@@ -21,11 +21,8 @@ use iroha_data_model::{
 };
 use iroha_primitives::const_vec::ConstVec;
 use mv::{
-    cell::{Block as CellBlock, Cell, Transaction as CellTransaction, View as CellView},
-    storage::{
-        Block as StorageBlock, Storage, StorageReadOnly, Transaction as StorageTransaction,
-        View as StorageView,
-    },
+    cell::{Block as CellBlock, Cell, View as CellView},
+    storage::{Block as StorageBlock, Storage, StorageReadOnly, View as StorageView},
 };
 use serde::{
     de::{DeserializeSeed, MapAccess, Visitor},
@@ -34,13 +31,14 @@ use serde::{
 use thiserror::Error;
 
 use crate::{
+    parallel::{TrackedCell, TrackedStorage},
     smartcontracts::{
         isi::triggers::specialized::{
             LoadedAction, LoadedActionTrait, SpecializedAction, SpecializedTrigger,
         },
         wasm,
     },
-    state::deserialize::WasmSeed,
+    state::{deserialize::WasmSeed, detach_storage},
 };
 
 /// Error type for [`Set`] operations.
@@ -59,7 +57,7 @@ type WasmSmartContractMap = Storage<HashOf<WasmSmartContract>, WasmSmartContract
 type WasmSmartContractMapBlock<'set> =
     StorageBlock<'set, HashOf<WasmSmartContract>, WasmSmartContractEntry>;
 type WasmSmartContractMapTransaction<'block, 'set> =
-    StorageTransaction<'block, 'set, HashOf<WasmSmartContract>, WasmSmartContractEntry>;
+    TrackedStorage<'block, 'set, HashOf<WasmSmartContract>, WasmSmartContractEntry>;
 type WasmSmartContractMapView<'set> =
     StorageView<'set, HashOf<WasmSmartContract>, WasmSmartContractEntry>;
 
@@ -76,6 +74,8 @@ pub struct Set {
     time_triggers: Storage<TriggerId, LoadedAction<TimeEventFilter>>,
     /// Triggers using [`ExecuteTriggerEventFilter`]
     by_call_triggers: Storage<TriggerId, LoadedAction<ExecuteTriggerEventFilter>>,
+    /// Triggers using [`CustomEventFilter`]
+    custom_triggers: Storage<TriggerId, LoadedAction<CustomEventFilter>>,
     /// Trigger ids with type of events they process
     ids: Storage<TriggerId, TriggeringEventType>,
     /// [`WasmSmartContract`]s map by wasm blob hash.
@@ -100,6 +100,8 @@ pub struct SetBlock<'set> {
     time_triggers: StorageBlock<'set, TriggerId, LoadedAction<TimeEventFilter>>,
     /// Triggers using [`ExecuteTriggerEventFilter`]
     by_call_triggers: StorageBlock<'set, TriggerId, LoadedAction<ExecuteTriggerEventFilter>>,
+    /// Triggers using [`CustomEventFilter`]
+    custom_triggers: StorageBlock<'set, TriggerId, LoadedAction<CustomEventFilter>>,
     /// Trigger ids with type of events they process
     ids: StorageBlock<'set, TriggerId, TriggeringEventType>,
     /// Original [`WasmSmartContract`]s by [`TriggerId`] for querying purposes.
@@ -112,22 +114,24 @@ pub struct SetBlock<'set> {
 /// Trigger set for transaction's aggregated changes
 pub struct SetTransaction<'block, 'set> {
     /// Triggers using [`DataEventFilter`]
-    data_triggers: StorageTransaction<'block, 'set, TriggerId, LoadedAction<DataEventFilter>>,
+    data_triggers: TrackedStorage<'block, 'set, TriggerId, LoadedAction<DataEventFilter>>,
     /// Triggers using [`PipelineEventFilterBox`]
     pipeline_triggers:
-        StorageTransaction<'block, 'set, TriggerId, LoadedAction<PipelineEventFilterBox>>,
+        TrackedStorage<'block, 'set, TriggerId, LoadedAction<PipelineEventFilterBox>>,
     /// Triggers using [`TimeEventFilter`]
-    time_triggers: StorageTransaction<'block, 'set, TriggerId, LoadedAction<TimeEventFilter>>,
+    time_triggers: TrackedStorage<'block, 'set, TriggerId, LoadedAction<TimeEventFilter>>,
     /// Triggers using [`ExecuteTriggerEventFilter`]
     by_call_triggers:
-        StorageTransaction<'block, 'set, TriggerId, LoadedAction<ExecuteTriggerEventFilter>>,
+        TrackedStorage<'block, 'set, TriggerId, LoadedAction<ExecuteTriggerEventFilter>>,
+    /// Triggers using [`CustomEventFilter`]
+    custom_triggers: TrackedStorage<'block, 'set, TriggerId, LoadedAction<CustomEventFilter>>,
     /// Trigger ids with type of events they process
-    ids: StorageTransaction<'block, 'set, TriggerId, TriggeringEventType>,
+    ids: TrackedStorage<'block, 'set, TriggerId, TriggeringEventType>,
     /// Original [`WasmSmartContract`]s by [`TriggerId`] for querying purposes.
     contracts: WasmSmartContractMapTransaction<'block, 'set>,
     /// List of actions that should be triggered by events provided by `handle_*` methods.
     /// Vector is used to save the exact triggers order.
-    matched_ids: CellTransaction<'block, 'set, Vec<(EventBox, TriggerId)>>,
+    matched_ids: TrackedCell<'block, 'set, Vec<(EventBox, TriggerId)>>,
 }
 
 /// Consistent point in time view of the [`Set`]
@@ -140,6 +144,8 @@ pub struct SetView<'set> {
     time_triggers: StorageView<'set, TriggerId, LoadedAction<TimeEventFilter>>,
     /// Triggers using [`ExecuteTriggerEventFilter`]
     by_call_triggers: StorageView<'set, TriggerId, LoadedAction<ExecuteTriggerEventFilter>>,
+    /// Triggers using [`CustomEventFilter`]
+    custom_triggers: StorageView<'set, TriggerId, LoadedAction<CustomEventFilter>>,
     /// Trigger ids with type of events they process
     ids: StorageView<'set, TriggerId, TriggeringEventType>,
     /// Original [`WasmSmartContract`]s by [`TriggerId`] for querying purposes.
@@ -187,6 +193,7 @@ impl<'de> DeserializeSeed<'de> for WasmSeed<'_, Set> {
                 let mut pipeline_triggers = None;
                 let mut time_triggers = None;
                 let mut by_call_triggers = None;
+                let mut custom_triggers = None;
                 let mut ids = None;
                 let mut contracts = None;
                 let mut matched_ids = None;
@@ -204,6 +211,9 @@ impl<'de> DeserializeSeed<'de> for WasmSeed<'_, Set> {
                         "by_call_triggers" => {
                             by_call_triggers = Some(map.next_value()?);
                         }
+                        "custom_triggers" => {
+                            custom_triggers = Some(map.next_value()?);
+                        }
                         "ids" => {
                             ids = Some(map.next_value()?);
                         }
@@ -229,6 +239,8 @@ impl<'de> DeserializeSeed<'de> for WasmSeed<'_, Set> {
                         .ok_or_else(|| serde::de::Error::missing_field("time_triggers"))?,
                     by_call_triggers: by_call_triggers
                         .ok_or_else(|| serde::de::Error::missing_field("by_call_triggers"))?,
+                    custom_triggers: custom_triggers
+                        .ok_or_else(|| serde::de::Error::missing_field("custom_triggers"))?,
                     ids: ids.ok_or_else(|| serde::de::Error::missing_field("ids"))?,
                     contracts: contracts
                         .ok_or_else(|| serde::de::Error::missing_field("contracts"))?,
@@ -307,6 +319,7 @@ pub trait SetReadOnly {
     fn by_call_triggers(
         &self,
     ) -> &impl StorageReadOnly<TriggerId, LoadedAction<ExecuteTriggerEventFilter>>;
+    fn custom_triggers(&self) -> &impl StorageReadOnly<TriggerId, LoadedAction<CustomEventFilter>>;
     fn ids(&self) -> &impl StorageReadOnly<TriggerId, TriggeringEventType>;
     fn contracts(&self)
         -> &impl StorageReadOnly<HashOf<WasmSmartContract>, WasmSmartContractEntry>;
@@ -406,6 +419,13 @@ pub trait SetReadOnly {
                     .expect("`Set::by_call_triggers` doesn't contain required id. This is a bug")
                     .executable
             }
+            TriggeringEventType::Custom => {
+                &self
+                    .custom_triggers()
+                    .get(id)
+                    .expect("`Set::custom_triggers` doesn't contain required id. This is a bug")
+                    .executable
+            }
         })
     }
 
@@ -446,6 +466,12 @@ pub trait SetReadOnly {
                     );
                     filter(action).then(|| f(id, action))
                 }
+                TriggeringEventType::Custom => {
+                    let action = self.custom_triggers().get(id).expect(
+                        "`Set::custom_triggers` doesn't contain required id. This is a bug",
+                    );
+                    filter(action).then(|| f(id, action))
+                }
             })
     }
 
@@ -479,6 +505,11 @@ pub trait SetReadOnly {
                 .get(id)
                 .map(|entry| f(entry))
                 .expect("`Set::by_call_triggers` doesn't contain required id. This is a bug"),
+            TriggeringEventType::Custom => self
+                .custom_triggers()
+                .get(id)
+                .map(|entry| f(entry))
+                .expect("`Set::custom_triggers` doesn't contain required id. This is a bug"),
         };
         Some(result)
     }
@@ -499,6 +530,9 @@ macro_rules! impl_set_ro {
             fn by_call_triggers(&self) -> &impl StorageReadOnly<TriggerId, LoadedAction<ExecuteTriggerEventFilter>> {
                 &self.by_call_triggers
             }
+            fn custom_triggers(&self) -> &impl StorageReadOnly<TriggerId, LoadedAction<CustomEventFilter>> {
+                &self.custom_triggers
+            }
             fn ids(&self) -> &impl StorageReadOnly<TriggerId, TriggeringEventType> {
                 &self.ids
             }
@@ -524,6 +558,7 @@ impl Set {
             pipeline_triggers: self.pipeline_triggers.block(),
             time_triggers: self.time_triggers.block(),
             by_call_triggers: self.by_call_triggers.block(),
+            custom_triggers: self.custom_triggers.block(),
             ids: self.ids.block(),
             contracts: self.contracts.block(),
             matched_ids: self.matched_ids.block(),
@@ -537,6 +572,7 @@ impl Set {
             pipeline_triggers: self.pipeline_triggers.block_and_revert(),
             time_triggers: self.time_triggers.block_and_revert(),
             by_call_triggers: self.by_call_triggers.block_and_revert(),
+            custom_triggers: self.custom_triggers.block_and_revert(),
             ids: self.ids.block_and_revert(),
             contracts: self.contracts.block_and_revert(),
             matched_ids: self.matched_ids.block_and_revert(),
@@ -550,6 +586,7 @@ impl Set {
             pipeline_triggers: self.pipeline_triggers.view(),
             time_triggers: self.time_triggers.view(),
             by_call_triggers: self.by_call_triggers.view(),
+            custom_triggers: self.custom_triggers.view(),
             ids: self.ids.view(),
             contracts: self.contracts.view(),
             matched_ids: self.matched_ids.view(),
@@ -557,17 +594,49 @@ impl Set {
     }
 }
 
+impl Set {
+    /// Copy the `set` at its current point, see [`detach_storage`]
+    pub(crate) fn detached_from(set: &impl SetReadOnly) -> Self {
+        Self {
+            data_triggers: detach_storage(set.data_triggers()),
+            pipeline_triggers: detach_storage(set.pipeline_triggers()),
+            time_triggers: detach_storage(set.time_triggers()),
+            by_call_triggers: detach_storage(set.by_call_triggers()),
+            custom_triggers: detach_storage(set.custom_triggers()),
+            ids: detach_storage(set.ids()),
+            contracts: detach_storage(set.contracts()),
+            matched_ids: Cell::new(set.matched_ids().to_vec()),
+        }
+    }
+}
+
 impl<'set> SetBlock<'set> {
     /// Create struct to apply transaction's changes
     pub fn transaction(&mut self) -> SetTransaction<'_, 'set> {
         SetTransaction {
-            data_triggers: self.data_triggers.transaction(),
-            pipeline_triggers: self.pipeline_triggers.transaction(),
-            time_triggers: self.time_triggers.transaction(),
-            by_call_triggers: self.by_call_triggers.transaction(),
-            ids: self.ids.transaction(),
-            contracts: self.contracts.transaction(),
-            matched_ids: self.matched_ids.transaction(),
+            data_triggers: self.data_triggers.transaction().into(),
+            pipeline_triggers: self.pipeline_triggers.transaction().into(),
+            time_triggers: self.time_triggers.transaction().into(),
+            by_call_triggers: self.by_call_triggers.transaction().into(),
+            custom_triggers: self.custom_triggers.transaction().into(),
+            ids: self.ids.transaction().into(),
+            contracts: self.contracts.transaction().into(),
+            matched_ids: self.matched_ids.transaction().into(),
+        }
+    }
+
+    /// Create struct to buffer transaction's changes without changing the block,
+    /// so that several transactions can be executed on top of it at once
+    pub fn buffered_transaction(&self) -> SetTransaction<'_, 'set> {
+        SetTransaction {
+            data_triggers: (&self.data_triggers).into(),
+            pipeline_triggers: (&self.pipeline_triggers).into(),
+            time_triggers: (&self.time_triggers).into(),
+            by_call_triggers: (&self.by_call_triggers).into(),
+            custom_triggers: (&self.custom_triggers).into(),
+            ids: (&self.ids).into(),
+            contracts: (&self.contracts).into(),
+            matched_ids: (&self.matched_ids).into(),
         }
     }
 
@@ -577,6 +646,7 @@ impl<'set> SetBlock<'set> {
         self.matched_ids.commit();
         self.contracts.commit();
         self.ids.commit();
+        self.custom_triggers.commit();
         self.by_call_triggers.commit();
         self.time_triggers.commit();
         self.pipeline_triggers.commit();
@@ -610,6 +680,11 @@ impl<'set> SetBlock<'set> {
     pub fn extract_matched_ids(&mut self) -> Vec<(EventBox, TriggerId)> {
         core::mem::take(&mut self.matched_ids)
     }
+
+    /// Keep matched ids to be processed after the next block
+    pub fn defer_matched_ids(&mut self, matched_ids: Vec<(EventBox, TriggerId)>) {
+        self.matched_ids.extend(matched_ids);
+    }
 }
 
 trait TriggeringEventFilter: EventFilter {}
@@ -617,6 +692,7 @@ impl TriggeringEventFilter for DataEventFilter {}
 impl TriggeringEventFilter for PipelineEventFilterBox {}
 impl TriggeringEventFilter for TimeEventFilter {}
 impl TriggeringEventFilter for ExecuteTriggerEventFilter {}
+impl TriggeringEventFilter for CustomEventFilter {}
 
 impl<'block, 'set> SetTransaction<'block, 'set> {
     /// Apply transaction's changes
@@ -625,12 +701,18 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
         self.matched_ids.apply();
         self.contracts.apply();
         self.ids.apply();
+        self.custom_triggers.apply();
         self.by_call_triggers.apply();
         self.time_triggers.apply();
         self.pipeline_triggers.apply();
         self.data_triggers.apply();
     }
 
+    /// Keep actions matched by the events of a transaction which was executed elsewhere
+    pub fn extend_matched_ids(&mut self, matched_ids: Vec<(EventBox, TriggerId)>) {
+        self.matched_ids.extend(matched_ids);
+    }
+
     /// Add trigger with [`DataEventFilter`]
     ///
     /// Return `false` if a trigger with given id already exists
@@ -703,6 +785,24 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
         })
     }
 
+    /// Add trigger with [`CustomEventFilter`]
+    ///
+    /// Returns `false` if a trigger with given id already exists
+    ///
+    /// # Errors
+    ///
+    /// Return [`Err`] if failed to preload wasm trigger
+    #[inline]
+    pub fn add_custom_trigger(
+        &mut self,
+        engine: &wasmtime::Engine,
+        trigger: SpecializedTrigger<CustomEventFilter>,
+    ) -> Result<bool> {
+        self.add_to(engine, trigger, TriggeringEventType::Custom, |me| {
+            &mut me.custom_triggers
+        })
+    }
+
     /// Add generic trigger to generic collection
     ///
     /// Returns `false` if a trigger with given id already exists
@@ -715,7 +815,7 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
         engine: &wasmtime::Engine,
         trigger: SpecializedTrigger<F>,
         event_type: TriggeringEventType,
-        map: impl FnOnce(&mut Self) -> &mut StorageTransaction<'block, 'set, TriggerId, LoadedAction<F>>,
+        map: impl FnOnce(&mut Self) -> &mut TrackedStorage<'block, 'set, TriggerId, LoadedAction<F>>,
     ) -> Result<bool> {
         let SpecializedTrigger {
             id: trigger_id,
@@ -735,27 +835,7 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
 
         let loaded_executable = match executable {
             Executable::Wasm(bytes) => {
-                let hash = HashOf::new(&bytes);
-                // Store original executable representation to respond to queries with.
-                if let Some(WasmSmartContractEntry { count, .. }) = self.contracts.get_mut(&hash) {
-                    // Considering 1 trigger registration takes 1 second,
-                    // it would take 584 942 417 355 years to overflow.
-                    *count = count.checked_add(1).expect(
-                        "There is no way someone could register 2^64 amount of same triggers",
-                    );
-                    // Cloning module is cheap, under Arc inside
-                } else {
-                    let module = wasm::load_module(engine, &bytes)?;
-                    self.contracts.insert(
-                        hash,
-                        WasmSmartContractEntry {
-                            original_contract: bytes,
-                            compiled_contract: module,
-                            count: NonZeroU64::MIN,
-                        },
-                    );
-                };
-                ExecutableRef::Wasm(hash)
+                ExecutableRef::Wasm(self.add_contract_module(engine, bytes)?)
             }
             Executable::Instructions(instructions) => ExecutableRef::Instructions(instructions),
         };
@@ -773,12 +853,58 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
         Ok(true)
     }
 
+    /// Store the `bytes` together with the compiled module or increase the counter
+    /// of the already stored ones.
+    ///
+    /// Returns the hash the module can be retrieved by.
+    ///
+    /// # Errors
+    ///
+    /// Return [`Err`] if failed to preload wasm module
+    pub fn add_contract_module(
+        &mut self,
+        engine: &wasmtime::Engine,
+        bytes: WasmSmartContract,
+    ) -> Result<HashOf<WasmSmartContract>> {
+        let hash = HashOf::new(&bytes);
+        // Store original executable representation to respond to queries with.
+        if let Some(WasmSmartContractEntry { count, .. }) = self.contracts.get_mut(&hash) {
+            // Considering 1 trigger registration takes 1 second,
+            // it would take 584 942 417 355 years to overflow.
+            *count = count
+                .checked_add(1)
+                .expect("There is no way someone could register 2^64 amount of same triggers");
+            // Cloning module is cheap, under Arc inside
+        } else {
+            let module = wasm::load_module(engine, &bytes)?;
+            self.contracts.insert(
+                hash,
+                WasmSmartContractEntry {
+                    original_contract: bytes,
+                    compiled_contract: module,
+                    count: NonZeroU64::MIN,
+                },
+            );
+        };
+        Ok(hash)
+    }
+
+    /// Decrease the counter of the module stored by [`Self::add_contract_module`]
+    /// or remove it if the counter reaches zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hash` is not in the [`Set::contracts`].
+    pub fn remove_contract_module(&mut self, hash: HashOf<WasmSmartContract>) {
+        Self::remove_original_trigger(&mut self.contracts, hash);
+    }
+
     /// Apply `f` to the trigger identified by `id`.
     ///
     /// Return [`None`] if [`Set`] doesn't contain the trigger with the given `id`.
     pub fn inspect_by_id_mut<F, R>(&mut self, id: &TriggerId, f: F) -> Option<R>
     where
-        F: Fn(&mut dyn LoadedActionTrait) -> R,
+        F: FnOnce(&mut dyn LoadedActionTrait) -> R,
     {
         let event_type = self.ids.get(id).copied()?;
 
@@ -803,6 +929,11 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
                 .get_mut(id)
                 .map(|entry| f(entry))
                 .expect("`Set::by_call_triggers` doesn't contain required id. This is a bug"),
+            TriggeringEventType::Custom => self
+                .custom_triggers
+                .get_mut(id)
+                .map(|entry| f(entry))
+                .expect("`Set::custom_triggers` doesn't contain required id. This is a bug"),
         };
         Some(result)
     }
@@ -832,6 +963,9 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
             TriggeringEventType::ExecuteTrigger => {
                 Self::remove_from(&mut self.contracts, &mut self.by_call_triggers, id)
             }
+            TriggeringEventType::Custom => {
+                Self::remove_from(&mut self.contracts, &mut self.custom_triggers, id)
+            }
         };
 
         assert!(
@@ -874,7 +1008,7 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
     /// Returns `true` if trigger was removed and `false` otherwise.
     fn remove_from<F: mv::Value + EventFilter>(
         contracts: &mut WasmSmartContractMapTransaction<'block, 'set>,
-        triggers: &mut StorageTransaction<'block, 'set, TriggerId, LoadedAction<F>>,
+        triggers: &mut TrackedStorage<'block, 'set, TriggerId, LoadedAction<F>>,
         trigger_id: TriggerId,
     ) -> bool {
         triggers
@@ -925,6 +1059,7 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
             pipeline_triggers,
             time_triggers,
             by_call_triggers,
+            custom_triggers,
             ids,
             contracts,
             ..
@@ -933,13 +1068,14 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
         Self::remove_zeros(ids, contracts, pipeline_triggers);
         Self::remove_zeros(ids, contracts, time_triggers);
         Self::remove_zeros(ids, contracts, by_call_triggers);
+        Self::remove_zeros(ids, contracts, custom_triggers);
     }
 
     /// Remove actions with zero execution count from `triggers`
     fn remove_zeros<F: mv::Value + EventFilter>(
-        ids: &mut StorageTransaction<'block, 'set, TriggerId, TriggeringEventType>,
+        ids: &mut TrackedStorage<'block, 'set, TriggerId, TriggeringEventType>,
         contracts: &mut WasmSmartContractMapTransaction<'block, 'set>,
-        triggers: &mut StorageTransaction<'block, 'set, TriggerId, LoadedAction<F>>,
+        triggers: &mut TrackedStorage<'block, 'set, TriggerId, LoadedAction<F>>,
     ) {
         let to_remove: Vec<TriggerId> = triggers
             .iter()
@@ -981,6 +1117,18 @@ impl<'block, 'set> SetTransaction<'block, 'set> {
         };
     }
 
+    /// Handle [`CustomEvent`].
+    ///
+    /// Find all actions that are triggered by `event` and store them.
+    /// These actions are inspected in the next [`Set::inspect_matched()`] call.
+    // Passing by value to follow other `handle_` methods interface
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn handle_custom_event(&mut self, event: CustomEvent) {
+        self.custom_triggers.iter().for_each(|entry| {
+            Self::match_and_insert_trigger(&mut self.matched_ids, event.clone(), entry)
+        });
+    }
+
     /// Match and insert a [`TriggerId`] into the set of matched ids.
     ///
     /// Skips insertion:
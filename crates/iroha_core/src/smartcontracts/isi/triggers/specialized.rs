@@ -106,6 +106,7 @@ impl_try_from_box! {
     Pipeline => PipelineEventFilterBox,
     Time => TimeEventFilter,
     ExecuteTrigger => ExecuteTriggerEventFilter,
+    Custom => CustomEventFilter,
 }
 
 /// Same as [`iroha_data_model::trigger::action::Action`] but with
@@ -140,6 +141,9 @@ pub trait LoadedActionTrait {
     /// Get action executable
     fn executable(&self) -> &ExecutableRef;
 
+    /// Replace action executable, returning the previous one
+    fn set_executable(&mut self, executable: ExecutableRef) -> ExecutableRef;
+
     /// Get action repeats enum
     fn repeats(&self) -> &Repeats;
 
@@ -170,6 +174,10 @@ impl<F: EventFilter + Into<EventFilterBox> + Clone> LoadedActionTrait for Loaded
         &self.executable
     }
 
+    fn set_executable(&mut self, executable: ExecutableRef) -> ExecutableRef {
+        core::mem::replace(&mut self.executable, executable)
+    }
+
     fn repeats(&self) -> &iroha_data_model::trigger::action::Repeats {
         &self.repeats
     }
@@ -246,6 +254,11 @@ mod tests {
                 EventFilterBox::TriggerCompleted(_) => {
                     unreachable!("Disallowed during deserialization")
                 }
+                EventFilterBox::Custom(_) => {
+                    SpecializedTrigger::<CustomEventFilter>::try_from(boxed)
+                        .map(|_| ())
+                        .unwrap()
+                }
             }
         }
     }
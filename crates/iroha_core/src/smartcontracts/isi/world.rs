@@ -38,6 +38,21 @@ pub mod isi {
         ) -> Result<(), Error> {
             let peer_id = self.object;
 
+            let staged = stage_validator_change(state_transaction, |peers| {
+                if peers.contains(&peer_id) {
+                    return Err(RepetitionError {
+                        instruction: InstructionType::Register,
+                        id: IdBox::PeerId(peer_id.clone()),
+                    }
+                    .into());
+                }
+                peers.push(peer_id.clone());
+                Ok(())
+            })?;
+            if staged {
+                return Ok(());
+            }
+
             let world = &mut state_transaction.world;
             if let PushResult::Duplicate(duplicate) = world.peers.push(peer_id.clone()) {
                 return Err(RepetitionError {
@@ -61,6 +76,18 @@ pub mod isi {
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let peer_id = self.object;
+
+            let staged = stage_validator_change(state_transaction, |peers| {
+                let Some(index) = peers.iter().position(|id| id == &peer_id) else {
+                    return Err(FindError::Peer(peer_id.clone()).into());
+                };
+                peers.remove(index);
+                Ok(())
+            })?;
+            if staged {
+                return Ok(());
+            }
+
             let world = &mut state_transaction.world;
             let Some(index) = world.peers.iter().position(|id| id == &peer_id) else {
                 return Err(FindError::Peer(peer_id).into());
@@ -74,6 +101,60 @@ pub mod isi {
         }
     }
 
+    /// Apply `change` to the validator set staged to be activated at an epoch boundary.
+    ///
+    /// Returns `false` without applying the change if epochs are disabled,
+    /// in which case the change is to be applied to the active validator set.
+    ///
+    /// There is no joint consensus phase: the block activating the staged set is the first one
+    /// committed by the new set alone, so the new set must be able to reach the quorum on its own.
+    fn stage_validator_change(
+        state_transaction: &mut StateTransaction<'_, '_>,
+        change: impl FnOnce(&mut Vec<PeerId>) -> Result<(), Error>,
+    ) -> Result<bool, Error> {
+        let curr_block = state_transaction.curr_block;
+        if curr_block.is_genesis() {
+            // Genesis peers make up the initial validator set
+            return Ok(false);
+        }
+        let sumeragi = state_transaction.world.parameters.sumeragi;
+        let Some(epoch) = sumeragi.epoch(curr_block.height()) else {
+            return Ok(false);
+        };
+
+        let world = &mut state_transaction.world;
+        let mut peers = match &*world.pending_validators {
+            Some(pending) => pending.peers.clone(),
+            None => world.peers.to_vec(),
+        };
+        change(&mut peers)?;
+
+        let joining = peers.iter().filter(|id| !world.peers.contains(id)).count();
+        let leaving = world.peers.iter().filter(|id| !peers.contains(id)).count();
+        let changes = joining + leaving;
+        if changes > 0 && peers.is_empty() {
+            return Err(Error::InvariantViolation(
+                "Validator set can't be empty".to_owned(),
+            ));
+        }
+        let max_changes = sumeragi.max_validator_changes;
+        if max_changes != 0 && changes as u64 > max_changes {
+            return Err(Error::InvariantViolation(format!(
+                "Validator set changes exceed the limit of {max_changes} peers per epoch"
+            )));
+        }
+
+        // Appending to the pending changes mustn't postpone their activation
+        let staged_in_epoch = Option::as_ref(&*world.pending_validators)
+            .map_or(epoch, PendingValidatorSet::staged_in_epoch);
+        *world.pending_validators = (changes > 0).then_some(PendingValidatorSet {
+            peers,
+            staged_in_epoch,
+        });
+
+        Ok(true)
+    }
+
     impl Execute for Register<Domain> {
         #[metrics("register_domain")]
         fn execute(
@@ -125,6 +206,9 @@ pub mod isi {
                 .collect::<Vec<_>>()
                 .into_iter()
                 .for_each(|trigger_id| {
+                    state_transaction
+                        .world
+                        .remove_smart_contract_storage(&StorageOwner::Trigger(trigger_id.clone()));
                     state_transaction
                         .world
                         .triggers
@@ -145,6 +229,11 @@ pub mod isi {
                     .remove(account.clone());
 
                 state_transaction.world.remove_account_roles(&account);
+                state_transaction.world.remove_account_aliases(&account);
+                state_transaction
+                    .world
+                    .remove_smart_contract_storage(&StorageOwner::Account(account.clone()));
+                state_transaction.world.remove_account_contracts(&account);
 
                 let remove_assets: Vec<AssetId> = state_transaction
                     .world
@@ -383,6 +472,9 @@ pub mod isi {
                 Sumeragi(sumeragi.max_clock_drift_ms) => SumeragiParameter::MaxClockDriftMs,
                 Sumeragi(sumeragi.block_time_ms) => SumeragiParameter::BlockTimeMs,
                 Sumeragi(sumeragi.commit_time_ms) => SumeragiParameter::CommitTimeMs,
+                Sumeragi(sumeragi.aggregate_signatures) => SumeragiParameter::AggregateSignatures,
+                Sumeragi(sumeragi.epoch_length) => SumeragiParameter::EpochLength,
+                Sumeragi(sumeragi.max_validator_changes) => SumeragiParameter::MaxValidatorChanges,
 
                 Block(block.max_transactions) => BlockParameter::MaxTransactions,
 
@@ -394,6 +486,13 @@ pub mod isi {
 
                 Executor(executor.fuel) => SmartContractParameter::Fuel,
                 Executor(executor.memory) => SmartContractParameter::Memory,
+
+                Trigger(trigger.max_depth) => TriggerParameter::MaxDepth,
+                Trigger(trigger.max_fuel) => TriggerParameter::MaxFuel,
+
+                Storage(storage.max_bytes) => StorageParameter::MaxBytes,
+
+                Contract(contract.max_call_depth) => ContractParameter::MaxCallDepth,
             );
 
             Ok(())
@@ -453,6 +552,41 @@ pub mod isi {
             Ok(())
         }
     }
+
+    impl Execute for EmitEvent {
+        #[metrics(+"emit_event")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let Self { name, payload } = self;
+
+            let data_model = &*state_transaction.world.executor_data_model;
+            if !data_model.events.contains(name.as_ref()) {
+                return Err(Error::InvariantViolation(format!(
+                    "Event `{name}` is not defined in the executor data model"
+                )));
+            }
+
+            crate::smartcontracts::isi::event::validate_payload(
+                &data_model.schema,
+                name.as_ref(),
+                &payload,
+            )
+            .map_err(|err| {
+                Error::InvariantViolation(format!("Invalid payload of event `{name}`: {err}"))
+            })?;
+
+            state_transaction.world.emit_custom_event(CustomEvent {
+                name,
+                emitter: authority.clone(),
+                payload,
+            });
+
+            Ok(())
+        }
+    }
 }
 /// Query module provides `IrohaQuery` Peer related implementations.
 pub mod query {
@@ -518,6 +652,35 @@ pub mod query {
         }
     }
 
+    impl ValidSingularQuery for FindValidatorSets {
+        #[metrics(+"find_validator_sets")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<ValidatorSets, Error> {
+            let world = state_ro.world();
+
+            Ok(ValidatorSets {
+                active: world.peers().to_vec(),
+                pending: world.pending_validators().cloned(),
+            })
+        }
+    }
+
+    impl ValidQuery for FindStorageEntries {
+        #[metrics(+"find_storage_entries")]
+        fn execute(
+            self,
+            filter: CompoundPredicate<StorageEntry>,
+            state_ro: &impl StateReadOnly,
+        ) -> Result<impl Iterator<Item = Self::Item>, Error> {
+            Ok(state_ro
+                .world()
+                .smart_contract_storage_iter(&self.owner, &[])
+                .map(|(key, value)| StorageEntry::new(key.to_vec(), value.to_vec()))
+                .filter(move |entry| filter.applies(entry))
+                .collect::<Vec<_>>()
+                .into_iter())
+        }
+    }
+
     impl ValidSingularQuery for FindExecutorDataModel {
         #[metrics(+"find_executor_data_model")]
         fn execute(&self, state_ro: &impl StateReadOnly) -> Result<ExecutorDataModel, Error> {
@@ -6,21 +6,26 @@ use std::{borrow::Borrow, num::NonZeroU64};
 
 use error::*;
 use import::traits::{ExecuteOperations as _, SetDataModel as _};
+use iroha_crypto::{HashAlgorithm, MerkleProof, Signature};
 use iroha_data_model::{
     account::AccountId,
     executor::{self, ExecutorDataModel},
     isi::InstructionBox,
     parameter::SmartContractParameters as Config,
     prelude::*,
-    query::{parameters::QueryId, AnyQueryBox, QueryOutput, QueryRequest, QueryResponse},
+    query::{
+        parameters::QueryId, AnyQueryBox, QueryOutput, QueryRequest, QueryResponse,
+        SingularQueryBox,
+    },
     smart_contract::payloads,
+    storage::StorageEntry,
     Level as LogLevel, ValidationFail,
 };
 use iroha_logger::debug;
 // NOTE: Using error_span so that span info is logged on every event
 use iroha_logger::{error_span as wasm_log_span, prelude::tracing::Span};
 use iroha_wasm_codec::{self as codec, WasmUsize};
-use parity_scale_codec::Encode;
+use parity_scale_codec::{DecodeAll, Encode};
 use wasmtime::{
     Caller, Config as WasmtimeConfig, Engine, Instance, Linker, Module, Store, StoreLimits,
     StoreLimitsBuilder, TypedFunc,
@@ -33,11 +38,12 @@ use crate::{
         wasm::state::{
             chain_state::WithMut,
             specific::executor::{Migrate, Validate},
-            CommonState,
+            CommonState, StorageOwnerState,
         },
         Execute,
     },
     state::{StateReadOnly, StateTransaction, WorldReadOnly},
+    storage::StorageKey,
 };
 
 /// Cache for WASM Runtime
@@ -54,6 +60,36 @@ mod export {
 
     pub const DBG: &str = "dbg";
     pub const LOG: &str = "log";
+
+    pub const VERIFY_SIGNATURE: &str = "verify_signature";
+    pub const HASH: &str = "hash";
+    pub const VERIFY_MERKLE_PROOF: &str = "verify_merkle_proof";
+
+    pub const STORAGE_GET: &str = "storage_get";
+    pub const STORAGE_SET: &str = "storage_set";
+    pub const STORAGE_REMOVE: &str = "storage_remove";
+    pub const STORAGE_ITER: &str = "storage_iter";
+
+    pub const CALL_CONTRACT: &str = "call_contract";
+
+    pub const GET_RANDOMNESS: &str = "get_randomness";
+}
+
+/// Fuel consumed by host functions on top of the fuel consumed by the module.
+///
+/// Every call consumes the base cost of the function plus `PER_BYTE`
+/// for each byte of its encoded arguments, so that e.g. verifying a signature
+/// over a large payload costs more than over a small one.
+mod fuel {
+    pub const VERIFY_SIGNATURE: u64 = 1_000_000;
+    pub const HASH: u64 = 10_000;
+    pub const VERIFY_MERKLE_PROOF: u64 = 50_000;
+    pub const STORAGE_READ: u64 = 10_000;
+    pub const STORAGE_WRITE: u64 = 100_000;
+    /// Consumed on top of the fuel consumed by the called contract
+    pub const CALL_CONTRACT: u64 = 100_000;
+    /// Consumed for each byte of the encoded arguments
+    pub const PER_BYTE: u64 = 10;
 }
 
 mod import {
@@ -62,10 +98,14 @@ mod import {
     pub const SMART_CONTRACT_DEALLOC: &str = "_iroha_smart_contract_dealloc";
 
     pub const TRIGGER_MAIN: &str = "_iroha_trigger_main";
+    pub const TRIGGER_MIGRATE: &str = "_iroha_trigger_migrate";
+
+    pub const CONTRACT_MAIN: &str = "_iroha_contract_main";
 
     pub const EXECUTOR_EXECUTE_TRANSACTION: &str = "_iroha_executor_execute_transaction";
     pub const EXECUTOR_EXECUTE_INSTRUCTION: &str = "_iroha_executor_execute_instruction";
     pub const EXECUTOR_VALIDATE_QUERY: &str = "_iroha_executor_validate_query";
+    pub const EXECUTOR_EXECUTE_CUSTOM_QUERY: &str = "_iroha_executor_execute_custom_query";
     pub const EXECUTOR_MIGRATE: &str = "_iroha_executor_migrate";
 
     pub mod traits {
@@ -393,6 +433,12 @@ pub mod state {
         ) -> Result<(), ValidationFail>;
     }
 
+    /// Trait to get the owner of the persistent storage available to the executable
+    pub trait StorageOwnerState {
+        /// Get the owner of the storage
+        fn storage_owner(&self) -> StorageOwner;
+    }
+
     pub mod chain_state {
         //! Strongly typed kinds of chain state
 
@@ -412,6 +458,11 @@ pub mod state {
         pub trait ConstState {
             /// Get immutable chain state.
             fn state(&self) -> &impl StateReadOnly;
+
+            /// Account for the fuel and linear memory consumed by a module.
+            ///
+            /// Consumption is only tracked by transactions, i.e. [`WithMut`].
+            fn record_consumption(&mut self, _fuel: u64, _memory: u64) {}
         }
 
         impl<S: StateReadOnly> ConstState for WithConst<'_, S> {
@@ -424,6 +475,10 @@ pub mod state {
             fn state(&self) -> &impl StateReadOnly {
                 self.0
             }
+
+            fn record_consumption(&mut self, fuel: u64, memory: u64) {
+                self.0.record_wasm_consumption(fuel, memory);
+            }
         }
     }
 
@@ -454,6 +509,18 @@ pub mod state {
             pub(in super::super) triggering_event: EventBox,
         }
 
+        /// Contract execution state
+        #[derive(Constructor)]
+        pub struct Contract {
+            pub(in super::super) id: ContractId,
+
+            /// Account which called this contract
+            pub(in super::super) caller: AccountId,
+
+            /// Arguments this contract was called with
+            pub(in super::super) args: Json,
+        }
+
         pub mod executor {
             //! States related to *Executor* execution.
 
@@ -477,6 +544,9 @@ pub mod state {
             /// State kind for executing `execute_instruction()` entrypoint of executor
             pub type ExecuteInstruction = Validate<InstructionBox>;
 
+            /// State kind for executing `execute_custom_query()` entrypoint of executor
+            pub type ExecuteCustomQuery = Validate<CustomQuery>;
+
             /// State kind for executing `migrate()` entrypoint of executor
             #[derive(Copy, Clone)]
             pub struct Migrate;
@@ -491,6 +561,10 @@ pub mod state {
     pub type Trigger<'wrld, 'block, 'state> =
         CommonState<chain_state::WithMut<'wrld, 'block, 'state>, specific::Trigger>;
 
+    /// State for contract execution
+    pub type Contract<'wrld, 'block, 'state> =
+        CommonState<chain_state::WithMut<'wrld, 'block, 'state>, specific::Contract>;
+
     impl ValidateQueryOperation for SmartContract<'_, '_, '_> {
         fn validate_query(
             &self,
@@ -519,6 +593,38 @@ pub mod state {
         }
     }
 
+    impl ValidateQueryOperation for Contract<'_, '_, '_> {
+        fn validate_query(
+            &self,
+            authority: &AccountId,
+            query: &QueryRequest,
+        ) -> Result<(), ValidationFail> {
+            let state_ro = self.state.state();
+            state_ro
+                .world()
+                .executor()
+                .validate_query(state_ro, authority, query)
+        }
+    }
+
+    impl StorageOwnerState for SmartContract<'_, '_, '_> {
+        fn storage_owner(&self) -> StorageOwner {
+            StorageOwner::Account(self.authority.clone())
+        }
+    }
+
+    impl StorageOwnerState for Trigger<'_, '_, '_> {
+        fn storage_owner(&self) -> StorageOwner {
+            StorageOwner::Trigger(self.specific_state.id.clone())
+        }
+    }
+
+    impl StorageOwnerState for Contract<'_, '_, '_> {
+        fn storage_owner(&self) -> StorageOwner {
+            StorageOwner::Contract(self.specific_state.id.clone())
+        }
+    }
+
     pub mod executor {
         //! States for different executor entrypoints
 
@@ -536,6 +642,10 @@ pub mod state {
         pub type ValidateQuery<'wrld, S> =
             CommonState<chain_state::WithConst<'wrld, S>, specific::executor::ValidateQuery>;
 
+        /// State for executing `execute_custom_query()` entrypoint
+        pub type ExecuteCustomQuery<'wrld, S> =
+            CommonState<chain_state::WithConst<'wrld, S>, specific::executor::ExecuteCustomQuery>;
+
         /// State for executing `execute_instruction()` entrypoint
         pub type ExecuteInstruction<'wrld, 'block, 'state> = CommonState<
             chain_state::WithMut<'wrld, 'block, 'state>,
@@ -575,6 +685,24 @@ pub mod state {
                 Ok(())
             }
         }
+
+        impl<S: StateReadOnly> ValidateQueryOperation for ExecuteCustomQuery<'_, S> {
+            fn validate_query(
+                &self,
+                _authority: &AccountId,
+                query: &QueryRequest,
+            ) -> Result<(), ValidationFail> {
+                // Resolving a custom query must not recurse into the executor
+                if let QueryRequest::Singular(SingularQueryBox::CustomQuery(_)) = query {
+                    return Err(ValidationFail::NotPermitted(
+                        "Custom queries cannot be executed while resolving a custom query"
+                            .to_owned(),
+                    ));
+                }
+
+                Ok(())
+            }
+        }
     }
 }
 
@@ -594,6 +722,18 @@ pub struct RuntimeFull<S> {
 }
 
 impl<S> Runtime<S> {
+    /// Fuel and linear memory in bytes consumed by the module `instance` so far
+    fn consumed_resources(&self, instance: &Instance, store: &mut Store<S>) -> (u64, u64) {
+        let fuel = self.config.fuel.get()
+            - store
+                .get_fuel()
+                .expect("Wasm Runtime config is malformed, this is a bug");
+        let memory = Self::get_memory(&mut (instance, &mut *store))
+            .map_or(0, |memory| memory.data_size(&*store) as u64);
+
+        (fuel, memory)
+    }
+
     fn get_memory(caller: &mut impl GetExport) -> Result<wasmtime::Memory, ExportError> {
         caller
             .get_export(WASM_MEMORY)
@@ -704,6 +844,61 @@ impl<S> Runtime<S> {
     fn dbg(msg: String) {
         eprintln!("{msg}");
     }
+
+    /// Host-defined function which checks that the payload is signed
+    /// with the private key corresponding to the public key
+    ///
+    /// # Errors
+    ///
+    /// If arguments decoding or result encoding fails
+    #[allow(clippy::needless_pass_by_value)]
+    #[codec::wrap(state = "S")]
+    fn verify_signature((public_key, signature, payload): (PublicKey, Signature, Vec<u8>)) -> bool {
+        signature.verify(&public_key, &payload).is_ok()
+    }
+
+    /// Host-defined function which computes the digest of the bytes
+    ///
+    /// # Errors
+    ///
+    /// If arguments decoding or result encoding fails
+    #[codec::wrap(state = "S")]
+    fn hash((algorithm, bytes): (HashAlgorithm, Vec<u8>)) -> Digest {
+        algorithm.digest(bytes)
+    }
+
+    /// Host-defined function which checks that the leaf is included into the Merkle tree
+    ///
+    /// # Errors
+    ///
+    /// If arguments decoding or result encoding fails
+    #[allow(clippy::needless_pass_by_value)]
+    #[codec::wrap(state = "S")]
+    fn verify_merkle_proof(
+        (leaf, proof, root): (Vec<u8>, MerkleProof, iroha_crypto::Hash),
+    ) -> bool {
+        proof.verify(leaf, &root)
+    }
+
+    /// Consume `fuel` for the host function call with `len` bytes of encoded arguments
+    ///
+    /// # Errors
+    ///
+    /// If the remaining fuel is insufficient
+    fn consume_fuel(
+        caller: &mut Caller<S>,
+        fuel: u64,
+        len: WasmUsize,
+    ) -> Result<(), wasmtime::Error> {
+        let fuel = fuel.saturating_add(fuel::PER_BYTE.saturating_mul(len.into()));
+        let remaining = caller.get_fuel()?;
+        let Some(remaining) = remaining.checked_sub(fuel) else {
+            caller.set_fuel(0)?;
+            return Err(wasmtime::Trap::OutOfFuel.into());
+        };
+
+        caller.set_fuel(remaining)
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -713,6 +908,9 @@ struct LogError(u8);
 /// It's required by `#[codec::wrap]` to parse well
 type WasmtimeError = wasmtime::Error;
 
+/// It's required by `#[codec::wrap]` to parse well
+type Digest = [u8; iroha_crypto::Hash::LENGTH];
+
 impl<W, S> Runtime<state::CommonState<W, S>> {
     /// Log the given string at the given log level
     ///
@@ -777,23 +975,25 @@ impl<W: state::chain_state::ConstState, T: Clone> Runtime<state::CommonState<W,
 where
     payloads::Validate<T>: Encode,
 {
-    fn execute_executor_execute_internal(
+    fn execute_executor_execute_internal<R: DecodeAll + core::fmt::Debug>(
         &self,
         module: &wasmtime::Module,
         state: state::CommonState<W, Validate<T>>,
         validate_fn_name: &'static str,
-    ) -> Result<executor::Result> {
+    ) -> Result<R> {
         let context = create_validate_context(&state);
         let mut store = self.create_store(state);
         let instance = self.instantiate_module(module, &mut store)?;
 
         let validation_res =
-            execute_executor_validate_part1(&mut store, &instance, context, validate_fn_name)?;
+            execute_executor_validate_part1(&mut store, &instance, context, validate_fn_name);
+        let (fuel, memory) = self.consumed_resources(&instance, &mut store);
 
-        let state = store.into_data();
+        let mut state = store.into_data();
+        state.state.record_consumption(fuel, memory);
         execute_executor_validate_part2(state);
 
-        Ok(validation_res)
+        validation_res
     }
 }
 
@@ -814,15 +1014,19 @@ where
             &self.instance,
             context,
             validate_fn_name,
-        )?;
+        );
+        let (fuel, memory) = self
+            .runtime
+            .consumed_resources(&self.instance, &mut self.store);
 
-        let state =
+        let mut state =
             self.store.data_mut().take().expect(
                 "Store data was set at the beginning of execute_executor_validate_internal",
             );
+        state.state.record_consumption(fuel, memory);
         execute_executor_validate_part2(state);
 
-        Ok(validation_res)
+        validation_res
     }
 
     fn set_store_state(&mut self, state: CommonState<W, Validate<T>>) {
@@ -838,12 +1042,12 @@ where
     }
 }
 
-fn execute_executor_validate_part1<S, T>(
+fn execute_executor_validate_part1<S, T, R: DecodeAll + core::fmt::Debug>(
     store: &mut Store<S>,
     instance: &Instance,
     context: payloads::Validate<T>,
     validate_fn_name: &'static str,
-) -> Result<executor::Result>
+) -> Result<R>
 where
     payloads::Validate<T>: Encode,
 {
@@ -952,6 +1156,162 @@ impl<'wrld, 'state, 'block, S>
             .clone() // Cloning executor is a cheap operation
             .execute_instruction(state.state.0, &authority, instruction)
     }
+
+    /// Host-defined function which calls the deployed `contract` with `args`
+    /// and returns its output.
+    ///
+    /// The called contract can't consume more fuel than remains to the caller,
+    /// and the fuel it consumes is charged to the caller on top of [`fuel::CALL_CONTRACT`].
+    /// Not wrapped with `#[codec::wrap]` because the caller is needed after the call
+    ///
+    /// # Errors
+    ///
+    /// - if arguments decoding or result encoding fails
+    /// - if the caller runs out of fuel
+    fn call_contract(
+        mut caller: Caller<
+            state::CommonState<state::chain_state::WithMut<'wrld, 'state, 'block>, S>,
+        >,
+        offset: WasmUsize,
+        len: WasmUsize,
+    ) -> Result<WasmUsize, WasmtimeError> {
+        Self::consume_fuel(&mut caller, fuel::CALL_CONTRACT, len)?;
+        let memory = Self::get_memory(&mut caller).expect("Checked at instantiation step");
+        let alloc_fn = Self::get_alloc_fn(&mut caller).expect("Checked at instantiation step");
+        let (contract, args): (ContractId, Json) =
+            codec::decode_from_memory(&memory, &caller, offset, len)?;
+
+        let remaining = caller.get_fuel()?;
+        let state = caller.data_mut();
+        let consumed_before = state.state.0.wasm_fuel_consumed;
+        state.state.0.contract_fuel = Some(remaining);
+
+        let instruction = CallContract { contract, args };
+        let output = Self::default_execute_instruction(instruction.into(), state).and_then(|()| {
+            state.state.0.contract_output.take().ok_or_else(|| {
+                ValidationFail::InternalError("Contract call produced no output".to_owned())
+            })
+        });
+
+        // Fuel consumed by the call is recorded as consumed by the caller
+        state.state.0.contract_fuel = None;
+        let consumed = state
+            .state
+            .0
+            .wasm_fuel_consumed
+            .saturating_sub(consumed_before);
+        state.state.0.wasm_fuel_consumed = consumed_before;
+        Self::consume_fuel(&mut caller, consumed, 0)?;
+
+        codec::encode_into_memory(&output, &memory, &alloc_fn, &mut caller)
+    }
+
+    /// Host-defined function which returns the verifiable randomness of the current block.
+    /// None for the genesis block
+    ///
+    /// # Errors
+    ///
+    /// If result encoding fails
+    #[codec::wrap]
+    fn get_randomness(
+        state: &state::CommonState<state::chain_state::WithMut<'wrld, 'state, 'block>, S>,
+    ) -> Option<iroha_crypto::Hash> {
+        state.state.0.curr_block.randomness()
+    }
+}
+
+impl<'wrld, 'block, 'state, S> Runtime<state::CommonState<WithMut<'wrld, 'block, 'state>, S>>
+where
+    CommonState<WithMut<'wrld, 'block, 'state>, S>: StorageOwnerState,
+{
+    /// Host-defined function which gets the value of the `key`
+    /// from the persistent storage of the executable
+    ///
+    /// # Errors
+    ///
+    /// If arguments decoding or result encoding fails
+    #[codec::wrap]
+    fn storage_get(
+        key: Vec<u8>,
+        state: &state::CommonState<WithMut<'wrld, 'block, 'state>, S>,
+    ) -> Option<Vec<u8>> {
+        let key = StorageKey::new(state.storage_owner(), key);
+        state
+            .state
+            .0
+            .world
+            .smart_contract_storage
+            .get(&key)
+            .cloned()
+    }
+
+    /// Host-defined function which sets the value of the entry
+    /// in the persistent storage of the executable.
+    ///
+    /// Fails with [`ValidationFail::TooComplex`] if keys and values kept in the storage
+    /// would exceed the `storage` smart contract parameter in bytes.
+    ///
+    /// # Errors
+    ///
+    /// If arguments decoding or result encoding fails
+    #[codec::wrap]
+    fn storage_set(
+        entry: StorageEntry,
+        state: &mut state::CommonState<WithMut<'wrld, 'block, 'state>, S>,
+    ) -> Result<(), ValidationFail> {
+        let key = StorageKey::new(state.storage_owner(), entry.key);
+        let world = &mut state.state.0.world;
+
+        let prev_size = world
+            .smart_contract_storage
+            .get(&key)
+            .map_or(0, |value| key.key.len() + value.len());
+        let size = world.smart_contract_storage_size(&key.owner) - prev_size as u64
+            + (key.key.len() + entry.value.len()) as u64;
+        if size > world.parameters().storage.max_bytes.get() {
+            return Err(ValidationFail::TooComplex);
+        }
+
+        world.set_smart_contract_storage_entry(key, entry.value);
+        Ok(())
+    }
+
+    /// Host-defined function which removes the `key` from the persistent storage
+    /// of the executable returning its value
+    ///
+    /// # Errors
+    ///
+    /// If arguments decoding or result encoding fails
+    #[codec::wrap]
+    fn storage_remove(
+        key: Vec<u8>,
+        state: &mut state::CommonState<WithMut<'wrld, 'block, 'state>, S>,
+    ) -> Option<Vec<u8>> {
+        let key = StorageKey::new(state.storage_owner(), key);
+        state.state.0.world.remove_smart_contract_storage_entry(key)
+    }
+
+    /// Host-defined function which gets the entries of the persistent storage
+    /// of the executable which keys start with the `prefix`, sorted by key
+    ///
+    /// # Errors
+    ///
+    /// If arguments decoding or result encoding fails
+    #[allow(clippy::needless_pass_by_value)]
+    #[codec::wrap]
+    fn storage_iter(
+        prefix: Vec<u8>,
+        state: &state::CommonState<WithMut<'wrld, 'block, 'state>, S>,
+    ) -> Vec<StorageEntry> {
+        let owner = state.storage_owner();
+        state
+            .state
+            .0
+            .world
+            .smart_contract_storage_iter(&owner, &prefix)
+            .map(|(key, value)| StorageEntry::new(key.to_vec(), value.to_vec()))
+            .collect()
+    }
 }
 
 impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::SmartContract<'wrld, 'block, 'state>> {
@@ -1019,13 +1379,15 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::SmartContract<'wrld, '
         let context = Self::get_smart_contract_context(&smart_contract, &mut store);
 
         // NOTE: This function takes ownership of the pointer
-        main_fn
-            .call(&mut store, context)
-            .map_err(ExportFnCallError::from)?;
+        let result = main_fn.call(&mut store, context);
+        let (fuel, memory) = self.consumed_resources(&smart_contract, &mut store);
+
         let mut state = store.into_data();
+        state.state.0.record_wasm_consumption(fuel, memory);
         let executed_queries = state.take_executed_queries();
         forget_all_executed_queries(state.state.0.query_handle, executed_queries);
 
+        result.map_err(ExportFnCallError::from)?;
         Ok(())
     }
 
@@ -1068,7 +1430,7 @@ impl<'wrld, 'block, 'state>
 }
 
 impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::Trigger<'wrld, 'block, 'state>> {
-    /// Executes the given wasm trigger module
+    /// Executes the given wasm trigger module and returns the amount of fuel consumed by it
     ///
     /// # Errors
     ///
@@ -1081,8 +1443,58 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::Trigger<'wrld, 'block,
         authority: AccountId,
         module: &wasmtime::Module,
         event: EventBox,
+    ) -> Result<u64> {
+        self.execute_trigger_export(
+            state_transaction,
+            id,
+            authority,
+            module,
+            event,
+            import::TRIGGER_MAIN,
+        )
+    }
+
+    /// Executes the migration entrypoint of the given wasm trigger module, if it is exported.
+    ///
+    /// Called once after the executable of the trigger was upgraded,
+    /// `event` is the upgrade event of the trigger.
+    ///
+    /// # Errors
+    ///
+    /// - if the execution of the migration fails
+    pub fn execute_trigger_migration(
+        &mut self,
+        state_transaction: &'wrld mut StateTransaction<'block, 'state>,
+        id: &TriggerId,
+        authority: AccountId,
+        module: &wasmtime::Module,
+        event: EventBox,
     ) -> Result<()> {
-        let span = wasm_log_span!("Trigger execution", %id, %authority);
+        if module.get_export(import::TRIGGER_MIGRATE).is_none() {
+            return Ok(());
+        }
+
+        self.execute_trigger_export(
+            state_transaction,
+            id,
+            authority,
+            module,
+            event,
+            import::TRIGGER_MIGRATE,
+        )
+        .map(|_fuel_consumed| ())
+    }
+
+    fn execute_trigger_export(
+        &mut self,
+        state_transaction: &'wrld mut StateTransaction<'block, 'state>,
+        id: &TriggerId,
+        authority: AccountId,
+        module: &wasmtime::Module,
+        event: EventBox,
+        export: &'static str,
+    ) -> Result<u64> {
+        let span = wasm_log_span!("Trigger execution", %id, %authority, export);
         let state = state::Trigger::new(
             authority,
             self.config,
@@ -1094,20 +1506,23 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::Trigger<'wrld, 'block,
         let mut store = self.create_store(state);
         let instance = self.instantiate_module(module, &mut store)?;
 
-        let main_fn: TypedFunc<_, ()> =
-            Self::get_typed_func(&instance, &mut store, import::TRIGGER_MAIN)?;
+        let main_fn: TypedFunc<_, ()> = Self::get_typed_func(&instance, &mut store, export)?;
         let context = Self::get_trigger_context(&instance, &mut store);
 
         // NOTE: This function takes ownership of the pointer
         main_fn
             .call(&mut store, context)
             .map_err(ExportFnCallError::from)?;
+        let fuel_consumed = self.config.fuel.get()
+            - store
+                .get_fuel()
+                .expect("Wasm Runtime config is malformed, this is a bug");
 
         let mut state = store.into_data();
         let executed_queries = state.take_executed_queries();
         forget_all_executed_queries(state.state.0.query_handle, executed_queries);
 
-        Ok(())
+        Ok(fuel_consumed)
     }
 
     fn get_trigger_context(
@@ -1145,6 +1560,101 @@ impl<'wrld, 'block, 'state> import::traits::ExecuteOperations<state::Trigger<'wr
     }
 }
 
+impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::Contract<'wrld, 'block, 'state>> {
+    /// Executes the wasm module of the deployed `contract` on behalf of its owner
+    /// and returns the output of the contract
+    ///
+    /// # Errors
+    ///
+    /// - if unable to find expected main function export
+    /// - if the execution of the contract fails
+    /// - if the output of the contract can't be decoded
+    pub fn execute_contract_module(
+        &mut self,
+        state_transaction: &'wrld mut StateTransaction<'block, 'state>,
+        contract: &Contract,
+        caller: AccountId,
+        module: &wasmtime::Module,
+        args: Json,
+    ) -> Result<Result<Json, ValidationFail>> {
+        let id = contract.id();
+        let authority = contract.owner().clone();
+        let span = wasm_log_span!("Contract execution", %id, %authority, %caller);
+        let state = state::Contract::new(
+            authority,
+            self.config,
+            span,
+            state::chain_state::WithMut(state_transaction),
+            state::specific::Contract::new(id.clone(), caller, args),
+        );
+
+        let mut store = self.create_store(state);
+        let instance = self.instantiate_module(module, &mut store)?;
+
+        let main_fn: TypedFunc<WasmUsize, WasmUsize> =
+            Self::get_typed_func(&instance, &mut store, import::CONTRACT_MAIN)?;
+        let context = Self::get_contract_context(&instance, &mut store);
+
+        // NOTE: This function takes ownership of the pointer
+        let offset = main_fn
+            .call(&mut store, context)
+            .map_err(ExportFnCallError::from)?;
+
+        let memory =
+            Self::get_memory(&mut (&instance, &mut store)).expect("Checked at instantiation step");
+        let dealloc_fn =
+            Self::get_typed_func(&instance, &mut store, import::SMART_CONTRACT_DEALLOC)
+                .expect("Checked at instantiation step");
+        let output =
+            codec::decode_with_length_prefix_from_memory(&memory, &dealloc_fn, &mut store, offset)
+                .map_err(Error::Decode)?;
+        let (fuel, memory) = self.consumed_resources(&instance, &mut store);
+
+        let mut state = store.into_data();
+        state.state.0.record_wasm_consumption(fuel, memory);
+        let executed_queries = state.take_executed_queries();
+        forget_all_executed_queries(state.state.0.query_handle, executed_queries);
+
+        Ok(output)
+    }
+
+    fn get_contract_context(
+        instance: &Instance,
+        store: &mut Store<state::Contract<'wrld, 'block, 'state>>,
+    ) -> WasmUsize {
+        let state = store.data();
+        let payload = payloads::ContractContext {
+            id: state.specific_state.id.clone(),
+            authority: state.authority.clone(),
+            caller: state.specific_state.caller.clone(),
+            curr_block: state.state.0.curr_block,
+            args: state.specific_state.args.clone(),
+        };
+        Runtime::encode_payload(instance, store, payload)
+    }
+}
+
+impl<'wrld, 'block, 'state>
+    import::traits::ExecuteOperations<state::Contract<'wrld, 'block, 'state>>
+    for Runtime<state::Contract<'wrld, 'block, 'state>>
+{
+    #[codec::wrap]
+    fn execute_query(
+        query_request: QueryRequest,
+        state: &mut state::Contract<'wrld, 'block, 'state>,
+    ) -> Result<QueryResponse, ValidationFail> {
+        Self::default_execute_query(query_request, state)
+    }
+
+    #[codec::wrap]
+    fn execute_instruction(
+        instruction: InstructionBox,
+        state: &mut state::Contract<'wrld, 'block, 'state>,
+    ) -> Result<(), ValidationFail> {
+        Self::default_execute_instruction(instruction, state)
+    }
+}
+
 /// Marker trait to auto-implement [`import_traits::ExecuteOperations`] for a concrete
 /// *Executor* [`Runtime`].
 ///
@@ -1388,6 +1898,78 @@ impl<'wrld, S: StateReadOnly> FakeSetExecutorDataModel<state::executor::Validate
     const ENTRYPOINT_FN_NAME: &'static str = "validate_query";
 }
 
+impl<'wrld, S: StateReadOnly> Runtime<state::executor::ExecuteCustomQuery<'wrld, S>> {
+    /// Execute `execute_custom_query()` entrypoint of the given module of runtime executor
+    ///
+    /// # Errors
+    ///
+    /// - if failed to instantiate provided `module`
+    /// - if unable to find expected function export
+    /// - if the execution of the smartcontract fails
+    /// - if unable to decode the query output
+    pub fn execute_executor_custom_query(
+        &self,
+        state_ro: &'wrld S,
+        authority: &AccountId,
+        module: &wasmtime::Module,
+        query: CustomQuery,
+    ) -> Result<Result<Json, ValidationFail>> {
+        let span = wasm_log_span!("Running `execute_custom_query()`");
+
+        let Some(latest_block) = state_ro.latest_block() else {
+            return Ok(Err(ValidationFail::NotPermitted(
+                "Genesis not committed".to_owned(),
+            )));
+        };
+
+        let state = state::executor::ExecuteCustomQuery::new(
+            authority.clone(),
+            self.config,
+            span,
+            state::chain_state::WithConst(state_ro),
+            state::specific::executor::ExecuteCustomQuery::new(
+                query,
+                latest_block.as_ref().header(),
+            ),
+        );
+
+        self.execute_executor_execute_internal(module, state, import::EXECUTOR_EXECUTE_CUSTOM_QUERY)
+    }
+}
+
+impl<'wrld, S: StateReadOnly>
+    import::traits::ExecuteOperations<state::executor::ExecuteCustomQuery<'wrld, S>>
+    for Runtime<state::executor::ExecuteCustomQuery<'wrld, S>>
+{
+    #[codec::wrap]
+    fn execute_query(
+        query_request: QueryRequest,
+        state: &mut state::executor::ExecuteCustomQuery<'wrld, S>,
+    ) -> Result<QueryResponse, ValidationFail> {
+        debug!(?query_request, "Executing as executor");
+
+        Runtime::default_execute_query(query_request, state)
+    }
+
+    #[codec::wrap]
+    fn execute_instruction(
+        _instruction: InstructionBox,
+        _state: &mut state::executor::ExecuteCustomQuery<'wrld, S>,
+    ) -> Result<(), ValidationFail> {
+        // Reachable by any executor, so it must not panic the host
+        Err(ValidationFail::NotPermitted(
+            "Executor `execute_custom_query()` entrypoint cannot execute instructions".to_owned(),
+        ))
+    }
+}
+
+impl<'wrld, S: StateReadOnly>
+    FakeSetExecutorDataModel<state::executor::ExecuteCustomQuery<'wrld, S>>
+    for Runtime<state::executor::ExecuteCustomQuery<'wrld, S>>
+{
+    const ENTRYPOINT_FN_NAME: &'static str = "execute_custom_query";
+}
+
 impl<'wrld, 'block, 'state> Runtime<state::executor::Migrate<'wrld, 'block, 'state>> {
     /// Execute `migrate()` entrypoint of *Executor*
     ///
@@ -1526,6 +2108,36 @@ macro_rules! create_imports {
                     |caller: ::wasmtime::Caller<$ty>, offset, len| Runtime::dbg(caller, offset, len),
                 )
             })
+            .and_then(|l| {
+                l.func_wrap(
+                    WASM_MODULE,
+                    export::VERIFY_SIGNATURE,
+                    |mut caller: ::wasmtime::Caller<$ty>, offset, len| {
+                        Runtime::<$ty>::consume_fuel(&mut caller, fuel::VERIFY_SIGNATURE, len)?;
+                        Runtime::<$ty>::verify_signature(caller, offset, len)
+                    },
+                )
+            })
+            .and_then(|l| {
+                l.func_wrap(
+                    WASM_MODULE,
+                    export::HASH,
+                    |mut caller: ::wasmtime::Caller<$ty>, offset, len| {
+                        Runtime::<$ty>::consume_fuel(&mut caller, fuel::HASH, len)?;
+                        Runtime::<$ty>::hash(caller, offset, len)
+                    },
+                )
+            })
+            .and_then(|l| {
+                l.func_wrap(
+                    WASM_MODULE,
+                    export::VERIFY_MERKLE_PROOF,
+                    |mut caller: ::wasmtime::Caller<$ty>, offset, len| {
+                        Runtime::<$ty>::consume_fuel(&mut caller, fuel::VERIFY_MERKLE_PROOF, len)?;
+                        Runtime::<$ty>::verify_merkle_proof(caller, offset, len)
+                    },
+                )
+            })
             $(.and_then(|l| {
                 l.func_wrap(
                     WASM_MODULE,
@@ -1537,6 +2149,52 @@ macro_rules! create_imports {
     };
 }
 
+/// Register host functions which access the persistent storage of the executable
+macro_rules! create_storage_imports {
+    ($linker:ident, $ty:ty) => {
+        $linker
+            .func_wrap(
+                WASM_MODULE,
+                export::STORAGE_GET,
+                |mut caller: ::wasmtime::Caller<$ty>, offset, len| {
+                    Runtime::<$ty>::consume_fuel(&mut caller, fuel::STORAGE_READ, len)?;
+                    Runtime::<$ty>::storage_get(caller, offset, len)
+                },
+            )
+            .and_then(|l| {
+                l.func_wrap(
+                    WASM_MODULE,
+                    export::STORAGE_SET,
+                    |mut caller: ::wasmtime::Caller<$ty>, offset, len| {
+                        Runtime::<$ty>::consume_fuel(&mut caller, fuel::STORAGE_WRITE, len)?;
+                        Runtime::<$ty>::storage_set(caller, offset, len)
+                    },
+                )
+            })
+            .and_then(|l| {
+                l.func_wrap(
+                    WASM_MODULE,
+                    export::STORAGE_REMOVE,
+                    |mut caller: ::wasmtime::Caller<$ty>, offset, len| {
+                        Runtime::<$ty>::consume_fuel(&mut caller, fuel::STORAGE_WRITE, len)?;
+                        Runtime::<$ty>::storage_remove(caller, offset, len)
+                    },
+                )
+            })
+            .and_then(|l| {
+                l.func_wrap(
+                    WASM_MODULE,
+                    export::STORAGE_ITER,
+                    |mut caller: ::wasmtime::Caller<$ty>, offset, len| {
+                        Runtime::<$ty>::consume_fuel(&mut caller, fuel::STORAGE_READ, len)?;
+                        Runtime::<$ty>::storage_iter(caller, offset, len)
+                    },
+                )
+            })
+            .map_err(Error::Initialization)
+    };
+}
+
 impl<'wrld, 'block, 'state> RuntimeBuilder<state::SmartContract<'wrld, 'block, 'state>> {
     /// Builds the [`Runtime`] for *Smart Contract* execution
     ///
@@ -1550,7 +2208,10 @@ impl<'wrld, 'block, 'state> RuntimeBuilder<state::SmartContract<'wrld, 'block, '
             create_imports!(linker, state::SmartContract<'wrld, 'block, 'state>,
                 export::EXECUTE_ISI => |caller: ::wasmtime::Caller<state::SmartContract<'wrld, 'block, 'state>>, offset, len| Runtime::execute_instruction(caller, offset, len),
                 export::EXECUTE_QUERY => |caller: ::wasmtime::Caller<state::SmartContract<'wrld, 'block, 'state>>, offset, len| Runtime::execute_query(caller, offset, len),
+                export::CALL_CONTRACT => |caller: ::wasmtime::Caller<state::SmartContract<'wrld, 'block, 'state>>, offset, len| Runtime::call_contract(caller, offset, len),
+                export::GET_RANDOMNESS => |caller: ::wasmtime::Caller<state::SmartContract<'wrld, 'block, 'state>>| Runtime::get_randomness(caller),
             )?;
+            create_storage_imports!(linker, state::SmartContract<'wrld, 'block, 'state>)?;
             Ok(linker)
         })
     }
@@ -1569,7 +2230,32 @@ impl<'wrld, 'block, 'state> RuntimeBuilder<state::Trigger<'wrld, 'block, 'state>
             create_imports!(linker, state::Trigger<'wrld, 'block, 'state>,
                 export::EXECUTE_ISI => |caller: ::wasmtime::Caller<state::Trigger<'wrld, 'block, 'state>>, offset, len| Runtime::execute_instruction(caller, offset, len),
                 export::EXECUTE_QUERY => |caller: ::wasmtime::Caller<state::Trigger<'wrld, 'block, 'state>>, offset, len| Runtime::execute_query(caller, offset, len),
+                export::CALL_CONTRACT => |caller: ::wasmtime::Caller<state::Trigger<'wrld, 'block, 'state>>, offset, len| Runtime::call_contract(caller, offset, len),
+                export::GET_RANDOMNESS => |caller: ::wasmtime::Caller<state::Trigger<'wrld, 'block, 'state>>| Runtime::get_randomness(caller),
+            )?;
+            create_storage_imports!(linker, state::Trigger<'wrld, 'block, 'state>)?;
+            Ok(linker)
+        })
+    }
+}
+
+impl<'wrld, 'block, 'state> RuntimeBuilder<state::Contract<'wrld, 'block, 'state>> {
+    /// Builds the [`Runtime`] for *Contract* execution
+    ///
+    /// # Errors
+    ///
+    /// Fails if failed to create default linker.
+    pub fn build(self) -> Result<Runtime<state::Contract<'wrld, 'block, 'state>>> {
+        self.finalize(|engine| {
+            let mut linker = Linker::new(engine);
+
+            create_imports!(linker, state::Contract<'wrld, 'block, 'state>,
+                export::EXECUTE_ISI => |caller: ::wasmtime::Caller<state::Contract<'wrld, 'block, 'state>>, offset, len| Runtime::execute_instruction(caller, offset, len),
+                export::EXECUTE_QUERY => |caller: ::wasmtime::Caller<state::Contract<'wrld, 'block, 'state>>, offset, len| Runtime::execute_query(caller, offset, len),
+                export::CALL_CONTRACT => |caller: ::wasmtime::Caller<state::Contract<'wrld, 'block, 'state>>, offset, len| Runtime::call_contract(caller, offset, len),
+                export::GET_RANDOMNESS => |caller: ::wasmtime::Caller<state::Contract<'wrld, 'block, 'state>>| Runtime::get_randomness(caller),
             )?;
+            create_storage_imports!(linker, state::Contract<'wrld, 'block, 'state>)?;
             Ok(linker)
         })
     }
@@ -1644,6 +2330,27 @@ impl<'wrld, S: StateReadOnly> RuntimeBuilder<state::executor::ValidateQuery<'wrl
     }
 }
 
+impl<'wrld, S: StateReadOnly> RuntimeBuilder<state::executor::ExecuteCustomQuery<'wrld, S>> {
+    /// Builds the [`Runtime`] for *Executor* `execute_custom_query()` execution
+    ///
+    /// # Errors
+    ///
+    /// Fails if failed to create default linker.
+    pub fn build(self) -> Result<Runtime<state::executor::ExecuteCustomQuery<'wrld, S>>> {
+        self.finalize(|engine| {
+            let mut linker = Linker::new(engine);
+
+            // NOTE: doesn't need closure here because `ExecuteCustomQuery` is covariant over 'wrld so 'static can be used and substituted with appropriate lifetime
+            create_imports!(linker, state::executor::ExecuteCustomQuery<'_, S>,
+                export::EXECUTE_ISI => |caller: ::wasmtime::Caller<state::executor::ExecuteCustomQuery<'_, S>>, offset, len| Runtime::execute_instruction(caller, offset, len),
+                export::EXECUTE_QUERY => |caller: ::wasmtime::Caller<state::executor::ExecuteCustomQuery<'_, S>>, offset, len| Runtime::execute_query(caller, offset, len),
+                export::SET_DATA_MODEL => |caller: ::wasmtime::Caller<state::executor::ExecuteCustomQuery<'_, S>>, offset, len| Runtime::set_data_model(caller, offset, len),
+            )?;
+            Ok(linker)
+        })
+    }
+}
+
 impl<'wrld, 'block, 'state> RuntimeBuilder<state::executor::Migrate<'wrld, 'block, 'state>> {
     // FIXME: outdated doc. I guess it executes `migrate` entrypoint?
     /// Builds the [`Runtime`] to execute `permissions()` entrypoint of *Executor*
@@ -1907,4 +2614,147 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    async fn verify_signature_exported_and_consumes_fuel() -> Result<(), Error> {
+        let (authority, _authority_keypair) = gen_account_in("wonderland");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::start_test();
+        let state = State::new(world_with_test_account(&authority), kura, query_handle);
+
+        let args_hex = {
+            let key_pair = KeyPair::random();
+            let payload = b"voucher".to_vec();
+            let signature = Signature::new(key_pair.private_key(), &payload);
+            encode_hex((key_pair.public_key(), signature, payload))
+        };
+        let args_len = args_hex.len() / 3;
+
+        let wat = format!(
+            r#"
+            (module
+                ;; Import host function to execute
+                (import "iroha" "{verify_fn_name}"
+                    (func $verify_fn (param i32 i32) (result i32)))
+
+                {memory_and_alloc}
+
+                ;; Function which starts the smartcontract execution
+                (func (export "{main_fn_name}") (param i32)
+                    ;; Skip the length prefix of the result
+                    (call $verify_fn (i32.const 0) (i32.const {args_len}))
+                    (i32.load8_u offset=4)
+
+                    ;; Trap unless the signature is valid
+                    i32.eqz
+                    (if (then unreachable))))
+            "#,
+            main_fn_name = import::SMART_CONTRACT_MAIN,
+            verify_fn_name = export::VERIFY_SIGNATURE,
+            memory_and_alloc = memory_and_alloc(&args_hex),
+        );
+
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+
+        let mut runtime = RuntimeBuilder::<state::SmartContract>::new().build()?;
+        let mut state_transaction = state_block.transaction();
+        runtime
+            .execute(&mut state_transaction, authority.clone(), &wat)
+            .expect("Execution failed");
+        state_transaction.apply();
+
+        let mut runtime = RuntimeBuilder::<state::SmartContract>::new()
+            .with_config(Config {
+                fuel: NonZeroU64::new(fuel::VERIFY_SIGNATURE).unwrap(),
+                ..Config::default()
+            })
+            .build()?;
+        let mut state_transaction = state_block.transaction();
+        let res = runtime.execute(&mut state_transaction, authority, &wat);
+        state_transaction.apply();
+        state_block.commit();
+
+        assert!(matches!(
+            res.expect_err("Execution should run out of fuel"),
+            Error::ExportFnCall(ExportFnCallError::ExecutionLimitsExceeded(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    async fn storage_set_exported_and_respects_quota() -> Result<(), Error> {
+        let (authority, _authority_keypair) = gen_account_in("wonderland");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::start_test();
+        let state = State::new(world_with_test_account(&authority), kura, query_handle);
+
+        let entry = StorageEntry::new(b"counter".to_vec(), 42_u32.encode());
+        let entry_hex = encode_hex(entry.clone());
+        let entry_len = entry_hex.len() / 3;
+
+        let wat = format!(
+            r#"
+            (module
+                ;; Import host function to execute
+                (import "iroha" "{storage_set_fn_name}"
+                    (func $set_fn (param i32 i32) (result i32)))
+
+                {memory_and_alloc}
+
+                ;; Function which starts the smartcontract execution
+                (func (export "{main_fn_name}") (param i32)
+                    ;; Skip the length prefix of the result
+                    (call $set_fn (i32.const 0) (i32.const {entry_len}))
+                    (i32.load8_u offset=4)
+
+                    ;; Trap unless the entry was stored
+                    (if (then unreachable))))
+            "#,
+            main_fn_name = import::SMART_CONTRACT_MAIN,
+            storage_set_fn_name = export::STORAGE_SET,
+            memory_and_alloc = memory_and_alloc(&entry_hex),
+        );
+
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+
+        let mut state_transaction = state_block.transaction();
+        RuntimeBuilder::<state::SmartContract>::new()
+            .build()?
+            .execute(&mut state_transaction, authority.clone(), &wat)
+            .expect("Execution failed");
+        state_transaction.apply();
+
+        let owner = StorageOwner::Account(authority.clone());
+        let entries = state_block
+            .world
+            .smart_contract_storage_iter(&owner, &[])
+            .map(|(key, value)| StorageEntry::new(key.to_vec(), value.to_vec()))
+            .collect::<Vec<_>>();
+        assert_eq!(entries, [entry]);
+
+        state_block.world.parameters.storage.max_bytes = nonzero!(1_u64);
+        let mut state_transaction = state_block.transaction();
+        state_transaction
+            .world
+            .remove_smart_contract_storage(&owner);
+        let res = RuntimeBuilder::<state::SmartContract>::new()
+            .build()?
+            .execute(&mut state_transaction, authority, &wat);
+        state_transaction.apply();
+        state_block.commit();
+
+        assert!(matches!(
+            res.expect_err("Storage quota should be exceeded"),
+            Error::ExportFnCall(ExportFnCallError::Other(_))
+        ));
+
+        Ok(())
+    }
 }
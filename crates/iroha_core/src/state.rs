@@ -1,13 +1,17 @@
 //! This module provides the [`State`] — an in-memory representation of the current blockchain state.
 use std::{
-    collections::BTreeSet, marker::PhantomData, num::NonZeroUsize, sync::Arc, time::Duration,
+    collections::{BTreeMap, BTreeSet},
+    marker::PhantomData,
+    num::{NonZeroU64, NonZeroUsize},
+    sync::Arc,
+    time::Duration,
 };
 
 use eyre::Result;
 use iroha_crypto::HashOf;
 use iroha_data_model::{
     account::AccountId,
-    block::{BlockHeader, SignedBlock},
+    block::{BlockHeader, SignedBlock, TriggerReceipt},
     events::{
         pipeline::BlockEvent,
         time::TimeEvent,
@@ -16,7 +20,7 @@ use iroha_data_model::{
     },
     executor::ExecutorDataModel,
     isi::error::{InstructionExecutionError as Error, MathError},
-    parameter::Parameters,
+    parameter::{Parameters, TriggerParameters},
     permission::Permissions,
     prelude::*,
     query::error::{FindError, QueryExecutionFail},
@@ -25,11 +29,8 @@ use iroha_data_model::{
 use iroha_logger::prelude::*;
 use iroha_primitives::{must_use::MustUse, numeric::Numeric, small::SmallVec};
 use mv::{
-    cell::{Block as CellBlock, Cell, Transaction as CellTransaction, View as CellView},
-    storage::{
-        Block as StorageBlock, RangeIter, Storage, StorageReadOnly,
-        Transaction as StorageTransaction, View as StorageView,
-    },
+    cell::{Block as CellBlock, Cell, View as CellView},
+    storage::{Block as StorageBlock, RangeIter, Storage, StorageReadOnly, View as StorageView},
 };
 use nonzero_ext::nonzero;
 use parking_lot::Mutex;
@@ -43,6 +44,7 @@ use crate::{
     block::CommittedBlock,
     executor::Executor,
     kura::Kura,
+    parallel::{TrackedCell, TrackedStorage, TrackedTriggers},
     query::store::LiveQueryStoreHandle,
     role::RoleIdWithOwner,
     smartcontracts::{
@@ -56,6 +58,7 @@ use crate::{
         },
         wasm, Execute,
     },
+    storage::StorageKey,
     Peers,
 };
 
@@ -67,6 +70,8 @@ pub struct World {
     pub(crate) parameters: Cell<Parameters>,
     /// Identifications of discovered peers.
     pub(crate) peers: Cell<Peers>,
+    /// Changes to the validator set staged until an epoch boundary.
+    pub(crate) pending_validators: Cell<Option<PendingValidatorSet>>,
     /// Registered domains.
     pub(crate) domains: Storage<DomainId, Domain>,
     /// Registered accounts.
@@ -83,8 +88,17 @@ pub struct World {
     pub(crate) account_permissions: Storage<AccountId, Permissions>,
     /// Roles of an account.
     pub(crate) account_roles: Storage<RoleIdWithOwner, ()>,
+    /// Accounts the aliases resolve to.
+    pub(crate) account_aliases: Storage<AccountAlias, AccountId>,
     /// Triggers
     pub(crate) triggers: TriggerSet,
+    /// Persistent key-value storage of smart contracts and triggers.
+    pub(crate) smart_contract_storage: Storage<StorageKey, Vec<u8>>,
+    /// Number of bytes of keys and values kept in the persistent storage of every owner.
+    #[serde(skip)]
+    pub(crate) smart_contract_storage_sizes: Storage<StorageOwner, u64>,
+    /// Contracts deployed to the contract registry.
+    pub(crate) contracts: Storage<ContractId, Contract>,
     /// Runtime Executor
     pub(crate) executor: Cell<Executor>,
     /// Executor-defined data model
@@ -97,6 +111,8 @@ pub struct WorldBlock<'world> {
     pub parameters: CellBlock<'world, Parameters>,
     /// Identifications of discovered peers.
     pub(crate) peers: CellBlock<'world, Peers>,
+    /// Changes to the validator set staged until an epoch boundary.
+    pub(crate) pending_validators: CellBlock<'world, Option<PendingValidatorSet>>,
     /// Registered domains.
     pub(crate) domains: StorageBlock<'world, DomainId, Domain>,
     /// Registered accounts.
@@ -113,8 +129,16 @@ pub struct WorldBlock<'world> {
     pub(crate) account_permissions: StorageBlock<'world, AccountId, Permissions>,
     /// Roles of an account.
     pub(crate) account_roles: StorageBlock<'world, RoleIdWithOwner, ()>,
+    /// Accounts the aliases resolve to.
+    pub(crate) account_aliases: StorageBlock<'world, AccountAlias, AccountId>,
     /// Triggers
     pub(crate) triggers: TriggerSetBlock<'world>,
+    /// Persistent key-value storage of smart contracts and triggers.
+    pub(crate) smart_contract_storage: StorageBlock<'world, StorageKey, Vec<u8>>,
+    /// Number of bytes of keys and values kept in the persistent storage of every owner.
+    pub(crate) smart_contract_storage_sizes: StorageBlock<'world, StorageOwner, u64>,
+    /// Contracts deployed to the contract registry.
+    pub(crate) contracts: StorageBlock<'world, ContractId, Contract>,
     /// Runtime Executor
     pub(crate) executor: CellBlock<'world, Executor>,
     /// Executor-defined data model
@@ -126,32 +150,42 @@ pub struct WorldBlock<'world> {
 /// Struct for single transaction's aggregated changes
 pub struct WorldTransaction<'block, 'world> {
     /// Iroha on-chain parameters.
-    pub(crate) parameters: CellTransaction<'block, 'world, Parameters>,
+    pub(crate) parameters: TrackedCell<'block, 'world, Parameters>,
     /// Identifications of discovered peers.
-    pub(crate) peers: CellTransaction<'block, 'world, Peers>,
+    pub(crate) peers: TrackedCell<'block, 'world, Peers>,
+    /// Changes to the validator set staged until an epoch boundary.
+    pub(crate) pending_validators: TrackedCell<'block, 'world, Option<PendingValidatorSet>>,
     /// Registered domains.
-    pub(crate) domains: StorageTransaction<'block, 'world, DomainId, Domain>,
+    pub(crate) domains: TrackedStorage<'block, 'world, DomainId, Domain>,
     /// Registered accounts.
-    pub(crate) accounts: StorageTransaction<'block, 'world, AccountId, Account>,
+    pub(crate) accounts: TrackedStorage<'block, 'world, AccountId, Account>,
     /// Registered asset definitions.
     pub(crate) asset_definitions:
-        StorageTransaction<'block, 'world, AssetDefinitionId, AssetDefinition>,
+        TrackedStorage<'block, 'world, AssetDefinitionId, AssetDefinition>,
     /// Registered assets.
-    pub(crate) assets: StorageTransaction<'block, 'world, AssetId, Asset>,
+    pub(crate) assets: TrackedStorage<'block, 'world, AssetId, Asset>,
     /// Registered NFTs.
-    pub(crate) nfts: StorageTransaction<'block, 'world, NftId, Nft>,
+    pub(crate) nfts: TrackedStorage<'block, 'world, NftId, Nft>,
     /// Roles. [`Role`] pairs.
-    pub(crate) roles: StorageTransaction<'block, 'world, RoleId, Role>,
+    pub(crate) roles: TrackedStorage<'block, 'world, RoleId, Role>,
     /// Permission tokens of an account.
-    pub(crate) account_permissions: StorageTransaction<'block, 'world, AccountId, Permissions>,
+    pub(crate) account_permissions: TrackedStorage<'block, 'world, AccountId, Permissions>,
     /// Roles of an account.
-    pub(crate) account_roles: StorageTransaction<'block, 'world, RoleIdWithOwner, ()>,
+    pub(crate) account_roles: TrackedStorage<'block, 'world, RoleIdWithOwner, ()>,
+    /// Accounts the aliases resolve to.
+    pub(crate) account_aliases: TrackedStorage<'block, 'world, AccountAlias, AccountId>,
     /// Triggers
-    pub(crate) triggers: TriggerSetTransaction<'block, 'world>,
+    pub(crate) triggers: TrackedTriggers<'block, 'world>,
+    /// Persistent key-value storage of smart contracts and triggers.
+    pub(crate) smart_contract_storage: TrackedStorage<'block, 'world, StorageKey, Vec<u8>>,
+    /// Number of bytes of keys and values kept in the persistent storage of every owner.
+    pub(crate) smart_contract_storage_sizes: TrackedStorage<'block, 'world, StorageOwner, u64>,
+    /// Contracts deployed to the contract registry.
+    pub(crate) contracts: TrackedStorage<'block, 'world, ContractId, Contract>,
     /// Runtime Executor
-    pub(crate) executor: CellTransaction<'block, 'world, Executor>,
+    pub(crate) executor: TrackedCell<'block, 'world, Executor>,
     /// Executor-defined data model
-    pub(crate) executor_data_model: CellTransaction<'block, 'world, ExecutorDataModel>,
+    pub(crate) executor_data_model: TrackedCell<'block, 'world, ExecutorDataModel>,
     /// Events produced during execution of a transaction
     events_buffer: TransactionEventBuffer<'block>,
 }
@@ -170,6 +204,8 @@ pub struct WorldView<'world> {
     pub(crate) parameters: CellView<'world, Parameters>,
     /// Identifications of discovered peers.
     pub(crate) peers: CellView<'world, Peers>,
+    /// Changes to the validator set staged until an epoch boundary.
+    pub(crate) pending_validators: CellView<'world, Option<PendingValidatorSet>>,
     /// Registered domains.
     pub(crate) domains: StorageView<'world, DomainId, Domain>,
     /// Registered accounts.
@@ -186,8 +222,16 @@ pub struct WorldView<'world> {
     pub(crate) account_permissions: StorageView<'world, AccountId, Permissions>,
     /// Roles of an account.
     pub(crate) account_roles: StorageView<'world, RoleIdWithOwner, ()>,
+    /// Accounts the aliases resolve to.
+    pub(crate) account_aliases: StorageView<'world, AccountAlias, AccountId>,
     /// Triggers
     pub(crate) triggers: TriggerSetView<'world>,
+    /// Persistent key-value storage of smart contracts and triggers.
+    pub(crate) smart_contract_storage: StorageView<'world, StorageKey, Vec<u8>>,
+    /// Number of bytes of keys and values kept in the persistent storage of every owner.
+    pub(crate) smart_contract_storage_sizes: StorageView<'world, StorageOwner, u64>,
+    /// Contracts deployed to the contract registry.
+    pub(crate) contracts: StorageView<'world, ContractId, Contract>,
     /// Runtime Executor
     pub(crate) executor: CellView<'world, Executor>,
     /// Executor-defined data model
@@ -225,6 +269,9 @@ pub struct State {
     /// Lock to prevent getting inconsistent view of the state
     #[serde(skip)]
     view_lock: parking_lot::RwLock<()>,
+    /// Number of threads executing the transactions of a block
+    #[serde(skip)]
+    execution_workers: NonZeroUsize,
 }
 
 /// Struct for block's aggregated changes
@@ -241,9 +288,11 @@ pub struct StateBlock<'state> {
     pub prev_commit_topology: CellBlock<'state, Vec<PeerId>>,
     /// Engine for WASM [`Runtime`](wasm::Runtime) to execute triggers.
     pub engine: &'state wasmtime::Engine,
+    /// Receipts of the triggers executed after the block was applied.
+    pub trigger_receipts: Vec<TriggerReceipt>,
 
     /// Reference to Kura subsystem.
-    kura: &'state Kura,
+    kura: &'state Arc<Kura>,
     /// Handle to the [`LiveQueryStore`].
     pub query_handle: &'state LiveQueryStoreHandle,
     /// Temporary metrics buffer of amounts of any asset that has been transacted.
@@ -251,6 +300,8 @@ pub struct StateBlock<'state> {
     pub new_tx_amounts: &'state Mutex<Vec<f64>>,
     /// Lock to prevent getting inconsistent view of the state
     view_lock: &'state parking_lot::RwLock<()>,
+    /// Number of threads executing the transactions of the block
+    pub(crate) execution_workers: NonZeroUsize,
 
     pub(crate) curr_block: BlockHeader,
 }
@@ -260,13 +311,13 @@ pub struct StateTransaction<'block, 'state> {
     /// The world. Contains `domains`, `triggers`, `roles` and other data representing the current state of the blockchain.
     pub world: WorldTransaction<'block, 'state>,
     /// Blockchain.
-    pub block_hashes: CellTransaction<'block, 'state, Vec<HashOf<BlockHeader>>>,
+    pub block_hashes: TrackedCell<'block, 'state, Vec<HashOf<BlockHeader>>>,
     /// Hashes of transactions mapped onto block height where they stored
-    pub transactions: StorageTransaction<'block, 'state, HashOf<SignedTransaction>, NonZeroUsize>,
+    pub transactions: TrackedStorage<'block, 'state, HashOf<SignedTransaction>, NonZeroUsize>,
     /// Topology used to commit latest block
-    pub commit_topology: CellTransaction<'block, 'state, Vec<PeerId>>,
+    pub commit_topology: TrackedCell<'block, 'state, Vec<PeerId>>,
     /// Topology used to commit previous block
-    pub prev_commit_topology: CellTransaction<'block, 'state, Vec<PeerId>>,
+    pub prev_commit_topology: TrackedCell<'block, 'state, Vec<PeerId>>,
     /// Engine for WASM [`Runtime`](wasm::Runtime) to execute triggers.
     pub engine: &'state wasmtime::Engine,
 
@@ -276,9 +327,20 @@ pub struct StateTransaction<'block, 'state> {
     pub query_handle: &'state LiveQueryStoreHandle,
     /// Temporary metrics buffer of amounts of any asset that has been transacted.
     /// TODO: this should be done through events
-    pub new_tx_amounts: &'state Mutex<Vec<f64>>,
+    pub new_tx_amounts: &'block Mutex<Vec<f64>>,
 
     pub(crate) curr_block: BlockHeader,
+    /// Number of nested contract calls currently being executed.
+    pub(crate) contract_call_depth: u64,
+    /// Fuel remaining to the contract which calls another contract.
+    /// Limits the fuel of the called contract instead of the configured one.
+    pub(crate) contract_fuel: Option<u64>,
+    /// Output of the latest finished contract call.
+    pub(crate) contract_output: Option<Json>,
+    /// Fuel consumed by WASM modules executed within this transaction.
+    pub(crate) wasm_fuel_consumed: u64,
+    /// Peak linear memory in bytes used by WASM modules executed within this transaction.
+    pub(crate) wasm_memory_consumed: u64,
 }
 
 /// Consistent point in time view of the [`State`]
@@ -361,6 +423,7 @@ impl World {
         WorldBlock {
             parameters: self.parameters.block(),
             peers: self.peers.block(),
+            pending_validators: self.pending_validators.block(),
             domains: self.domains.block(),
             accounts: self.accounts.block(),
             asset_definitions: self.asset_definitions.block(),
@@ -369,7 +432,11 @@ impl World {
             roles: self.roles.block(),
             account_permissions: self.account_permissions.block(),
             account_roles: self.account_roles.block(),
+            account_aliases: self.account_aliases.block(),
             triggers: self.triggers.block(),
+            smart_contract_storage: self.smart_contract_storage.block(),
+            smart_contract_storage_sizes: self.smart_contract_storage_sizes.block(),
+            contracts: self.contracts.block(),
             executor: self.executor.block(),
             executor_data_model: self.executor_data_model.block(),
             events_buffer: Vec::new(),
@@ -381,6 +448,7 @@ impl World {
         WorldBlock {
             parameters: self.parameters.block_and_revert(),
             peers: self.peers.block_and_revert(),
+            pending_validators: self.pending_validators.block_and_revert(),
             domains: self.domains.block_and_revert(),
             accounts: self.accounts.block_and_revert(),
             asset_definitions: self.asset_definitions.block_and_revert(),
@@ -389,7 +457,11 @@ impl World {
             roles: self.roles.block_and_revert(),
             account_permissions: self.account_permissions.block_and_revert(),
             account_roles: self.account_roles.block_and_revert(),
+            account_aliases: self.account_aliases.block_and_revert(),
             triggers: self.triggers.block_and_revert(),
+            smart_contract_storage: self.smart_contract_storage.block_and_revert(),
+            smart_contract_storage_sizes: self.smart_contract_storage_sizes.block_and_revert(),
+            contracts: self.contracts.block_and_revert(),
             executor: self.executor.block_and_revert(),
             executor_data_model: self.executor_data_model.block_and_revert(),
             events_buffer: Vec::new(),
@@ -401,6 +473,7 @@ impl World {
         WorldView {
             parameters: self.parameters.view(),
             peers: self.peers.view(),
+            pending_validators: self.pending_validators.view(),
             domains: self.domains.view(),
             accounts: self.accounts.view(),
             asset_definitions: self.asset_definitions.view(),
@@ -409,11 +482,49 @@ impl World {
             roles: self.roles.view(),
             account_permissions: self.account_permissions.view(),
             account_roles: self.account_roles.view(),
+            account_aliases: self.account_aliases.view(),
             triggers: self.triggers.view(),
+            smart_contract_storage: self.smart_contract_storage.view(),
+            smart_contract_storage_sizes: self.smart_contract_storage_sizes.view(),
+            contracts: self.contracts.view(),
             executor: self.executor.view(),
             executor_data_model: self.executor_data_model.view(),
         }
     }
+
+    /// Copy the `world` at its current point,
+    /// so that it can be changed without affecting or locking the original.
+    pub(crate) fn detached_from(world: &impl WorldReadOnly) -> Self {
+        Self {
+            parameters: Cell::new(world.parameters().clone()),
+            peers: Cell::new(world.peers().clone()),
+            pending_validators: Cell::new(world.pending_validators().cloned()),
+            domains: detach_storage(world.domains()),
+            accounts: detach_storage(world.accounts()),
+            asset_definitions: detach_storage(world.asset_definitions()),
+            assets: detach_storage(world.assets()),
+            nfts: detach_storage(world.nfts()),
+            roles: detach_storage(world.roles()),
+            account_permissions: detach_storage(world.account_permissions()),
+            account_roles: detach_storage(world.account_roles()),
+            account_aliases: detach_storage(world.account_aliases()),
+            triggers: TriggerSet::detached_from(world.triggers()),
+            smart_contract_storage: detach_storage(world.smart_contract_storage()),
+            smart_contract_storage_sizes: detach_storage(world.smart_contract_storage_sizes()),
+            contracts: detach_storage(world.contracts()),
+            executor: Cell::new(world.executor().clone()),
+            executor_data_model: Cell::new(world.executor_data_model().clone()),
+        }
+    }
+}
+
+/// Copy entries of the storage at the current point of the `view` into a new storage
+pub(crate) fn detach_storage<K: mv::Key, V: mv::Value>(
+    view: &impl StorageReadOnly<K, V>,
+) -> Storage<K, V> {
+    view.iter()
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
 }
 
 /// Trait to perform read-only operations on [`WorldBlock`], [`WorldTransaction`] and [`WorldView`]
@@ -421,6 +532,7 @@ impl World {
 pub trait WorldReadOnly {
     fn parameters(&self) -> &Parameters;
     fn peers(&self) -> &Peers;
+    fn pending_validators(&self) -> Option<&PendingValidatorSet>;
     fn domains(&self) -> &impl StorageReadOnly<DomainId, Domain>;
     fn accounts(&self) -> &impl StorageReadOnly<AccountId, Account>;
     fn asset_definitions(&self) -> &impl StorageReadOnly<AssetDefinitionId, AssetDefinition>;
@@ -429,10 +541,26 @@ pub trait WorldReadOnly {
     fn roles(&self) -> &impl StorageReadOnly<RoleId, Role>;
     fn account_permissions(&self) -> &impl StorageReadOnly<AccountId, Permissions>;
     fn account_roles(&self) -> &impl StorageReadOnly<RoleIdWithOwner, ()>;
+    fn account_aliases(&self) -> &impl StorageReadOnly<AccountAlias, AccountId>;
     fn triggers(&self) -> &impl TriggerSetReadOnly;
+    fn smart_contract_storage(&self) -> &impl StorageReadOnly<StorageKey, Vec<u8>>;
+    fn smart_contract_storage_sizes(&self) -> &impl StorageReadOnly<StorageOwner, u64>;
+    fn contracts(&self) -> &impl StorageReadOnly<ContractId, Contract>;
     fn executor(&self) -> &Executor;
     fn executor_data_model(&self) -> &ExecutorDataModel;
 
+    /// Staged validator set which the block at the given `height` may activate.
+    ///
+    /// The set is activatable in any epoch after the one it was staged in,
+    /// or right away if epochs were disabled after it was staged.
+    fn activatable_validators(&self, height: NonZeroU64) -> Option<&PendingValidatorSet> {
+        let pending = self.pending_validators()?;
+        match self.parameters().sumeragi().epoch(height) {
+            Some(epoch) if epoch <= pending.staged_in_epoch() => None,
+            _ => Some(pending),
+        }
+    }
+
     // Domain-related methods
 
     /// Get `Domain` without an ability to modify it.
@@ -570,6 +698,47 @@ pub trait WorldReadOnly {
             .map(|(role, ())| &role.id)
     }
 
+    /// Get aliases registered for the [`Account`]
+    fn account_aliases_iter<'slf>(
+        &'slf self,
+        id: &'slf AccountId,
+    ) -> impl Iterator<Item = &'slf AccountAlias> {
+        self.account_aliases()
+            .iter()
+            .filter(move |(_, account_id)| *account_id == id)
+            .map(|(alias, _)| alias)
+    }
+
+    /// Iterate entries of the persistent storage of the `owner` which keys start with `prefix`
+    fn smart_contract_storage_iter<'slf>(
+        &'slf self,
+        owner: &'slf StorageOwner,
+        prefix: &'slf [u8],
+    ) -> impl Iterator<Item = (&'slf [u8], &'slf [u8])> {
+        self.smart_contract_storage()
+            .range::<dyn AsStorageKeyByOwner>(StorageKeyByOwnerBounds::new(owner, prefix))
+            .map(|(key, value)| (key.key.as_slice(), value.as_slice()))
+            .take_while(move |(key, _)| key.starts_with(prefix))
+    }
+
+    /// Get the number of bytes of keys and values kept in the persistent storage of the `owner`
+    fn smart_contract_storage_size(&self, owner: &StorageOwner) -> u64 {
+        self.smart_contract_storage_sizes()
+            .get(owner)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Get [`Contract`] by its id
+    ///
+    /// # Errors
+    /// Fails if there is no contract with the given id
+    fn contract(&self, id: &ContractId) -> Result<&Contract, FindError> {
+        self.contracts()
+            .get(id)
+            .ok_or_else(|| FindError::Contract(id.clone()))
+    }
+
     /// Return a set of all permission tokens granted to this account.
     ///
     /// # Errors
@@ -701,6 +870,9 @@ macro_rules! impl_world_ro {
             fn peers(&self) -> &Peers {
                 &self.peers
             }
+            fn pending_validators(&self) -> Option<&PendingValidatorSet> {
+                Option::as_ref(&self.pending_validators)
+            }
             fn domains(&self) -> &impl StorageReadOnly<DomainId, Domain> {
                 &self.domains
             }
@@ -725,9 +897,21 @@ macro_rules! impl_world_ro {
             fn account_roles(&self) -> &impl StorageReadOnly<RoleIdWithOwner, ()> {
                 &self.account_roles
             }
+            fn account_aliases(&self) -> &impl StorageReadOnly<AccountAlias, AccountId> {
+                &self.account_aliases
+            }
             fn triggers(&self) -> &impl TriggerSetReadOnly {
                 &self.triggers
             }
+            fn smart_contract_storage(&self) -> &impl StorageReadOnly<StorageKey, Vec<u8>> {
+                &self.smart_contract_storage
+            }
+            fn smart_contract_storage_sizes(&self) -> &impl StorageReadOnly<StorageOwner, u64> {
+                &self.smart_contract_storage_sizes
+            }
+            fn contracts(&self) -> &impl StorageReadOnly<ContractId, Contract> {
+                &self.contracts
+            }
             fn executor(&self) -> &Executor {
                 &self.executor
             }
@@ -746,19 +930,24 @@ impl<'world> WorldBlock<'world> {
     /// Create struct to apply transaction's changes
     pub fn trasaction(&mut self) -> WorldTransaction<'_, 'world> {
         WorldTransaction {
-            parameters: self.parameters.transaction(),
-            peers: self.peers.transaction(),
-            domains: self.domains.transaction(),
-            accounts: self.accounts.transaction(),
-            asset_definitions: self.asset_definitions.transaction(),
-            assets: self.assets.transaction(),
-            nfts: self.nfts.transaction(),
-            roles: self.roles.transaction(),
-            account_permissions: self.account_permissions.transaction(),
-            account_roles: self.account_roles.transaction(),
-            triggers: self.triggers.transaction(),
-            executor: self.executor.transaction(),
-            executor_data_model: self.executor_data_model.transaction(),
+            parameters: self.parameters.transaction().into(),
+            peers: self.peers.transaction().into(),
+            pending_validators: self.pending_validators.transaction().into(),
+            domains: self.domains.transaction().into(),
+            accounts: self.accounts.transaction().into(),
+            asset_definitions: self.asset_definitions.transaction().into(),
+            assets: self.assets.transaction().into(),
+            nfts: self.nfts.transaction().into(),
+            roles: self.roles.transaction().into(),
+            account_permissions: self.account_permissions.transaction().into(),
+            account_roles: self.account_roles.transaction().into(),
+            account_aliases: self.account_aliases.transaction().into(),
+            triggers: self.triggers.transaction().into(),
+            smart_contract_storage: self.smart_contract_storage.transaction().into(),
+            smart_contract_storage_sizes: self.smart_contract_storage_sizes.transaction().into(),
+            contracts: self.contracts.transaction().into(),
+            executor: self.executor.transaction().into(),
+            executor_data_model: self.executor_data_model.transaction().into(),
             events_buffer: TransactionEventBuffer {
                 events_buffer: &mut self.events_buffer,
                 events_created_in_transaction: 0,
@@ -766,12 +955,50 @@ impl<'world> WorldBlock<'world> {
         }
     }
 
+    /// Create struct to buffer transaction's changes without changing the block,
+    /// so that several transactions can be executed on top of it at once
+    pub(crate) fn buffered_transaction<'block>(
+        &'block self,
+        events_buffer: &'block mut Vec<EventBox>,
+    ) -> WorldTransaction<'block, 'world> {
+        WorldTransaction {
+            parameters: (&self.parameters).into(),
+            peers: (&self.peers).into(),
+            pending_validators: (&self.pending_validators).into(),
+            domains: (&self.domains).into(),
+            accounts: (&self.accounts).into(),
+            asset_definitions: (&self.asset_definitions).into(),
+            assets: (&self.assets).into(),
+            nfts: (&self.nfts).into(),
+            roles: (&self.roles).into(),
+            account_permissions: (&self.account_permissions).into(),
+            account_roles: (&self.account_roles).into(),
+            account_aliases: (&self.account_aliases).into(),
+            triggers: self.triggers.buffered_transaction().into(),
+            smart_contract_storage: (&self.smart_contract_storage).into(),
+            smart_contract_storage_sizes: (&self.smart_contract_storage_sizes).into(),
+            contracts: (&self.contracts).into(),
+            executor: (&self.executor).into(),
+            executor_data_model: (&self.executor_data_model).into(),
+            events_buffer: TransactionEventBuffer {
+                events_buffer,
+                events_created_in_transaction: 0,
+            },
+        }
+    }
+
+    /// Take events produced by the block so far
+    pub fn take_events(&mut self) -> Vec<EventBox> {
+        core::mem::take(&mut self.events_buffer)
+    }
+
     /// Commit block's changes
     pub fn commit(self) {
         // NOTE: intentionally destruct self not to forget commit some fields
         let Self {
             parameters,
             peers,
+            pending_validators,
             domains,
             accounts,
             asset_definitions,
@@ -780,7 +1007,11 @@ impl<'world> WorldBlock<'world> {
             roles,
             account_permissions,
             account_roles,
+            account_aliases,
             triggers,
+            smart_contract_storage,
+            smart_contract_storage_sizes,
+            contracts,
             executor,
             executor_data_model,
             events_buffer: _,
@@ -788,7 +1019,11 @@ impl<'world> WorldBlock<'world> {
         // IMPORTANT!!! Commit fields in reverse order, this way consistent results are insured
         executor_data_model.commit();
         executor.commit();
+        contracts.commit();
+        smart_contract_storage_sizes.commit();
+        smart_contract_storage.commit();
         triggers.commit();
+        account_aliases.commit();
         account_roles.commit();
         account_permissions.commit();
         roles.commit();
@@ -797,6 +1032,7 @@ impl<'world> WorldBlock<'world> {
         asset_definitions.commit();
         accounts.commit();
         domains.commit();
+        pending_validators.commit();
         peers.commit();
         parameters.commit();
     }
@@ -809,6 +1045,7 @@ impl WorldTransaction<'_, '_> {
         let Self {
             parameters,
             peers,
+            pending_validators,
             domains,
             accounts,
             asset_definitions,
@@ -817,14 +1054,22 @@ impl WorldTransaction<'_, '_> {
             roles,
             account_permissions,
             account_roles,
+            account_aliases,
             triggers,
+            smart_contract_storage,
+            smart_contract_storage_sizes,
+            contracts,
             executor,
             executor_data_model,
             mut events_buffer,
         } = self;
         executor_data_model.apply();
         executor.apply();
+        contracts.apply();
+        smart_contract_storage_sizes.apply();
+        smart_contract_storage.apply();
         triggers.apply();
+        account_aliases.apply();
         account_roles.apply();
         account_permissions.apply();
         roles.apply();
@@ -833,6 +1078,7 @@ impl WorldTransaction<'_, '_> {
         asset_definitions.apply();
         accounts.apply();
         domains.apply();
+        pending_validators.apply();
         peers.apply();
         parameters.apply();
         events_buffer.events_created_in_transaction = 0;
@@ -902,6 +1148,91 @@ impl WorldTransaction<'_, '_> {
         }
     }
 
+    /// Remove all aliases registered for the [`Account`]
+    pub fn remove_account_aliases(&mut self, account: &AccountId) {
+        let aliases_to_remove = self
+            .account_aliases_iter(account)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for alias in aliases_to_remove {
+            self.account_aliases.remove(alias);
+        }
+    }
+
+    /// Remove all entries of the persistent storage of the `owner`
+    pub fn remove_smart_contract_storage(&mut self, owner: &StorageOwner) {
+        let keys_to_remove = self
+            .smart_contract_storage_iter(owner, &[])
+            .map(|(key, _)| StorageKey::new(owner.clone(), key.to_vec()))
+            .collect::<Vec<_>>();
+
+        for key in keys_to_remove {
+            self.smart_contract_storage.remove(key);
+        }
+        self.smart_contract_storage_sizes.remove(owner.clone());
+    }
+
+    /// Set the value of the entry in the persistent storage of its owner
+    /// keeping track of the number of bytes kept in the storage
+    pub fn set_smart_contract_storage_entry(&mut self, key: StorageKey, value: Vec<u8>) {
+        let owner = key.owner.clone();
+        let key_len = key.key.len();
+        let added = (key_len + value.len()) as u64;
+        let removed = self
+            .smart_contract_storage
+            .insert(key, value)
+            .map_or(0, |prev_value| (key_len + prev_value.len()) as u64);
+
+        let size = self.smart_contract_storage_size(&owner) - removed + added;
+        self.smart_contract_storage_sizes.insert(owner, size);
+    }
+
+    /// Remove the entry from the persistent storage of its owner returning its value
+    /// and keeping track of the number of bytes kept in the storage
+    pub fn remove_smart_contract_storage_entry(&mut self, key: StorageKey) -> Option<Vec<u8>> {
+        let owner = key.owner.clone();
+        let key_len = key.key.len();
+        let value = self.smart_contract_storage.remove(key)?;
+
+        let size = self.smart_contract_storage_size(&owner) - (key_len + value.len()) as u64;
+        if size == 0 {
+            self.smart_contract_storage_sizes.remove(owner);
+        } else {
+            self.smart_contract_storage_sizes.insert(owner, size);
+        }
+
+        Some(value)
+    }
+
+    /// Remove the contract together with its persistent storage and release its module
+    ///
+    /// Returns `false` if there is no contract with the given id
+    pub fn remove_contract(&mut self, id: &ContractId) -> bool {
+        let Some(contract) = self.contracts.remove(id.clone()) else {
+            return false;
+        };
+
+        self.triggers.remove_contract_module(contract.code_hash);
+        self.remove_smart_contract_storage(&StorageOwner::Contract(id.clone()));
+
+        true
+    }
+
+    /// Remove all contracts deployed by the `account`
+    pub fn remove_account_contracts(&mut self, account: &AccountId) {
+        let contracts_to_remove = self
+            .contracts
+            .iter()
+            .filter(|(_, contract)| contract.owner() == account)
+            .map(|(id, _)| id.clone())
+            .collect::<Vec<_>>();
+
+        for id in contracts_to_remove {
+            self.remove_contract(&id);
+        }
+    }
+
     /// Get mutable reference to [`Asset`]
     ///
     /// # Errors
@@ -931,7 +1262,7 @@ impl WorldTransaction<'_, '_> {
             let asset = Asset::new(asset_id.clone(), default_asset_value.into());
 
             Self::emit_events_impl(
-                &mut self.triggers,
+                self.triggers.matching(),
                 &mut self.events_buffer,
                 Some(AssetEvent::Created(asset.clone())),
             );
@@ -1068,7 +1399,17 @@ impl WorldTransaction<'_, '_> {
     /// - If this method is called by ISI inside *trigger*,
     ///   then *trigger* will be executed on the **next** block
     pub fn execute_trigger(&mut self, event: ExecuteTriggerEvent) {
-        self.triggers.handle_execute_trigger_event(event.clone());
+        self.triggers
+            .matching()
+            .handle_execute_trigger_event(event.clone());
+        self.events_buffer.push(event.into());
+    }
+
+    /// Emit custom event produced by `EmitEvent` instruction
+    ///
+    /// Produces [`CustomEvent`].
+    pub fn emit_custom_event(&mut self, event: CustomEvent) {
+        self.triggers.matching().handle_custom_event(event.clone());
         self.events_buffer.push(event.into());
     }
 
@@ -1076,7 +1417,26 @@ impl WorldTransaction<'_, '_> {
     /// Events should be produced in the order of expanding scope: from specific to general.
     /// Example: account events before domain events.
     pub fn emit_events<I: IntoIterator<Item = T>, T: Into<DataEvent>>(&mut self, world_events: I) {
-        Self::emit_events_impl(&mut self.triggers, &mut self.events_buffer, world_events)
+        Self::emit_events_impl(
+            self.triggers.matching(),
+            &mut self.events_buffer,
+            world_events,
+        )
+    }
+
+    /// Events produced by the transaction so far
+    pub(crate) fn transaction_events(&self) -> &[EventBox] {
+        let TransactionEventBuffer {
+            events_buffer,
+            events_created_in_transaction,
+        } = &self.events_buffer;
+        &events_buffer[events_buffer.len() - events_created_in_transaction..]
+    }
+
+    /// Put `events` into `events_buffer` without matching triggers against them,
+    /// because the matched actions are known from another execution of the transaction.
+    pub(crate) fn emit_matched_events(&mut self, events: Vec<EventBox>) {
+        self.events_buffer.extend(events);
     }
 
     /// Implementation of [`Self::emit_events()`].
@@ -1140,9 +1500,18 @@ impl State {
             kura,
             query_handle,
             view_lock: parking_lot::RwLock::new(()),
+            execution_workers: nonzero!(1_usize),
         }
     }
 
+    /// Execute the transactions of a block with the given number of threads,
+    /// see [`StateBlock::validate_transactions`].
+    #[must_use]
+    pub fn with_execution_workers(mut self, execution_workers: NonZeroUsize) -> Self {
+        self.execution_workers = execution_workers;
+        self
+    }
+
     /// Create structure to execute a block
     pub fn block(&self, curr_block: BlockHeader) -> StateBlock<'_> {
         StateBlock {
@@ -1152,10 +1521,12 @@ impl State {
             commit_topology: self.commit_topology.block(),
             prev_commit_topology: self.prev_commit_topology.block(),
             engine: &self.engine,
+            trigger_receipts: Vec::new(),
             kura: &self.kura,
             query_handle: &self.query_handle,
             new_tx_amounts: &self.new_tx_amounts,
             view_lock: &self.view_lock,
+            execution_workers: self.execution_workers,
             curr_block,
         }
     }
@@ -1169,14 +1540,43 @@ impl State {
             commit_topology: self.commit_topology.block_and_revert(),
             prev_commit_topology: self.prev_commit_topology.block_and_revert(),
             engine: &self.engine,
+            trigger_receipts: Vec::new(),
             kura: &self.kura,
             query_handle: &self.query_handle,
             new_tx_amounts: &self.new_tx_amounts,
             view_lock: &self.view_lock,
+            execution_workers: self.execution_workers,
             curr_block,
         }
     }
 
+    /// Copy the latest committed state, so that it can be changed without affecting
+    /// or locking this state, e.g. to execute transactions which are never committed.
+    ///
+    /// Hashes of committed transactions are not copied, they are only used to
+    /// reject transactions which are already in the blockchain.
+    #[must_use]
+    pub fn detach(&self) -> Self {
+        Self::detached_from(&self.view(), Arc::clone(&self.kura))
+    }
+
+    /// Copy the `state` at its current point, see [`Self::detach`]
+    fn detached_from(state: &impl StateReadOnly, kura: Arc<Kura>) -> Self {
+        Self {
+            world: World::detached_from(state.world()),
+            block_hashes: Cell::new(state.block_hashes().to_vec()),
+            transactions: Storage::new(),
+            commit_topology: Cell::new(state.commit_topology().to_vec()),
+            prev_commit_topology: Cell::new(state.prev_commit_topology().to_vec()),
+            engine: state.engine().clone(),
+            kura,
+            query_handle: state.query_handle().clone(),
+            new_tx_amounts: Arc::new(Mutex::new(Vec::new())),
+            view_lock: parking_lot::RwLock::new(()),
+            execution_workers: nonzero!(1_usize),
+        }
+    }
+
     /// Create point in time view of [`WorldState`]
     pub fn view(&self) -> StateView<'_> {
         let _view_lock = self.view_lock.read();
@@ -1326,15 +1726,48 @@ impl<'state> StateBlock<'state> {
     pub fn transaction(&mut self) -> StateTransaction<'_, 'state> {
         StateTransaction {
             world: self.world.trasaction(),
-            block_hashes: self.block_hashes.transaction(),
-            transactions: self.transactions.transaction(),
-            commit_topology: self.commit_topology.transaction(),
-            prev_commit_topology: self.prev_commit_topology.transaction(),
+            block_hashes: self.block_hashes.transaction().into(),
+            transactions: self.transactions.transaction().into(),
+            commit_topology: self.commit_topology.transaction().into(),
+            prev_commit_topology: self.prev_commit_topology.transaction().into(),
             engine: self.engine,
             kura: self.kura,
             query_handle: self.query_handle,
             new_tx_amounts: self.new_tx_amounts,
             curr_block: self.curr_block,
+            contract_call_depth: 0,
+            contract_fuel: None,
+            contract_output: None,
+            wasm_fuel_consumed: 0,
+            wasm_memory_consumed: 0,
+        }
+    }
+
+    /// Create struct to buffer changes of a transaction without changing the block,
+    /// so that several transactions can be executed on top of it at once.
+    ///
+    /// Events and amounts transacted by the transaction are written to the given buffers.
+    pub(crate) fn buffered_transaction<'block>(
+        &'block self,
+        events_buffer: &'block mut Vec<EventBox>,
+        new_tx_amounts: &'block Mutex<Vec<f64>>,
+    ) -> StateTransaction<'block, 'state> {
+        StateTransaction {
+            world: self.world.buffered_transaction(events_buffer),
+            block_hashes: (&self.block_hashes).into(),
+            transactions: (&self.transactions).into(),
+            commit_topology: (&self.commit_topology).into(),
+            prev_commit_topology: (&self.prev_commit_topology).into(),
+            engine: self.engine,
+            kura: self.kura,
+            query_handle: self.query_handle,
+            new_tx_amounts,
+            curr_block: self.curr_block,
+            contract_call_depth: 0,
+            contract_fuel: None,
+            contract_output: None,
+            wasm_fuel_consumed: 0,
+            wasm_memory_consumed: 0,
         }
     }
 
@@ -1406,6 +1839,38 @@ impl<'state> StateBlock<'state> {
         Ok(())
     }
 
+    /// Replace the validator set with the staged one.
+    ///
+    /// Called before the transactions of a block which activates the staged set are executed,
+    /// see [`BlockHeader::validator_set_hash`].
+    pub(crate) fn activate_validators(&mut self) {
+        let mut transaction = self.transaction();
+        let world = &mut transaction.world;
+        let Some(pending) = core::mem::take(&mut *world.pending_validators) else {
+            return;
+        };
+
+        let peers: Peers = pending.peers.into_iter().collect();
+        let events = world
+            .peers
+            .iter()
+            .filter(|id| !peers.contains(id))
+            .cloned()
+            .map(PeerEvent::Removed)
+            .chain(
+                peers
+                    .iter()
+                    .filter(|id| !world.peers.contains(id))
+                    .cloned()
+                    .map(PeerEvent::Added),
+            )
+            .collect::<Vec<_>>();
+        *world.peers = peers;
+
+        world.emit_events(events);
+        transaction.apply();
+    }
+
     /// Apply transactions without actually executing them.
     /// It's assumed that block's transaction was already executed (as part of validation for example).
     #[iroha_logger::log(skip_all, fields(block_height = block.as_ref().header().height))]
@@ -1435,6 +1900,19 @@ impl<'state> StateBlock<'state> {
                 self.transactions.insert(tx_hash, block_height);
             });
 
+        if block.as_ref().equivocations().len() > 0 {
+            // Emitted as data events, so that triggers can react to the recorded misbehavior
+            let mut transaction = self.transaction();
+            transaction.world.emit_events(
+                block
+                    .as_ref()
+                    .equivocations()
+                    .cloned()
+                    .map(|evidence| PeerEvent::Equivocated(Box::new(evidence))),
+            );
+            transaction.apply();
+        }
+
         self.world.triggers.handle_time_event(time_event);
 
         let res = self.process_triggers();
@@ -1477,55 +1955,164 @@ impl<'state> StateBlock<'state> {
     }
 
     /// Process every trigger in `matched_ids`
+    ///
+    /// Events emitted by the executed triggers are matched against the trigger set again,
+    /// so trigger chains are executed within the same block breadth-first
+    /// and bounded by [`TriggerParameters`].
+    /// A trigger matched by an event it caused itself is deferred until the next block.
+    #[allow(clippy::too_many_lines)]
     fn process_triggers(&mut self) -> Result<(), Vec<eyre::Report>> {
-        // Cloning and clearing `self.matched_ids` so that `handle_` call won't deadlock
-        let matched_ids = self.world.triggers.extract_matched_ids();
-        let mut succeed = Vec::<TriggerId>::with_capacity(matched_ids.len());
+        let TriggerParameters {
+            max_depth,
+            max_fuel,
+        } = self.world.parameters().trigger;
+        let mut fuel_left = max_fuel.get();
+        let mut executions = BTreeMap::<TriggerId, u32>::new();
+        let mut succeed = Vec::<TriggerId>::new();
+        let mut deferred = Vec::new();
         let mut errors = Vec::new();
-        for (event, id) in matched_ids {
-            // Eliding the closure triggers a lifetime mismatch
-            #[allow(clippy::redundant_closure_for_method_calls)]
-            let action = self
-                .world
-                .triggers
-                .inspect_by_id(&id, |action| action.clone_and_box());
-            if let Some(action) = action {
+
+        // Cloning and clearing `self.matched_ids` so that `handle_` call won't deadlock
+        let mut matched: Vec<_> = self
+            .world
+            .triggers
+            .extract_matched_ids()
+            .into_iter()
+            .map(|(event, id)| MatchedTrigger::new(event, id, Vec::new()))
+            .collect();
+        let mut depth = 1_u64;
+
+        while !matched.is_empty() {
+            let mut next_matched = Vec::new();
+
+            for MatchedTrigger { event, id, causes } in matched {
+                // Eliding the closure triggers a lifetime mismatch
+                #[allow(clippy::redundant_closure_for_method_calls)]
+                let Some(action) = self
+                    .world
+                    .triggers
+                    .inspect_by_id(&id, |action| action.clone_and_box())
+                else {
+                    continue;
+                };
+                let executed = executions.get(&id).copied().unwrap_or(0);
                 if let Repeats::Exactly(repeats) = action.repeats() {
-                    if *repeats == 0 {
+                    if *repeats <= executed {
                         continue;
                     }
                 }
-                // Execute every trigger in it's own transaction
-                let event = {
+
+                if causes.contains(&id) {
+                    deferred.push((event, id));
+                    continue;
+                }
+
+                let limits = if depth > max_depth.get() {
+                    Err(TriggerChainError::MaxDepthExceeded(max_depth))
+                } else if fuel_left == 0 {
+                    Err(TriggerChainError::FuelExhausted(max_fuel))
+                } else {
+                    Ok(())
+                };
+
+                let events_before = self.world.events_buffer.len();
+                let result = limits.map_err(eyre::Report::from).and_then(|()| {
+                    let fuel_limit = NonZeroU64::new(fuel_left)
+                        .expect("checked above")
+                        .min(self.world.parameters().smart_contract.fuel);
+                    // Execute every trigger in it's own transaction
                     let mut transaction = self.transaction();
-                    match transaction.process_trigger(&id, &action, event) {
-                        Ok(()) => {
-                            transaction.apply();
-                            succeed.push(id.clone());
-                            TriggerCompletedEvent::new(id, TriggerCompletedOutcome::Success)
-                        }
-                        Err(error) => {
-                            let event = TriggerCompletedEvent::new(
-                                id,
-                                TriggerCompletedOutcome::Failure(error.to_string()),
-                            );
-                            errors.push(error);
-                            event
-                        }
+                    let result =
+                        transaction.process_trigger(&id, &action, event.clone(), fuel_limit);
+                    let fuel_charged = match (&result, action.executable()) {
+                        (Ok(fuel), _) => *fuel,
+                        (Err(_), triggers::set::ExecutableRef::Wasm(_)) => fuel_limit.get(),
+                        (Err(_), triggers::set::ExecutableRef::Instructions(_)) => 0,
+                    };
+                    fuel_left = fuel_left.saturating_sub(fuel_charged);
+                    if result.is_ok() {
+                        transaction.apply();
+                        // Triggers matched by the events emitted by this trigger
+                        let mut causes = causes;
+                        causes.push(id.clone());
+                        next_matched.extend(
+                            self.world.triggers.extract_matched_ids().into_iter().map(
+                                |(event, matched_id)| {
+                                    MatchedTrigger::new(event, matched_id, causes.clone())
+                                },
+                            ),
+                        );
+                    }
+                    result
+                });
+
+                let (outcome, fuel) = match result {
+                    Ok(fuel) => {
+                        executions.insert(id.clone(), executed + 1);
+                        succeed.push(id.clone());
+                        (TriggerCompletedOutcome::Success, fuel)
+                    }
+                    Err(error) => {
+                        let outcome = TriggerCompletedOutcome::Failure(error.to_string());
+                        errors.push(error);
+                        (outcome, 0)
                     }
                 };
-                self.world.events_buffer.push(event.into());
+                // Events of a failed execution were discarded along with its transaction
+                let events = self.world.events_buffer[events_before..]
+                    .iter()
+                    .filter_map(|event| match event {
+                        EventBox::Data(event) => Some(event.clone()),
+                        _ => None,
+                    })
+                    .collect();
+                self.trigger_receipts.push(TriggerReceipt {
+                    trigger_id: id.clone(),
+                    event,
+                    outcome: outcome.clone(),
+                    fuel,
+                    events,
+                });
+                self.world
+                    .events_buffer
+                    .push(TriggerCompletedEvent::new(id, outcome).into());
             }
+
+            matched = next_matched;
+            depth += 1;
         }
 
         let mut transaction = self.transaction();
         transaction.world.triggers.decrease_repeats(&succeed);
         transaction.apply();
+        self.world.triggers.defer_matched_ids(deferred);
 
         errors.is_empty().then_some(()).ok_or(errors)
     }
 }
 
+/// Trigger matched by an event together with the triggers whose events led to the match
+struct MatchedTrigger {
+    event: EventBox,
+    id: TriggerId,
+    causes: Vec<TriggerId>,
+}
+
+impl MatchedTrigger {
+    fn new(event: EventBox, id: TriggerId, causes: Vec<TriggerId>) -> Self {
+        Self { event, id, causes }
+    }
+}
+
+/// Reason a matched trigger was not executed after a block
+#[derive(Debug, Clone, Copy, displaydoc::Display, thiserror::Error)]
+pub enum TriggerChainError {
+    /// Trigger chain exceeded the maximum depth of {0}
+    MaxDepthExceeded(NonZeroU64),
+    /// Trigger fuel limit of {0} per block is exhausted
+    FuelExhausted(NonZeroU64),
+}
+
 impl StateTransaction<'_, '_> {
     /// Apply transaction making it's changes visible
     pub fn apply(self) {
@@ -1545,6 +2132,12 @@ impl StateTransaction<'_, '_> {
         world.apply();
     }
 
+    /// Account for the fuel and linear memory consumed by a WASM module
+    pub(crate) fn record_wasm_consumption(&mut self, fuel: u64, memory: u64) {
+        self.wasm_fuel_consumed = self.wasm_fuel_consumed.saturating_add(fuel);
+        self.wasm_memory_consumed = self.wasm_memory_consumed.max(memory);
+    }
+
     fn process_executable(&mut self, executable: &Executable, authority: AccountId) -> Result<()> {
         match executable {
             Executable::Instructions(instructions) => {
@@ -1573,19 +2166,22 @@ impl StateTransaction<'_, '_> {
         })
     }
 
+    /// Execute the trigger allowing a WASM executable to consume at most `fuel`
+    /// and return the amount of fuel consumed by it
     fn process_trigger(
         &mut self,
         id: &TriggerId,
         action: &dyn LoadedActionTrait,
         event: EventBox,
-    ) -> Result<()> {
+        fuel: NonZeroU64,
+    ) -> Result<u64> {
         use triggers::set::ExecutableRef::*;
         let authority = action.authority();
 
         match action.executable() {
-            Instructions(instructions) => {
-                self.process_instructions(instructions.iter().cloned(), authority)
-            }
+            Instructions(instructions) => self
+                .process_instructions(instructions.iter().cloned(), authority)
+                .map(|()| 0),
             Wasm(blob_hash) => {
                 let module = self
                     .world
@@ -1593,8 +2189,12 @@ impl StateTransaction<'_, '_> {
                     .get_compiled_contract(blob_hash)
                     .expect("INTERNAL BUG: contract is not present")
                     .clone();
+                let config = SmartContractParameters {
+                    fuel,
+                    ..self.world().parameters().smart_contract
+                };
                 let mut wasm_runtime = wasm::RuntimeBuilder::<wasm::state::Trigger>::new()
-                    .with_config(self.world().parameters().smart_contract)
+                    .with_config(config)
                     .with_engine(self.engine.clone()) // Cloning engine is cheap
                     .build()?;
                 wasm_runtime
@@ -1612,7 +2212,7 @@ mod range_bounds {
     use iroha_primitives::{cmpext::MinMaxExt, impl_as_dyn_key};
 
     use super::*;
-    use crate::role::RoleIdWithOwner;
+    use crate::{role::RoleIdWithOwner, storage::StorageKey};
 
     /// Key for range queries over account for roles
     #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
@@ -1668,6 +2268,61 @@ mod range_bounds {
         trait: AsRoleIdByAccount
     }
 
+    /// Key for range queries over owner for the persistent storage entries
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+    pub struct StorageKeyByOwner<'a> {
+        owner: &'a StorageOwner,
+        key: MinMaxExt<&'a [u8]>,
+    }
+
+    /// Bounds for range queries over owner for the persistent storage entries
+    pub struct StorageKeyByOwnerBounds<'a> {
+        start: StorageKeyByOwner<'a>,
+        end: StorageKeyByOwner<'a>,
+    }
+
+    impl<'a> StorageKeyByOwnerBounds<'a> {
+        /// Create range bounds for range queries of the entries with keys
+        /// not less than `prefix` over owner
+        pub fn new(owner: &'a StorageOwner, prefix: &'a [u8]) -> Self {
+            Self {
+                start: StorageKeyByOwner {
+                    owner,
+                    key: MinMaxExt::Value(prefix),
+                },
+                end: StorageKeyByOwner {
+                    owner,
+                    key: MinMaxExt::Max,
+                },
+            }
+        }
+    }
+
+    impl<'a> RangeBounds<dyn AsStorageKeyByOwner + 'a> for StorageKeyByOwnerBounds<'a> {
+        fn start_bound(&self) -> Bound<&(dyn AsStorageKeyByOwner + 'a)> {
+            Bound::Included(&self.start)
+        }
+
+        fn end_bound(&self) -> Bound<&(dyn AsStorageKeyByOwner + 'a)> {
+            Bound::Excluded(&self.end)
+        }
+    }
+
+    impl AsStorageKeyByOwner for StorageKey {
+        fn as_key(&self) -> StorageKeyByOwner<'_> {
+            StorageKeyByOwner {
+                owner: &self.owner,
+                key: self.key.as_slice().into(),
+            }
+        }
+    }
+
+    impl_as_dyn_key! {
+        target: StorageKey,
+        key: StorageKeyByOwner<'_>,
+        trait: AsStorageKeyByOwner
+    }
+
     /// `DomainId` wrapper for fetching accounts beloning to a domain from the global store
     #[derive(PartialEq, Eq, Ord, PartialOrd, Copy, Clone)]
     pub struct AccountIdDomainCompare<'a> {
@@ -1981,6 +2636,7 @@ pub(crate) mod deserialize {
                 {
                     let mut parameters = None;
                     let mut peers = None;
+                    let mut pending_validators = None;
                     let mut domains = None;
                     let mut accounts = None;
                     let mut asset_definitions = None;
@@ -1989,7 +2645,10 @@ pub(crate) mod deserialize {
                     let mut roles = None;
                     let mut account_permissions = None;
                     let mut account_roles = None;
+                    let mut account_aliases = None;
                     let mut triggers = None;
+                    let mut smart_contract_storage = None;
+                    let mut contracts = None;
                     let mut executor = None;
                     let mut executor_data_model = None;
 
@@ -2001,6 +2660,9 @@ pub(crate) mod deserialize {
                             "peers" => {
                                 peers = Some(map.next_value()?);
                             }
+                            "pending_validators" => {
+                                pending_validators = Some(map.next_value()?);
+                            }
                             "domains" => {
                                 domains = Some(map.next_value()?);
                             }
@@ -2025,10 +2687,19 @@ pub(crate) mod deserialize {
                             "account_roles" => {
                                 account_roles = Some(map.next_value()?);
                             }
+                            "account_aliases" => {
+                                account_aliases = Some(map.next_value()?);
+                            }
                             "triggers" => {
                                 triggers =
                                     Some(map.next_value_seed(self.loader.cast::<TriggerSet>())?);
                             }
+                            "smart_contract_storage" => {
+                                smart_contract_storage = Some(map.next_value()?);
+                            }
+                            "contracts" => {
+                                contracts = Some(map.next_value()?);
+                            }
                             "executor" => {
                                 executor = Some(map.next_value_seed(CellSeeded {
                                     seed: self.loader.cast::<Executor>(),
@@ -2042,10 +2713,23 @@ pub(crate) mod deserialize {
                         }
                     }
 
+                    let smart_contract_storage: Storage<StorageKey, Vec<u8>> =
+                        smart_contract_storage.ok_or_else(|| {
+                            serde::de::Error::missing_field("smart_contract_storage")
+                        })?;
+                    let mut sizes = BTreeMap::<StorageOwner, u64>::new();
+                    for (key, value) in smart_contract_storage.view().iter() {
+                        *sizes.entry(key.owner.clone()).or_default() +=
+                            (key.key.len() + value.len()) as u64;
+                    }
+                    let smart_contract_storage_sizes = Storage::from_iter(sizes);
+
                     Ok(World {
                         parameters: parameters
                             .ok_or_else(|| serde::de::Error::missing_field("parameters"))?,
                         peers: peers.ok_or_else(|| serde::de::Error::missing_field("peers"))?,
+                        pending_validators: pending_validators
+                            .ok_or_else(|| serde::de::Error::missing_field("pending_validators"))?,
                         domains: domains
                             .ok_or_else(|| serde::de::Error::missing_field("domains"))?,
                         accounts: accounts
@@ -2060,8 +2744,14 @@ pub(crate) mod deserialize {
                         })?,
                         account_roles: account_roles
                             .ok_or_else(|| serde::de::Error::missing_field("account_roles"))?,
+                        account_aliases: account_aliases
+                            .ok_or_else(|| serde::de::Error::missing_field("account_aliases"))?,
                         triggers: triggers
                             .ok_or_else(|| serde::de::Error::missing_field("triggers"))?,
+                        smart_contract_storage,
+                        smart_contract_storage_sizes,
+                        contracts: contracts
+                            .ok_or_else(|| serde::de::Error::missing_field("contracts"))?,
                         executor: executor
                             .ok_or_else(|| serde::de::Error::missing_field("executor"))?,
                         executor_data_model: executor_data_model.ok_or_else(|| {
@@ -2076,11 +2766,15 @@ pub(crate) mod deserialize {
                 &[
                     "parameters",
                     "peers",
+                    "pending_validators",
                     "domains",
                     "roles",
                     "account_permissions",
                     "account_roles",
+                    "account_aliases",
                     "triggers",
+                    "smart_contract_storage",
+                    "contracts",
                     "executor",
                     "executor_data_model",
                 ],
@@ -2169,6 +2863,7 @@ pub(crate) mod deserialize {
                         engine,
                         new_tx_amounts: Arc::new(Mutex::new(Vec::new())),
                         view_lock: parking_lot::RwLock::new(()),
+                        execution_workers: nonzero!(1_usize),
                     })
                 }
             }
@@ -2361,4 +3056,34 @@ mod tests {
         let range = view.range(AssetByAccountBounds::new(&account_id));
         assert_eq!(range.count(), 2);
     }
+
+    #[test]
+    fn smart_contract_storage_size_is_tracked() {
+        let world = World::default();
+        let mut world_block = world.block();
+        let mut transaction = world_block.trasaction();
+
+        let owner = StorageOwner::Account(gen_account_in("wonderland").0);
+        let key = |key: &[u8]| StorageKey::new(owner.clone(), key.to_vec());
+
+        transaction.set_smart_contract_storage_entry(key(b"a"), vec![0; 3]);
+        transaction.set_smart_contract_storage_entry(key(b"bc"), vec![0; 5]);
+        assert_eq!(transaction.smart_contract_storage_size(&owner), 11);
+
+        transaction.set_smart_contract_storage_entry(key(b"a"), vec![0; 1]);
+        assert_eq!(transaction.smart_contract_storage_size(&owner), 9);
+
+        assert_eq!(
+            transaction.remove_smart_contract_storage_entry(key(b"bc")),
+            Some(vec![0; 5])
+        );
+        assert_eq!(
+            transaction.remove_smart_contract_storage_entry(key(b"bc")),
+            None
+        );
+        assert_eq!(transaction.smart_contract_storage_size(&owner), 2);
+
+        transaction.remove_smart_contract_storage(&owner);
+        assert_eq!(transaction.smart_contract_storage_size(&owner), 0);
+    }
 }
@@ -0,0 +1,215 @@
+//! Detection of peers which sign conflicting blocks in the same round of consensus.
+
+use std::collections::{btree_map::Entry, BTreeMap, BTreeSet};
+
+use iroha_crypto::SignatureOf;
+use iroha_data_model::{
+    block::{BlockHeader, EquivocationEvidence, SignedBlock, SignedBlockHeader},
+    peer::PeerId,
+};
+
+/// Round of consensus: block height and view change index
+type Round = (u64, u32);
+
+/// Number of blocks after the equivocation during which its evidence can still be recorded
+pub const EVIDENCE_EXPIRY_BLOCKS: u64 = 256;
+
+/// Whether the `evidence` is too old to be recorded in the block at `block_height`
+pub fn is_expired(evidence: &EquivocationEvidence, block_height: u64) -> bool {
+    evidence
+        .height()
+        .get()
+        .saturating_add(EVIDENCE_EXPIRY_BLOCKS)
+        < block_height
+}
+
+/// Collects block headers signed by peers and produces [`EquivocationEvidence`]
+/// once a peer is seen signing two different headers in the same round.
+#[derive(Debug, Default)]
+pub struct EquivocationDetector {
+    /// The first header signed by a peer in the round
+    signed_headers: BTreeMap<(Round, PeerId), SignedBlockHeader>,
+    /// Evidence which is to be recorded in the blockchain
+    pending: BTreeSet<EquivocationEvidence>,
+    /// Evidence which was recently recorded in the blockchain
+    recorded: BTreeSet<EquivocationEvidence>,
+    /// Height of the latest committed block
+    committed_height: u64,
+}
+
+impl EquivocationDetector {
+    /// Remember the header signed by the `peer`. Signature must be verified by the caller.
+    ///
+    /// Returns new evidence if the peer has already signed a different header in the same round.
+    pub fn observe(
+        &mut self,
+        peer: &PeerId,
+        header: BlockHeader,
+        signature: SignatureOf<BlockHeader>,
+    ) -> Option<EquivocationEvidence> {
+        let round = (header.height().get(), header.view_change_index);
+        let signed_header = SignedBlockHeader { header, signature };
+
+        match self.signed_headers.entry((round, peer.clone())) {
+            Entry::Vacant(entry) => {
+                entry.insert(signed_header);
+                None
+            }
+            Entry::Occupied(entry) => {
+                if entry.get().header.hash() == header.hash() {
+                    return None;
+                }
+
+                let evidence =
+                    EquivocationEvidence::new(peer.clone(), entry.get().clone(), signed_header);
+                self.insert(evidence.clone()).then_some(evidence)
+            }
+        }
+    }
+
+    /// Add evidence detected by this or some other peer.
+    ///
+    /// Returns `false` if the evidence is already known or is too old to be recorded
+    pub fn insert(&mut self, evidence: EquivocationEvidence) -> bool {
+        // Expired evidence could have been recorded and then forgotten
+        if is_expired(&evidence, self.committed_height + 1) || self.recorded.contains(&evidence) {
+            return false;
+        }
+
+        self.pending.insert(evidence)
+    }
+
+    /// Evidence which is to be recorded in the blockchain
+    pub fn pending(&self) -> impl Iterator<Item = &EquivocationEvidence> {
+        self.pending.iter()
+    }
+
+    /// Forget rounds which are over and the evidence which was recorded or has expired.
+    ///
+    /// Evidence which wasn't recorded in the committed block is kept for the next blocks
+    pub fn block_committed(&mut self, block: &SignedBlock) {
+        for evidence in block.equivocations() {
+            self.pending.remove(evidence);
+            self.recorded.insert(evidence.clone());
+        }
+
+        self.height_committed(block.header().height().get());
+    }
+
+    fn height_committed(&mut self, committed_height: u64) {
+        self.committed_height = committed_height;
+
+        self.signed_headers
+            .retain(|((height, _), _), _| *height >= committed_height);
+        // Once expired, evidence can't be recorded in any of the next blocks
+        self.pending
+            .retain(|evidence| !is_expired(evidence, committed_height + 1));
+        self.recorded
+            .retain(|evidence| !is_expired(evidence, committed_height + 1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU64;
+
+    use iroha_crypto::{Hash, HashOf, KeyPair};
+
+    use super::*;
+
+    fn signed_header(key_pair: &KeyPair, view_change_index: u32, seed: u8) -> SignedBlockHeader {
+        signed_header_at(key_pair, 2, view_change_index, seed)
+    }
+
+    fn signed_header_at(
+        key_pair: &KeyPair,
+        height: u64,
+        view_change_index: u32,
+        seed: u8,
+    ) -> SignedBlockHeader {
+        let transactions_hash =
+            HashOf::from_untyped_unchecked(Hash::prehashed([seed; Hash::LENGTH]));
+        let header = BlockHeader {
+            height: NonZeroU64::new(height).unwrap(),
+            prev_block_hash: None,
+            transactions_hash: Some(transactions_hash),
+            prev_trigger_receipts_hash: None,
+            creation_time_ms: 0,
+            view_change_index,
+            randomness: None,
+            equivocations_hash: None,
+            validator_set_hash: None,
+        };
+
+        SignedBlockHeader {
+            header,
+            signature: SignatureOf::new(key_pair.private_key(), &header),
+        }
+    }
+
+    fn observe(
+        detector: &mut EquivocationDetector,
+        peer: &PeerId,
+        signed_header: SignedBlockHeader,
+    ) -> Option<EquivocationEvidence> {
+        detector.observe(peer, signed_header.header, signed_header.signature)
+    }
+
+    #[test]
+    fn conflicting_headers_in_the_same_round_are_detected() {
+        let key_pair = KeyPair::random();
+        let peer = PeerId::new(key_pair.public_key().clone());
+        let mut detector = EquivocationDetector::default();
+
+        assert!(observe(&mut detector, &peer, signed_header(&key_pair, 0, 1)).is_none());
+        assert!(observe(&mut detector, &peer, signed_header(&key_pair, 0, 1)).is_none());
+        // Signing a different block after the view change is not an equivocation
+        assert!(observe(&mut detector, &peer, signed_header(&key_pair, 1, 2)).is_none());
+
+        let evidence = observe(&mut detector, &peer, signed_header(&key_pair, 0, 2))
+            .expect("Equivocation must be detected");
+        evidence.verify().expect("Evidence must be valid");
+        assert_eq!(evidence.peer(), &peer);
+        assert_eq!(evidence.view_change_index(), 0);
+
+        // The same evidence is reported only once
+        assert!(observe(&mut detector, &peer, signed_header(&key_pair, 0, 2)).is_none());
+        assert!(!detector.insert(evidence.clone()));
+        assert_eq!(detector.pending().collect::<Vec<_>>(), vec![&evidence]);
+    }
+
+    #[test]
+    fn evidence_is_ordered_by_header_hash() {
+        let key_pair = KeyPair::random();
+        let peer = PeerId::new(key_pair.public_key().clone());
+        let first = signed_header(&key_pair, 0, 1);
+        let second = signed_header(&key_pair, 0, 2);
+
+        assert_eq!(
+            EquivocationEvidence::new(peer.clone(), first.clone(), second.clone()),
+            EquivocationEvidence::new(peer, second, first)
+        );
+    }
+
+    #[test]
+    fn evidence_is_pending_until_expired() {
+        let key_pair = KeyPair::random();
+        let peer = PeerId::new(key_pair.public_key().clone());
+        let evidence = EquivocationEvidence::new(
+            peer,
+            signed_header_at(&key_pair, 2, 0, 1),
+            signed_header_at(&key_pair, 2, 0, 2),
+        );
+        let mut detector = EquivocationDetector::default();
+        assert!(detector.insert(evidence.clone()));
+
+        // Committed blocks didn't record the evidence
+        detector.height_committed(3);
+        detector.height_committed(1 + EVIDENCE_EXPIRY_BLOCKS);
+        assert_eq!(detector.pending().collect::<Vec<_>>(), vec![&evidence]);
+
+        detector.height_committed(2 + EVIDENCE_EXPIRY_BLOCKS);
+        assert_eq!(detector.pending().count(), 0);
+        assert!(!detector.insert(evidence));
+    }
+}
@@ -1,13 +1,19 @@
 //! The main event loop that powers sumeragi.
-use std::{collections::BTreeSet, ops::Deref, sync::mpsc};
+use std::{collections::BTreeSet, num::NonZeroU64, ops::Deref, sync::mpsc};
 
-use iroha_crypto::{HashOf, KeyPair};
-use iroha_data_model::{block::*, events::pipeline::PipelineEventBox, peer::PeerId};
+use iroha_crypto::{HashOf, KeyPair, SignatureOf};
+use iroha_data_model::{
+    block::*,
+    events::pipeline::PipelineEventBox,
+    peer::{PeerId, PendingValidatorSet},
+};
 use iroha_futures::supervisor::ShutdownSignal;
-use iroha_p2p::UpdateTopology;
+use iroha_p2p::{scoring::Misbehavior, UpdateTopology};
 use tracing::{span, Level};
 
-use super::{view_change::ProofBuilder, *};
+use super::{
+    equivocation::EquivocationDetector, validator_set::SyncedPeers, view_change::ProofBuilder, *,
+};
 use crate::{
     block::*, peers_gossiper::PeersGossiperHandle, queue::TransactionGuard,
     sumeragi::tracing::instrument,
@@ -38,6 +44,10 @@ pub struct Sumeragi {
     /// Only used in testing. Causes the genesis peer to withhold blocks when it
     /// is the proxy tail.
     pub debug_force_soft_fork: bool,
+    /// Whether this peer is a replica which only follows committed blocks and never votes.
+    pub replica: bool,
+    /// Source of time used to measure block and view change timeouts
+    pub time_source: TimeSource,
     /// The current network topology.
     pub topology: Topology,
     /// In order to *be fast*, we must minimize communication with
@@ -45,16 +55,20 @@ pub struct Sumeragi {
     /// sumeragi is more dependent on the code that is internal to the
     /// subsystem.
     pub transaction_cache: Vec<TransactionGuard>,
+    /// Detector of peers signing conflicting blocks and the evidence to be recorded
+    pub equivocations: EquivocationDetector,
+    /// Latest blocks of the peers, used to check that peers joining the validator set are synced
+    pub synced_peers: SyncedPeers,
     /// Metrics for reporting number of view changes in current round
     #[cfg(feature = "telemetry")]
     pub view_changes_metric: iroha_telemetry::metrics::ViewChangesGauge,
 
     /// Was there a commit in previous round?
     pub was_commit: bool,
-    /// Instant when the current round started
+    /// Time when the current round started
     // NOTE: Round is only restarted on a block commit, so that in the case of
     // a view change a new block is immediately created by the leader
-    pub round_start_time: Instant,
+    pub round_start_time: Duration,
 }
 
 #[allow(clippy::missing_fields_in_debug)]
@@ -69,9 +83,17 @@ impl Debug for Sumeragi {
 
 impl Sumeragi {
     fn role(&self) -> Role {
+        if self.replica {
+            return Role::Undefined;
+        }
         self.topology.role(&self.peer.id)
     }
 
+    /// Current time according to the time source of this peer
+    fn now(&self) -> Duration {
+        self.time_source.get_unix_time()
+    }
+
     /// Send a sumeragi packet over the network to the specified `peer`.
     /// # Errors
     /// Fails if network sending fails
@@ -116,8 +138,18 @@ impl Sumeragi {
     }
 
     /// Connect or disconnect peers according to the current network topology.
-    fn connect_peers(&self, topology: &Topology) {
-        let update = UpdateTopology(topology.iter().cloned().collect());
+    ///
+    /// Peers of the staged validator set are connected too, so that the joining ones
+    /// can sync before the set is activated.
+    fn connect_peers(&self, topology: &Topology, pending: Option<&PendingValidatorSet>) {
+        let update = UpdateTopology(
+            topology
+                .iter()
+                .chain(pending.into_iter().flat_map(PendingValidatorSet::peers))
+                .cloned()
+                .collect(),
+        );
+        self.synced_peers.track(update.0.iter().cloned());
         self.network.update_topology(update.clone());
         self.peers_gossiper.update_topology(update);
     }
@@ -126,6 +158,105 @@ impl Sumeragi {
         let _ = self.events_sender.send(event.into());
     }
 
+    /// Check that the header signed by the `peer` doesn't conflict with the headers
+    /// it signed before in the same round. Signature must be already verified.
+    fn detect_equivocation(
+        &mut self,
+        peer: &PeerId,
+        header: BlockHeader,
+        signature: SignatureOf<BlockHeader>,
+    ) {
+        if let Some(evidence) = self.equivocations.observe(peer, header, signature) {
+            warn!(
+                peer_id=%self.peer,
+                role=%self.role(),
+                offender=%evidence.peer(),
+                height=%evidence.height(),
+                view_change_index=%evidence.view_change_index(),
+                "Peer signed conflicting blocks"
+            );
+
+            self.broadcast_packet(EquivocationDetected::from(&evidence));
+        }
+    }
+
+    /// Evidence of equivocation of the registered peers which is to be recorded in the next block
+    fn pending_equivocations(&self, state: &impl StateReadOnly) -> Vec<EquivocationEvidence> {
+        self.equivocations
+            .pending()
+            .filter(|evidence| state.world().peers().contains(evidence.peer()))
+            .cloned()
+            .collect()
+    }
+
+    /// Staged validator set which the next block is to activate.
+    ///
+    /// The set is activated only once every peer joining it is live: connected to this peer
+    /// and reported to have synced at least up to the first block of the current epoch.
+    fn validator_set_to_activate(&self, state: &impl StateReadOnly) -> Option<Vec<PeerId>> {
+        let latest_height = state.height() as u64;
+        let pending = state
+            .world()
+            .activatable_validators(NonZeroU64::new(latest_height + 1)?)?;
+
+        let sumeragi = state.world().parameters().sumeragi();
+        let synced_height = NonZeroU64::new(latest_height)
+            .and_then(|height| sumeragi.epoch(height))
+            .map_or(latest_height, |epoch| epoch * sumeragi.epoch_length + 1);
+        let online_peers = self.network.online_peers(|peers| {
+            peers
+                .iter()
+                .map(|peer| peer.id().clone())
+                .collect::<BTreeSet<_>>()
+        });
+        let is_live = |peer: &PeerId| {
+            online_peers.contains(peer)
+                && self
+                    .synced_peers
+                    .latest_block(peer)
+                    .and_then(|hash| self.kura.get_block_height_by_hash(hash))
+                    .is_some_and(|height| height.get() as u64 >= synced_height)
+        };
+
+        let mut joining = pending
+            .peers()
+            .iter()
+            .filter(|peer| !state.world().peers().contains(peer));
+        if !joining.all(is_live) {
+            trace!(
+                peer_id=%self.peer,
+                "Peers joining the validator set aren't live yet, postponing its activation"
+            );
+            return None;
+        }
+
+        Some(pending.peers().clone())
+    }
+
+    /// Leader signs the block it proposes, so conflicting proposals prove its equivocation
+    fn detect_leader_equivocation(&mut self, block: &SignedBlock) {
+        let header = block.header();
+        if header.view_change_index as usize != self.topology.view_change_index() {
+            // Leader of other rounds isn't known
+            return;
+        }
+
+        let leader = self.topology.leader().clone();
+        let Some(signature) = block.signatures().next() else {
+            return;
+        };
+        if signature.index() != self.topology.leader_index() as u64
+            || signature
+                .payload()
+                .verify(leader.public_key(), &header)
+                .is_err()
+        {
+            return;
+        }
+
+        self.detect_equivocation(&leader, header, signature.payload().clone());
+    }
+
     fn receive_network_packet(
         &self,
         latest_block: HashOf<BlockHeader>,
@@ -199,7 +330,8 @@ impl Sumeragi {
                 // Block sync updates are exempt from early pruning.
                 BlockMessage::BlockSigned(_)
                 | BlockMessage::BlockCommitted(_)
-                | BlockMessage::BlockSyncUpdate(_) => {}
+                | BlockMessage::BlockSyncUpdate(_)
+                | BlockMessage::EquivocationDetected(_) => {}
             }
             return Ok(Some(block_msg));
         }
@@ -228,7 +360,7 @@ impl Sumeragi {
                 Ok(message) => {
                     let block = match message {
                         BlockMessage::BlockCreated(BlockCreated { block })
-                        | BlockMessage::BlockSyncUpdate(BlockSyncUpdate { block }) => block,
+                        | BlockMessage::BlockSyncUpdate(BlockSyncUpdate { block, .. }) => block,
                         msg => {
                             trace!(?msg, "Not handling the message, waiting for genesis...");
                             continue;
@@ -241,6 +373,7 @@ impl Sumeragi {
                         &self.topology,
                         &self.chain_id,
                         genesis_account,
+                        &self.time_source,
                         &mut state_block,
                     )
                     .unpack(|e| self.send_event(e))
@@ -309,6 +442,7 @@ impl Sumeragi {
             &self.topology,
             &self.chain_id,
             genesis_account,
+            &self.time_source,
             &mut state_block,
         )
         .unpack(|e| self.send_event(e))
@@ -349,19 +483,37 @@ impl Sumeragi {
 
     fn update_state<Strategy: ApplyBlockStrategy>(
         &mut self,
-        block: CommittedBlock,
+        mut block: CommittedBlock,
         mut state_block: StateBlock<'_>,
     ) {
         let prev_role = self.role();
 
+        if !block.as_ref().header().is_genesis() {
+            // Signatures of the committed block are verified with the current topology
+            let header = block.as_ref().header();
+            let signatures = block.as_ref().signatures().cloned().collect::<Vec<_>>();
+            for BlockSignature(index, signature) in signatures {
+                let signatory = usize::try_from(index)
+                    .ok()
+                    .and_then(|index| self.topology.as_ref().get(index))
+                    .cloned();
+                if let Some(signatory) = signatory {
+                    self.detect_equivocation(&signatory, header, signature);
+                }
+            }
+        }
+        self.equivocations.block_committed(block.as_ref());
+
         self.topology
             .block_committed(state_block.world.peers().clone());
 
         let state_events =
             state_block.apply_without_execution(&block, self.topology.as_ref().to_owned());
+        // Receipts of the block received from other peers are replaced with the locally produced ones
+        block.set_trigger_receipts(core::mem::take(&mut state_block.trigger_receipts));
 
         self.cache_transaction(&state_block);
-        self.connect_peers(&self.topology);
+        self.connect_peers(&self.topology, state_block.world.pending_validators());
 
         let block_hash = block.as_ref().hash();
         let block_height = block.as_ref().header().height();
@@ -389,7 +541,7 @@ impl Sumeragi {
         // so it should be done AFTER public facing state update
         state_events.into_iter().for_each(|e| self.send_event(e));
 
-        self.round_start_time = Instant::now();
+        self.round_start_time = self.now();
         self.was_commit = true;
     }
 
@@ -412,6 +564,7 @@ impl Sumeragi {
             topology,
             &self.chain_id,
             genesis_account,
+            &self.time_source,
             state,
             existing_voting_block,
             false,
@@ -451,9 +604,25 @@ impl Sumeragi {
         voting_signatures: &mut BTreeSet<BlockSignature>,
         #[cfg_attr(not(debug_assertions), allow(unused_variables))] is_genesis_peer: bool,
     ) {
+        if let BlockMessage::BlockCreated(BlockCreated { block }) = &message {
+            self.detect_leader_equivocation(block);
+        }
+
         #[allow(clippy::suspicious_operation_groupings)]
         match (message, self.role()) {
-            (BlockMessage::BlockSyncUpdate(BlockSyncUpdate { block }), _) => {
+            (BlockMessage::EquivocationDetected(EquivocationDetected { evidence }), _) => {
+                info!(
+                    peer_id=%self.peer,
+                    role=%self.role(),
+                    offender=%evidence.peer(),
+                    "Equivocation evidence received"
+                );
+
+                if self.topology.iter().any(|peer| peer == evidence.peer()) {
+                    self.equivocations.insert(*evidence);
+                }
+            }
+            (BlockMessage::BlockSyncUpdate(BlockSyncUpdate { block, peer }), _) => {
                 info!(
                     peer_id=%self.peer,
                     role=%self.role(),
@@ -467,6 +636,7 @@ impl Sumeragi {
                     block,
                     state,
                     genesis_account,
+                    &self.time_source,
                     &|e| self.send_event(e),
                     block_sync_type,
                     voting_block,
@@ -500,6 +670,9 @@ impl Sumeragi {
                             ?error,
                             "Block not valid."
                         );
+                        if let Some(peer) = peer.filter(|_| error.proves_invalid_block()) {
+                            self.network.penalize(peer, Misbehavior::InvalidBlock);
+                        }
                     }
                     Err((block, BlockSyncError::SoftForkBlockNotValid(error))) => {
                         error!(
@@ -509,6 +682,9 @@ impl Sumeragi {
                             ?error,
                             "Soft-fork block not valid."
                         );
+                        if let Some(peer) = peer.filter(|_| error.proves_invalid_block()) {
+                            self.network.penalize(peer, Misbehavior::InvalidBlock);
+                        }
                     }
                     Err((
                         block,
@@ -676,6 +852,8 @@ impl Sumeragi {
                             "Signatory is proxy tail"
                         ),
                         _ => {
+                            let signatory = signatory.clone();
+
                             if let Some(mut voted_block) = voting_block.take() {
                                 let actual_hash = voted_block.block.as_ref().hash();
 
@@ -688,8 +866,9 @@ impl Sumeragi {
                                         "Block hash mismatch"
                                     );
                                     *voting_block = Some(voted_block);
-                                } else if let Err(err) =
-                                    voted_block.block.add_signature(signature, &self.topology)
+                                } else if let Err(err) = voted_block
+                                    .block
+                                    .add_signature(signature.clone(), &self.topology)
                                 {
                                     error!(
                                         peer_id=%self.peer,
@@ -699,6 +878,11 @@ impl Sumeragi {
                                     );
                                     *voting_block = Some(voted_block);
                                 } else {
+                                    self.detect_equivocation(
+                                        &signatory,
+                                        voted_block.block.as_ref().header(),
+                                        signature.1,
+                                    );
                                     *voting_block =
                                         self.try_commit_block(voted_block, is_genesis_peer);
                                 }
@@ -726,7 +910,11 @@ impl Sumeragi {
             (BlockMessage::BlockCommitted(BlockCommitted { .. }), Role::Leader)
                 if self.topology.is_consensus_required().is_none() => {}
             (
-                BlockMessage::BlockCommitted(BlockCommitted { hash, signatures }),
+                BlockMessage::BlockCommitted(BlockCommitted {
+                    hash,
+                    signatures,
+                    aggregate_signature,
+                }),
                 Role::Leader | Role::ValidatingPeer | Role::ObservingPeer,
             ) => {
                 info!(
@@ -743,10 +931,10 @@ impl Sumeragi {
                             .block
                             // NOTE: The manipulation of the topology relies upon all peers seeing the same signature set.
                             // Therefore we must clear the signatures and accept what the proxy tail has giveth.
-                            .replace_signatures(signatures, &self.topology)
+                            .replace_signatures(signatures, aggregate_signature, &self.topology)
                             .unpack(|e| self.send_event(e))
                         {
-                            Ok(prev_signatures) => {
+                            Ok((prev_signatures, prev_aggregate_signature)) => {
                                 match voted_block
                                     .block
                                     .commit(&self.topology)
@@ -764,7 +952,11 @@ impl Sumeragi {
                                         );
 
                                         block
-                                            .replace_signatures(prev_signatures, &self.topology)
+                                            .replace_signatures(
+                                                prev_signatures,
+                                                prev_aggregate_signature,
+                                                &self.topology,
+                                            )
                                             .unpack(|e| self.send_event(e))
                                             .expect("INTERNAL BUG: Failed to replace signatures");
                                         voted_block.block = block;
@@ -822,6 +1014,15 @@ impl Sumeragi {
         let votes_count = voting_block.block.as_ref().signatures().len();
         if votes_count + 1 >= self.topology.min_votes_for_commit() {
             voting_block.block.sign(&self.key_pair, &self.topology);
+            if voting_block
+                .state_block
+                .world
+                .parameters()
+                .sumeragi
+                .aggregate_signatures
+            {
+                voting_block.block.aggregate_signatures(&self.topology);
+            }
 
             let committed_block = voting_block
                 .block
@@ -881,14 +1082,19 @@ impl Sumeragi {
         let tx_cache_full = self.transaction_cache.len() >= max_transactions.get();
         let view_change_in_progress = self.topology.view_change_index() > 0;
         let block_time = state.world.view().parameters.sumeragi.block_time();
-        let deadline_reached = self.round_start_time.elapsed() > block_time;
+        let deadline_reached = self.now().saturating_sub(self.round_start_time) > block_time;
 
         let tx_cache_non_empty = !self.transaction_cache.is_empty();
         let prev_block_is_empty = state
             .view()
             .latest_block()
             .map_or(true, |block| block.is_empty());
-        let block_expected = tx_cache_non_empty || !prev_block_is_empty;
+        let equivocations = self.pending_equivocations(&state.view());
+        let validator_set = self.validator_set_to_activate(&state.view());
+        let block_expected = tx_cache_non_empty
+            || !prev_block_is_empty
+            || !equivocations.is_empty()
+            || validator_set.is_some();
 
         if tx_cache_full || block_expected && (view_change_in_progress || deadline_reached) {
             let transactions = self
@@ -897,13 +1103,16 @@ impl Sumeragi {
                 .map(|tx| tx.deref().clone())
                 .collect::<Vec<_>>();
 
-            let unverified_block = BlockBuilder::new(transactions)
-                .chain(
-                    self.topology.view_change_index(),
-                    state.view().latest_block().as_deref(),
-                )
-                .sign(self.key_pair.private_key())
-                .unpack(|e| self.send_event(e));
+            let unverified_block =
+                BlockBuilder::new_with_time_source(transactions, self.time_source.clone())
+                    .with_equivocations(equivocations)
+                    .with_validator_set(validator_set)
+                    .chain(
+                        self.topology.view_change_index(),
+                        state.view().latest_block().as_deref(),
+                    )
+                    .sign(self.key_pair.private_key())
+                    .unpack(|e| self.send_event(e));
             info!(
                 peer_id=%self.peer,
                 block_hash=%unverified_block.header().hash(),
@@ -954,13 +1163,14 @@ pub enum ReceiveNetworkPacketError {
 #[allow(clippy::too_many_arguments)]
 fn reset_state(
     peer_id: &PeerId,
+    now: Duration,
     pipeline_time: Duration,
     view_change_index: usize,
     was_commit: &mut bool,
     topology: &mut Topology,
     voting_block: &mut Option<VotingBlock>,
     voting_signatures: &mut BTreeSet<BlockSignature>,
-    last_view_change_time: &mut Instant,
+    last_view_change_time: &mut Duration,
     view_change_time: &mut Duration,
 ) {
     let mut was_commit_or_view_change = *was_commit;
@@ -992,92 +1202,73 @@ fn reset_state(
     if was_commit_or_view_change {
         *voting_block = None;
         voting_signatures.clear();
-        *last_view_change_time = Instant::now();
+        *last_view_change_time = now;
         *view_change_time = pipeline_time;
 
         *was_commit = false;
     }
 }
 
-#[iroha_logger::log(name = "consensus", skip_all)]
-/// Execute the main loop of [`Sumeragi`]
-pub(crate) fn run(
-    genesis_network: GenesisWithPubKey,
-    mut sumeragi: Sumeragi,
-    shutdown_signal: &ShutdownSignal,
-    state: Arc<State>,
-) {
-    // Connect peers with initial topology
-    sumeragi.connect_peers(&sumeragi.topology);
-
-    let genesis_account = AccountId::new(
-        iroha_genesis::GENESIS_DOMAIN_ID.clone(),
-        genesis_network.public_key.clone(),
-    );
-
-    let span = span!(tracing::Level::TRACE, "genesis").entered();
-    let is_genesis_peer =
-        if state.view().height() == 0 || state.view().latest_block_hash().is_none() {
-            if let Some(genesis) = genesis_network.genesis {
-                sumeragi.init_commit_genesis(genesis, &genesis_account, &state);
-                true
-            } else {
-                if let Err(err) =
-                    sumeragi.init_listen_for_genesis(&genesis_account, &state, shutdown_signal)
-                {
-                    info!(?err, "Sumeragi Thread is being shut down.");
-                    return;
-                }
-                false
-            }
-        } else {
-            false
-        };
-    span.exit();
-
-    info!(
-        peer_id=%sumeragi.peer,
-        role=%sumeragi.role(),
-        "Sumeragi initialized",
-    );
-
-    let mut voting_block = None;
-    // Proxy tail collection of voting block signatures
-    let mut voting_signatures = BTreeSet::new();
-    let mut should_sleep = false;
-    let mut view_change_proof_chain = ProofChain::default();
-    // Duration after which a view change is suggested
-    let mut view_change_time = state.world.view().parameters().sumeragi.pipeline_time(
-        sumeragi.topology.view_change_index(),
-        sumeragi.topology.max_faults() + 1,
-    );
-    // Instant when the previous view change or round happened.
-    let mut last_view_change_time = Instant::now();
+/// State of the main loop which is carried over between its iterations
+pub(crate) struct LoopState<'state> {
+    voting_block: Option<VotingBlock<'state>>,
+    /// Proxy tail collection of voting block signatures
+    voting_signatures: BTreeSet<BlockSignature>,
+    /// Whether there were no messages to handle in the previous iteration
+    pub(crate) should_sleep: bool,
+    view_change_proof_chain: ProofChain,
+    /// Duration after which a view change is suggested
+    view_change_time: Duration,
+    /// Time when the previous view change or round happened.
+    last_view_change_time: Duration,
+}
 
-    sumeragi.was_commit = false;
-    sumeragi.round_start_time = Instant::now();
-    while !shutdown_signal.is_sent() {
-        if should_sleep {
-            let span = span!(Level::TRACE, "main_thread_sleep");
-            let _enter = span.enter();
-            std::thread::sleep(std::time::Duration::from_millis(5));
+impl Sumeragi {
+    /// Start the first round of consensus once the genesis is committed
+    pub(crate) fn loop_state<'state>(&mut self, state: &'state State) -> LoopState<'state> {
+        self.was_commit = false;
+        self.round_start_time = self.now();
+
+        LoopState {
+            voting_block: None,
+            voting_signatures: BTreeSet::new(),
+            should_sleep: false,
+            view_change_proof_chain: ProofChain::default(),
+            view_change_time: state.world.view().parameters().sumeragi.pipeline_time(
+                self.topology.view_change_index(),
+                self.topology.max_faults() + 1,
+            ),
+            last_view_change_time: self.now(),
         }
+    }
+
+    /// Execute a single iteration of the main loop
+    ///
+    /// # Errors
+    /// Fails if some message pump is disconnected
+    #[allow(clippy::too_many_lines)]
+    pub(crate) fn step<'state>(
+        &mut self,
+        state: &'state State,
+        loop_state: &mut LoopState<'state>,
+        genesis_account: &AccountId,
+        is_genesis_peer: bool,
+    ) -> Result<(), ReceiveNetworkPacketError> {
         let span_for_sumeragi_cycle = span!(Level::TRACE, "main_thread_cycle");
         let _enter_for_sumeragi_cycle = span_for_sumeragi_cycle.enter();
 
         let state_view = state.view();
-        sumeragi
-            .transaction_cache
+        self.transaction_cache
             // Checking if transactions are in the blockchain is costly
             .retain(|tx| {
-                let expired = sumeragi.queue.is_expired(tx);
+                let expired = self.queue.is_expired(tx);
                 if expired {
                     debug!(tx=%tx.as_ref().hash(), "Transaction expired")
                 }
                 !expired
             });
 
-        sumeragi.queue.get_transactions_for_block(
+        self.queue.get_transactions_for_block(
             &state_view,
             state
                 .world
@@ -1087,93 +1278,89 @@ pub(crate) fn run(
                 .max_transactions
                 .try_into()
                 .expect("INTERNAL BUG: transactions in block exceed usize::MAX"),
-            &mut sumeragi.transaction_cache,
+            &mut self.transaction_cache,
         );
 
-        let view_change_index = sumeragi.prune_view_change_proofs_and_calculate_current_index(
+        let view_change_index = self.prune_view_change_proofs_and_calculate_current_index(
             state_view
                 .latest_block_hash()
                 .expect("INTERNAL BUG: No latest block"),
-            &mut view_change_proof_chain,
+            &mut loop_state.view_change_proof_chain,
         );
 
         reset_state(
-            &sumeragi.peer.id,
+            &self.peer.id,
+            self.now(),
             state
                 .world
                 .view()
                 .parameters()
                 .sumeragi
-                .pipeline_time(view_change_index, sumeragi.topology.max_faults() + 1),
+                .pipeline_time(view_change_index, self.topology.max_faults() + 1),
             view_change_index,
-            &mut sumeragi.was_commit,
-            &mut sumeragi.topology,
-            &mut voting_block,
-            &mut voting_signatures,
-            &mut last_view_change_time,
-            &mut view_change_time,
+            &mut self.was_commit,
+            &mut self.topology,
+            &mut loop_state.voting_block,
+            &mut loop_state.voting_signatures,
+            &mut loop_state.last_view_change_time,
+            &mut loop_state.view_change_time,
         );
         #[cfg(feature = "telemetry")]
-        sumeragi
-            .view_changes_metric
-            .set(sumeragi.topology.view_change_index() as u64);
+        self.view_changes_metric
+            .set(self.topology.view_change_index() as u64);
 
         if let Some(message) = {
-            let (msg, sleep) = match sumeragi.receive_network_packet(
+            let (msg, sleep) = self.receive_network_packet(
                 state_view
                     .latest_block_hash()
                     .expect("INTERNAL BUG: No latest block"),
-                &mut view_change_proof_chain,
-            ) {
-                Ok(x) => x,
-                Err(ReceiveNetworkPacketError::ChannelDisconnected) => {
-                    if shutdown_signal.is_sent() {
-                        break;
-                    }
-                    panic!("INTERNAL BUG: Sumeragi message pumps are disconnected while there is no shutdown signal yet.")
-                }
-            };
-            should_sleep = sleep;
+                &mut loop_state.view_change_proof_chain,
+            )?;
+            loop_state.should_sleep = sleep;
             msg
         } {
-            sumeragi.handle_message(
+            self.handle_message(
                 message,
-                &state,
-                &mut voting_block,
+                state,
+                &mut loop_state.voting_block,
                 view_change_index,
-                &genesis_account,
-                &mut voting_signatures,
+                genesis_account,
+                &mut loop_state.voting_signatures,
                 is_genesis_peer,
             );
         }
 
         // State could be changed after handling message so it is necessary to reset state before handling message independent step
         let state_view = state.view();
-        let view_change_index = sumeragi.prune_view_change_proofs_and_calculate_current_index(
+        let view_change_index = self.prune_view_change_proofs_and_calculate_current_index(
             state_view
                 .latest_block_hash()
                 .expect("INTERNAL BUG: No latest block"),
-            &mut view_change_proof_chain,
+            &mut loop_state.view_change_proof_chain,
         );
 
         // We broadcast our view change suggestion after having processed the latest from others inside `receive_network_packet`
-        let tx_cache_non_empty = !sumeragi.transaction_cache.is_empty();
+        let tx_cache_non_empty = !self.transaction_cache.is_empty();
         let prev_block_is_empty = state_view
             .latest_block()
             .map_or(true, |block| block.is_empty());
-        let block_expected = tx_cache_non_empty || !prev_block_is_empty;
+        let equivocations_pending = !self.pending_equivocations(&state_view).is_empty();
+        let block_expected = tx_cache_non_empty || !prev_block_is_empty || equivocations_pending;
 
         let view_change_in_progress = view_change_index > 0;
-        if (block_expected || view_change_in_progress)
-            && last_view_change_time.elapsed() > view_change_time
+        // Replica is not part of the topology, so its view change suggestions would be rejected
+        if !self.replica
+            && (block_expected || view_change_in_progress)
+            && self.now().saturating_sub(loop_state.last_view_change_time)
+                > loop_state.view_change_time
         {
             if block_expected {
-                if let Some(VotingBlock { block, .. }) = voting_block.as_ref() {
+                if let Some(VotingBlock { block, .. }) = loop_state.voting_block.as_ref() {
                     // NOTE: Suspecting the tail node because it hasn't committed the block yet
 
                     warn!(
-                        peer_id=%sumeragi.peer,
-                        role=%sumeragi.role(),
+                        peer_id=%self.peer,
+                        role=%self.role(),
                         block=%block.as_ref().hash(),
                         "Block not committed in due time, requesting view change..."
                     );
@@ -1182,8 +1369,8 @@ pub(crate) fn run(
                     // If the current node has a transaction, leader should have as well
 
                     warn!(
-                        peer_id=%sumeragi.peer,
-                        role=%sumeragi.role(),
+                        peer_id=%self.peer,
+                        role=%self.role(),
                         "No block produced in due time, requesting view change..."
                     );
                 }
@@ -1192,10 +1379,11 @@ pub(crate) fn run(
                     .latest_block_hash()
                     .expect("INTERNAL BUG: No latest block");
                 let suspect_proof =
-                    ProofBuilder::new(latest_block, view_change_index).sign(&sumeragi.key_pair);
+                    ProofBuilder::new(latest_block, view_change_index).sign(&self.key_pair);
 
-                view_change_proof_chain
-                    .insert_proof(suspect_proof, &sumeragi.topology, latest_block)
+                loop_state
+                    .view_change_proof_chain
+                    .insert_proof(suspect_proof, &self.topology, latest_block)
                     .unwrap_or_else(|err| error!("{err}"));
             }
 
@@ -1205,56 +1393,122 @@ pub(crate) fn run(
                 view_change_index
                     .checked_sub(1)
                     .and_then(|view_change_index| {
-                        view_change_proof_chain.get_proof_for_view_change(view_change_index)
+                        loop_state
+                            .view_change_proof_chain
+                            .get_proof_for_view_change(view_change_index)
                     })
             {
                 let msg = ControlFlowMessage::new(latest_verified_proof);
-                sumeragi.broadcast_control_flow_packet(msg);
+                self.broadcast_control_flow_packet(msg);
             }
 
             // If exist broadcast proof for current view change index.
             // Proof might not exist for example when view_change_time is up,
             // but there is no transactions in the queue so there is nothing to complain about.
-            if let Some(proof_for_current_view_change_index) =
-                view_change_proof_chain.get_proof_for_view_change(view_change_index)
+            if let Some(proof_for_current_view_change_index) = loop_state
+                .view_change_proof_chain
+                .get_proof_for_view_change(view_change_index)
             {
                 let msg = ControlFlowMessage::new(proof_for_current_view_change_index);
-                sumeragi.broadcast_control_flow_packet(msg);
+                self.broadcast_control_flow_packet(msg);
             }
 
             // NOTE: View change must be periodically suggested until it is accepted.
             // Must be initialized to pipeline time but can increase by chosen amount
-            view_change_time += state
+            loop_state.view_change_time += state
                 .world
                 .view()
                 .parameters()
                 .sumeragi
-                .pipeline_time(view_change_index, sumeragi.topology.max_faults() + 1);
+                .pipeline_time(view_change_index, self.topology.max_faults() + 1);
         }
 
         reset_state(
-            &sumeragi.peer.id,
+            &self.peer.id,
+            self.now(),
             state
                 .world
                 .view()
                 .parameters()
                 .sumeragi
-                .pipeline_time(view_change_index, sumeragi.topology.max_faults() + 1),
+                .pipeline_time(view_change_index, self.topology.max_faults() + 1),
             view_change_index,
-            &mut sumeragi.was_commit,
-            &mut sumeragi.topology,
-            &mut voting_block,
-            &mut voting_signatures,
-            &mut last_view_change_time,
-            &mut view_change_time,
+            &mut self.was_commit,
+            &mut self.topology,
+            &mut loop_state.voting_block,
+            &mut loop_state.voting_signatures,
+            &mut loop_state.last_view_change_time,
+            &mut loop_state.view_change_time,
         );
         #[cfg(feature = "telemetry")]
-        sumeragi
-            .view_changes_metric
-            .set(sumeragi.topology.view_change_index() as u64);
+        self.view_changes_metric
+            .set(self.topology.view_change_index() as u64);
+
+        if self.role() == Role::Leader && loop_state.voting_block.is_none() {
+            self.try_create_block(state, &mut loop_state.voting_block);
+        }
+
+        Ok(())
+    }
+}
+
+#[iroha_logger::log(name = "consensus", skip_all)]
+/// Execute the main loop of [`Sumeragi`]
+pub(crate) fn run(
+    genesis_network: GenesisWithPubKey,
+    mut sumeragi: Sumeragi,
+    shutdown_signal: &ShutdownSignal,
+    state: Arc<State>,
+) {
+    // Connect peers with initial topology
+    sumeragi.connect_peers(&sumeragi.topology, state.view().world.pending_validators());
+
+    let genesis_account = AccountId::new(
+        iroha_genesis::GENESIS_DOMAIN_ID.clone(),
+        genesis_network.public_key.clone(),
+    );
+
+    let span = span!(tracing::Level::TRACE, "genesis").entered();
+    let is_genesis_peer =
+        if state.view().height() == 0 || state.view().latest_block_hash().is_none() {
+            if let Some(genesis) = genesis_network.genesis {
+                sumeragi.init_commit_genesis(genesis, &genesis_account, &state);
+                true
+            } else {
+                if let Err(err) =
+                    sumeragi.init_listen_for_genesis(&genesis_account, &state, shutdown_signal)
+                {
+                    info!(?err, "Sumeragi Thread is being shut down.");
+                    return;
+                }
+                false
+            }
+        } else {
+            false
+        };
+    span.exit();
+
+    info!(
+        peer_id=%sumeragi.peer,
+        role=%sumeragi.role(),
+        "Sumeragi initialized",
+    );
+
+    let mut loop_state = sumeragi.loop_state(&state);
+    while !shutdown_signal.is_sent() {
+        if loop_state.should_sleep {
+            let span = span!(Level::TRACE, "main_thread_sleep");
+            let _enter = span.enter();
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
 
-        if sumeragi.role() == Role::Leader && voting_block.is_none() {
-            sumeragi.try_create_block(&state, &mut voting_block);
+        if let Err(ReceiveNetworkPacketError::ChannelDisconnected) =
+            sumeragi.step(&state, &mut loop_state, &genesis_account, is_genesis_peer)
+        {
+            if shutdown_signal.is_sent() {
+                break;
+            }
+            panic!("INTERNAL BUG: Sumeragi message pumps are disconnected while there is no shutdown signal yet.")
         }
     }
 }
@@ -1336,17 +1590,20 @@ fn handle_block_sync<'state, F: Fn(PipelineEventBox)>(
         block,
         state,
         genesis_account,
+        &TimeSource::new_system(),
         handle_events,
         block_sync_type,
         &mut None,
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 fn handle_categorized_block_sync<'state, F: Fn(PipelineEventBox)>(
     chain_id: &ChainId,
     block: SignedBlock,
     state: &'state State,
     genesis_account: &AccountId,
+    time_source: &TimeSource,
     handle_events: &F,
     block_sync_type: Result<BlockSyncType, BlockSyncError>,
     voting_block: &mut Option<VotingBlock>,
@@ -1373,6 +1630,7 @@ fn handle_categorized_block_sync<'state, F: Fn(PipelineEventBox)>(
         &topology,
         chain_id,
         genesis_account,
+        time_source,
         state,
         voting_block,
         soft_fork,
@@ -1821,7 +2079,9 @@ mod tests {
             valid_block.as_ref().signatures().next().unwrap().1.clone(),
         );
         let mut block: SignedBlock = valid_block.into();
-        let _prev_signatures = block.replace_signatures(vec![dummy_signature]).unwrap();
+        let _prev_signatures = block
+            .replace_signatures(vec![dummy_signature], None)
+            .unwrap();
         let dummy_block = ValidBlock::new_dummy(&leader_private_key);
         let dummy_state_block = state.block(dummy_block.as_ref().header());
         let mut voting_block = Some(VotingBlock::new(dummy_block, dummy_state_block));
@@ -1832,6 +2092,7 @@ mod tests {
             block,
             &state,
             &genesis_public_key,
+            &TimeSource::new_system(),
             &|_| {},
             block_sync_type,
             &mut voting_block,
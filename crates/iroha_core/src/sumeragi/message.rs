@@ -1,6 +1,11 @@
 //! Contains message structures for p2p communication during consensus.
 use iroha_crypto::HashOf;
-use iroha_data_model::block::{BlockHeader, BlockSignature, SignedBlock};
+use iroha_data_model::{
+    block::{
+        AggregateBlockSignature, BlockHeader, BlockSignature, EquivocationEvidence, SignedBlock,
+    },
+    peer::PeerId,
+};
 use iroha_macro::*;
 use parity_scale_codec::{Decode, Encode};
 
@@ -19,6 +24,8 @@ pub enum BlockMessage {
     BlockCommitted(BlockCommitted),
     /// This message is sent by `BlockSync` when new block is received
     BlockSyncUpdate(BlockSyncUpdate),
+    /// This message is broadcast by a peer which caught another peer signing conflicting blocks.
+    EquivocationDetected(EquivocationDetected),
 }
 
 /// Specialization of `MessagePacket`
@@ -91,6 +98,8 @@ pub struct BlockCommitted {
     pub hash: HashOf<BlockHeader>,
     /// Set of signatures.
     pub signatures: Vec<BlockSignature>,
+    /// Aggregated signature of peers with BLS keys.
+    pub aggregate_signature: Option<AggregateBlockSignature>,
 }
 
 impl From<&CommittedBlock> for BlockCommitted {
@@ -98,6 +107,7 @@ impl From<&CommittedBlock> for BlockCommitted {
         Self {
             hash: block.as_ref().hash(),
             signatures: block.as_ref().signatures().cloned().collect(),
+            aggregate_signature: block.as_ref().aggregate_signature().cloned(),
         }
     }
 }
@@ -107,6 +117,19 @@ impl From<&CommittedBlock> for BlockCommitted {
 pub struct BlockSyncUpdate {
     /// The corresponding block.
     pub block: SignedBlock,
+    /// Peer which shared the block, penalized if the block turns out to be invalid.
+    /// It's never sent over the network since the receiver knows the sender of the message.
+    #[codec(skip)]
+    pub peer: Option<PeerId>,
+}
+
+impl BlockSyncUpdate {
+    /// Attribute the update to the peer which shared the block
+    #[must_use]
+    pub fn with_peer(mut self, peer: PeerId) -> Self {
+        self.peer = Some(peer);
+        self
+    }
 }
 
 impl From<&SignedBlock> for BlockSyncUpdate {
@@ -114,6 +137,22 @@ impl From<&SignedBlock> for BlockSyncUpdate {
         // TODO: Redundant clone
         Self {
             block: block.clone(),
+            peer: None,
+        }
+    }
+}
+
+/// `EquivocationDetected` message structure.
+#[derive(Debug, Clone, Decode, Encode)]
+pub struct EquivocationDetected {
+    /// Evidence of the equivocation. It is verified when the message is decoded.
+    pub evidence: Box<EquivocationEvidence>,
+}
+
+impl From<&EquivocationEvidence> for EquivocationDetected {
+    fn from(evidence: &EquivocationEvidence) -> Self {
+        Self {
+            evidence: Box::new(evidence.clone()),
         }
     }
 }
@@ -130,6 +169,8 @@ mod candidate {
         pub hash: HashOf<BlockHeader>,
         /// Set of signatures.
         pub signatures: Vec<BlockSignature>,
+        /// Aggregated signature of peers with BLS keys.
+        pub aggregate_signature: Option<AggregateBlockSignature>,
     }
 
     impl BlockCommittedCandidate {
@@ -139,17 +180,23 @@ mod candidate {
             Ok(BlockCommitted {
                 hash: self.hash,
                 signatures: self.signatures,
+                aggregate_signature: self.aggregate_signature,
             })
         }
 
         fn validate_signatures(&self) -> Result<(), &'static str> {
-            if self.signatures.is_empty() {
+            if self.signatures.is_empty() && self.aggregate_signature.is_none() {
                 return Err("No signatures in block");
             }
 
             self.signatures
                 .iter()
-                .map(|signature| &signature.0)
+                .map(|signature| signature.0)
+                .chain(
+                    self.aggregate_signature
+                        .iter()
+                        .flat_map(AggregateBlockSignature::signatories),
+                )
                 .try_fold(IndexSet::new(), |mut acc, elem| {
                     if !acc.insert(elem) {
                         return Err("Duplicate signature");
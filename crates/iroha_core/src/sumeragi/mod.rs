@@ -10,10 +10,16 @@ use std::{
 
 use eyre::Result;
 use iroha_config::parameters::actual::{Common as CommonConfig, Sumeragi as SumeragiConfig};
-use iroha_data_model::{account::AccountId, block::SignedBlock, prelude::*};
+use iroha_crypto::HashOf;
+use iroha_data_model::{
+    account::AccountId,
+    block::{BlockHeader, SignedBlock},
+    prelude::*,
+};
 use iroha_futures::supervisor::{spawn_os_thread_as_future, Child, OnShutdown, ShutdownSignal};
 use iroha_genesis::GenesisBlock;
 use iroha_logger::prelude::*;
+use iroha_primitives::time::TimeSource;
 use network_topology::{Role, Topology};
 
 use crate::{
@@ -22,12 +28,19 @@ use crate::{
     state::{State, StateBlock},
 };
 
+pub mod equivocation;
 pub mod main_loop;
 pub mod message;
 pub mod network_topology;
+#[cfg(test)]
+mod simulation;
+pub mod validator_set;
 pub mod view_change;
 
-use self::{message::*, view_change::ProofChain};
+use self::{
+    equivocation::EquivocationDetector, message::*, validator_set::SyncedPeers,
+    view_change::ProofChain,
+};
 use crate::{
     kura::Kura, peers_gossiper::PeersGossiperHandle, prelude::*, queue::Queue, EventsSender,
     IrohaNetwork, NetworkMessage,
@@ -43,6 +56,7 @@ pub struct SumeragiHandle {
     // Should be dropped after `_thread_handle` to prevent sumeargi thread from panicking
     control_message_sender: mpsc::SyncSender<ControlFlowMessage>,
     message_sender: mpsc::SyncSender<BlockMessage>,
+    synced_peers: SyncedPeers,
 }
 
 impl SumeragiHandle {
@@ -62,6 +76,11 @@ impl SumeragiHandle {
         }
     }
 
+    /// Record the latest block which the `peer` reported to have during the block synchronization.
+    pub fn peer_synced(&self, peer: PeerId, latest_block: HashOf<BlockHeader>) {
+        self.synced_peers.report(peer, latest_block);
+    }
+
     /// Deposit a sumeragi network message.
     pub fn incoming_block_message(&self, msg: impl Into<BlockMessage>) {
         let msg = msg.into();
@@ -69,10 +88,13 @@ impl SumeragiHandle {
             BlockMessage::BlockCommitted(BlockCommitted { hash, .. }) => ("BlockCommitted", *hash),
             BlockMessage::BlockCreated(BlockCreated { block }) => ("BlockCreated", block.hash()),
             BlockMessage::BlockSigned(BlockSigned { hash, .. }) => ("BlockSigned", *hash),
-            BlockMessage::BlockSyncUpdate(BlockSyncUpdate { block }) => {
+            BlockMessage::BlockSyncUpdate(BlockSyncUpdate { block, .. }) => {
                 trace!(ty="BlockSyncUpdate", block=%block.hash(), "Incoming message");
                 ("BlockSyncUpdate", block.hash())
             }
+            BlockMessage::EquivocationDetected(EquivocationDetected { evidence }) => {
+                ("EquivocationDetected", evidence.first().header.hash())
+            }
         };
         trace!(ty, %block, "Incoming message");
 
@@ -105,6 +127,7 @@ impl SumeragiHandle {
             topology,
             chain_id,
             genesis_account,
+            &TimeSource::new_system(),
             state_block,
         )
         .unpack(|e| {
@@ -140,9 +163,13 @@ impl SumeragiStartArgs {
     #[allow(clippy::too_many_lines)]
     pub fn start(self, shutdown_signal: ShutdownSignal) -> (SumeragiHandle, Child) {
         let Self {
-            config: SumeragiConfig {
-                debug_force_soft_fork,
-            },
+            config:
+                SumeragiConfig {
+                    replica,
+                    // Transactions are executed by the state, see `State::with_execution_workers`
+                    execution_workers: _,
+                    debug_force_soft_fork,
+                },
             common_config,
             events_sender,
             state,
@@ -162,6 +189,7 @@ impl SumeragiStartArgs {
 
         let (control_message_sender, control_message_receiver) = mpsc::sync_channel(100);
         let (message_sender, message_receiver) = mpsc::sync_channel(100);
+        let synced_peers = SyncedPeers::default();
 
         let blocks_iter;
         let mut topology;
@@ -177,6 +205,15 @@ impl SumeragiStartArgs {
             });
 
             topology = match state_view.height() {
+                // Replica is never part of the topology
+                0 if replica => Topology::new(
+                    common_config
+                        .trusted_peers
+                        .value()
+                        .others
+                        .iter()
+                        .map(|peer| peer.id.clone()),
+                ),
                 0 => Topology::new(
                     common_config
                         .trusted_peers
@@ -222,12 +259,16 @@ impl SumeragiStartArgs {
             control_message_receiver,
             message_receiver,
             debug_force_soft_fork,
+            replica,
+            time_source: TimeSource::new_system(),
             topology,
             transaction_cache: Vec::new(),
+            equivocations: EquivocationDetector::default(),
+            synced_peers: synced_peers.clone(),
             #[cfg(feature = "telemetry")]
             view_changes_metric: view_changes,
             was_commit: false,
-            round_start_time: Instant::now(),
+            round_start_time: Duration::ZERO,
         };
 
         let child = Child::new(
@@ -247,6 +288,7 @@ impl SumeragiStartArgs {
                 dropped_messages_metric: dropped_messages,
                 control_message_sender,
                 message_sender,
+                synced_peers,
             },
             child,
         )
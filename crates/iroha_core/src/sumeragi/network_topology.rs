@@ -131,6 +131,30 @@ impl Topology {
     where
         <I as IntoIterator>::IntoIter: 'a,
     {
+        let filtered = self.indices_by_roles(roles);
+
+        signatures.into_iter().filter(move |signature| {
+            filtered.contains(
+                &(usize::try_from(signature.0).expect("Peer index should fit into usize")),
+            )
+        })
+    }
+
+    /// Filter topology indices of signatories by roles in the topology.
+    pub fn filter_signatories_by_roles<I: IntoIterator<Item = u64>>(
+        &self,
+        roles: &[Role],
+        signatories: I,
+    ) -> impl Iterator<Item = u64> {
+        let filtered = self.indices_by_roles(roles);
+
+        signatories.into_iter().filter(move |signatory| {
+            filtered
+                .contains(&(usize::try_from(*signatory).expect("Peer index should fit into usize")))
+        })
+    }
+
+    fn indices_by_roles(&self, roles: &[Role]) -> IndexSet<usize> {
         let mut filtered = IndexSet::new();
 
         for role in roles {
@@ -151,11 +175,7 @@ impl Topology {
             };
         }
 
-        signatures.into_iter().filter(move |signature| {
-            filtered.contains(
-                &(usize::try_from(signature.0).expect("Peer index should fit into usize")),
-            )
-        })
+        filtered
     }
 
     /// What role does this peer have in the topology.
@@ -365,6 +385,24 @@ mod tests {
         assert!(observing_peers_signatures.iter().map(|s| s.0).eq(5..7));
     }
 
+    #[test]
+    fn filter_signatories_by_role() {
+        let topology = test_topology(7);
+
+        let voting_signatories = topology
+            .filter_signatories_by_roles(
+                &[Role::Leader, Role::ValidatingPeer, Role::ProxyTail],
+                0..7,
+            )
+            .collect::<Vec<_>>();
+        assert_eq!(voting_signatories, vec![0, 1, 2, 3, 4]);
+
+        let observing_signatories = topology
+            .filter_signatories_by_roles(&[Role::ObservingPeer], [6, 2, 5])
+            .collect::<Vec<_>>();
+        assert_eq!(observing_signatories, vec![6, 5]);
+    }
+
     #[test]
     fn filter_by_role_1() {
         let key_pairs = core::iter::repeat_with(KeyPair::random)
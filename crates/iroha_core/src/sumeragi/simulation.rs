@@ -0,0 +1,669 @@
+//! Deterministic simulation of a network of peers running [`Sumeragi`].
+//!
+//! Peers are stepped in turn on a single thread. Messages between them are delivered by
+//! a simulated network with scriptable delays, losses and partitions, and timeouts are
+//! measured with a virtual clock. Therefore, a scenario is reproducible from its seed.
+
+use std::{
+    any::Any,
+    collections::BTreeMap,
+    num::NonZeroUsize,
+    sync::{mpsc, Arc},
+    time::Duration,
+};
+
+use iroha_config::parameters::actual::Queue as QueueConfig;
+use iroha_crypto::HashOf;
+use iroha_data_model::{
+    block::BlockHeader, isi::InstructionBox, peer::PeerId, transaction::TransactionBuilder,
+};
+use iroha_genesis::GENESIS_DOMAIN_ID;
+use iroha_p2p::network::SimulatedNetwork;
+use iroha_primitives::{
+    addr::socket_addr,
+    time::{MockTimeHandle, TimeSource},
+};
+use rand::{rngs::StdRng, Rng as _, SeedableRng as _};
+
+use super::{
+    main_loop::{LoopState, Sumeragi},
+    *,
+};
+use crate::{block::BlockBuilder, query::store::LiveQueryStore, smartcontracts::Registrable as _};
+
+/// Virtual time at which the simulation starts, so that blocks don't depend on the real time
+const START: Duration = Duration::from_secs(1_700_000_000);
+/// Virtual time which passes between the iterations of the simulation
+const TICK: Duration = Duration::from_millis(10);
+/// Interval at which peers request missing blocks from each other
+const BLOCK_SYNC_INTERVAL: Duration = Duration::from_secs(1);
+/// Upper bound on the iterations of the main loop a peer makes in a single tick
+const MAX_STEPS_PER_TICK: usize = 100;
+
+/// Peers taking part in the simulation with their blockchains.
+///
+/// Every peer starts with the same genesis block already committed.
+struct SimulatedPeers {
+    chain_id: ChainId,
+    genesis_account: AccountId,
+    /// Account which submits transactions
+    account_id: AccountId,
+    key_pair: KeyPair,
+    peers: Vec<SimulatedPeer>,
+}
+
+struct SimulatedPeer {
+    key_pair: KeyPair,
+    kura: Arc<Kura>,
+    state: State,
+}
+
+impl SimulatedPeers {
+    /// Create `n_peers` peers. Their keys are derived from their indices
+    fn new(n_peers: usize) -> Self {
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+        let key_pair = KeyPair::from_seed(b"alice".to_vec(), Algorithm::Ed25519);
+        let account_id = AccountId::new(
+            "wonderland".parse().expect("Valid"),
+            key_pair.public_key().clone(),
+        );
+        let genesis_account =
+            AccountId::new(GENESIS_DOMAIN_ID.clone(), key_pair.public_key().clone());
+
+        let peers = (0..n_peers)
+            .map(|index| {
+                let domain = Domain::new(account_id.domain().clone()).build(&account_id);
+                let account = Account::new(account_id.clone()).build(&account_id);
+                let kura = Kura::blank_kura_for_testing();
+                let state = State::new(
+                    World::with([domain], [account], []),
+                    Arc::clone(&kura),
+                    LiveQueryStore::start_test(),
+                );
+
+                SimulatedPeer {
+                    key_pair: KeyPair::from_seed(
+                        format!("peer{index}").into_bytes(),
+                        Algorithm::Ed25519,
+                    ),
+                    kura,
+                    state,
+                }
+            })
+            .collect();
+
+        let peers = Self {
+            chain_id,
+            genesis_account,
+            account_id,
+            key_pair,
+            peers,
+        };
+        peers.commit_genesis();
+        peers
+    }
+
+    fn topology(&self) -> Topology {
+        Topology::new(
+            self.peers
+                .iter()
+                .map(|peer| PeerId::new(peer.key_pair.public_key().clone())),
+        )
+    }
+
+    /// NOTE: imitate peer registration in the genesis block
+    fn commit_genesis(&self) {
+        let topology = self.topology();
+        let register_peers = topology
+            .iter()
+            .cloned()
+            .map(Register::peer)
+            .map(InstructionBox::from);
+        let (_time, time_source) = TimeSource::new_mock(START);
+        let genesis = BlockBuilder::new_with_time_source(
+            vec![self.transaction(register_peers, &time_source)],
+            time_source,
+        )
+        .chain(0, None)
+        .sign(self.key_pair.private_key())
+        .unpack(|_| {});
+
+        for peer in &self.peers {
+            let mut state_block = peer.state.block(genesis.header());
+            let genesis = genesis
+                .clone()
+                .categorize(&mut state_block)
+                .unpack(|_| {})
+                .commit(&topology)
+                .unpack(|_| {})
+                .expect("Genesis is valid");
+
+            let _events =
+                state_block.apply_without_execution(&genesis, topology.as_ref().to_owned());
+            state_block.commit();
+            peer.kura.store_block(genesis);
+        }
+    }
+
+    fn transaction(
+        &self,
+        instructions: impl IntoIterator<Item = InstructionBox>,
+        time_source: &TimeSource,
+    ) -> AcceptedTransaction {
+        let (max_clock_drift, tx_limits) = {
+            let state_view = self.peers[0].state.world.view();
+            let params = state_view.parameters();
+            (params.sumeragi().max_clock_drift(), params.transaction)
+        };
+
+        let tx = TransactionBuilder::new_with_time_source(
+            self.chain_id.clone(),
+            self.account_id.clone(),
+            time_source,
+        )
+        .with_instructions(instructions)
+        .sign(self.key_pair.private_key());
+        AcceptedTransaction::accept(tx, &self.chain_id, max_clock_drift, tx_limits).expect("Valid")
+    }
+
+    /// Start the simulation whose randomness is derived from the `seed`
+    fn simulation(&self, seed: u64) -> Simulation<'_> {
+        let (time, time_source) = TimeSource::new_mock(START);
+
+        let topology = self.topology();
+        let nodes = self
+            .peers
+            .iter()
+            .enumerate()
+            .map(|(index, peer)| self.node(index, peer, &topology, &time_source))
+            .collect::<Vec<_>>();
+        let n_peers = nodes.len();
+
+        Simulation {
+            peers: self,
+            nodes,
+            time,
+            elapsed: Duration::ZERO,
+            rng: StdRng::seed_from_u64(seed),
+            conditions: NetworkConditions::default(),
+            partitions: vec![0; n_peers],
+            in_flight: BTreeMap::new(),
+            sent: 0,
+            submitted: 0,
+            next_block_sync: BLOCK_SYNC_INTERVAL,
+            final_blocks: Vec::new(),
+            heights: vec![1; n_peers],
+            trace: Vec::new(),
+        }
+    }
+
+    fn node<'peers>(
+        &self,
+        index: usize,
+        peer: &'peers SimulatedPeer,
+        topology: &Topology,
+        time_source: &TimeSource,
+    ) -> SimulatedNode<'peers> {
+        let (network, simulated_network) = IrohaNetwork::simulated();
+        let (peers_gossiper, peers_gossiper_receivers) = PeersGossiperHandle::test();
+        let (control_message_sender, control_message_receiver) = mpsc::sync_channel(100);
+        let (message_sender, message_receiver) = mpsc::sync_channel(100);
+        let port = 1337 + u16::try_from(index).expect("Too many peers");
+        #[cfg(feature = "telemetry")]
+        let metrics = iroha_telemetry::metrics::Metrics::default();
+
+        let mut sumeragi = Sumeragi {
+            chain_id: self.chain_id.clone(),
+            key_pair: peer.key_pair.clone(),
+            queue: Arc::new(Queue::test(QueueConfig::default(), time_source)),
+            peer: Peer::new(
+                socket_addr!(127.0.0.1:port),
+                peer.key_pair.public_key().clone(),
+            ),
+            events_sender: tokio::sync::broadcast::channel(1).0,
+            kura: Arc::clone(&peer.kura),
+            network,
+            peers_gossiper,
+            control_message_receiver,
+            message_receiver,
+            debug_force_soft_fork: false,
+            replica: false,
+            time_source: time_source.clone(),
+            topology: topology.clone(),
+            transaction_cache: Vec::new(),
+            equivocations: EquivocationDetector::default(),
+            synced_peers: SyncedPeers::default(),
+            #[cfg(feature = "telemetry")]
+            view_changes_metric: metrics.view_changes.clone(),
+            was_commit: false,
+            round_start_time: Duration::ZERO,
+        };
+        let loop_state = sumeragi.loop_state(&peer.state);
+
+        SimulatedNode {
+            sumeragi,
+            loop_state,
+            state: &peer.state,
+            network: simulated_network,
+            control_message_sender,
+            message_sender,
+            _peers_gossiper: Box::new(peers_gossiper_receivers),
+        }
+    }
+}
+
+/// Peer running [`Sumeragi`] on top of the simulated network
+struct SimulatedNode<'peers> {
+    sumeragi: Sumeragi,
+    loop_state: LoopState<'peers>,
+    state: &'peers State,
+    network: SimulatedNetwork<NetworkMessage>,
+    control_message_sender: mpsc::SyncSender<ControlFlowMessage>,
+    message_sender: mpsc::SyncSender<BlockMessage>,
+    /// Keeps the channels of the peers gossiper handle open
+    _peers_gossiper: Box<dyn Any>,
+}
+
+impl SimulatedNode<'_> {
+    fn height(&self) -> usize {
+        self.state.view().height()
+    }
+
+    fn block(&self, height: usize) -> Option<Arc<SignedBlock>> {
+        NonZeroUsize::new(height).and_then(|height| self.sumeragi.kura.get_block(height))
+    }
+
+    /// Messages are dropped if the peer doesn't keep up with them as in the real network
+    fn deliver(&self, message: Message) {
+        match message {
+            Message::Block(message) => {
+                let _ = self.message_sender.try_send(message);
+            }
+            Message::ControlFlow(message) => {
+                let _ = self.control_message_sender.try_send(message);
+            }
+        }
+    }
+
+    /// Run the main loop until there are no messages left to handle
+    fn step(&mut self, genesis_account: &AccountId) {
+        for _ in 0..MAX_STEPS_PER_TICK {
+            let result =
+                self.sumeragi
+                    .step(self.state, &mut self.loop_state, genesis_account, false);
+            assert!(
+                result.is_ok(),
+                "Message pumps are kept open by the simulation"
+            );
+
+            if self.loop_state.should_sleep {
+                break;
+            }
+        }
+    }
+}
+
+/// Consensus message travelling over the simulated network
+#[derive(Clone)]
+enum Message {
+    Block(BlockMessage),
+    ControlFlow(ControlFlowMessage),
+}
+
+/// Conditions of the simulated network
+#[derive(Debug, Clone, Copy)]
+struct NetworkConditions {
+    /// Minimal delay of a message
+    min_delay: Duration,
+    /// Maximal delay of a message. Messages sent with different delays are reordered
+    max_delay: Duration,
+    /// Probability of a message being lost
+    drop_probability: f64,
+}
+
+impl Default for NetworkConditions {
+    fn default() -> Self {
+        Self {
+            min_delay: TICK,
+            max_delay: TICK,
+            drop_probability: 0.0,
+        }
+    }
+}
+
+/// Simulation of the network of [`SimulatedPeers`] reproducible from its seed
+struct Simulation<'peers> {
+    peers: &'peers SimulatedPeers,
+    nodes: Vec<SimulatedNode<'peers>>,
+    time: MockTimeHandle,
+    /// Virtual time elapsed since the start of the simulation
+    elapsed: Duration,
+    rng: StdRng,
+    conditions: NetworkConditions,
+    /// Partition of each peer. Peers in different partitions can't communicate
+    partitions: Vec<usize>,
+    /// Messages in flight by the time of delivery and the order of sending
+    in_flight: BTreeMap<(Duration, u64), (usize, usize, Message)>,
+    /// Number of messages sent so far
+    sent: u64,
+    /// Number of transactions submitted so far
+    submitted: usize,
+    next_block_sync: Duration,
+    /// Blocks on top of which some peer has committed another block
+    final_blocks: Vec<HashOf<BlockHeader>>,
+    /// Height of the blockchain of each peer
+    heights: Vec<usize>,
+    /// Virtual time at which a peer's blockchain changed its height
+    trace: Vec<(Duration, usize, usize)>,
+}
+
+impl Simulation<'_> {
+    /// Put each group of peers into a separate partition
+    fn partition(&mut self, groups: &[&[usize]]) {
+        for (partition, peers) in groups.iter().enumerate() {
+            for &peer in *peers {
+                self.partitions[peer] = partition;
+            }
+        }
+    }
+
+    /// Let every peer communicate with every other peer again
+    fn heal(&mut self) {
+        self.partitions.fill(0);
+    }
+
+    fn connected(&self, peer: usize, other: usize) -> bool {
+        self.partitions[peer] == self.partitions[other]
+    }
+
+    /// Submit a transaction to the queue of every peer
+    fn submit(&mut self) -> HashOf<SignedTransaction> {
+        let domain_id = format!("domain{}", self.submitted).parse().expect("Valid");
+        let tx = self.peers.transaction(
+            [Register::domain(Domain::new(domain_id)).into()],
+            &self.time.source(),
+        );
+        self.submitted += 1;
+
+        for node in &self.nodes {
+            node.sumeragi
+                .queue
+                .push(tx.clone(), node.state.view())
+                .expect("Transaction is accepted");
+        }
+        tx.as_ref().hash()
+    }
+
+    fn send(&mut self, from: usize, to: usize, message: Message) {
+        if self.rng.gen_bool(self.conditions.drop_probability) {
+            return;
+        }
+
+        let delay = self
+            .rng
+            .gen_range(self.conditions.min_delay..=self.conditions.max_delay);
+        self.in_flight
+            .insert((self.elapsed + delay, self.sent), (from, to, message));
+        self.sent += 1;
+    }
+
+    /// Schedule delivery of the messages the peer sent in the last step
+    fn collect_sent_messages(&mut self, from: usize) {
+        while let Some((peer_id, data)) = self.nodes[from].network.try_recv() {
+            let to = peer_id.map(|peer_id| {
+                self.nodes
+                    .iter()
+                    .position(|node| node.sumeragi.peer.id == peer_id)
+            });
+            let message = match data {
+                NetworkMessage::SumeragiBlock(message) => Message::Block(*message),
+                NetworkMessage::SumeragiControlFlow(message) => Message::ControlFlow(*message),
+                _ => continue,
+            };
+
+            if let Some(to) = to {
+                // Messages to unknown peers are lost
+                if let Some(to) = to {
+                    self.send(from, to, message);
+                }
+            } else {
+                for to in (0..self.nodes.len()).filter(|&to| to != from) {
+                    self.send(from, to, message.clone());
+                }
+            }
+        }
+    }
+
+    /// Deliver the messages which are due unless their peers were partitioned in the meantime
+    fn deliver_messages(&mut self) {
+        while let Some(entry) = self.in_flight.first_entry() {
+            if entry.key().0 > self.elapsed {
+                break;
+            }
+
+            let (from, to, message) = entry.remove();
+            if self.connected(from, to) {
+                self.nodes[to].deliver(message);
+            }
+        }
+    }
+
+    /// Imitate block synchronization: every peer receives the block it is missing
+    /// from the first peer it can communicate with
+    fn sync_blocks(&mut self) {
+        for to in 0..self.nodes.len() {
+            let update = (0..self.nodes.len())
+                .filter(|&from| from != to && self.connected(from, to))
+                .find_map(|from| Some((from, self.block_sync_update(from, to)?)));
+
+            if let Some((from, block)) = update {
+                let message = BlockMessage::BlockSyncUpdate(BlockSyncUpdate::from(&*block));
+                self.send(from, to, Message::Block(message));
+            }
+        }
+    }
+
+    fn block_sync_update(&self, from: usize, to: usize) -> Option<Arc<SignedBlock>> {
+        let height = self.nodes[to].height();
+        if let Some(block) = self.nodes[from].block(height + 1) {
+            return Some(block);
+        }
+
+        // Top block could have been replaced by the block with a higher view change index
+        let latest_block = self.nodes[to].block(height)?;
+        let block = self.nodes[from].block(height)?;
+        (block.header().view_change_index > latest_block.header().view_change_index)
+            .then_some(block)
+    }
+
+    fn record_heights(&mut self) {
+        for (peer, node) in self.nodes.iter().enumerate() {
+            let height = node.height();
+
+            if self.heights[peer] != height {
+                self.heights[peer] = height;
+                self.trace.push((self.elapsed, peer, height));
+            }
+        }
+    }
+
+    /// Check that no two peers have committed different blocks at the same height.
+    ///
+    /// Top block can be replaced with a block of a higher view change index, so only
+    /// the blocks which some peer has committed another block on top of are compared.
+    /// Such blocks are final and must never change.
+    fn check_final_blocks(&mut self) {
+        for (peer, node) in self.nodes.iter().enumerate() {
+            for height in 1..node.height() {
+                let hash = node.block(height).expect("Block is committed").hash();
+
+                if let Some(final_block) = self.final_blocks.get(height - 1) {
+                    assert_eq!(
+                        *final_block, hash,
+                        "Peer {peer} committed a different block at height {height}"
+                    );
+                } else {
+                    self.final_blocks.push(hash);
+                }
+            }
+        }
+    }
+
+    /// Advance the virtual time by a single tick and let every peer handle the messages
+    /// delivered by then
+    fn tick(&mut self) {
+        self.time.advance(TICK);
+        self.elapsed += TICK;
+
+        self.deliver_messages();
+        if self.elapsed >= self.next_block_sync {
+            self.sync_blocks();
+            self.next_block_sync = self.elapsed + BLOCK_SYNC_INTERVAL;
+        }
+        for peer in 0..self.nodes.len() {
+            self.nodes[peer].step(&self.peers.genesis_account);
+            self.collect_sent_messages(peer);
+        }
+
+        self.record_heights();
+        self.check_final_blocks();
+    }
+
+    fn run_for(&mut self, duration: Duration) {
+        let until = self.elapsed + duration;
+        while self.elapsed < until {
+            self.tick();
+        }
+    }
+
+    /// Run until the `condition` holds.
+    ///
+    /// Returns `false` if it didn't hold within the `timeout`
+    fn run_until(&mut self, timeout: Duration, condition: impl Fn(&Self) -> bool) -> bool {
+        let until = self.elapsed + timeout;
+        while !condition(self) {
+            if self.elapsed >= until {
+                return false;
+            }
+            self.tick();
+        }
+
+        true
+    }
+
+    /// Check liveness: a transaction submitted now must be committed by every peer
+    fn assert_liveness(&mut self, timeout: Duration) {
+        let tx = self.submit();
+
+        let committed = self.run_until(timeout, |simulation| {
+            simulation
+                .nodes
+                .iter()
+                .all(|node| node.state.view().has_transaction(tx))
+        });
+        assert!(
+            committed,
+            "Transaction wasn't committed by every peer in {timeout:?}, heights: {:?}",
+            self.heights
+        );
+    }
+}
+
+mod tests {
+    use tokio::test;
+
+    use super::*;
+
+    #[test]
+    async fn honest_peers_commit_transactions() {
+        let peers = SimulatedPeers::new(4);
+        let mut simulation = peers.simulation(0);
+
+        for _ in 0..3 {
+            simulation.assert_liveness(Duration::from_secs(10));
+        }
+        assert!(simulation.final_blocks.len() >= 3);
+    }
+
+    #[test]
+    async fn consensus_tolerates_delays_reordering_and_losses() {
+        let peers = SimulatedPeers::new(4);
+        let mut simulation = peers.simulation(1);
+        simulation.conditions = NetworkConditions {
+            min_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(300),
+            drop_probability: 0.02,
+        };
+
+        for _ in 0..3 {
+            simulation.assert_liveness(Duration::from_secs(60));
+        }
+    }
+
+    #[test]
+    async fn isolated_peer_catches_up_after_healing() {
+        let peers = SimulatedPeers::new(4);
+        let mut simulation = peers.simulation(2);
+        simulation.assert_liveness(Duration::from_secs(10));
+
+        // Remaining peers are enough to commit blocks
+        simulation.partition(&[&[0, 1, 2], &[3]]);
+        let tx = simulation.submit();
+        let committed = simulation.run_until(Duration::from_secs(60), |simulation| {
+            simulation.nodes[..3]
+                .iter()
+                .all(|node| node.state.view().has_transaction(tx))
+        });
+        assert!(committed, "Majority didn't commit the transaction");
+        assert!(!simulation.nodes[3].state.view().has_transaction(tx));
+
+        simulation.heal();
+        simulation.assert_liveness(Duration::from_secs(60));
+    }
+
+    #[test]
+    async fn split_network_recovers_after_healing() {
+        let peers = SimulatedPeers::new(4);
+        let mut simulation = peers.simulation(3);
+        simulation.assert_liveness(Duration::from_secs(10));
+        // Let the blocks in progress be committed
+        simulation.run_for(Duration::from_secs(10));
+
+        // Neither half has enough votes to commit a block
+        simulation.partition(&[&[0, 1], &[2, 3]]);
+        let heights = simulation.heights.clone();
+        simulation.submit();
+        simulation.run_for(Duration::from_secs(30));
+        assert_eq!(simulation.heights, heights);
+
+        simulation.heal();
+        simulation.assert_liveness(Duration::from_secs(120));
+    }
+
+    #[test]
+    async fn same_seed_reproduces_the_same_run() {
+        /// Heights of the peers over the run and the blocks finalized in it
+        type Run = (Vec<(Duration, usize, usize)>, Vec<HashOf<BlockHeader>>);
+
+        fn run(seed: u64) -> Run {
+            let peers = SimulatedPeers::new(4);
+            let mut simulation = peers.simulation(seed);
+            simulation.conditions = NetworkConditions {
+                min_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(300),
+                drop_probability: 0.05,
+            };
+
+            simulation.submit();
+            simulation.run_for(Duration::from_secs(15));
+            simulation.submit();
+            simulation.run_for(Duration::from_secs(15));
+            (simulation.trace, simulation.final_blocks)
+        }
+
+        let (trace, blocks) = run(4);
+        assert!(!trace.is_empty());
+        assert!(blocks.len() > 1, "No blocks were committed");
+        assert_eq!((trace, blocks), run(4));
+    }
+}
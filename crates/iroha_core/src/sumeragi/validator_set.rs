@@ -0,0 +1,93 @@
+//! Liveness of the peers joining the validator set staged until an epoch boundary.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use iroha_crypto::HashOf;
+use iroha_data_model::{block::BlockHeader, peer::PeerId};
+use parking_lot::Mutex;
+
+/// Latest blocks which peers reported to have while requesting blocks from this peer.
+///
+/// The leader activates the staged validator set only once every joining peer has caught up.
+/// Only the peers of the current and the pending validator sets are tracked,
+/// so that the reports of other peers can't grow it without bound.
+#[derive(Debug, Clone, Default)]
+pub struct SyncedPeers(Arc<Mutex<SyncedPeersInner>>);
+
+#[derive(Debug, Default)]
+struct SyncedPeersInner {
+    tracked: BTreeSet<PeerId>,
+    latest_blocks: BTreeMap<PeerId, HashOf<BlockHeader>>,
+}
+
+impl SyncedPeers {
+    /// Track only the given `peers` forgetting the latest blocks of all other peers
+    pub fn track(&self, peers: impl IntoIterator<Item = PeerId>) {
+        let mut inner = self.0.lock();
+        inner.tracked = peers.into_iter().collect();
+
+        let SyncedPeersInner {
+            tracked,
+            latest_blocks,
+        } = &mut *inner;
+        latest_blocks.retain(|peer, _| tracked.contains(peer));
+    }
+
+    /// Remember the latest block reported by the `peer` if it's tracked
+    pub fn report(&self, peer: PeerId, latest_block: HashOf<BlockHeader>) {
+        let mut inner = self.0.lock();
+        if inner.tracked.contains(&peer) {
+            inner.latest_blocks.insert(peer, latest_block);
+        }
+    }
+
+    /// Latest block reported by the `peer`
+    pub fn latest_block(&self, peer: &PeerId) -> Option<HashOf<BlockHeader>> {
+        self.0.lock().latest_blocks.get(peer).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_crypto::{Hash, KeyPair};
+
+    use super::*;
+
+    fn peer() -> PeerId {
+        PeerId::new(KeyPair::random().into_parts().0)
+    }
+
+    fn block() -> HashOf<BlockHeader> {
+        HashOf::from_untyped_unchecked(Hash::prehashed([1; Hash::LENGTH]))
+    }
+
+    #[test]
+    fn only_tracked_peers_are_remembered() {
+        let synced_peers = SyncedPeers::default();
+        let (validator, stranger) = (peer(), peer());
+        synced_peers.track([validator.clone()]);
+
+        synced_peers.report(validator.clone(), block());
+        synced_peers.report(stranger.clone(), block());
+
+        assert_eq!(synced_peers.latest_block(&validator), Some(block()));
+        assert_eq!(synced_peers.latest_block(&stranger), None);
+    }
+
+    #[test]
+    fn untracked_peers_are_forgotten() {
+        let synced_peers = SyncedPeers::default();
+        let (leaving, staying) = (peer(), peer());
+        synced_peers.track([leaving.clone(), staying.clone()]);
+        synced_peers.report(leaving.clone(), block());
+        synced_peers.report(staying.clone(), block());
+
+        synced_peers.track([staying.clone()]);
+
+        assert_eq!(synced_peers.latest_block(&leaving), None);
+        assert_eq!(synced_peers.latest_block(&staying), Some(block()));
+    }
+}
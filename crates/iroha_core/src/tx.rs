@@ -8,23 +8,27 @@
 //! This is also where the actual execution of instructions, as well
 //! as various forms of validation are performed.
 
-use std::time::{Duration, SystemTime};
+use std::{
+    num::NonZeroU64,
+    time::{Duration, SystemTime},
+};
 
 use eyre::Result;
 use iroha_crypto::SignatureOf;
 pub use iroha_data_model::prelude::*;
 use iroha_data_model::{
+    block::BlockHeader,
     isi::error::Mismatch,
     query::error::FindError,
     transaction::{error::TransactionLimitError, TransactionPayload},
 };
 use iroha_logger::{debug, error};
 use iroha_macro::FromVariant;
-use mv::storage::StorageReadOnly;
 
 use crate::{
+    parallel,
     smartcontracts::{wasm, wasm::cache::WasmCache},
-    state::{StateBlock, StateTransaction},
+    state::{State, StateBlock, StateReadOnly, StateTransaction},
 };
 
 /// `AcceptedTransaction` — a transaction accepted by Iroha peer.
@@ -208,7 +212,7 @@ impl StateBlock<'_> {
     ) -> Result<SignedTransaction, (SignedTransaction, TransactionRejectionReason)> {
         let mut state_transaction = self.transaction();
         if let Err(rejection_reason) =
-            Self::validate_internal(tx.clone(), &mut state_transaction, wasm_cache)
+            Self::validate_internal(tx.clone(), &mut state_transaction, true, wasm_cache)
         {
             return Err((tx.0, rejection_reason));
         }
@@ -217,17 +221,115 @@ impl StateBlock<'_> {
         Ok(tx.0)
     }
 
-    fn validate_internal(
+    /// Validate `transactions` of the block in their order, see [`Self::validate`].
+    ///
+    /// If the peer is configured with several execution workers, the transactions are
+    /// executed speculatively in parallel first and then committed in their order,
+    /// executing again the ones which read anything written by the transactions before them.
+    /// The outcome is the same as if the transactions were validated one by one.
+    /// Genesis is always validated one by one, since its transactions depend on each other.
+    pub fn validate_transactions(
+        &mut self,
+        transactions: &[AcceptedTransaction],
+    ) -> Vec<Result<(), TransactionRejectionReason>> {
+        let workers = self.execution_workers.get().min(transactions.len());
+        if workers > 1 && !self.curr_block.is_genesis() {
+            return parallel::validate_transactions(self, transactions, workers);
+        }
+
+        let mut wasm_cache = WasmCache::new();
+        transactions
+            .iter()
+            // FIXME: Redundant clone
+            .cloned()
+            .map(|tx| {
+                self.validate(tx, &mut wasm_cache)
+                    .map(|_| ())
+                    .map_err(|(_, reason)| reason)
+            })
+            .collect()
+    }
+
+    /// Execute the transaction on top of this block and report the outcome of the execution.
+    ///
+    /// The block is consumed, so changes of the transaction are never committed.
+    /// Unless `check_threshold` is set, the transaction only has to be signed
+    /// by any key of its authority regardless of the signature threshold.
+    pub fn simulate(
+        mut self,
+        tx: AcceptedTransaction,
+        check_threshold: bool,
+    ) -> TransactionSimulation {
+        // Triggers deferred from the previous blocks are not of interest
+        self.world.triggers.extract_matched_ids();
+
+        let mut wasm_cache = WasmCache::new();
+        let (rejection_reason, fuel_consumed, memory_consumed) = {
+            let mut state_transaction = self.transaction();
+            let result = Self::validate_internal(
+                tx,
+                &mut state_transaction,
+                check_threshold,
+                &mut wasm_cache,
+            );
+            let fuel_consumed = state_transaction.wasm_fuel_consumed;
+            let memory_consumed = state_transaction.wasm_memory_consumed;
+            if result.is_ok() {
+                state_transaction.apply();
+            }
+
+            (result.err(), fuel_consumed, memory_consumed)
+        };
+
+        let events = self
+            .world
+            .take_events()
+            .into_iter()
+            .filter_map(|event| match event {
+                EventBox::Data(event) => Some(event),
+                _ => None,
+            })
+            .collect();
+        let triggers = self
+            .world
+            .triggers
+            .extract_matched_ids()
+            .into_iter()
+            .map(|(_, id)| id)
+            .collect();
+
+        TransactionSimulation {
+            rejection_reason,
+            events,
+            triggers,
+            fuel_consumed,
+            memory_consumed,
+        }
+    }
+
+    pub(crate) fn validate_internal(
         tx: AcceptedTransaction,
         state_transaction: &mut StateTransaction<'_, '_>,
+        check_threshold: bool,
         wasm_cache: &mut WasmCache<'_, '_, '_>,
     ) -> Result<(), TransactionRejectionReason> {
         let authority = tx.as_ref().authority();
 
-        if state_transaction.world.accounts.get(authority).is_none() {
+        let Some(account) = state_transaction.world.accounts.get(authority) else {
             return Err(TransactionRejectionReason::AccountDoesNotExist(
                 FindError::Account(authority.clone()),
             ));
+        };
+
+        let signed = if check_threshold {
+            account.keys.is_authorized(tx.as_ref().signatories())
+        } else {
+            tx.as_ref()
+                .signatories()
+                .any(|signatory| account.keys.contains(signatory))
+        };
+        if !signed {
+            return Err(TransactionRejectionReason::InsufficientSignatures);
         }
 
         debug!(tx=%tx.as_ref().hash(), "Validating transaction");
@@ -296,3 +398,53 @@ impl StateBlock<'_> {
             })
     }
 }
+
+impl State {
+    /// Execute the transaction on top of the latest committed block
+    /// without committing it, see [`StateBlock::simulate`].
+    ///
+    /// The transaction is executed on a detached copy of the state, so blocks
+    /// can be applied to this state in the meantime. The executor and the smart contract
+    /// of the transaction are given at most `max_fuel` regardless of the parameters.
+    pub fn simulate_transaction(
+        &self,
+        tx: AcceptedTransaction,
+        check_threshold: bool,
+        max_fuel: NonZeroU64,
+    ) -> TransactionSimulation {
+        let state = self.detach();
+        let (height, prev_block_hash) = {
+            let state_view = state.view();
+            (state_view.height(), state_view.latest_block_hash())
+        };
+        let creation_time_ms = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_millis()
+            .try_into()
+            .expect("Time should fit into u64");
+        let header = BlockHeader {
+            height: u64::try_from(height)
+                .ok()
+                .and_then(|height| height.checked_add(1))
+                .and_then(NonZeroU64::new)
+                .expect("INTERNAL BUG: Blockchain height exceeds u64::MAX"),
+            prev_block_hash,
+            transactions_hash: None,
+            prev_trigger_receipts_hash: None,
+            creation_time_ms,
+            view_change_index: 0,
+            // Only the leader can produce the randomness of the next block
+            randomness: None,
+            equivocations_hash: None,
+            validator_set_hash: None,
+        };
+
+        // NOTE: The block is never committed
+        let mut block = state.block(header);
+        let parameters = &mut block.world.parameters;
+        parameters.executor.fuel = parameters.executor.fuel.min(max_fuel);
+        parameters.smart_contract.fuel = parameters.smart_contract.fuel.min(max_fuel);
+        block.simulate(tx, check_threshold)
+    }
+}
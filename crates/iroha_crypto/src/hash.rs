@@ -78,6 +78,55 @@ impl Hash {
     }
 }
 
+/// Algorithm producing a [`Hash::LENGTH`] bytes long digest
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Decode,
+    Encode,
+    Deserialize,
+    Serialize,
+    IntoSchema,
+)]
+pub enum HashAlgorithm {
+    /// `Blake2b` with 256 bit output
+    Blake2b256,
+    /// `SHA-256`
+    Sha256,
+    /// Original `Keccak-256` as used in Ethereum
+    Keccak256,
+}
+
+#[cfg(not(feature = "ffi_import"))]
+impl HashAlgorithm {
+    /// Compute the digest of the given bytes.
+    ///
+    /// Unlike [`Hash::new`], the least significant bit is left as is
+    pub fn digest(self, bytes: impl AsRef<[u8]>) -> [u8; Hash::LENGTH] {
+        use sha2::Digest as _;
+
+        match self {
+            Self::Blake2b256 => {
+                let mut hash = [0; Hash::LENGTH];
+                Blake2bVar::new(Hash::LENGTH)
+                    .expect("Failed to initialize variable size hash")
+                    .chain(bytes)
+                    .finalize_variable(&mut hash)
+                    .expect("Output buffer has the requested size");
+                hash
+            }
+            Self::Sha256 => sha2::Sha256::digest(bytes).into(),
+            Self::Keccak256 => sha3::Keccak256::digest(bytes).into(),
+        }
+    }
+}
+
 impl From<Hash> for [u8; Hash::LENGTH] {
     #[inline]
     fn from(hash: Hash) -> Self {
@@ -334,4 +383,20 @@ mod tests {
                 [..]
         );
     }
+
+    #[test]
+    fn hash_algorithm_digests() {
+        assert_eq!(
+            HashAlgorithm::Blake2b256.digest(hex_literal::hex!("6920616d2064617461")),
+            hex_literal::hex!("BA67336EFD6A3DF3A70EEB757860763036785C182FF4CF587541A0068D09F5B2")
+        );
+        assert_eq!(
+            HashAlgorithm::Sha256.digest(b""),
+            hex_literal::hex!("E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855")
+        );
+        assert_eq!(
+            HashAlgorithm::Keccak256.digest(b""),
+            hex_literal::hex!("C5D2460186F7233C927E7DB2DCC703C0E500B653CA82273B7BFAD8045D85A470")
+        );
+    }
 }
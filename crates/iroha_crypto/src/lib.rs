@@ -10,6 +10,7 @@ mod hash;
 #[cfg(not(feature = "ffi_import"))]
 pub mod kex;
 mod merkle;
+mod merkle_proof;
 #[cfg(not(feature = "ffi_import"))]
 mod multihash;
 mod secrecy;
@@ -41,6 +42,7 @@ use iroha_schema::{Declaration, IntoSchema, MetaMap, Metadata, NamedFieldsMeta,
 #[cfg(target_family = "wasm")]
 use lazy::PublicKeyLazy;
 pub use merkle::MerkleTree;
+pub use merkle_proof::MerkleProof;
 #[cfg(not(feature = "ffi_import"))]
 use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize, Serializer};
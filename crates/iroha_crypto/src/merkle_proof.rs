@@ -0,0 +1,255 @@
+//! Proofs of inclusion into Merkle trees committing to arbitrary data, e.g. of bridged chains.
+//!
+//! Unlike [`MerkleTree`](crate::MerkleTree), which commits to the contents of a block,
+//! these trees follow [RFC 6962](https://www.rfc-editor.org/rfc/rfc6962#section-2.1):
+//! leaves and inner nodes are hashed with distinct prefixes, children are concatenated in order,
+//! and a proof is only valid for the size of the tree it was built for.
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use crate::Hash;
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+/// Proof of inclusion of a leaf into a Merkle tree of the given size
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize)]
+pub struct MerkleProof {
+    /// Number of leaves in the tree
+    tree_size: u32,
+    /// Index of the leaf
+    leaf_index: u32,
+    /// Roots of the sibling subtrees on the path from the leaf to the root
+    audit_path: Vec<Hash>,
+}
+
+impl MerkleProof {
+    /// Compute the root of the tree with the given leaves.
+    pub fn root<L: AsRef<[u8]>>(leaves: &[L]) -> Hash {
+        if leaves.is_empty() {
+            return Hash::new([]);
+        }
+
+        subtree_root(&leaves.iter().map(leaf_hash).collect::<Vec<_>>())
+    }
+
+    /// Build the proof of inclusion of the `leaf_index`-th of the `leaves`.
+    pub fn build<L: AsRef<[u8]>>(leaves: &[L], leaf_index: u32) -> Option<Self> {
+        let tree_size = u32::try_from(leaves.len()).ok()?;
+        let index = usize::try_from(leaf_index).ok()?;
+        if leaves.len() <= index {
+            return None;
+        }
+
+        let hashes = leaves.iter().map(leaf_hash).collect::<Vec<_>>();
+        let mut audit_path = Vec::new();
+        collect_audit_path(&hashes, index, &mut audit_path);
+
+        Some(Self {
+            tree_size,
+            leaf_index,
+            audit_path,
+        })
+    }
+
+    /// Number of leaves in the tree the proof was built for.
+    pub fn tree_size(&self) -> u32 {
+        self.tree_size
+    }
+
+    /// Index of the proven leaf.
+    pub fn leaf_index(&self) -> u32 {
+        self.leaf_index
+    }
+
+    /// Roots of the sibling subtrees on the path from the leaf to the root.
+    pub fn audit_path(&self) -> &[Hash] {
+        &self.audit_path
+    }
+
+    /// Check that `leaf` is the leaf at [`Self::leaf_index`] of the tree of [`Self::tree_size`] leaves with the `root` hash.
+    ///
+    /// The length of the audit path must match the position of the leaf in the tree exactly.
+    pub fn verify(&self, leaf: impl AsRef<[u8]>, root: &Hash) -> bool {
+        if self.tree_size <= self.leaf_index {
+            return false;
+        }
+
+        // Index of the node on the path and of the last node at the same level
+        let mut index = self.leaf_index;
+        let mut last = self.tree_size - 1;
+        let mut node = leaf_hash(leaf);
+        for sibling in &self.audit_path {
+            if last == 0 {
+                // The path is longer than the tree is high
+                return false;
+            }
+
+            if index % 2 == 1 || index == last {
+                node = node_hash(sibling, &node);
+                // Skip the levels where the node has no right sibling
+                while index % 2 == 0 && index != 0 {
+                    index >>= 1;
+                    last >>= 1;
+                }
+            } else {
+                node = node_hash(&node, sibling);
+            }
+            index >>= 1;
+            last >>= 1;
+        }
+
+        last == 0 && node == *root
+    }
+}
+
+fn leaf_hash(leaf: impl AsRef<[u8]>) -> Hash {
+    let leaf = leaf.as_ref();
+    let mut bytes = Vec::with_capacity(1 + leaf.len());
+    bytes.push(LEAF_PREFIX);
+    bytes.extend_from_slice(leaf);
+    Hash::new(bytes)
+}
+
+fn node_hash(l_node: &Hash, r_node: &Hash) -> Hash {
+    let mut bytes = Vec::with_capacity(1 + 2 * Hash::LENGTH);
+    bytes.push(NODE_PREFIX);
+    bytes.extend_from_slice(l_node.as_ref());
+    bytes.extend_from_slice(r_node.as_ref());
+    Hash::new(bytes)
+}
+
+/// Number of leaves in the left subtree: the largest power of two less than `n`
+fn split(n: usize) -> usize {
+    n.next_power_of_two() / 2
+}
+
+fn subtree_root(leaf_hashes: &[Hash]) -> Hash {
+    if let [leaf_hash] = leaf_hashes {
+        return *leaf_hash;
+    }
+
+    let (l_leaves, r_leaves) = leaf_hashes.split_at(split(leaf_hashes.len()));
+    node_hash(&subtree_root(l_leaves), &subtree_root(r_leaves))
+}
+
+fn collect_audit_path(leaf_hashes: &[Hash], index: usize, audit_path: &mut Vec<Hash>) {
+    if leaf_hashes.len() <= 1 {
+        return;
+    }
+
+    let (l_leaves, r_leaves) = leaf_hashes.split_at(split(leaf_hashes.len()));
+    if index < l_leaves.len() {
+        collect_audit_path(l_leaves, index, audit_path);
+        audit_path.push(subtree_root(r_leaves));
+    } else {
+        collect_audit_path(r_leaves, index - l_leaves.len(), audit_path);
+        audit_path.push(subtree_root(l_leaves));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_leaves(n_leaves: u8) -> Vec<[u8; 1]> {
+        (1..=n_leaves).map(|i| [i]).collect()
+    }
+
+    #[test]
+    fn proof() {
+        for n_leaves in 1..=9 {
+            let leaves = test_leaves(n_leaves);
+            let root = MerkleProof::root(&leaves);
+
+            for (idx, leaf) in leaves.iter().enumerate() {
+                let proof = MerkleProof::build(&leaves, idx.try_into().unwrap()).unwrap();
+                assert!(proof.verify(leaf, &root));
+
+                let other_leaf = &leaves[(idx + 1) % leaves.len()];
+                if other_leaf != leaf {
+                    assert!(!proof.verify(other_leaf, &root));
+                }
+            }
+            assert!(MerkleProof::build(&leaves, n_leaves.into()).is_none());
+        }
+
+        let leaves = test_leaves(5);
+        let other_root = MerkleProof::root(&test_leaves(4));
+        let proof = MerkleProof::build(&leaves, 0).unwrap();
+        assert!(!proof.verify(leaves[0], &other_root));
+    }
+
+    #[test]
+    fn forged_sibling_is_rejected() {
+        let leaves = test_leaves(4);
+        let root = MerkleProof::root(&leaves);
+        let proof = MerkleProof::build(&leaves, 0).unwrap();
+
+        // With commutative node hashing a sibling could be solved for as `(L + R) - X`
+        let forged_leaf = [42_u8];
+        let l_hash = leaf_hash(leaves[0]);
+        let r_hash = proof.audit_path()[0];
+        let x_hash = leaf_hash(forged_leaf);
+        let forged_sibling: [u8; Hash::LENGTH] = core::array::from_fn(|i| {
+            l_hash.as_ref()[i]
+                .wrapping_add(r_hash.as_ref()[i])
+                .wrapping_sub(x_hash.as_ref()[i])
+        });
+        let forged_proof = MerkleProof {
+            audit_path: vec![Hash::prehashed(forged_sibling), proof.audit_path()[1]],
+            ..proof.clone()
+        };
+        assert!(!forged_proof.verify(forged_leaf, &root));
+
+        // Swapping the order of the children changes the parent
+        let swapped_proof = MerkleProof {
+            leaf_index: 1,
+            ..proof
+        };
+        assert!(!swapped_proof.verify(leaves[0], &root));
+    }
+
+    #[test]
+    fn inner_node_is_rejected_as_leaf() {
+        let leaves = test_leaves(4);
+        let root = MerkleProof::root(&leaves);
+        let proof = MerkleProof::build(&leaves, 0).unwrap();
+
+        // Present the parent of the first two leaves as a leaf of a tree half as large
+        let inner_node = subtree_root(&[leaf_hash(leaves[0]), leaf_hash(leaves[1])]);
+        let shortened_proof = MerkleProof {
+            tree_size: 2,
+            leaf_index: 0,
+            audit_path: proof.audit_path()[1..].to_vec(),
+        };
+        assert!(!shortened_proof.verify(inner_node.as_ref(), &root));
+    }
+
+    #[test]
+    fn path_length_must_match_tree_size() {
+        let leaves = test_leaves(4);
+        let root = MerkleProof::root(&leaves);
+        let proof = MerkleProof::build(&leaves, 2).unwrap();
+        assert!(proof.verify(leaves[2], &root));
+
+        let truncated_proof = MerkleProof {
+            audit_path: proof.audit_path()[..1].to_vec(),
+            ..proof.clone()
+        };
+        assert!(!truncated_proof.verify(leaves[2], &root));
+
+        let mut extended_proof = proof.clone();
+        extended_proof.audit_path.push(root);
+        assert!(!extended_proof.verify(leaves[2], &root));
+
+        let resized_proof = MerkleProof {
+            tree_size: 8,
+            ..proof
+        };
+        assert!(!resized_proof.verify(leaves[2], &root));
+    }
+}
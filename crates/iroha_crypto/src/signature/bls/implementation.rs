@@ -4,13 +4,14 @@ use core::marker::PhantomData;
 
 #[cfg(feature = "rand")]
 use rand_chacha::rand_core::OsRng;
-use sha2::Sha256;
+use sha2::{Digest as _, Sha256};
 // TODO: Better to use `SecretKey`, not `SecretKeyVT`, but it requires to implement
 // interior mutability
 use w3f_bls::{EngineBLS as _, PublicKey, SecretKeyVT as SecretKey, SerializableToBytes as _};
 use zeroize::Zeroize as _;
 
 pub(super) const MESSAGE_CONTEXT: &[u8; 20] = b"for signing messages";
+const AGGREGATION_CONTEXT: &[u8; 23] = b"for aggregating signers";
 
 use crate::{Algorithm, Error, KeyGenOption, ParseError};
 
@@ -70,6 +71,76 @@ impl<C: BlsConfiguration + ?Sized> BlsImpl<C> {
         Ok(())
     }
 
+    /// Aggregate signatures weighting each one by the coefficient of its signatory
+    /// so that no signatory can choose its key to cancel out the keys of the others
+    pub fn aggregate(signatures: &[(&PublicKey<C::Engine>, &[u8])]) -> Result<Vec<u8>, Error> {
+        let pks = signatures.iter().map(|(pk, _)| *pk).collect::<Vec<_>>();
+        let mut signatures =
+            signatures
+                .iter()
+                .zip(Self::coefficients(&pks))
+                .map(|((_, signature), coefficient)| {
+                    let mut signature = w3f_bls::Signature::<C::Engine>::from_bytes(signature)
+                        .map_err(|_| ParseError("Failed to parse signature.".to_owned()))?
+                        .0;
+                    signature *= coefficient;
+                    Ok::<_, ParseError>(signature)
+                });
+        let first = signatures
+            .next()
+            .ok_or_else(|| Error::Signing("No signatures to aggregate".to_owned()))??;
+        let aggregate = signatures.try_fold(first, |acc, signature| {
+            Ok::<_, ParseError>(acc + signature?)
+        })?;
+
+        Ok(w3f_bls::Signature::<C::Engine>(aggregate).to_bytes())
+    }
+
+    pub fn verify_aggregate(
+        message: &[u8],
+        signature: &[u8],
+        pks: &[&PublicKey<C::Engine>],
+    ) -> Result<(), Error> {
+        let mut pks = pks
+            .iter()
+            .zip(Self::coefficients(pks))
+            .map(|(pk, coefficient)| {
+                let mut pk = pk.0;
+                pk *= coefficient;
+                pk
+            });
+        let first = pks.next().ok_or(Error::BadSignature)?;
+        let aggregate = pks.fold(first, |acc, pk| acc + pk);
+
+        Self::verify(message, signature, &PublicKey(aggregate))
+    }
+
+    // Coefficients are derived from the key of the signatory and the keys of all the signatories
+    // as in https://eprint.iacr.org/2018/483, so that they are known only once all keys are fixed
+    fn coefficients(
+        pks: &[&PublicKey<C::Engine>],
+    ) -> Vec<<C::Engine as w3f_bls::EngineBLS>::Scalar> {
+        let pks = pks.iter().map(|pk| pk.to_bytes()).collect::<Vec<_>>();
+        let mut signatories = pks.clone();
+        // Coefficients don't depend on the order in which signatures are aggregated
+        signatories.sort_unstable();
+
+        let mut hasher = Sha256::new();
+        hasher.update(AGGREGATION_CONTEXT);
+        for signatory in &signatories {
+            hasher.update(signatory);
+        }
+
+        pks.iter()
+            .map(|pk| {
+                let digest = hasher.clone().chain_update(pk).finalize();
+                let mut coefficient = [0; 16];
+                coefficient.copy_from_slice(&digest[..16]);
+                u128::from_le_bytes(coefficient).into()
+            })
+            .collect()
+    }
+
     pub fn parse_public_key(payload: &[u8]) -> Result<PublicKey<C::Engine>, ParseError> {
         PublicKey::from_bytes(payload).map_err(|err| ParseError(err.to_string()))
     }
@@ -1,4 +1,4 @@
-use w3f_bls::SerializableToBytes as _;
+use w3f_bls::{PublicKey, SerializableToBytes as _};
 
 use super::{
     implementation::{BlsConfiguration, BlsImpl},
@@ -48,6 +48,33 @@ fn test_signature_verification_different_keys<C: BlsConfiguration>() {
         .expect_err("Signature verification for wrong public key should fail");
 }
 
+#[allow(clippy::similar_names)]
+fn test_aggregate_signature_verification<C: BlsConfiguration>() {
+    let (pk_1, sk_1) = BlsImpl::<C>::keypair(KeyGenOption::Random);
+    let (pk_2, sk_2) = BlsImpl::<C>::keypair(KeyGenOption::Random);
+
+    let signature_1 = BlsImpl::<C>::sign(MESSAGE_1, &sk_1);
+    let signature_2 = BlsImpl::<C>::sign(MESSAGE_1, &sk_2);
+    let aggregate =
+        BlsImpl::<C>::aggregate(&[(&pk_1, &signature_1[..]), (&pk_2, &signature_2[..])])
+            .expect("Signatures should be aggregated");
+    BlsImpl::<C>::verify_aggregate(MESSAGE_1, &aggregate, &[&pk_2, &pk_1])
+        .expect("Aggregated signature verification should succeed regardless of signatories order");
+    BlsImpl::<C>::verify_aggregate(MESSAGE_1, &aggregate, &[&pk_1])
+        .expect_err("Aggregated signature verification without a signatory should fail");
+}
+
+fn test_aggregate_signature_rogue_key<C: BlsConfiguration>() {
+    let (victim_pk, _victim_sk) = BlsImpl::<C>::keypair(KeyGenOption::Random);
+    let (attacker_public_key, attacker_private_key) = BlsImpl::<C>::keypair(KeyGenOption::Random);
+
+    // The rogue key cancels out the key of the victim if the keys are simply added up
+    let rogue_pk = PublicKey::<C::Engine>(attacker_public_key.0 - victim_pk.0);
+    let forged = BlsImpl::<C>::sign(MESSAGE_1, &attacker_private_key);
+    BlsImpl::<C>::verify_aggregate(MESSAGE_1, &forged, &[&victim_pk, &rogue_pk])
+        .expect_err("Aggregated signature forged with a rogue key should fail verification");
+}
+
 mod normal {
     use super::*;
 
@@ -70,6 +97,16 @@ mod normal {
     fn signature_verification_different_keys() {
         test_signature_verification_different_keys::<NormalConfiguration>();
     }
+
+    #[test]
+    fn aggregate_signature_verification() {
+        test_aggregate_signature_verification::<NormalConfiguration>();
+    }
+
+    #[test]
+    fn aggregate_signature_rogue_key() {
+        test_aggregate_signature_rogue_key::<NormalConfiguration>();
+    }
 }
 
 mod small {
@@ -94,4 +131,14 @@ mod small {
     fn signature_verification_different_keys() {
         test_signature_verification_different_keys::<SmallConfiguration>();
     }
+
+    #[test]
+    fn aggregate_signature_verification() {
+        test_aggregate_signature_verification::<SmallConfiguration>();
+    }
+
+    #[test]
+    fn aggregate_signature_rogue_key() {
+        test_aggregate_signature_rogue_key::<SmallConfiguration>();
+    }
 }
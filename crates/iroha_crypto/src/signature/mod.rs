@@ -11,7 +11,7 @@ pub(crate) mod ed25519;
 pub(crate) mod secp256k1;
 
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::String, vec, vec::Vec};
+use alloc::{borrow::ToOwned as _, format, string::String, vec, vec::Vec};
 use core::{borrow::Borrow as _, marker::PhantomData};
 
 use arrayref::array_ref;
@@ -114,6 +114,98 @@ impl Signature {
 
         Ok(())
     }
+
+    /// Aggregate BLS signatures of the same payload into one signature.
+    ///
+    /// Each signature is weighted by a coefficient derived from the public keys of all signatories,
+    /// so that the aggregated signature can't be forged by a signatory with a rogue key.
+    ///
+    /// # Errors
+    /// - `signatures` is empty
+    /// - Public keys are not of the same BLS algorithm
+    /// - Any of the signatures isn't a signature of that algorithm
+    pub fn aggregate<'a>(
+        signatures: impl IntoIterator<Item = (&'a PublicKey, &'a Signature)>,
+    ) -> Result<Self, Error> {
+        let signatures = signatures
+            .into_iter()
+            .map(|(public_key, signature)| (public_key.0.borrow(), signature.payload()))
+            .collect::<Vec<(&crate::PublicKeyInner, &[u8])>>();
+        let mismatch = || Error::Signing("Signatories use different algorithms".to_owned());
+
+        let signature = match signatures.first() {
+            Some((crate::PublicKeyInner::BlsNormal(_), _)) => {
+                let signatures = signatures
+                    .iter()
+                    .map(|(public_key, signature)| match public_key {
+                        crate::PublicKeyInner::BlsNormal(pk) => Ok((pk, *signature)),
+                        _ => Err(mismatch()),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                bls::BlsNormal::aggregate(&signatures)
+            }
+            Some((crate::PublicKeyInner::BlsSmall(_), _)) => {
+                let signatures = signatures
+                    .iter()
+                    .map(|(public_key, signature)| match public_key {
+                        crate::PublicKeyInner::BlsSmall(pk) => Ok((pk, *signature)),
+                        _ => Err(mismatch()),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                bls::BlsSmall::aggregate(&signatures)
+            }
+            Some((public_key, _)) => Err(Error::Signing(format!(
+                "{} signatures can't be aggregated",
+                public_key.algorithm()
+            ))),
+            None => Err(Error::Signing("No signatures to aggregate".to_owned())),
+        }?;
+
+        Ok(Self {
+            payload: ConstVec::new(signature),
+        })
+    }
+
+    /// Verify `payload` using aggregated signature and [`KeyPair::public_key`]s of all signatories.
+    ///
+    /// # Errors
+    /// Fails if public keys are not of the same BLS algorithm
+    /// or if the message doesn't pass verification
+    #[expect(single_use_lifetimes)] // FP, the suggested change is not allowed on stable
+    pub fn verify_aggregate<'a>(
+        &self,
+        public_keys: impl IntoIterator<Item = &'a PublicKey>,
+        payload: &[u8],
+    ) -> Result<(), Error> {
+        let public_keys = public_keys
+            .into_iter()
+            .map(|public_key| public_key.0.borrow())
+            .collect::<Vec<&crate::PublicKeyInner>>();
+
+        match public_keys.first() {
+            Some(crate::PublicKeyInner::BlsNormal(_)) => {
+                let public_keys = public_keys
+                    .iter()
+                    .map(|public_key| match public_key {
+                        crate::PublicKeyInner::BlsNormal(pk) => Ok(pk),
+                        _ => Err(Error::BadSignature),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                bls::BlsNormal::verify_aggregate(payload, &self.payload, &public_keys)
+            }
+            Some(crate::PublicKeyInner::BlsSmall(_)) => {
+                let public_keys = public_keys
+                    .iter()
+                    .map(|public_key| match public_key {
+                        crate::PublicKeyInner::BlsSmall(pk) => Ok(pk),
+                        _ => Err(Error::BadSignature),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                bls::BlsSmall::verify_aggregate(payload, &self.payload, &public_keys)
+            }
+            _ => Err(Error::BadSignature),
+        }
+    }
 }
 
 // TODO: Enable in ffi_import
@@ -221,6 +313,37 @@ impl<T> SignatureOf<T> {
     pub fn verify_hash(&self, public_key: &PublicKey, hash: HashOf<T>) -> Result<(), Error> {
         self.0.verify(public_key, hash.as_ref())
     }
+
+    /// Aggregate BLS signatures of the same hash into one signature.
+    ///
+    /// # Errors
+    /// See [`Signature::aggregate`]
+    pub fn aggregate<'a>(
+        signatures: impl IntoIterator<Item = (&'a PublicKey, &'a Self)>,
+    ) -> Result<Self, Error>
+    where
+        T: 'a,
+    {
+        Signature::aggregate(
+            signatures
+                .into_iter()
+                .map(|(public_key, signature)| (public_key, &signature.0)),
+        )
+        .map(|signature| Self(signature, PhantomData))
+    }
+
+    /// Verify aggregated signature for this hash
+    ///
+    /// # Errors
+    /// See [`Signature::verify_aggregate`]
+    #[expect(single_use_lifetimes)] // FP, the suggested change is not allowed on stable
+    pub fn verify_aggregate_hash<'a>(
+        &self,
+        public_keys: impl IntoIterator<Item = &'a PublicKey>,
+        hash: HashOf<T>,
+    ) -> Result<(), Error> {
+        self.0.verify_aggregate(public_keys, hash.as_ref())
+    }
 }
 
 impl<T: parity_scale_codec::Encode> SignatureOf<T> {
@@ -242,6 +365,19 @@ impl<T: parity_scale_codec::Encode> SignatureOf<T> {
     pub fn verify(&self, public_key: &PublicKey, value: &T) -> Result<(), Error> {
         self.verify_hash(public_key, HashOf::new(value))
     }
+
+    /// Verifies aggregated signature for this item
+    ///
+    /// # Errors
+    /// Fails if verification fails
+    #[expect(single_use_lifetimes)] // FP, the suggested change is not allowed on stable
+    pub fn verify_aggregate<'a>(
+        &self,
+        public_keys: impl IntoIterator<Item = &'a PublicKey>,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.verify_aggregate_hash(public_keys, HashOf::new(value))
+    }
 }
 
 #[cfg(test)]
@@ -287,6 +423,45 @@ mod tests {
         signature.verify(key_pair.public_key(), message).unwrap();
     }
 
+    #[test]
+    #[cfg(feature = "rand")]
+    fn aggregate_signature_bls_normal() {
+        let key_pairs = [
+            KeyPair::random_with_algorithm(Algorithm::BlsNormal),
+            KeyPair::random_with_algorithm(Algorithm::BlsNormal),
+            KeyPair::random_with_algorithm(Algorithm::BlsNormal),
+        ];
+        let message = b"Test message to sign.";
+        let signatures = key_pairs
+            .iter()
+            .map(|key_pair| Signature::new(key_pair.private_key(), message))
+            .collect::<Vec<_>>();
+
+        let aggregate =
+            Signature::aggregate(key_pairs.iter().map(KeyPair::public_key).zip(&signatures))
+                .unwrap();
+        aggregate
+            .verify_aggregate(key_pairs.iter().map(KeyPair::public_key), message)
+            .unwrap();
+        aggregate
+            .verify_aggregate(key_pairs[..2].iter().map(KeyPair::public_key), message)
+            .unwrap_err();
+        aggregate
+            .verify_aggregate(
+                key_pairs.iter().map(KeyPair::public_key),
+                b"Another message.",
+            )
+            .unwrap_err();
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn aggregate_signature_ed25519_fails() {
+        let key_pair = KeyPair::random_with_algorithm(Algorithm::Ed25519);
+        let signature = Signature::new(key_pair.private_key(), b"Test message to sign.");
+        Signature::aggregate([(key_pair.public_key(), &signature)]).unwrap_err();
+    }
+
     #[test]
     fn signature_serialized_representation() {
         let input = json!("3A7991AF1ABB77F3FD27CC148404A6AE4439D095A63591B77C788D53F708A02A1509A611AD6D97B01D871E58ED00C8FD7C3917B6CA61A8C2833A19E000AAC2E4");
@@ -1,7 +1,14 @@
 //! Structures, traits and impls related to `Account`s.
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::String, vec::Vec};
-use core::str::FromStr;
+use alloc::{
+    collections::{btree_map, BTreeMap},
+    format,
+    string::String,
+    vec::Vec,
+};
+use core::{num::NonZeroU16, str::FromStr};
+#[cfg(feature = "std")]
+use std::collections::{btree_map, BTreeMap};
 
 use derive_more::{Constructor, DebugCustom, Display};
 use iroha_data_model_derive::{model, IdEqOrdHash};
@@ -12,8 +19,8 @@ use serde_with::{DeserializeFromStr, SerializeDisplay};
 
 pub use self::model::*;
 use crate::{
-    domain::prelude::*, metadata::Metadata, HasMetadata, Identifiable, ParseError, PublicKey,
-    Registered,
+    domain::prelude::*, metadata::Metadata, name::Name, HasMetadata, Identifiable, ParseError,
+    PublicKey, Registered,
 };
 
 #[model]
@@ -22,8 +29,9 @@ mod model {
 
     use super::*;
 
-    /// Identification of [`Account`] by the combination of the [`PublicKey`] as its sole signatory and the [`Domain`](crate::domain::Domain) it belongs to.
-    /// TODO #4373 include multi-signatory use.
+    /// Identification of [`Account`] by the combination of the [`PublicKey`] as its initial signatory and the [`Domain`](crate::domain::Domain) it belongs to.
+    ///
+    /// The identifier is stable: the account keeps it even after its initial signatory is rotated out of [`AccountKeys`].
     ///
     /// # Examples
     ///
@@ -59,10 +67,49 @@ mod model {
     pub struct AccountId {
         /// [`Domain`](crate::domain::Domain) that the [`Account`] belongs to.
         pub domain: DomainId,
-        /// Sole signatory of the [`Account`].
+        /// Initial signatory of the [`Account`].
         pub signatory: PublicKey,
     }
 
+    /// Human-readable alias of an [`Account`], unique within the [`Domain`](crate::domain::Domain) the account belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iroha_data_model::account::AccountAlias;
+    ///
+    /// let alias: AccountAlias = "alice@wonderland"
+    ///     .parse()
+    ///     .expect("name@domain should be valid format");
+    /// ```
+    #[derive(
+        DebugCustom,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        DeserializeFromStr,
+        SerializeDisplay,
+        IntoSchema,
+    )]
+    #[display(fmt = "{name}@{domain}")]
+    #[debug(fmt = "{name}@{domain}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct AccountAlias {
+        /// [`Domain`](crate::domain::Domain) within which the alias is unique.
+        pub domain: DomainId,
+        /// Name the alias is registered under.
+        pub name: Name,
+    }
+
     /// Account entity is an authority which is used to execute `Iroha Special Instructions`.
     #[derive(
         Debug, Display, Clone, IdEqOrdHash, Decode, Encode, Deserialize, Serialize, IntoSchema,
@@ -75,6 +122,34 @@ mod model {
         pub id: AccountId,
         /// Metadata of this account as a key-value store.
         pub metadata: Metadata,
+        /// Keys authorized to sign transactions on behalf of this account.
+        pub keys: AccountKeys,
+    }
+
+    /// Keys authorized to sign transactions on behalf of an [`Account`] along with their relative weights.
+    ///
+    /// A transaction is authorized once the total weight of the keys that signed it reaches the threshold.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct AccountKeys {
+        /// Registered keys and their relative weights.
+        pub weights: BTreeMap<PublicKey, u8>,
+        /// Total weight of signing keys required to authorize a transaction.
+        pub threshold: NonZeroU16,
     }
 
     /// Builder which should be submitted in a transaction to create a new [`Account`]
@@ -110,11 +185,71 @@ impl Account {
         <Self as Registered>::With::new(id)
     }
 
-    /// Return a reference to the `Account` signatory.
+    /// Return a reference to the `Account` initial signatory.
     #[inline]
     pub fn signatory(&self) -> &PublicKey {
         &self.id.signatory
     }
+
+    /// Return keys authorized to sign transactions on behalf of the `Account`.
+    #[inline]
+    pub fn keys(&self) -> &AccountKeys {
+        &self.keys
+    }
+}
+
+impl AccountKeys {
+    /// Construct [`Self`] authorizing any transaction signed by the given key alone.
+    pub fn single(public_key: PublicKey) -> Self {
+        Self {
+            weights: [(public_key, 1)].into(),
+            threshold: NonZeroU16::MIN,
+        }
+    }
+
+    /// Check if the given key is registered.
+    pub fn contains(&self, public_key: &PublicKey) -> bool {
+        self.weights.contains_key(public_key)
+    }
+
+    /// Total weight of the given keys, ignoring unregistered ones.
+    #[expect(single_use_lifetimes)] // FP, the suggested change is not allowed on stable
+    pub fn weight_of<'a>(&self, public_keys: impl IntoIterator<Item = &'a PublicKey>) -> u16 {
+        public_keys
+            .into_iter()
+            .filter_map(|public_key| self.weights.get(public_key))
+            .fold(0, |acc, weight| acc.saturating_add(u16::from(*weight)))
+    }
+
+    /// Total weight of all the registered keys.
+    pub fn total_weight(&self) -> u16 {
+        self.weight_of(self.weights.keys())
+    }
+
+    /// Check if the given keys together reach the threshold.
+    #[expect(single_use_lifetimes)] // FP, the suggested change is not allowed on stable
+    pub fn is_authorized<'a>(&self, public_keys: impl IntoIterator<Item = &'a PublicKey>) -> bool {
+        self.threshold.get() <= self.weight_of(public_keys)
+    }
+
+    /// Check if the registered keys together can reach the threshold.
+    pub fn is_reachable(&self) -> bool {
+        self.threshold.get() <= self.total_weight()
+    }
+
+    /// Iterate over the registered keys and their weights.
+    pub fn iter(&self) -> btree_map::Iter<'_, PublicKey, u8> {
+        self.weights.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a AccountKeys {
+    type Item = (&'a PublicKey, &'a u8);
+    type IntoIter = btree_map::Iter<'a, PublicKey, u8>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
 
 impl NewAccount {
@@ -139,6 +274,7 @@ impl NewAccount {
     /// Convert into [`Account`].
     pub fn into_account(self) -> Account {
         Account {
+            keys: AccountKeys::single(self.id.signatory.clone()),
             id: self.id,
             metadata: self.metadata,
         }
@@ -188,9 +324,36 @@ impl FromStr for AccountId {
     }
 }
 
+impl FromStr for AccountAlias {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.rsplit_once('@') {
+            None => Err(ParseError {
+                reason: "Account alias should have format `name@domain`",
+            }),
+            Some(("", _)) => Err(ParseError {
+                reason: "Empty `name` part in `name@domain`",
+            }),
+            Some((_, "")) => Err(ParseError {
+                reason: "Empty `domain` part in `name@domain`",
+            }),
+            Some((name_candidate, domain_id_candidate)) => {
+                let name = name_candidate.parse().map_err(|_| ParseError {
+                    reason: "Failed to parse `name` part in `name@domain`",
+                })?;
+                let domain_id = domain_id_candidate.parse().map_err(|_| ParseError {
+                    reason: "Failed to parse `domain` part in `name@domain`",
+                })?;
+                Ok(Self::new(domain_id, name))
+            }
+        }
+    }
+}
+
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
-    pub use super::{Account, AccountId};
+    pub use super::{Account, AccountAlias, AccountId, AccountKeys};
 }
 
 #[cfg(test)]
@@ -215,4 +378,20 @@ mod tests {
             .parse::<AccountId>()
             .expect_err("signatory#domain should not be valid");
     }
+
+    #[test]
+    fn parse_account_alias() {
+        let _ok = "alice@wonderland"
+            .parse::<AccountAlias>()
+            .expect("should be valid");
+        let _err_empty_name = "@wonderland"
+            .parse::<AccountAlias>()
+            .expect_err("@domain should not be valid");
+        let _err_empty_domain = "alice@"
+            .parse::<AccountAlias>()
+            .expect_err("name@ should not be valid");
+        let _err_violates_format = "alice#wonderland"
+            .parse::<AccountAlias>()
+            .expect_err("name#domain should not be valid");
+    }
 }
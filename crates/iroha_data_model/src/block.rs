@@ -4,12 +4,12 @@
 
 #[cfg(not(feature = "std"))]
 use alloc::{boxed::Box, collections::BTreeMap, format, string::String, vec::Vec};
-use core::{fmt::Display, time::Duration};
+use core::{fmt::Display, num::NonZeroU64, time::Duration};
 #[cfg(feature = "std")]
 use std::collections::BTreeMap;
 
 use derive_more::Display;
-use iroha_crypto::{HashOf, MerkleTree, SignatureOf};
+use iroha_crypto::{Hash, HashOf, MerkleTree, SignatureOf};
 use iroha_data_model_derive::model;
 use iroha_macro::FromVariant;
 use iroha_schema::IntoSchema;
@@ -18,12 +18,15 @@ use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 pub use self::model::*;
-use crate::transaction::{error::TransactionRejectionReason, prelude::*};
+use crate::{
+    events::prelude::{DataEvent, EventBox, TriggerCompletedOutcome},
+    peer::PeerId,
+    transaction::{error::TransactionRejectionReason, prelude::*},
+    trigger::TriggerId,
+};
 
 #[model]
 mod model {
-    use core::num::NonZeroU64;
-
     use getset::{CopyGetters, Getters};
 
     use super::*;
@@ -59,12 +62,33 @@ mod model {
         /// None if no transactions (empty block).
         #[getset(get_copy = "pub")]
         pub transactions_hash: Option<HashOf<MerkleTree<SignedTransaction>>>,
+        /// Hash of merkle tree root of receipts of the triggers executed after the previous block.
+        /// Triggers are executed once a block is committed,
+        /// so their receipts are covered by the next block.
+        /// None if no triggers were executed.
+        #[getset(get_copy = "pub")]
+        pub prev_trigger_receipts_hash: Option<HashOf<MerkleTree<TriggerReceipt>>>,
         /// Creation timestamp (unix time in milliseconds).
         #[getset(skip)]
         pub creation_time_ms: u64,
         /// Value of view change index. Used to resolve soft forks.
         #[getset(skip)]
         pub view_change_index: u32,
+        /// Randomness of the block. If the leader has a BLS key, it is the hash of the leader's
+        /// signature over [`Self::randomness_seed`] of the previous block. BLS signatures are
+        /// unique, so it can't be predicted before the block is created or chosen by the leader.
+        /// Leaders with keys of other algorithms carry the seed of the previous block over.
+        /// None for the genesis block.
+        #[getset(get_copy = "pub")]
+        pub randomness: Option<Hash>,
+        /// Hash of the equivocation evidence recorded in the block.
+        /// None if the block records no evidence.
+        #[getset(get_copy = "pub")]
+        pub equivocations_hash: Option<HashOf<Vec<EquivocationEvidence>>>,
+        /// Hash of the validator set activated by the block before its transactions are executed.
+        /// None if the block doesn't change the validator set.
+        #[getset(get_copy = "pub")]
+        pub validator_set_hash: Option<HashOf<Vec<PeerId>>>,
     }
 
     #[derive(
@@ -77,6 +101,84 @@ mod model {
         pub header: BlockHeader,
         /// array of transactions, which successfully passed validation and consensus step.
         pub transactions: Vec<SignedTransaction>,
+        /// Leader's BLS signature over the randomness seed of the previous block.
+        /// Proves that [`BlockHeader::randomness`] is derived from it.
+        /// None if the leader doesn't have a BLS key.
+        pub randomness_proof: Option<SignatureOf<Hash>>,
+        /// Evidence of peers' equivocation detected by the consensus.
+        pub equivocations: Vec<EquivocationEvidence>,
+    }
+
+    /// Block header together with a signature of some peer over it
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct SignedBlockHeader {
+        /// Block header
+        pub header: BlockHeader,
+        /// Signature of the peer over the header
+        pub signature: SignatureOf<BlockHeader>,
+    }
+
+    /// Proof that a peer signed two different blocks with the same height and view change index.
+    ///
+    /// In a single round the leader proposes only one block and validators vote only for one,
+    /// so the evidence can't be produced by an honest peer and can be verified by anyone.
+    #[derive(
+        Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Getters, Encode, Serialize, IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct EquivocationEvidence {
+        /// Peer which signed both blocks
+        pub(super) peer: PeerId,
+        /// Signed header of the block with the smaller hash
+        pub(super) first: SignedBlockHeader,
+        /// Signed header of the block with the greater hash
+        pub(super) second: SignedBlockHeader,
+    }
+
+    /// Receipt of a trigger executed after the transactions of a block
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct TriggerReceipt {
+        /// Id of the executed trigger
+        pub trigger_id: TriggerId,
+        /// Event which matched the trigger filter
+        pub event: EventBox,
+        /// Outcome of the execution
+        pub outcome: TriggerCompletedOutcome,
+        /// Fuel consumed by a successful WASM executable, zero for instructions and failures
+        pub fuel: u64,
+        /// Data events emitted during the execution, empty if it failed
+        pub events: Vec<DataEvent>,
     }
 
     /// Signature of a block
@@ -100,6 +202,28 @@ mod model {
         pub SignatureOf<BlockHeader>,
     );
 
+    /// BLS signatures of several peers over a block aggregated into one
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub struct AggregateBlockSignature {
+        /// Bitmap of the signatories: bit `i % 8` of byte `i / 8` is set
+        /// if the peer with index `i` in the topology signed the block
+        pub signers: Vec<u8>,
+        /// Aggregated signature
+        pub signature: SignatureOf<BlockHeader>,
+    }
+
     /// Signed block
     #[version_with_scale(version = 1, versioned_alias = "SignedBlock")]
     #[derive(
@@ -110,6 +234,9 @@ mod model {
     pub struct SignedBlockV1 {
         /// Signatures of peers which approved this block.
         pub(super) signatures: Vec<BlockSignature>,
+        /// Aggregated signature of peers with BLS keys which approved this block.
+        /// These peers don't have their signatures in [`Self::signatures`].
+        pub(super) aggregate_signature: Option<AggregateBlockSignature>,
         /// Block payload
         pub(super) payload: BlockPayload,
         /// Collection of rejection reasons for every transaction if exists
@@ -118,6 +245,13 @@ mod model {
         ///
         /// Transaction errors are not part of the block hash or protected by the block signature.
         pub(super) errors: BTreeMap<u64, TransactionRejectionReason>,
+        /// Receipts of the triggers executed after the block was applied
+        ///
+        /// # Warning
+        ///
+        /// Trigger receipts are not protected by the block signature.
+        /// They are covered by the hash in the header of the next block.
+        pub(super) trigger_receipts: Vec<TriggerReceipt>,
     }
 }
 
@@ -143,6 +277,13 @@ impl BlockHeader {
     pub fn hash(&self) -> HashOf<BlockHeader> {
         iroha_crypto::HashOf::new(self)
     }
+
+    /// Seed from which the randomness of the next block is derived.
+    /// Genesis block has no randomness, so its hash is used instead.
+    #[inline]
+    pub fn randomness_seed(&self) -> Hash {
+        self.randomness.unwrap_or_else(|| self.hash().into())
+    }
 }
 
 impl SignedBlockV1 {
@@ -166,14 +307,20 @@ impl SignedBlock {
         signature: BlockSignature,
         header: BlockHeader,
         transactions: impl IntoIterator<Item = SignedTransaction>,
+        randomness_proof: Option<SignatureOf<Hash>>,
+        equivocations: Vec<EquivocationEvidence>,
     ) -> SignedBlock {
         SignedBlockV1 {
             signatures: vec![signature],
+            aggregate_signature: None,
             payload: BlockPayload {
                 header,
                 transactions: transactions.into_iter().collect(),
+                randomness_proof,
+                equivocations,
             },
             errors: BTreeMap::new(),
+            trigger_receipts: Vec::new(),
         }
         .into()
     }
@@ -194,6 +341,15 @@ impl SignedBlock {
         self
     }
 
+    /// Setter for trigger receipts
+    #[cfg(feature = "transparent_api")]
+    pub fn set_trigger_receipts(&mut self, receipts: Vec<TriggerReceipt>) -> &mut Self {
+        let SignedBlock::V1(block) = self;
+        block.trigger_receipts = receipts;
+
+        self
+    }
+
     /// Return error for the transaction index
     pub fn error(&self, tx: usize) -> Option<&TransactionRejectionReason> {
         let SignedBlock::V1(block) = self;
@@ -223,6 +379,23 @@ impl SignedBlock {
         block.signatures.iter()
     }
 
+    /// Aggregated signature of peers with BLS keys which approved this block.
+    #[inline]
+    pub fn aggregate_signature(&self) -> Option<&AggregateBlockSignature> {
+        let SignedBlock::V1(block) = self;
+        block.aggregate_signature.as_ref()
+    }
+
+    /// Topology indices of all peers which approved this block
+    /// either with a separate or with the aggregated signature.
+    pub fn signatories(&self) -> impl Iterator<Item = u64> + '_ {
+        self.signatures().map(BlockSignature::index).chain(
+            self.aggregate_signature()
+                .into_iter()
+                .flat_map(AggregateBlockSignature::signatories),
+        )
+    }
+
     /// Block transactions
     #[inline]
     pub fn transactions(&self) -> impl ExactSizeIterator<Item = &SignedTransaction> {
@@ -230,6 +403,21 @@ impl SignedBlock {
         block.payload.transactions.iter()
     }
 
+    /// Leader's BLS signature over the randomness seed of the previous block,
+    /// None if the leader doesn't have a BLS key
+    #[inline]
+    pub fn randomness_proof(&self) -> Option<&SignatureOf<Hash>> {
+        let SignedBlock::V1(block) = self;
+        block.payload.randomness_proof.as_ref()
+    }
+
+    /// Evidence of peers' equivocation recorded in the block
+    #[inline]
+    pub fn equivocations(&self) -> impl ExactSizeIterator<Item = &EquivocationEvidence> {
+        let SignedBlock::V1(block) = self;
+        block.payload.equivocations.iter()
+    }
+
     /// Check if block is empty (has no transactions)
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -247,6 +435,26 @@ impl SignedBlock {
         block.errors.iter()
     }
 
+    /// Receipts of the triggers executed after the block was applied
+    ///
+    /// # Warning
+    ///
+    /// Trigger receipts are not protected by the block signature.
+    /// They are covered by the hash in the header of the next block.
+    pub fn trigger_receipts(&self) -> impl ExactSizeIterator<Item = &TriggerReceipt> {
+        let SignedBlock::V1(block) = self;
+        block.trigger_receipts.iter()
+    }
+
+    /// Calculate hash of merkle tree root of the trigger receipts.
+    /// None if no triggers were executed.
+    pub fn trigger_receipts_hash(&self) -> Option<HashOf<MerkleTree<TriggerReceipt>>> {
+        self.trigger_receipts()
+            .map(HashOf::new)
+            .collect::<MerkleTree<_>>()
+            .hash()
+    }
+
     /// Calculate block hash
     #[inline]
     pub fn hash(&self) -> HashOf<BlockHeader> {
@@ -272,7 +480,7 @@ impl SignedBlock {
     /// if signature is invalid
     #[cfg(feature = "transparent_api")]
     pub fn add_signature(&mut self, signature: BlockSignature) -> Result<(), iroha_crypto::Error> {
-        if self.signatures().any(|s| signature.0 == s.0) {
+        if self.signatories().any(|signatory| signature.0 == signatory) {
             return Err(iroha_crypto::Error::Signing(
                 "Duplicate signature".to_owned(),
             ));
@@ -284,7 +492,8 @@ impl SignedBlock {
         Ok(())
     }
 
-    /// Replace signatures without verification
+    /// Replace signatures and the aggregated signature without verification.
+    /// Returns previous signatures and the aggregated signature.
     ///
     /// # Errors
     ///
@@ -293,19 +502,26 @@ impl SignedBlock {
     pub fn replace_signatures(
         &mut self,
         signatures: Vec<BlockSignature>,
-    ) -> Result<Vec<BlockSignature>, iroha_crypto::Error> {
+        aggregate_signature: Option<AggregateBlockSignature>,
+    ) -> Result<(Vec<BlockSignature>, Option<AggregateBlockSignature>), iroha_crypto::Error> {
         #[cfg(not(feature = "std"))]
         use alloc::collections::BTreeSet;
         #[cfg(feature = "std")]
         use std::collections::BTreeSet;
 
-        if signatures.is_empty() {
+        if signatures.is_empty() && aggregate_signature.is_none() {
             return Err(iroha_crypto::Error::Signing("Signatures empty".to_owned()));
         }
 
-        signatures.iter().map(|signature| signature.0).try_fold(
-            BTreeSet::new(),
-            |mut acc, elem| {
+        signatures
+            .iter()
+            .map(|signature| signature.0)
+            .chain(
+                aggregate_signature
+                    .iter()
+                    .flat_map(AggregateBlockSignature::signatories),
+            )
+            .try_fold(BTreeSet::new(), |mut acc, elem| {
                 if !acc.insert(elem) {
                     return Err(iroha_crypto::Error::Signing(format!(
                         "{elem}: Duplicate signature"
@@ -313,11 +529,13 @@ impl SignedBlock {
                 }
 
                 Ok(acc)
-            },
-        )?;
+            })?;
 
         let SignedBlock::V1(block) = self;
-        Ok(core::mem::replace(&mut block.signatures, signatures))
+        Ok((
+            core::mem::replace(&mut block.signatures, signatures),
+            core::mem::replace(&mut block.aggregate_signature, aggregate_signature),
+        ))
     }
 
     /// Creates genesis block signed with genesis private key (and not signed by any peer)
@@ -339,20 +557,28 @@ impl SignedBlock {
             height: nonzero!(1_u64),
             prev_block_hash: None,
             transactions_hash: Some(transactions_hash),
+            prev_trigger_receipts_hash: None,
             creation_time_ms,
             view_change_index: 0,
+            randomness: None,
+            equivocations_hash: None,
+            validator_set_hash: None,
         };
 
         let signature = BlockSignature(0, SignatureOf::new(private_key, &header));
         let payload = BlockPayload {
             header,
             transactions,
+            randomness_proof: None,
+            equivocations: Vec::new(),
         };
 
         SignedBlockV1 {
             signatures: vec![signature],
+            aggregate_signature: None,
             payload,
             errors: BTreeMap::new(),
+            trigger_receipts: Vec::new(),
         }
         .into()
     }
@@ -391,6 +617,106 @@ impl BlockSignature {
     }
 }
 
+impl EquivocationEvidence {
+    /// Construct [`Self`] from two different headers signed by the `peer`.
+    /// Headers are ordered by hash, so the same equivocation always has the same evidence.
+    pub fn new(peer: PeerId, first: SignedBlockHeader, second: SignedBlockHeader) -> Self {
+        let (first, second) = if first.header.hash() <= second.header.hash() {
+            (first, second)
+        } else {
+            (second, first)
+        };
+
+        Self {
+            peer,
+            first,
+            second,
+        }
+    }
+
+    /// Height of the conflicting blocks
+    pub fn height(&self) -> NonZeroU64 {
+        self.first.header.height
+    }
+
+    /// View change index of the conflicting blocks
+    pub fn view_change_index(&self) -> u32 {
+        self.first.header.view_change_index
+    }
+
+    /// Check that both headers are signed by the peer and conflict with each other
+    ///
+    /// # Errors
+    ///
+    /// If the evidence doesn't prove the equivocation of the peer
+    #[cfg(not(target_family = "wasm"))]
+    pub fn verify(&self) -> Result<(), &'static str> {
+        let (first, second) = (&self.first.header, &self.second.header);
+
+        if first.height != second.height || first.view_change_index != second.view_change_index {
+            return Err("Equivocating blocks must have the same height and view change index");
+        }
+        if first.hash() >= second.hash() {
+            return Err("Equivocating blocks must be different and ordered by hash");
+        }
+
+        for signed_header in [&self.first, &self.second] {
+            signed_header
+                .signature
+                .verify(self.peer.public_key(), &signed_header.header)
+                .map_err(|_| "Equivocation evidence signature is invalid")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl AggregateBlockSignature {
+    /// Construct [`Self`] from topology indices of the signatories and their aggregated signature
+    pub fn new(
+        signatories: impl IntoIterator<Item = u64>,
+        signature: SignatureOf<BlockHeader>,
+    ) -> Self {
+        let mut signers = Vec::new();
+
+        for signatory in signatories {
+            let byte = usize::try_from(signatory / 8)
+                .expect("INTERNAL BUG: Signatory index exceeds usize::MAX");
+            if signers.len() <= byte {
+                signers.resize(byte + 1, 0);
+            }
+            signers[byte] |= 1_u8 << (signatory % 8);
+        }
+
+        Self { signers, signature }
+    }
+
+    /// Topology indices of the signatories in ascending order
+    pub fn signatories(&self) -> impl Iterator<Item = u64> + '_ {
+        self.signers
+            .iter()
+            .zip(0_u64..)
+            .flat_map(|(&byte, byte_idx)| {
+                (0..8_u64)
+                    .filter(move |bit| byte & (1_u8 << bit) != 0)
+                    .map(move |bit| byte_idx * 8 + bit)
+            })
+    }
+
+    /// Check if the peer with the given topology index is among the signatories
+    pub fn is_signed_by(&self, index: u64) -> bool {
+        usize::try_from(index / 8)
+            .ok()
+            .and_then(|byte| self.signers.get(byte))
+            .is_some_and(|byte| byte & (1_u8 << (index % 8)) != 0)
+    }
+
+    /// Aggregated signature
+    pub fn payload(&self) -> &SignatureOf<BlockHeader> {
+        &self.signature
+    }
+}
+
 mod candidate {
     use parity_scale_codec::Input;
 
@@ -399,14 +725,25 @@ mod candidate {
     #[derive(Decode, Deserialize)]
     struct SignedBlockCandidate {
         signatures: Vec<BlockSignature>,
+        aggregate_signature: Option<AggregateBlockSignature>,
         payload: BlockPayload,
         errors: BTreeMap<u64, TransactionRejectionReason>,
+        trigger_receipts: Vec<TriggerReceipt>,
     }
 
     #[derive(Decode, Deserialize)]
     struct BlockPayloadCandidate {
         header: BlockHeader,
         transactions: Vec<SignedTransaction>,
+        randomness_proof: Option<SignatureOf<Hash>>,
+        equivocations: Vec<EquivocationEvidence>,
+    }
+
+    #[derive(Decode, Deserialize)]
+    struct EquivocationEvidenceCandidate {
+        peer: PeerId,
+        first: SignedBlockHeader,
+        second: SignedBlockHeader,
     }
 
     impl BlockPayloadCandidate {
@@ -419,6 +756,8 @@ mod candidate {
             Ok(BlockPayload {
                 header: self.header,
                 transactions: self.transactions,
+                randomness_proof: self.randomness_proof,
+                equivocations: self.equivocations,
             })
         }
 
@@ -445,10 +784,47 @@ mod candidate {
                 Ok(())
             })?;
 
+            let expected_randomness = self
+                .randomness_proof
+                .as_ref()
+                .map(|proof| Hash::new(proof.payload()));
+            if expected_randomness != self.header.randomness {
+                return Err("Block randomness doesn't correspond to the randomness proof");
+            }
+
+            let expected_equivocations_hash =
+                (!self.equivocations.is_empty()).then(|| HashOf::new(&self.equivocations));
+            if expected_equivocations_hash != self.header.equivocations_hash {
+                return Err("Equivocations' hash incorrect");
+            }
+
+            self.equivocations.iter().try_for_each(|evidence| {
+                if evidence.height() > self.header.height {
+                    return Err("Equivocation evidence is ahead of the block");
+                }
+
+                Ok(())
+            })?;
+
             Ok(())
         }
     }
 
+    impl EquivocationEvidenceCandidate {
+        fn validate(self) -> Result<EquivocationEvidence, &'static str> {
+            let evidence = EquivocationEvidence {
+                peer: self.peer,
+                first: self.first,
+                second: self.second,
+            };
+
+            #[cfg(not(target_family = "wasm"))]
+            evidence.verify()?;
+
+            Ok(evidence)
+        }
+    }
+
     impl SignedBlockCandidate {
         fn validate(self) -> Result<SignedBlockV1, &'static str> {
             #[cfg(not(target_family = "wasm"))]
@@ -462,8 +838,10 @@ mod candidate {
 
             Ok(SignedBlockV1 {
                 signatures: self.signatures,
+                aggregate_signature: self.aggregate_signature,
                 payload: self.payload,
                 errors: self.errors,
+                trigger_receipts: self.trigger_receipts,
             })
         }
 
@@ -474,13 +852,32 @@ mod candidate {
             #[cfg(feature = "std")]
             use std::collections::BTreeSet;
 
-            if self.signatures.is_empty() && self.payload.header.height.get() != 1 {
+            if self.signatures.is_empty()
+                && self.aggregate_signature.is_none()
+                && self.payload.header.height.get() != 1
+            {
                 return Err("Block missing signatures");
             }
 
+            if let Some(aggregate_signature) = &self.aggregate_signature {
+                // Trailing zero bytes would make the same set of signatories encodable in many ways
+                if aggregate_signature
+                    .signers
+                    .last()
+                    .map_or(true, |byte| *byte == 0)
+                {
+                    return Err("Aggregated signature has malformed signatories");
+                }
+            }
+
             self.signatures
                 .iter()
                 .map(|signature| signature.0)
+                .chain(
+                    self.aggregate_signature
+                        .iter()
+                        .flat_map(AggregateBlockSignature::signatories),
+                )
                 .try_fold(BTreeSet::new(), |mut acc, elem| {
                     if !acc.insert(elem) {
                         return Err("Duplicate signature in block");
@@ -500,6 +897,10 @@ mod candidate {
                 return Err("Genesis transaction must not contain errors");
             }
 
+            if !self.payload.equivocations.is_empty() {
+                return Err("Genesis block must not record equivocations");
+            }
+
             for transaction in transactions {
                 let Executable::Instructions(_) = transaction.instructions() else {
                     return Err("Genesis transaction must contain instructions");
@@ -530,6 +931,27 @@ mod candidate {
         }
     }
 
+    impl Decode for EquivocationEvidence {
+        fn decode<I: Input>(input: &mut I) -> Result<Self, parity_scale_codec::Error> {
+            EquivocationEvidenceCandidate::decode(input)?
+                .validate()
+                .map_err(Into::into)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for EquivocationEvidence {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            use serde::de::Error as _;
+
+            EquivocationEvidenceCandidate::deserialize(deserializer)?
+                .validate()
+                .map_err(D::Error::custom)
+        }
+    }
+
     impl Decode for super::BlockPayload {
         fn decode<I: Input>(input: &mut I) -> Result<Self, parity_scale_codec::Error> {
             BlockPayloadCandidate::decode(input)?
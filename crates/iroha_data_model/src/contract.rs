@@ -0,0 +1,83 @@
+//! Structures related to smart contracts deployed to the contract registry.
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+
+use derive_more::{Constructor, Display, FromStr};
+use getset::Getters;
+use iroha_crypto::HashOf;
+use iroha_data_model_derive::{model, IdEqOrdHash};
+use iroha_schema::IntoSchema;
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+pub use self::model::*;
+use crate::{account::AccountId, transaction::WasmSmartContract, Identifiable, Name};
+
+#[model]
+mod model {
+    use super::*;
+
+    /// Identification of a deployed [`Contract`].
+    #[derive(
+        Debug,
+        Display,
+        FromStr,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        DeserializeFromStr,
+        SerializeDisplay,
+        IntoSchema,
+    )]
+    #[display(fmt = "{name}")]
+    #[getset(get = "pub")]
+    #[repr(transparent)]
+    #[ffi_type(opaque)]
+    pub struct ContractId {
+        /// Name given to the contract by its deployer.
+        pub name: Name,
+    }
+
+    /// Smart contract deployed to the contract registry.
+    ///
+    /// Only the hash of the module is kept here, the module itself is shared
+    /// between all contracts and triggers with the same code.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        IdEqOrdHash,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{id}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct Contract {
+        /// Identification of the contract.
+        pub id: ContractId,
+        /// Account which deployed the contract.
+        pub owner: AccountId,
+        /// Hash of the WASM module of the contract.
+        pub code_hash: HashOf<WasmSmartContract>,
+    }
+}
+
+/// The prelude re-exports most commonly used traits, structs and macros from this crate.
+pub mod prelude {
+    pub use super::{Contract, ContractId};
+}
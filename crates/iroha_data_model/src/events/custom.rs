@@ -0,0 +1,128 @@
+//! Custom events emitted by smart contracts and triggers and their filter
+
+use getset::Getters;
+use iroha_data_model_derive::model;
+
+pub use self::model::*;
+use super::*;
+use crate::prelude::*;
+
+#[model]
+mod model {
+    use super::*;
+
+    /// Custom event. Produced every time the `EmitEvent` instruction is executed.
+    ///
+    /// Type of the payload is defined by the executor data model under the event name.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct CustomEvent {
+        /// Name of the event type
+        pub name: Name,
+        /// Account on behalf of which the event was emitted
+        pub emitter: AccountId,
+        /// Payload of the event
+        #[getset(skip)]
+        pub payload: Json,
+    }
+
+    /// Filter for [`CustomEvent`]
+    #[derive(
+        Debug,
+        Clone,
+        PartialOrd,
+        Ord,
+        PartialEq,
+        Eq,
+        Default,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub struct CustomEventFilter {
+        /// Name of the event type to catch
+        pub(super) name: Option<Name>,
+        /// Account the event is emitted on behalf of
+        pub(super) emitter: Option<AccountId>,
+    }
+}
+
+impl CustomEvent {
+    /// Payload of the event
+    pub fn payload(&self) -> &Json {
+        &self.payload
+    }
+}
+
+impl CustomEventFilter {
+    /// Creates a new [`CustomEventFilter`] accepting all [`CustomEvent`]s
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            name: None,
+            emitter: None,
+        }
+    }
+
+    /// Modifies a [`CustomEventFilter`] to accept only [`CustomEvent`]s of a specific type
+    #[must_use]
+    #[inline]
+    pub fn for_event(mut self, name: Name) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Modifies a [`CustomEventFilter`] to accept only [`CustomEvent`]s emitted on behalf of a specific account
+    #[must_use]
+    #[inline]
+    pub fn from_emitter(mut self, emitter: AccountId) -> Self {
+        self.emitter = Some(emitter);
+        self
+    }
+}
+
+#[cfg(feature = "transparent_api")]
+impl EventFilter for CustomEventFilter {
+    type Event = CustomEvent;
+
+    /// Check if `event` matches filter
+    ///
+    /// Event considered as matched if names and emitters are equal
+    fn matches(&self, event: &CustomEvent) -> bool {
+        if let Some(name) = &self.name {
+            if name != &event.name {
+                return false;
+            }
+        }
+        if let Some(emitter) = &self.emitter {
+            if emitter != &event.emitter {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Exports common structs and enums from this module.
+pub mod prelude {
+    pub use super::{CustomEvent, CustomEventFilter};
+}
@@ -91,6 +91,8 @@ mod model {
         Configuration(config::ConfigurationEvent),
         /// Executor event
         Executor(executor::ExecutorEvent),
+        /// Contract event
+        Contract(contract::ContractEvent),
     }
 }
 
@@ -267,13 +269,21 @@ mod nft {
 mod peer {
     //! This module contains `PeerEvent` and its impls
 
+    #[cfg(not(feature = "std"))]
+    use alloc::boxed::Box;
+
     use super::*;
+    use crate::block::EquivocationEvidence;
 
     data_event! {
         #[has_origin(origin = Peer)]
         pub enum PeerEvent {
             Added(PeerId),
             Removed(PeerId),
+            /// Peer was caught signing conflicting blocks.
+            /// The evidence is recorded in the block which emitted this event.
+            #[has_origin(evidence => evidence.peer())]
+            Equivocated(Box<EquivocationEvidence>),
         }
     }
 }
@@ -361,6 +371,12 @@ mod account {
             MetadataInserted(AccountMetadataChanged),
             #[has_origin(metadata_changed => &metadata_changed.target)]
             MetadataRemoved(AccountMetadataChanged),
+            #[has_origin(keys_changed => &keys_changed.account)]
+            KeysChanged(AccountKeysChanged),
+            #[has_origin(alias_changed => &alias_changed.account)]
+            AliasAdded(AccountAliasChanged),
+            #[has_origin(alias_changed => &alias_changed.account)]
+            AliasRemoved(AccountAliasChanged),
         }
     }
 
@@ -413,6 +429,50 @@ mod account {
             pub account: AccountId,
             pub role: RoleId,
         }
+
+        /// Event indicating that the set of keys authorized to sign on behalf of the account has changed
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct AccountKeysChanged {
+            pub account: AccountId,
+            pub keys: AccountKeys,
+        }
+
+        /// Depending on the wrapping event, [`AccountAliasChanged`] represents the added or removed account alias
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct AccountAliasChanged {
+            pub account: AccountId,
+            pub alias: AccountAlias,
+        }
     }
 
     impl AccountPermissionChanged {
@@ -503,6 +563,8 @@ mod trigger {
             MetadataInserted(TriggerMetadataChanged),
             #[has_origin(metadata_changed => &metadata_changed.target)]
             MetadataRemoved(TriggerMetadataChanged),
+            #[has_origin(trigger_upgraded => &trigger_upgraded.trigger)]
+            Upgraded(TriggerUpgraded),
         }
     }
 
@@ -531,6 +593,83 @@ mod trigger {
             pub trigger: TriggerId,
             pub by: u32,
         }
+
+        /// Executable of the trigger was replaced.
+        ///
+        /// Hashes of the WASM modules are recorded for audit,
+        /// `None` means that the executable consists of instructions.
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct TriggerUpgraded {
+            pub trigger: TriggerId,
+            pub prev_module_hash: Option<HashOf<WasmSmartContract>>,
+            pub new_module_hash: Option<HashOf<WasmSmartContract>>,
+        }
+    }
+}
+
+mod contract {
+    //! This module contains `ContractEvent` and its impls
+
+    use iroha_data_model_derive::model;
+
+    pub use self::model::*;
+    use super::*;
+
+    data_event! {
+        #[has_origin(origin = Contract)]
+        pub enum ContractEvent {
+            #[has_origin(contract => contract.id())]
+            Deployed(Contract),
+            Removed(ContractId),
+            #[has_origin(contract_called => &contract_called.contract)]
+            Called(ContractCalled),
+        }
+    }
+
+    #[model]
+    mod model {
+        use super::*;
+
+        /// Result of a successful contract call.
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct ContractCalled {
+            /// Id of the called contract
+            pub contract: ContractId,
+            /// Account which called the contract
+            pub caller: AccountId,
+            /// Value returned by the contract
+            pub output: Json,
+        }
     }
 }
 
@@ -689,6 +828,7 @@ impl DataEvent {
         match self {
             Self::Domain(event) => Some(event.origin()),
             Self::Configuration(_)
+            | Self::Contract(_)
             | Self::Executor(_)
             | Self::Peer(_)
             | Self::Role(_)
@@ -716,19 +856,25 @@ impl<Id> MetadataChanged<Id> {
 
 pub mod prelude {
     pub use super::{
-        account::{AccountEvent, AccountEventSet, AccountPermissionChanged, AccountRoleChanged},
+        account::{
+            AccountAliasChanged, AccountEvent, AccountEventSet, AccountKeysChanged,
+            AccountPermissionChanged, AccountRoleChanged,
+        },
         asset::{
             AssetChanged, AssetDefinitionEvent, AssetDefinitionEventSet,
             AssetDefinitionOwnerChanged, AssetDefinitionTotalQuantityChanged, AssetEvent,
             AssetEventSet,
         },
         config::{ConfigurationEvent, ConfigurationEventSet, ParameterChanged},
+        contract::{ContractCalled, ContractEvent, ContractEventSet},
         domain::{DomainEvent, DomainEventSet, DomainOwnerChanged},
         executor::{ExecutorEvent, ExecutorEventSet, ExecutorUpgrade},
         nft::{NftEvent, NftEventSet, NftOwnerChanged},
         peer::{PeerEvent, PeerEventSet},
         role::{RoleEvent, RoleEventSet, RolePermissionChanged},
-        trigger::{TriggerEvent, TriggerEventSet, TriggerNumberOfExecutionsChanged},
+        trigger::{
+            TriggerEvent, TriggerEventSet, TriggerNumberOfExecutionsChanged, TriggerUpgraded,
+        },
         DataEvent, HasOrigin, MetadataChanged,
     };
 }
@@ -53,6 +53,8 @@ mod model {
         Configuration(ConfigurationEventFilter),
         /// Matches [`ExecutorEvent`]s
         Executor(ExecutorEventFilter),
+        /// Matches [`ContractEvent`]s
+        Contract(ContractEventFilter),
     }
 
     /// An event filter for [`PeerEvent`]s
@@ -271,6 +273,28 @@ mod model {
         /// Matches only event from this set
         pub(super) event_set: ExecutorEventSet,
     }
+
+    /// An event filter for [`ContractEvent`]s
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub struct ContractEventFilter {
+        /// If specified matches only events originating from this contract
+        pub(super) id_matcher: Option<super::ContractId>,
+        /// Matches only event from this set
+        pub(super) event_set: ContractEventSet,
+    }
 }
 
 impl PeerEventFilter {
@@ -735,6 +759,55 @@ impl super::EventFilter for ExecutorEventFilter {
     }
 }
 
+impl ContractEventFilter {
+    /// Creates a new [`ContractEventFilter`] accepting all [`ContractEvent`]s.
+    pub const fn new() -> Self {
+        Self {
+            id_matcher: None,
+            event_set: ContractEventSet::all(),
+        }
+    }
+
+    /// Modifies a [`ContractEventFilter`] to accept only [`ContractEvent`]s originating from ids matching `id_matcher`.
+    #[must_use]
+    pub fn for_contract(mut self, id_matcher: ContractId) -> Self {
+        self.id_matcher = Some(id_matcher);
+        self
+    }
+
+    /// Modifies a [`ContractEventFilter`] to accept only [`ContractEvent`]s of types matching `event_set`.
+    #[must_use]
+    pub const fn for_events(mut self, event_set: ContractEventSet) -> Self {
+        self.event_set = event_set;
+        self
+    }
+}
+
+impl Default for ContractEventFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "transparent_api")]
+impl super::EventFilter for ContractEventFilter {
+    type Event = super::ContractEvent;
+
+    fn matches(&self, event: &Self::Event) -> bool {
+        if let Some(id_matcher) = &self.id_matcher {
+            if id_matcher != event.origin() {
+                return false;
+            }
+        }
+
+        if !self.event_set.matches(event) {
+            return false;
+        }
+
+        true
+    }
+}
+
 #[cfg(feature = "transparent_api")]
 impl EventFilter for DataEventFilter {
     type Event = DataEvent;
@@ -761,6 +834,7 @@ impl EventFilter for DataEventFilter {
             (DataEvent::Role(event), Role(filter)) => filter.matches(event),
             (DataEvent::Configuration(event), Configuration(filter)) => filter.matches(event),
             (DataEvent::Executor(event), Executor(filter)) => filter.matches(event),
+            (DataEvent::Contract(event), Contract(filter)) => filter.matches(event),
 
             (
                 DataEvent::Peer(_)
@@ -768,7 +842,8 @@ impl EventFilter for DataEventFilter {
                 | DataEvent::Trigger(_)
                 | DataEvent::Role(_)
                 | DataEvent::Configuration(_)
-                | DataEvent::Executor(_),
+                | DataEvent::Executor(_)
+                | DataEvent::Contract(_),
                 Any,
             ) => true,
             (
@@ -777,7 +852,8 @@ impl EventFilter for DataEventFilter {
                 | DataEvent::Trigger(_)
                 | DataEvent::Role(_)
                 | DataEvent::Configuration(_)
-                | DataEvent::Executor(_),
+                | DataEvent::Executor(_)
+                | DataEvent::Contract(_),
                 _,
             ) => false,
         }
@@ -787,8 +863,8 @@ impl EventFilter for DataEventFilter {
 pub mod prelude {
     pub use super::{
         AccountEventFilter, AssetDefinitionEventFilter, AssetEventFilter, ConfigurationEventFilter,
-        DataEventFilter, DomainEventFilter, ExecutorEventFilter, NftEventFilter, PeerEventFilter,
-        RoleEventFilter, TriggerEventFilter,
+        ContractEventFilter, DataEventFilter, DomainEventFilter, ExecutorEventFilter,
+        NftEventFilter, PeerEventFilter, RoleEventFilter, TriggerEventFilter,
     };
 }
 #[cfg(test)]
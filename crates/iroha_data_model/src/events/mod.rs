@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 
 pub use self::model::*;
 
+pub mod custom;
 pub mod data;
 pub mod execute_trigger;
 pub mod pipeline;
@@ -49,6 +50,8 @@ mod model {
         ExecuteTrigger(execute_trigger::ExecuteTriggerEvent),
         /// Trigger completion event.
         TriggerCompleted(trigger_completed::TriggerCompletedEvent),
+        /// Custom event.
+        Custom(custom::CustomEvent),
     }
 
     /// Event type which could invoke trigger execution.
@@ -64,6 +67,8 @@ mod model {
         Time,
         /// Trigger execution event.
         ExecuteTrigger,
+        /// Custom event.
+        Custom,
     }
 
     /// Event filter.
@@ -95,6 +100,8 @@ mod model {
         ExecuteTrigger(execute_trigger::ExecuteTriggerEventFilter),
         /// Listen to trigger completion event with filter.
         TriggerCompleted(trigger_completed::TriggerCompletedEventFilter),
+        /// Listen to custom events with filter.
+        Custom(custom::CustomEventFilter),
     }
 }
 
@@ -186,18 +193,21 @@ impl EventFilter for EventFilterBox {
             (EventBox::TriggerCompleted(event), Self::TriggerCompleted(filter)) => {
                 filter.matches(event)
             }
+            (EventBox::Custom(event), Self::Custom(filter)) => filter.matches(event),
             // Fail to compile in case when new variant to event or filter is added
             (
                 EventBox::Pipeline(_)
                 | EventBox::Data(_)
                 | EventBox::Time(_)
                 | EventBox::ExecuteTrigger(_)
-                | EventBox::TriggerCompleted(_),
+                | EventBox::TriggerCompleted(_)
+                | EventBox::Custom(_),
                 Self::Pipeline(_)
                 | Self::Data(_)
                 | Self::Time(_)
                 | Self::ExecuteTrigger(_)
-                | Self::TriggerCompleted(_),
+                | Self::TriggerCompleted(_)
+                | Self::Custom(_),
             ) => false,
         }
     }
@@ -245,6 +255,7 @@ mod conversions {
         RoleEventFilter             => DataEventFilter => EventFilterBox,
         ConfigurationEventFilter    => DataEventFilter => EventFilterBox,
         ExecutorEventFilter         => DataEventFilter => EventFilterBox,
+        ContractEventFilter         => DataEventFilter => EventFilterBox,
 
         TransactionEventFilter => PipelineEventFilterBox => EventFilterBox,
         BlockEventFilter       => PipelineEventFilterBox => EventFilterBox,
@@ -293,7 +304,8 @@ pub mod prelude {
     #[cfg(feature = "transparent_api")]
     pub use super::EventFilter;
     pub use super::{
-        data::prelude::*, execute_trigger::prelude::*, pipeline::prelude::*, time::prelude::*,
-        trigger_completed::prelude::*, EventBox, EventFilterBox, TriggeringEventType,
+        custom::prelude::*, data::prelude::*, execute_trigger::prelude::*, pipeline::prelude::*,
+        time::prelude::*, trigger_completed::prelude::*, EventBox, EventFilterBox,
+        TriggeringEventType,
     };
 }
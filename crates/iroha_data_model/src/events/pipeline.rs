@@ -357,8 +357,12 @@ mod tests {
                 height,
                 prev_block_hash: None,
                 transactions_hash: Some(transactions_hash),
+                prev_trigger_receipts_hash: None,
                 creation_time_ms: 0,
                 view_change_index: 0,
+                randomness: None,
+                equivocations_hash: None,
+                validator_set_hash: None,
             }
         }
     }
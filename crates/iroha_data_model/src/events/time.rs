@@ -1,5 +1,5 @@
 //! Time event and filter
-use core::{ops::Range, time::Duration};
+use core::{num::NonZeroU32, ops::Range, str::FromStr, time::Duration};
 
 use derive_more::Constructor;
 use getset::Getters;
@@ -7,9 +7,21 @@ use iroha_data_model_derive::model;
 
 pub use self::model::*;
 use super::*;
+use crate::ParseError;
+
+#[cfg(feature = "transparent_api")]
+const MS_PER_MINUTE: u64 = 60_000;
+#[cfg(feature = "transparent_api")]
+const MINUTES_PER_DAY: u64 = 24 * 60;
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const DAY_OF_WEEK_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
 
 #[model]
 mod model {
+    use serde_with::{DeserializeFromStr, SerializeDisplay};
+
     use super::*;
 
     /// Special event that is emitted when state is ready for handling time-triggers
@@ -80,6 +92,34 @@ mod model {
     }
 
     /// Schedule of the trigger
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Encode, Serialize, IntoSchema)]
+    pub struct Schedule {
+        /// The first execution time
+        pub start_ms: u64,
+        /// If some, the period between cyclic executions
+        pub period_ms: Option<u64>,
+        /// If some, executions happen at the minutes matching the cron expression
+        /// which are not earlier than the first execution time.
+        /// Can't be combined with the period
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub cron: Option<CronExpression>,
+        /// If some, the time from which on there are no executions
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub end_ms: Option<u64>,
+        /// If some, the maximal number of executions counted from the first execution time
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub max_occurrences: Option<NonZeroU32>,
+    }
+
+    /// Cron expression matching minutes in UTC
+    ///
+    /// Consists of five space separated fields: minute (0-59), hour (0-23),
+    /// day of month (1-31 or `L` for the last day of the month),
+    /// month (1-12 or `JAN`-`DEC`) and day of week (0-7 or `SUN`-`SAT`, both 0 and 7 are Sunday).
+    /// Every field is a comma separated list of `*`, values, ranges `a-b`
+    /// and steps `*/n`, `a-b/n` or `a/n`.
+    /// If both day of month and day of week don't match every day,
+    /// a day matching either of them is matched.
     #[derive(
         Debug,
         Clone,
@@ -88,17 +128,24 @@ mod model {
         Eq,
         PartialOrd,
         Ord,
-        Decode,
         Encode,
-        Deserialize,
-        Serialize,
+        DeserializeFromStr,
+        SerializeDisplay,
         IntoSchema,
     )]
-    pub struct Schedule {
-        /// The first execution time
-        pub start_ms: u64,
-        /// If some, the period between cyclic executions
-        pub period_ms: Option<u64>,
+    pub struct CronExpression {
+        /// Bit `n` is set if minute `n` matches
+        pub(super) minutes: u64,
+        /// Bit `n` is set if hour `n` matches
+        pub(super) hours: u32,
+        /// Bit `n` is set if day `n` of month matches
+        pub(super) days_of_month: u32,
+        /// Whether the last day of month matches
+        pub(super) last_day_of_month: bool,
+        /// Bit `n` is set if month `n` matches
+        pub(super) months: u16,
+        /// Bit `n` is set if day `n` of week matches, Sunday being 0
+        pub(super) days_of_week: u8,
     }
 
     /// Time interval in which `TimeAction` should appear
@@ -149,59 +196,29 @@ impl EventFilter for TimeEventFilter {
             self.0,
             ExecutionTime::Schedule(Schedule {
                 period_ms: None,
+                cron: None,
                 ..
             })
         )
     }
 }
 
-/// Count something with the `schedule` within the `interval`
+/// Count executions of the `schedule` within the `interval`
 #[cfg(feature = "transparent_api")]
 fn count_matches_in_interval(schedule: &Schedule, interval: &TimeInterval) -> u32 {
-    schedule.period().map_or_else(
-        || u32::from(Range::from(*interval).contains(&schedule.start())),
-        |period| {
-            #[allow(clippy::integer_division)]
-            let k = interval
-                .since()
-                .saturating_sub(schedule.start())
-                .as_millis()
-                / period.as_millis();
-            let start = schedule.start() + multiply_duration_by_u128(period, k);
-            let range = Range::from(*interval);
-            (0..)
-                .map(|i| start + period * i)
-                .skip_while(|time| *time < interval.since())
-                .take_while(|time| range.contains(time))
-                .count()
-                .try_into()
-                .expect("Overflow. The schedule is too frequent relative to the interval length")
-        },
-    )
-}
-
-/// Multiply `duration` by `n`
-///
-/// Usage of this function allows to operate with much longer time *intervals*
-/// with much less *periods* than just `impl Mul<u32> for Duration` does
-///
-/// # Panics
-/// Panics if resulting number in seconds can't be represented as `u64`
-#[cfg(feature = "transparent_api")]
-fn multiply_duration_by_u128(duration: Duration, n: u128) -> Duration {
-    if let Ok(n) = u32::try_from(n) {
-        return duration * n;
-    }
-
-    let new_ms = duration.as_millis() * n;
-    if let Ok(ms) = u64::try_from(new_ms) {
-        return Duration::from_millis(ms);
-    }
-
-    #[allow(clippy::integer_division)]
-    let new_secs = u64::try_from(new_ms / 1000)
-        .expect("Overflow. Resulting number in seconds can't be represented as `u64`");
-    Duration::from_secs(new_secs)
+    let since_ms = interval.since_ms;
+    let until_ms = since_ms.saturating_add(interval.length_ms);
+    let limit = schedule
+        .max_occurrences
+        .map_or(u64::MAX, |max_occurrences| {
+            let max_occurrences = u64::from(max_occurrences.get());
+            max_occurrences - schedule.count_between(schedule.start_ms, since_ms, max_occurrences)
+        });
+
+    schedule
+        .count_between(since_ms, until_ms, limit)
+        .try_into()
+        .expect("Overflow. The schedule is too frequent relative to the interval length")
 }
 
 impl Schedule {
@@ -215,10 +232,13 @@ impl Schedule {
                 .try_into()
                 .expect("INTERNAL BUG: Unix timestamp exceedes u64::MAX"),
             period_ms: None,
+            cron: None,
+            end_ms: None,
+            max_occurrences: None,
         }
     }
 
-    /// Add `period` to `self`
+    /// Add `period` to `self` replacing the cron expression
     #[must_use]
     #[inline]
     pub fn with_period(mut self, period: Duration) -> Self {
@@ -228,6 +248,36 @@ impl Schedule {
                 .try_into()
                 .expect("INTERNAL BUG: Unix timestamp exceedes u64::MAX"),
         );
+        self.cron = None;
+        self
+    }
+
+    /// Add `cron` expression to `self` replacing the period
+    #[must_use]
+    #[inline]
+    pub fn with_cron(mut self, cron: CronExpression) -> Self {
+        self.cron = Some(cron);
+        self.period_ms = None;
+        self
+    }
+
+    /// Stop executions at `end`
+    #[must_use]
+    #[inline]
+    pub fn until(mut self, end: Duration) -> Self {
+        self.end_ms = Some(
+            end.as_millis()
+                .try_into()
+                .expect("INTERNAL BUG: Unix timestamp exceedes u64::MAX"),
+        );
+        self
+    }
+
+    /// Limit the number of executions to `max_occurrences`
+    #[must_use]
+    #[inline]
+    pub fn with_max_occurrences(mut self, max_occurrences: NonZeroU32) -> Self {
+        self.max_occurrences = Some(max_occurrences);
         self
     }
 
@@ -240,6 +290,385 @@ impl Schedule {
     pub fn period(&self) -> Option<Duration> {
         self.period_ms.map(Duration::from_millis)
     }
+
+    /// Instant from which on there are no executions
+    pub fn end(&self) -> Option<Duration> {
+        self.end_ms.map(Duration::from_millis)
+    }
+
+    /// Count executions within `[from_ms, to_ms)`, but not more than `limit`
+    #[cfg(feature = "transparent_api")]
+    fn count_between(&self, from_ms: u64, to_ms: u64, limit: u64) -> u64 {
+        let from_ms = from_ms.max(self.start_ms);
+        let to_ms = self.end_ms.map_or(to_ms, |end_ms| end_ms.min(to_ms));
+        if from_ms >= to_ms {
+            return 0;
+        }
+
+        let count = match (self.cron, self.period_ms) {
+            (Some(cron), _) => cron.count_between(from_ms, to_ms, limit),
+            (None, Some(period_ms)) => {
+                // Executions happen at `start_ms + k * period_ms`
+                let executions_before =
+                    |time_ms: u64| (time_ms - self.start_ms).div_ceil(period_ms);
+                executions_before(to_ms) - executions_before(from_ms)
+            }
+            (None, None) => u64::from((from_ms..to_ms).contains(&self.start_ms)),
+        };
+
+        count.min(limit)
+    }
+}
+
+impl CronExpression {
+    /// Count minutes matching `self` within `[from_ms, to_ms)`, but not more than `limit`
+    #[cfg(feature = "transparent_api")]
+    fn count_between(&self, from_ms: u64, to_ms: u64, limit: u64) -> u64 {
+        let first_minute = from_ms.div_ceil(MS_PER_MINUTE);
+        let end_minute = to_ms.div_ceil(MS_PER_MINUTE);
+
+        let mut count = 0;
+        let mut day = first_minute / MINUTES_PER_DAY;
+        while day * MINUTES_PER_DAY < end_minute && count < limit {
+            let (year, month, day_of_month) = civil_from_days(day);
+            let last_day_of_month = days_in_month(year, month);
+            if self.months & (1 << month) == 0 {
+                // Skip to the first day of the next month
+                day += last_day_of_month - day_of_month + 1;
+                continue;
+            }
+
+            // 1970-01-01 was Thursday
+            let day_of_week = (day + 4) % 7;
+            if self.matches_day(day_of_month, last_day_of_month, day_of_week) {
+                let day_start = day * MINUTES_PER_DAY;
+                let since = first_minute.max(day_start) - day_start;
+                let until = end_minute.min(day_start + MINUTES_PER_DAY) - day_start;
+                count += self.count_minutes_of_day(since, until);
+            }
+            day += 1;
+        }
+
+        count.min(limit)
+    }
+
+    #[cfg(feature = "transparent_api")]
+    fn matches_day(&self, day_of_month: u64, last_day_of_month: u64, day_of_week: u64) -> bool {
+        let by_month = self.days_of_month & (1 << day_of_month) != 0
+            || (self.last_day_of_month && day_of_month == last_day_of_month);
+        let by_week = self.days_of_week & (1 << day_of_week) != 0;
+
+        let every_day_of_month =
+            !self.last_day_of_month && u64::from(self.days_of_month) == bits_between(1, 32);
+        let every_day_of_week = u64::from(self.days_of_week) == bits_between(0, 7);
+        if every_day_of_month || every_day_of_week {
+            by_month && by_week
+        } else {
+            by_month || by_week
+        }
+    }
+
+    /// Count matching minutes within `[since, until)` minutes after the midnight of a matching day
+    #[cfg(feature = "transparent_api")]
+    fn count_minutes_of_day(&self, since: u64, until: u64) -> u64 {
+        (0..24_u64)
+            .filter(|hour| self.hours & (1 << hour) != 0)
+            .map(|hour| {
+                let hour_start = hour * 60;
+                let from = since.clamp(hour_start, hour_start + 60) - hour_start;
+                let to = until.clamp(hour_start, hour_start + 60) - hour_start;
+                u64::from((self.minutes & bits_between(from, to)).count_ones())
+            })
+            .sum()
+    }
+}
+
+impl FromStr for CronExpression {
+    type Err = ParseError;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        let mut fields = expression.split_whitespace();
+        let mut next_field = || {
+            fields.next().ok_or(ParseError {
+                reason: "Cron expression must consist of 5 fields",
+            })
+        };
+
+        let minutes = parse_cron_field(next_field()?, 0, 59, &[])?;
+        let hours = parse_cron_field(next_field()?, 0, 23, &[])?;
+        let day_of_month_field = next_field()?;
+        let months = parse_cron_field(next_field()?, 1, 12, &MONTH_NAMES)?;
+        let days_of_week = parse_cron_field(next_field()?, 0, 7, &DAY_OF_WEEK_NAMES)?;
+        if fields.next().is_some() {
+            return Err(ParseError {
+                reason: "Cron expression must consist of 5 fields",
+            });
+        }
+
+        let last_day_of_month = day_of_month_field.split(',').any(|item| item == "L");
+        let days_of_month = day_of_month_field
+            .split(',')
+            .filter(|item| *item != "L")
+            .try_fold(0, |mask, item| {
+                parse_cron_field(item, 1, 31, &[]).map(|days| mask | days)
+            })?;
+
+        Ok(Self {
+            minutes,
+            hours: hours.try_into().expect("Hours are within 0-23"),
+            days_of_month: days_of_month
+                .try_into()
+                .expect("Days of month are within 1-31"),
+            last_day_of_month,
+            months: months.try_into().expect("Months are within 1-12"),
+            // Sunday is both 0 and 7
+            days_of_week: ((days_of_week | days_of_week >> 7) & bits_between(0, 7))
+                .try_into()
+                .expect("Days of week are within 0-6"),
+        })
+    }
+}
+
+impl core::fmt::Display for CronExpression {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_cron_field(f, self.minutes, 0, 59, false)?;
+        f.write_str(" ")?;
+        fmt_cron_field(f, self.hours.into(), 0, 23, false)?;
+        f.write_str(" ")?;
+        fmt_cron_field(f, self.days_of_month.into(), 1, 31, self.last_day_of_month)?;
+        f.write_str(" ")?;
+        fmt_cron_field(f, self.months.into(), 1, 12, false)?;
+        f.write_str(" ")?;
+        fmt_cron_field(f, self.days_of_week.into(), 0, 6, false)
+    }
+}
+
+/// Parse a comma separated list of cron field items into a mask of values within `[min, max]`
+///
+/// Value `min + n` can be written as `names[n]`
+fn parse_cron_field(field: &str, min: u64, max: u64, names: &[&str]) -> Result<u64, ParseError> {
+    let parse_value = |value: &str| {
+        names
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(value))
+            .map(|position| min + position as u64)
+            .or_else(|| value.parse().ok())
+            .ok_or(ParseError {
+                reason: "Invalid value in cron expression",
+            })
+    };
+
+    field
+        .split(',')
+        .try_fold(0, |mut mask: u64, item| -> Result<u64, ParseError> {
+            let (range, step) = match item.split_once('/') {
+                Some((range, step)) => (range, Some(step)),
+                None => (item, None),
+            };
+            let step = step.map_or(Ok(1), |step| {
+                step.parse::<usize>()
+                    .ok()
+                    .filter(|step| *step > 0)
+                    .ok_or(ParseError {
+                        reason: "Invalid step in cron expression",
+                    })
+            })?;
+            let (first, last) = if range == "*" {
+                (min, max)
+            } else if let Some((first, last)) = range.split_once('-') {
+                (parse_value(first)?, parse_value(last)?)
+            } else {
+                let value = parse_value(range)?;
+                (value, if step > 1 { max } else { value })
+            };
+            if first < min || last > max || first > last {
+                return Err(ParseError {
+                    reason: "Value out of range in cron expression",
+                });
+            }
+
+            for value in (first..=last).step_by(step) {
+                mask |= 1 << value;
+            }
+            Ok(mask)
+        })
+}
+
+/// Write `mask` of values within `[min, max]` as a comma separated list of values and ranges
+fn fmt_cron_field(
+    f: &mut core::fmt::Formatter<'_>,
+    mask: u64,
+    min: u64,
+    max: u64,
+    last_day_of_month: bool,
+) -> core::fmt::Result {
+    if !last_day_of_month && mask == bits_between(min, max + 1) {
+        return f.write_str("*");
+    }
+
+    let mut separator = "";
+    let mut value = min;
+    while value <= max {
+        if mask & (1 << value) == 0 {
+            value += 1;
+            continue;
+        }
+
+        let first = value;
+        while value < max && mask & (1 << (value + 1)) != 0 {
+            value += 1;
+        }
+        write!(f, "{separator}{first}")?;
+        if value > first {
+            write!(f, "-{value}")?;
+        }
+        separator = ",";
+        value += 1;
+    }
+    if last_day_of_month {
+        write!(f, "{separator}L")?;
+    }
+
+    Ok(())
+}
+
+/// Mask with bits `[from, to)` set
+const fn bits_between(from: u64, to: u64) -> u64 {
+    ((1 << to) - 1) & !((1 << from) - 1)
+}
+
+/// Convert days since the Unix epoch into the `(year, month, day)` date
+#[cfg(feature = "transparent_api")]
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    // See http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    (year, month, day)
+}
+
+/// Number of days in the `month` of the `year`
+#[cfg(feature = "transparent_api")]
+const fn days_in_month(year: u64, month: u64) -> u64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+mod candidate {
+    use parity_scale_codec::Input;
+
+    use super::*;
+
+    #[derive(Decode, Deserialize)]
+    struct ScheduleCandidate {
+        start_ms: u64,
+        period_ms: Option<u64>,
+        #[serde(default)]
+        cron: Option<CronExpression>,
+        #[serde(default)]
+        end_ms: Option<u64>,
+        #[serde(default)]
+        max_occurrences: Option<NonZeroU32>,
+    }
+
+    impl ScheduleCandidate {
+        fn validate(self) -> Result<Schedule, &'static str> {
+            if self.period_ms == Some(0) {
+                return Err("Schedule period must be positive");
+            }
+            if self.period_ms.is_some() && self.cron.is_some() {
+                return Err("Schedule can't have both period and cron expression");
+            }
+
+            Ok(Schedule {
+                start_ms: self.start_ms,
+                period_ms: self.period_ms,
+                cron: self.cron,
+                end_ms: self.end_ms,
+                max_occurrences: self.max_occurrences,
+            })
+        }
+    }
+
+    impl Decode for Schedule {
+        fn decode<I: Input>(input: &mut I) -> Result<Self, parity_scale_codec::Error> {
+            ScheduleCandidate::decode(input)?
+                .validate()
+                .map_err(Into::into)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Schedule {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            use serde::de::Error as _;
+
+            ScheduleCandidate::deserialize(deserializer)?
+                .validate()
+                .map_err(D::Error::custom)
+        }
+    }
+
+    #[derive(Decode)]
+    struct CronExpressionCandidate {
+        minutes: u64,
+        hours: u32,
+        days_of_month: u32,
+        last_day_of_month: bool,
+        months: u16,
+        days_of_week: u8,
+    }
+
+    impl CronExpressionCandidate {
+        fn validate(self) -> Result<CronExpression, &'static str> {
+            let within = |mask: u64, from, to| mask != 0 && mask & !bits_between(from, to) == 0;
+            let days_of_month = u64::from(self.days_of_month);
+
+            if !within(self.minutes, 0, 60)
+                || !within(self.hours.into(), 0, 24)
+                || !(within(days_of_month, 1, 32) || (days_of_month == 0 && self.last_day_of_month))
+                || !within(self.months.into(), 1, 13)
+                || !within(self.days_of_week.into(), 0, 7)
+            {
+                return Err("Cron expression field is empty or out of range");
+            }
+
+            Ok(CronExpression {
+                minutes: self.minutes,
+                hours: self.hours,
+                days_of_month: self.days_of_month,
+                last_day_of_month: self.last_day_of_month,
+                months: self.months,
+                days_of_week: self.days_of_week,
+            })
+        }
+    }
+
+    impl Decode for CronExpression {
+        fn decode<I: Input>(input: &mut I) -> Result<Self, parity_scale_codec::Error> {
+            CronExpressionCandidate::decode(input)?
+                .validate()
+                .map_err(Into::into)
+        }
+    }
 }
 
 impl TimeInterval {
@@ -284,7 +713,8 @@ impl From<TimeInterval> for Range<Duration> {
 /// Exports common structs and enums from this module.
 pub mod prelude {
     pub use super::{
-        ExecutionTime, Schedule as TimeSchedule, TimeEvent, TimeEventFilter, TimeInterval,
+        CronExpression, ExecutionTime, Schedule as TimeSchedule, TimeEvent, TimeEventFilter,
+        TimeInterval,
     };
 }
 
@@ -533,4 +963,125 @@ mod tests {
             assert_eq!(filter.count_matches(&event), 0);
         }
     }
+
+    // Tests for cron schedules
+    mod cron {
+        use super::*;
+
+        /// 2025-01-01T00:00:00Z, Wednesday
+        const NEW_YEAR_2025_MS: u64 = 1_735_689_600_000;
+        const DAY_MS: u64 = 86_400_000;
+        const HOUR_MS: u64 = 3_600_000;
+
+        fn count(schedule: &Schedule, since_ms: u64, to_ms: u64) -> u32 {
+            let interval = TimeInterval::new_since_to(
+                Duration::from_millis(since_ms),
+                Duration::from_millis(to_ms),
+            );
+            count_matches_in_interval(schedule, &interval)
+        }
+
+        fn cron_schedule(expression: &str) -> Schedule {
+            Schedule::starting_at(Duration::ZERO).with_cron(expression.parse().unwrap())
+        }
+
+        #[test]
+        fn test_weekdays_morning() {
+            let schedule = cron_schedule("0 9 * * MON-FRI");
+            // 2025-01-06 is Monday
+            let monday_ms = NEW_YEAR_2025_MS + 5 * DAY_MS;
+            assert_eq!(count(&schedule, monday_ms, monday_ms + 7 * DAY_MS), 5);
+            assert_eq!(count(&schedule, monday_ms, monday_ms + 9 * HOUR_MS), 0);
+            assert_eq!(
+                count(&schedule, monday_ms + 9 * HOUR_MS, monday_ms + DAY_MS),
+                1
+            );
+        }
+
+        #[test]
+        fn test_last_day_of_month() {
+            let schedule = cron_schedule("0 0 L * *");
+            assert_eq!(
+                count(&schedule, NEW_YEAR_2025_MS, NEW_YEAR_2025_MS + 365 * DAY_MS),
+                12
+            );
+
+            // 2024-02-29 is the last day of February in the leap year
+            let schedule = cron_schedule("30 12 L FEB *");
+            let leap_day_ms = NEW_YEAR_2025_MS - 307 * DAY_MS;
+            assert_eq!(count(&schedule, leap_day_ms, leap_day_ms + DAY_MS), 1);
+        }
+
+        #[test]
+        fn test_day_of_month_or_day_of_week() {
+            // 13th of January 2025 and every Friday
+            let schedule = cron_schedule("0 0 13 * FRI");
+            assert_eq!(
+                count(&schedule, NEW_YEAR_2025_MS, NEW_YEAR_2025_MS + 31 * DAY_MS),
+                6
+            );
+        }
+
+        #[test]
+        fn test_end_and_max_occurrences() {
+            let schedule = cron_schedule("*/15 * * * *");
+            let end_ms = NEW_YEAR_2025_MS + HOUR_MS / 2;
+            let bounded = schedule.until(Duration::from_millis(end_ms));
+            assert_eq!(
+                count(&schedule, NEW_YEAR_2025_MS, NEW_YEAR_2025_MS + HOUR_MS),
+                4
+            );
+            assert_eq!(
+                count(&bounded, NEW_YEAR_2025_MS, NEW_YEAR_2025_MS + HOUR_MS),
+                2
+            );
+
+            let limited = Schedule::starting_at(Duration::from_millis(NEW_YEAR_2025_MS))
+                .with_cron(schedule.cron.unwrap())
+                .with_max_occurrences(NonZeroU32::new(3).unwrap());
+            assert_eq!(
+                count(&limited, NEW_YEAR_2025_MS, NEW_YEAR_2025_MS + HOUR_MS),
+                3
+            );
+            assert_eq!(
+                count(
+                    &limited,
+                    NEW_YEAR_2025_MS + HOUR_MS,
+                    NEW_YEAR_2025_MS + DAY_MS
+                ),
+                0
+            );
+        }
+
+        #[test]
+        fn test_periodic_max_occurrences() {
+            let schedule = Schedule::starting_at(Duration::from_secs(TIMESTAMP))
+                .with_period(Duration::from_secs(1))
+                .with_max_occurrences(NonZeroU32::new(5).unwrap());
+            let since = Duration::from_secs(TIMESTAMP + 3);
+            let interval = TimeInterval::new(since, Duration::from_secs(10));
+            assert_eq!(count_matches_in_interval(&schedule, &interval), 2);
+        }
+
+        #[test]
+        fn test_parse_and_display() {
+            let cron: CronExpression = "*/15 9-17 L,1 * mon-fri".parse().unwrap();
+            assert_eq!(cron.to_string(), "0,15,30,45 9-17 1,L * 1-5");
+            assert_eq!(cron.to_string().parse::<CronExpression>().unwrap(), cron);
+
+            let sundays: CronExpression = "* * * * 7".parse().unwrap();
+            assert_eq!(sundays, "* * * * SUN".parse().unwrap());
+            assert_eq!(sundays.to_string(), "* * * * 0");
+
+            for invalid in [
+                "60 * * * *",
+                "* * * *",
+                "* * 0 * *",
+                "* * * * * *",
+                "*/0 * * * *",
+            ] {
+                assert!(invalid.parse::<CronExpression>().is_err(), "{invalid}");
+            }
+        }
+    }
 }
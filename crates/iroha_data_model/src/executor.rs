@@ -61,6 +61,7 @@ mod model {
     ///
     /// - Permission tokens (see [`crate::permission::Permission`])
     /// - Configuration parameters (see [`crate::parameter::Parameter`])
+    /// - Custom events (see [`crate::events::custom::CustomEvent`])
     #[derive(
         Default,
         Debug,
@@ -88,7 +89,10 @@ mod model {
         pub instructions: BTreeSet<Ident>,
         /// Ids of permission tokens supported by the executor.
         pub permissions: BTreeSet<Ident>,
-        /// Schema of executor defined data types (instructions, parameters, permissions)
+        /// Corresponds to the [`crate::events::custom::CustomEvent`].
+        /// Names of the types the custom events can be emitted with.
+        pub events: BTreeSet<Ident>,
+        /// Schema of executor defined data types (instructions, parameters, permissions, events)
         pub schema: Json,
     }
 
@@ -104,6 +108,11 @@ impl ExecutorDataModel {
         &self.permissions
     }
 
+    /// Getter
+    pub fn events(&self) -> &BTreeSet<Ident> {
+        &self.events
+    }
+
     /// Getter
     pub fn schema(&self) -> &Json {
         &self.schema
@@ -2,7 +2,10 @@
 
 #[cfg(not(feature = "std"))]
 use alloc::{format, string::String, vec::Vec};
-use core::fmt::{Debug, Display};
+use core::{
+    fmt::{Debug, Display},
+    num::NonZeroU16,
+};
 
 use derive_more::{Constructor, DebugCustom, Display};
 use iroha_data_model_derive::{model, EnumRef};
@@ -117,6 +120,19 @@ mod model {
         Upgrade(Upgrade),
         #[debug(fmt = "{_0:?}")]
         Log(Log),
+        #[debug(fmt = "{_0:?}")]
+        #[enum_ref(transparent)]
+        AccountKey(AccountKeyBox),
+        #[debug(fmt = "{_0:?}")]
+        #[enum_ref(transparent)]
+        AccountAlias(AccountAliasBox),
+        #[debug(fmt = "{_0:?}")]
+        #[enum_ref(transparent)]
+        Contract(ContractBox),
+        #[debug(fmt = "{_0:?}")]
+        EmitEvent(EmitEvent),
+        #[debug(fmt = "{_0:?}")]
+        UpgradeTrigger(UpgradeTrigger),
 
         #[debug(fmt = "{_0:?}")]
         Custom(CustomInstruction),
@@ -177,7 +193,19 @@ impl_instruction! {
     SetParameter,
     Upgrade,
     ExecuteTrigger,
+    UpgradeTrigger,
     Log,
+    AddAccountKey,
+    RemoveAccountKey,
+    RotateAccountKey,
+    SetAccountKeyThreshold,
+    RegisterAccountAlias,
+    TransferAccountAlias,
+    UnregisterAccountAlias,
+    DeployContract,
+    RemoveContract,
+    CallContract,
+    EmitEvent,
 }
 
 impl Instruction for InstructionBox {}
@@ -262,6 +290,13 @@ mod transparent {
         pub struct SetParameter(pub Parameter);
     }
 
+    impl SetParameter {
+        /// Parameter to set
+        pub fn parameter(&self) -> &Parameter {
+            &self.0
+        }
+    }
+
     isi! {
         /// Generic instruction to set key value at the object.
         pub struct SetKeyValue<O: Identifiable> {
@@ -934,6 +969,22 @@ mod transparent {
         }
     }
 
+    isi! {
+        /// Instruction to replace the executable of the trigger in place,
+        /// keeping its id, repetitions, metadata and storage.
+        ///
+        /// If the new WASM executable exports `_iroha_trigger_migrate` entrypoint,
+        /// it is called once to migrate the storage of the trigger.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "UPGRADE `{trigger}`")]
+        pub struct UpgradeTrigger {
+            /// Id of the trigger to upgrade
+            pub trigger: TriggerId,
+            /// New executable of the trigger
+            pub executable: Executable,
+        }
+    }
+
     isi! {
         /// Generic instruction for upgrading runtime objects.
         #[derive(Constructor, Display)]
@@ -959,6 +1010,185 @@ mod transparent {
         }
     }
 
+    isi! {
+        /// Register a further key authorized to sign transactions on behalf of the account
+        #[derive(Constructor, Display)]
+        #[display(fmt = "ADD KEY `{public_key}` WITH WEIGHT `{weight}` TO `{account}`")]
+        pub struct AddAccountKey {
+            /// Account to add the key to
+            pub account: AccountId,
+            /// Key to add
+            pub public_key: PublicKey,
+            /// Relative weight of the key
+            pub weight: u8,
+        }
+    }
+
+    isi! {
+        /// Unregister a key from the keys authorized to sign transactions on behalf of the account
+        #[derive(Constructor, Display)]
+        #[display(fmt = "REMOVE KEY `{public_key}` FROM `{account}`")]
+        pub struct RemoveAccountKey {
+            /// Account to remove the key from
+            pub account: AccountId,
+            /// Key to remove
+            pub public_key: PublicKey,
+        }
+    }
+
+    isi! {
+        /// Replace a key authorized to sign transactions on behalf of the account, keeping its weight
+        #[derive(Constructor, Display)]
+        #[display(fmt = "ROTATE KEY `{old_key}` TO `{new_key}` OF `{account}`")]
+        pub struct RotateAccountKey {
+            /// Account to rotate the key of
+            pub account: AccountId,
+            /// Key to be replaced
+            pub old_key: PublicKey,
+            /// Key to replace with
+            pub new_key: PublicKey,
+        }
+    }
+
+    isi! {
+        /// Change the total weight of signing keys required to authorize a transaction on behalf of the account
+        #[derive(Constructor, Display)]
+        #[display(fmt = "SET KEY THRESHOLD `{threshold}` OF `{account}`")]
+        pub struct SetAccountKeyThreshold {
+            /// Account to set the threshold of
+            pub account: AccountId,
+            /// New threshold
+            pub threshold: NonZeroU16,
+        }
+    }
+
+    impl_into_box! {
+        AddAccountKey |
+        RemoveAccountKey |
+        RotateAccountKey |
+        SetAccountKeyThreshold
+    => AccountKeyBox => InstructionBox[AccountKey],
+    => AccountKeyBoxRef<'a> => InstructionBoxRef<'a>[AccountKey]
+    }
+
+    isi! {
+        /// Register a human-readable alias for the account
+        #[derive(Constructor, Display)]
+        #[display(fmt = "REGISTER ALIAS `{alias}` FOR `{account}`")]
+        pub struct RegisterAccountAlias {
+            /// Alias to register
+            pub alias: AccountAlias,
+            /// Account the alias resolves to
+            pub account: AccountId,
+        }
+    }
+
+    isi! {
+        /// Make the alias resolve to another account of the same domain
+        #[derive(Constructor, Display)]
+        #[display(fmt = "TRANSFER ALIAS `{alias}` TO `{destination}`")]
+        pub struct TransferAccountAlias {
+            /// Alias to transfer
+            pub alias: AccountAlias,
+            /// Account the alias resolves to afterwards
+            pub destination: AccountId,
+        }
+    }
+
+    isi! {
+        /// Unregister an account alias, making it available for registration again
+        #[derive(Constructor, Display)]
+        #[display(fmt = "UNREGISTER ALIAS `{alias}`")]
+        pub struct UnregisterAccountAlias {
+            /// Alias to unregister
+            pub alias: AccountAlias,
+        }
+    }
+
+    impl_into_box! {
+        RegisterAccountAlias |
+        TransferAccountAlias |
+        UnregisterAccountAlias
+    => AccountAliasBox => InstructionBox[AccountAlias],
+    => AccountAliasBoxRef<'a> => InstructionBoxRef<'a>[AccountAlias]
+    }
+
+    isi! {
+        /// Upload a WASM module to the contract registry under the given id
+        #[derive(Constructor, Display)]
+        #[display(fmt = "DEPLOY CONTRACT `{contract}`")]
+        pub struct DeployContract {
+            /// Id of the contract to deploy
+            pub contract: ContractId,
+            /// WASM module of the contract
+            pub wasm: WasmSmartContract,
+        }
+    }
+
+    isi! {
+        /// Remove a contract from the contract registry
+        #[derive(Constructor, Display)]
+        #[display(fmt = "REMOVE CONTRACT `{contract}`")]
+        #[serde(transparent)]
+        #[repr(transparent)]
+        pub struct RemoveContract {
+            /// Id of the contract to remove
+            pub contract: ContractId,
+        }
+    }
+
+    isi! {
+        /// Invoke a deployed contract with the given arguments
+        #[derive(Display)]
+        #[display(fmt = "CALL CONTRACT `{contract}`")]
+        pub struct CallContract {
+            /// Id of the contract to call
+            pub contract: ContractId,
+            /// Arguments passed to the contract
+            pub args: Json,
+        }
+    }
+
+    impl CallContract {
+        /// Constructor for [`Self`]
+        pub fn new(contract: ContractId) -> Self {
+            Self {
+                contract,
+                args: Json::default(),
+            }
+        }
+
+        /// Add contract call args
+        #[must_use]
+        pub fn with_args<T: serde::Serialize>(mut self, args: &T) -> Self {
+            self.args = Json::new(args);
+            self
+        }
+    }
+
+    impl_into_box! {
+        DeployContract |
+        RemoveContract |
+        CallContract
+    => ContractBox => InstructionBox[Contract],
+    => ContractBoxRef<'a> => InstructionBoxRef<'a>[Contract]
+    }
+
+    isi! {
+        /// Emit a custom event of the type registered in the executor data model.
+        ///
+        /// The `name` is checked against [`ExecutorDataModel::events`] and the `payload`
+        /// against the schema of the event type in [`ExecutorDataModel::schema`].
+        #[derive(Constructor, Display)]
+        #[display(fmt = "EMIT EVENT `{name}`")]
+        pub struct EmitEvent {
+            /// Name of the event type
+            pub name: Name,
+            /// Payload of the event
+            pub payload: Json,
+        }
+    }
+
     isi! {
         /// Blockchain specific instruction (defined in the executor).
         /// Can be used to extend instruction set or add expression system.
@@ -1188,6 +1418,59 @@ isi_box! {
     }
 }
 
+isi_box! {
+    #[strum_discriminants(
+        vis(pub(crate)),
+        name(AccountKeyType),
+        derive(Encode),
+    )]
+    /// Enum with all supported instructions managing [`AccountKeys`](crate::account::AccountKeys).
+    pub enum AccountKeyBox {
+        /// Add a key.
+        Add(AddAccountKey),
+        /// Remove a key.
+        Remove(RemoveAccountKey),
+        /// Rotate a key.
+        Rotate(RotateAccountKey),
+        /// Set the signature threshold.
+        SetThreshold(SetAccountKeyThreshold),
+    }
+}
+
+isi_box! {
+    #[strum_discriminants(
+        vis(pub(crate)),
+        name(AccountAliasType),
+        derive(Encode),
+    )]
+    /// Enum with all supported instructions managing [`AccountAlias`]es.
+    pub enum AccountAliasBox {
+        /// Register an alias.
+        Register(RegisterAccountAlias),
+        /// Transfer an alias.
+        Transfer(TransferAccountAlias),
+        /// Unregister an alias.
+        Unregister(UnregisterAccountAlias),
+    }
+}
+
+isi_box! {
+    #[strum_discriminants(
+        vis(pub(crate)),
+        name(ContractType),
+        derive(Encode),
+    )]
+    /// Enum with all supported instructions managing [`Contract`]s.
+    pub enum ContractBox {
+        /// Deploy a contract.
+        Deploy(DeployContract),
+        /// Remove a contract.
+        Remove(RemoveContract),
+        /// Call a contract.
+        Call(CallContract),
+    }
+}
+
 pub mod error {
     //! Module containing errors that can occur during instruction evaluation
 
@@ -1485,9 +1768,12 @@ pub mod error {
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
     pub use super::{
-        Burn, BurnBox, CustomInstruction, ExecuteTrigger, Grant, GrantBox, InstructionBox, Log,
-        Mint, MintBox, Register, RegisterBox, RemoveKeyValue, RemoveKeyValueBox, Revoke, RevokeBox,
-        SetKeyValue, SetKeyValueBox, SetParameter, Transfer, TransferBox, Unregister,
-        UnregisterBox, Upgrade,
+        AccountAliasBox, AccountKeyBox, AddAccountKey, Burn, BurnBox, CallContract, ContractBox,
+        CustomInstruction, DeployContract, EmitEvent, ExecuteTrigger, Grant, GrantBox,
+        InstructionBox, Log, Mint, MintBox, Register, RegisterAccountAlias, RegisterBox,
+        RemoveAccountKey, RemoveContract, RemoveKeyValue, RemoveKeyValueBox, Revoke, RevokeBox,
+        RotateAccountKey, SetAccountKeyThreshold, SetKeyValue, SetKeyValueBox, SetParameter,
+        Transfer, TransferAccountAlias, TransferBox, Unregister, UnregisterAccountAlias,
+        UnregisterBox, Upgrade, UpgradeTrigger,
     };
 }
@@ -34,6 +34,7 @@ use crate::name::Name;
 pub mod account;
 pub mod asset;
 pub mod block;
+pub mod contract;
 pub mod domain;
 pub mod events;
 pub mod executor;
@@ -48,6 +49,7 @@ pub mod permission;
 pub mod query;
 pub mod role;
 pub mod smart_contract;
+pub mod storage;
 pub mod transaction;
 pub mod trigger;
 pub mod visit;
@@ -120,6 +122,18 @@ mod seal {
         Upgrade,
         ExecuteTrigger,
         Log,
+        AddAccountKey,
+        RemoveAccountKey,
+        RotateAccountKey,
+        SetAccountKeyThreshold,
+        RegisterAccountAlias,
+        TransferAccountAlias,
+        UnregisterAccountAlias,
+        DeployContract,
+        RemoveContract,
+        CallContract,
+        EmitEvent,
+        UpgradeTrigger,
 
         // Boxed queries
         SingularQueryBox,
@@ -132,6 +146,8 @@ mod seal {
         FindPeers,
         FindBlocks,
         FindBlockHeaders,
+        FindTriggerReceipts,
+        FindStorageEntries,
         FindTransactions,
         FindPermissionsByAccountId,
         FindExecutorDataModel,
@@ -141,6 +157,13 @@ mod seal {
         FindRoleIds,
         FindRolesByAccountId,
         FindParameters,
+        FindAccountIdByAlias,
+        FindAliasesByAccountId,
+        FindContracts,
+        FindRandomness,
+        FindEquivocations,
+        FindValidatorSets,
+        CustomQuery,
     }
 }
 
@@ -269,6 +292,8 @@ mod model {
         Permission(permission::Permission),
         /// [`CustomParameter`](`parameter::CustomParameter`) variant.
         CustomParameterId(parameter::CustomParameterId),
+        /// [`ContractId`](`contract::ContractId`) variant.
+        ContractId(contract::ContractId),
     }
 
     /// Operation validation failed.
@@ -390,6 +415,7 @@ impl_encode_as_id_box! {
     trigger::TriggerId,
     permission::Permission,
     role::RoleId,
+    contract::ContractId,
 }
 
 impl Decode for ChainId {
@@ -487,10 +513,11 @@ pub mod prelude {
     };
 
     pub use super::{
-        account::prelude::*, asset::prelude::*, domain::prelude::*, events::prelude::*,
-        executor::prelude::*, isi::prelude::*, metadata::prelude::*, name::prelude::*,
-        nft::prelude::*, parameter::prelude::*, peer::prelude::*, permission::prelude::*,
-        query::prelude::*, role::prelude::*, transaction::prelude::*, trigger::prelude::*, ChainId,
-        EnumTryAsError, HasMetadata, IdBox, Identifiable, ValidationFail,
+        account::prelude::*, asset::prelude::*, contract::prelude::*, domain::prelude::*,
+        events::prelude::*, executor::prelude::*, isi::prelude::*, metadata::prelude::*,
+        name::prelude::*, nft::prelude::*, parameter::prelude::*, peer::prelude::*,
+        permission::prelude::*, query::prelude::*, role::prelude::*, storage::prelude::*,
+        transaction::prelude::*, trigger::prelude::*, ChainId, EnumTryAsError, HasMetadata, IdBox,
+        Identifiable, ValidationFail,
     };
 }
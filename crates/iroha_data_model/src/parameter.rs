@@ -68,7 +68,9 @@ mod model {
         Serialize,
         IntoSchema,
     )]
-    #[display(fmt = "{block_time_ms},{commit_time_ms}{max_clock_drift_ms}_SL")]
+    #[display(
+        fmt = "{block_time_ms},{commit_time_ms}{max_clock_drift_ms},{aggregate_signatures},{epoch_length},{max_validator_changes}_SL"
+    )]
     pub struct SumeragiParameters {
         /// Maximal amount of time (in milliseconds) a peer will wait before forcing creation of a new block.
         ///
@@ -88,6 +90,23 @@ mod model {
         /// This value should be kept as low as possible to not affect soundness of the consensus
         #[serde(default = "defaults::sumeragi::max_clock_drift_ms")]
         pub max_clock_drift_ms: u64,
+        /// Whether signatures of peers with BLS keys are aggregated into one
+        /// when a block is committed, to make blocks smaller and faster to verify
+        #[serde(default)]
+        pub aggregate_signatures: bool,
+        /// Number of blocks in an epoch.
+        ///
+        /// If not zero, changes to the validator set are staged and activated
+        /// no earlier than the first block of the next epoch, and only once every
+        /// peer joining the validator set is reachable and synced.
+        /// If zero, changes take effect with the next block.
+        #[serde(default)]
+        pub epoch_length: u64,
+        /// Maximal number of peers joining or leaving the validator set in one activation.
+        ///
+        /// Zero means no limit. Only applied when [`Self::epoch_length`] is not zero.
+        #[serde(default)]
+        pub max_validator_changes: u64,
     }
 
     /// Single Sumeragi parameter
@@ -112,6 +131,9 @@ mod model {
         BlockTimeMs(u64),
         CommitTimeMs(u64),
         MaxClockDriftMs(u64),
+        AggregateSignatures(bool),
+        EpochLength(u64),
+        MaxValidatorChanges(u64),
     }
 
     /// Limits that a block must obey to be accepted.
@@ -219,6 +241,160 @@ mod model {
         Memory(NonZeroU64),
     }
 
+    /// Limits that triggers executed after a block must obey.
+    ///
+    /// Events emitted by a trigger are matched against other triggers within the same block,
+    /// so triggers can form chains which are executed breadth-first.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        CopyGetters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{max_depth},{max_fuel}_TRL")]
+    #[getset(get_copy = "pub")]
+    pub struct TriggerParameters {
+        /// Maximal depth of a trigger chain.
+        ///
+        /// Triggers matched by the events of the block's transactions have depth 1,
+        /// triggers matched by the events of those triggers have depth 2 and so on.
+        #[serde(default = "defaults::trigger::max_depth")]
+        pub max_depth: NonZeroU64,
+        /// Maximal amount of fuel all WASM triggers executed after a block can consume.
+        ///
+        /// A failed WASM trigger is charged the whole fuel it was allowed to consume.
+        #[serde(default = "defaults::trigger::max_fuel")]
+        pub max_fuel: NonZeroU64,
+    }
+
+    /// Single trigger parameter
+    ///
+    /// Check [`TriggerParameters`] for more details
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Serialize,
+        Deserialize,
+        IntoSchema,
+    )]
+    pub enum TriggerParameter {
+        MaxDepth(NonZeroU64),
+        MaxFuel(NonZeroU64),
+    }
+
+    /// Limits of the persistent storage of smart contracts and triggers.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        CopyGetters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{max_bytes}_STL")]
+    #[getset(get_copy = "pub")]
+    pub struct StorageParameters {
+        /// Maximum number of bytes of keys and values that a smart contract
+        /// or a trigger can keep in its persistent storage
+        #[serde(default = "defaults::storage::max_bytes")]
+        pub max_bytes: NonZeroU64,
+    }
+
+    /// Single storage parameter
+    ///
+    /// Check [`StorageParameters`] for more details
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Serialize,
+        Deserialize,
+        IntoSchema,
+    )]
+    pub enum StorageParameter {
+        MaxBytes(NonZeroU64),
+    }
+
+    /// Limits that calls of deployed contracts must obey.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        CopyGetters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{max_call_depth}_CTL")]
+    #[getset(get_copy = "pub")]
+    pub struct ContractParameters {
+        /// Maximum number of nested contract calls, including the outermost one
+        #[serde(default = "defaults::contract::max_call_depth")]
+        pub max_call_depth: NonZeroU64,
+    }
+
+    /// Single contract parameter
+    ///
+    /// Check [`ContractParameters`] for more details
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Serialize,
+        Deserialize,
+        IntoSchema,
+    )]
+    pub enum ContractParameter {
+        MaxCallDepth(NonZeroU64),
+    }
+
     /// Blockchain specific parameter defined in the executor
     #[derive(
         Debug, Display, Clone, IdEqOrdHash, Decode, Encode, Deserialize, Serialize, IntoSchema,
@@ -273,6 +449,18 @@ mod model {
         #[getset(get_copy = "pub")]
         #[serde(default)]
         pub smart_contract: SmartContractParameters,
+        /// Trigger parameters
+        #[getset(get_copy = "pub")]
+        #[serde(default)]
+        pub trigger: TriggerParameters,
+        /// Storage parameters
+        #[getset(get_copy = "pub")]
+        #[serde(default)]
+        pub storage: StorageParameters,
+        /// Contract parameters
+        #[getset(get_copy = "pub")]
+        #[serde(default)]
+        pub contract: ContractParameters,
         /// Collection of blockchain specific parameters
         #[getset(get = "pub")]
         #[serde(default)]
@@ -304,6 +492,9 @@ mod model {
         Transaction(TransactionParameter),
         SmartContract(SmartContractParameter),
         Executor(SmartContractParameter),
+        Trigger(TriggerParameter),
+        Storage(StorageParameter),
+        Contract(ContractParameter),
         Custom(CustomParameter),
     }
 }
@@ -315,6 +506,9 @@ impl core::fmt::Display for Parameter {
             Self::Block(v) => core::fmt::Display::fmt(&v, f),
             Self::Transaction(v) => core::fmt::Display::fmt(&v, f),
             Self::SmartContract(v) | Self::Executor(v) => core::fmt::Display::fmt(&v, f),
+            Self::Trigger(v) => core::fmt::Display::fmt(&v, f),
+            Self::Storage(v) => core::fmt::Display::fmt(&v, f),
+            Self::Contract(v) => core::fmt::Display::fmt(&v, f),
             Self::Custom(v) => write!(f, "{}({})", v.id, v.payload),
         }
     }
@@ -345,6 +539,11 @@ impl SumeragiParameters {
         Duration::from_millis(self.commit_time_ms)
     }
 
+    /// Epoch of the block at the given `height`, [`None`] if epochs are disabled
+    pub fn epoch(&self, height: NonZeroU64) -> Option<u64> {
+        (height.get() - 1).checked_div(self.epoch_length)
+    }
+
     /// Maximal amount of time it takes to commit a block
     #[cfg(feature = "transparent_api")]
     pub fn pipeline_time(&self, view_change_index: usize, shift: usize) -> Duration {
@@ -408,6 +607,39 @@ mod defaults {
             nonzero!(55_000_000_u64)
         }
     }
+
+    pub mod trigger {
+        use core::num::NonZeroU64;
+
+        use nonzero_ext::nonzero;
+
+        pub const fn max_depth() -> NonZeroU64 {
+            nonzero!(8_u64)
+        }
+        pub const fn max_fuel() -> NonZeroU64 {
+            nonzero!(550_000_000_u64)
+        }
+    }
+
+    pub mod storage {
+        use core::num::NonZeroU64;
+
+        use nonzero_ext::nonzero;
+
+        pub const fn max_bytes() -> NonZeroU64 {
+            nonzero!(2_u64.pow(20))
+        }
+    }
+
+    pub mod contract {
+        use core::num::NonZeroU64;
+
+        use nonzero_ext::nonzero;
+
+        pub const fn max_call_depth() -> NonZeroU64 {
+            nonzero!(8_u64)
+        }
+    }
 }
 
 impl Default for SumeragiParameters {
@@ -417,6 +649,9 @@ impl Default for SumeragiParameters {
             block_time_ms: block_time_ms(),
             commit_time_ms: commit_time_ms(),
             max_clock_drift_ms: max_clock_drift_ms(),
+            aggregate_signatures: false,
+            epoch_length: 0,
+            max_validator_changes: 0,
         }
     }
 }
@@ -443,6 +678,32 @@ impl Default for SmartContractParameters {
     }
 }
 
+impl Default for TriggerParameters {
+    fn default() -> Self {
+        use defaults::trigger::*;
+        Self {
+            max_depth: max_depth(),
+            max_fuel: max_fuel(),
+        }
+    }
+}
+
+impl Default for StorageParameters {
+    fn default() -> Self {
+        Self {
+            max_bytes: defaults::storage::max_bytes(),
+        }
+    }
+}
+
+impl Default for ContractParameters {
+    fn default() -> Self {
+        Self {
+            max_call_depth: defaults::contract::max_call_depth(),
+        }
+    }
+}
+
 impl FromIterator<Parameter> for Parameters {
     fn from_iter<T: IntoIterator<Item = Parameter>>(iter: T) -> Self {
         iter.into_iter().fold(Parameters::default(), |mut acc, x| {
@@ -466,6 +727,9 @@ impl Parameters {
                     .parameters()
                     .map(Parameter::SmartContract),
             )
+            .chain(self.trigger.parameters().map(Parameter::Trigger))
+            .chain(self.storage.parameters().map(Parameter::Storage))
+            .chain(self.contract.parameters().map(Parameter::Contract))
             .chain(self.custom.values().cloned().map(Parameter::Custom))
     }
 
@@ -490,6 +754,9 @@ impl Parameters {
             Sumeragi(sumeragi.max_clock_drift_ms) => SumeragiParameter::MaxClockDriftMs,
             Sumeragi(sumeragi.block_time_ms) => SumeragiParameter::BlockTimeMs,
             Sumeragi(sumeragi.commit_time_ms) => SumeragiParameter::CommitTimeMs,
+            Sumeragi(sumeragi.aggregate_signatures) => SumeragiParameter::AggregateSignatures,
+            Sumeragi(sumeragi.epoch_length) => SumeragiParameter::EpochLength,
+            Sumeragi(sumeragi.max_validator_changes) => SumeragiParameter::MaxValidatorChanges,
 
             Block(block.max_transactions) => BlockParameter::MaxTransactions,
 
@@ -501,6 +768,13 @@ impl Parameters {
 
             Executor(executor.fuel) => SmartContractParameter::Fuel,
             Executor(executor.memory) => SmartContractParameter::Memory,
+
+            Trigger(trigger.max_depth) => TriggerParameter::MaxDepth,
+            Trigger(trigger.max_fuel) => TriggerParameter::MaxFuel,
+
+            Storage(storage.max_bytes) => StorageParameter::MaxBytes,
+
+            Contract(contract.max_call_depth) => ContractParameter::MaxCallDepth,
         );
     }
 }
@@ -521,6 +795,9 @@ impl SumeragiParameters {
                 .as_millis()
                 .try_into()
                 .expect("INTERNAL BUG: Time should fit into u64"),
+            aggregate_signatures: false,
+            epoch_length: 0,
+            max_validator_changes: 0,
         }
     }
 
@@ -530,6 +807,9 @@ impl SumeragiParameters {
             SumeragiParameter::BlockTimeMs(self.block_time_ms),
             SumeragiParameter::CommitTimeMs(self.commit_time_ms),
             SumeragiParameter::MaxClockDriftMs(self.max_clock_drift_ms),
+            SumeragiParameter::AggregateSignatures(self.aggregate_signatures),
+            SumeragiParameter::EpochLength(self.epoch_length),
+            SumeragiParameter::MaxValidatorChanges(self.max_validator_changes),
         ]
         .into_iter()
     }
@@ -577,6 +857,31 @@ impl SmartContractParameters {
     }
 }
 
+impl TriggerParameters {
+    /// Convert [`Self`] into iterator of individual parameters
+    pub fn parameters(&self) -> impl Iterator<Item = TriggerParameter> {
+        [
+            TriggerParameter::MaxDepth(self.max_depth),
+            TriggerParameter::MaxFuel(self.max_fuel),
+        ]
+        .into_iter()
+    }
+}
+
+impl StorageParameters {
+    /// Convert [`Self`] into iterator of individual parameters
+    pub fn parameters(&self) -> impl Iterator<Item = StorageParameter> {
+        [StorageParameter::MaxBytes(self.max_bytes)].into_iter()
+    }
+}
+
+impl ContractParameters {
+    /// Convert [`Self`] into iterator of individual parameters
+    pub fn parameters(&self) -> impl Iterator<Item = ContractParameter> {
+        [ContractParameter::MaxCallDepth(self.max_call_depth)].into_iter()
+    }
+}
+
 impl CustomParameterId {
     /// Getter for name
     pub fn name(&self) -> &Name {
@@ -856,5 +1161,8 @@ mod candidate {
 pub mod prelude {
     //! Prelude: re-export of most commonly used traits, structs and macros in this crate.
 
-    pub use super::{Parameter, Parameters, SmartContractParameters, TransactionParameters};
+    pub use super::{
+        ContractParameters, Parameter, Parameters, SmartContractParameters, StorageParameters,
+        TransactionParameters, TriggerParameters,
+    };
 }
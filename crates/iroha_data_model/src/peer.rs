@@ -14,10 +14,11 @@ use crate::{Identifiable, ParseError, Registered};
 
 #[model]
 mod model {
-    use getset::Getters;
+    use getset::{CopyGetters, Getters};
     use iroha_data_model_derive::IdEqOrdHash;
     use iroha_schema::IntoSchema;
     use parity_scale_codec::{Decode, Encode};
+    use serde::{Deserialize, Serialize};
     use serde_with::{DeserializeFromStr, SerializeDisplay};
 
     use super::*;
@@ -76,6 +77,57 @@ mod model {
         /// Peer Identification.
         pub id: PeerId,
     }
+
+    /// Changes to the validator set staged to be activated at an epoch boundary.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        CopyGetters,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    pub struct PendingValidatorSet {
+        /// Peers which will commit blocks once the changes are activated
+        #[getset(get = "pub")]
+        pub peers: Vec<PeerId>,
+        /// Epoch in which the first of the changes was staged.
+        /// The set is activated no earlier than in the next epoch.
+        #[getset(get_copy = "pub")]
+        pub staged_in_epoch: u64,
+    }
+
+    /// Validator set committing blocks along with the one staged to replace it
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct ValidatorSets {
+        /// Peers which commit blocks
+        pub active: Vec<PeerId>,
+        /// Staged changes, [`None`] if there are none
+        pub pending: Option<PendingValidatorSet>,
+    }
 }
 
 impl FromStr for PeerId {
@@ -136,5 +188,5 @@ impl Registered for Peer {
 
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
-    pub use super::{Peer, PeerId};
+    pub use super::{Peer, PeerId, PendingValidatorSet, ValidatorSets};
 }
@@ -9,8 +9,10 @@ use iroha_primitives::{json::Json, numeric::Numeric};
 use crate::{
     account::{Account, AccountId},
     asset::{Asset, AssetDefinition, AssetDefinitionId, AssetId},
-    block::{BlockHeader, SignedBlock},
+    block::{BlockHeader, EquivocationEvidence, SignedBlock, TriggerReceipt},
+    contract::{Contract, ContractId},
     domain::{Domain, DomainId},
+    events::trigger_completed::TriggerCompletedOutcome,
     metadata::Metadata,
     name::Name,
     nft::{Nft, NftId},
@@ -23,18 +25,21 @@ use crate::{
                 AccountIdPrototype, AccountPrototype, ActionPrototype, AssetDefinitionIdPrototype,
                 AssetDefinitionPrototype, AssetIdPrototype, AssetPrototype,
                 BlockHeaderHashPrototype, BlockHeaderPrototype, CommittedTransactionPrototype,
-                DomainIdPrototype, DomainPrototype, JsonPrototype, MetadataPrototype,
-                NamePrototype, NftIdPrototype, NftPrototype, NumericPrototype, ParameterPrototype,
-                PeerIdPrototype, PermissionPrototype, PublicKeyPrototype, RoleIdPrototype,
-                RolePrototype, SignedBlockPrototype, SignedTransactionPrototype, StringPrototype,
-                TransactionErrorPrototype, TransactionHashPrototype, TriggerIdPrototype,
-                TriggerPrototype,
+                CommittedTriggerReceiptPrototype, ContractIdPrototype, ContractPrototype,
+                DomainIdPrototype, DomainPrototype, EquivocationEvidencePrototype, JsonPrototype,
+                MetadataPrototype, NamePrototype, NftIdPrototype, NftPrototype, NumericPrototype,
+                ParameterPrototype, PeerIdPrototype, PermissionPrototype, PublicKeyPrototype,
+                RoleIdPrototype, RolePrototype, SignedBlockPrototype, SignedTransactionPrototype,
+                StorageEntryPrototype, StringPrototype, TransactionErrorPrototype,
+                TransactionHashPrototype, TriggerIdPrototype, TriggerPrototype,
+                TriggerReceiptPrototype,
             },
             CompoundPredicate, ObjectProjector, PredicateMarker,
         },
-        CommittedTransaction,
+        CommittedTransaction, CommittedTriggerReceipt,
     },
     role::{Role, RoleId},
+    storage::StorageEntry,
     transaction::{error::TransactionRejectionReason, SignedTransaction},
     trigger::{action, Trigger, TriggerId},
 };
@@ -316,6 +321,12 @@ impl_predicate_atom! {
         IsSome [is_some] => input.is_some(),
     }
     CommittedTransactionPredicateAtom(_input: CommittedTransaction) [CommittedTransactionPrototype] {}
+    TriggerReceiptPredicateAtom(input: TriggerReceipt) [TriggerReceiptPrototype] {
+        /// Checks if the execution of the trigger failed.
+        IsFailure [is_failure] => matches!(input.outcome, TriggerCompletedOutcome::Failure(_)),
+    }
+    CommittedTriggerReceiptPredicateAtom(_input: CommittedTriggerReceipt) [CommittedTriggerReceiptPrototype] {}
+    EquivocationEvidencePredicateAtom(_input: EquivocationEvidence) [EquivocationEvidencePrototype] {}
 
     // domain
     DomainPredicateAtom(_input: Domain) [DomainPrototype] {}
@@ -347,6 +358,19 @@ impl_predicate_atom! {
     }
     TriggerPredicateAtom(_input: Trigger) [TriggerPrototype] {}
     ActionPredicateAtom(_input: action::Action) [ActionPrototype] {}
+
+    // storage
+    StorageEntryPredicateAtom(input: StorageEntry) [StorageEntryPrototype] {
+        /// Checks if the key of the entry starts with the expected bytes.
+        KeyStartsWith(prefix: Vec<u8>) [key_starts_with] => input.key.starts_with(prefix),
+    }
+
+    // contract
+    ContractIdPredicateAtom(input: ContractId) [ContractIdPrototype] {
+        /// Checks if the input is equal to the expected value.
+        Equals(expected: ContractId) [eq] => input == expected,
+    }
+    ContractPredicateAtom(_input: Contract) [ContractPrototype] {}
 }
 
 pub mod prelude {
@@ -355,11 +379,14 @@ pub mod prelude {
         AccountIdPredicateAtom, AccountPredicateAtom, ActionPredicateAtom,
         AssetDefinitionIdPredicateAtom, AssetDefinitionPredicateAtom, AssetIdPredicateAtom,
         AssetPredicateAtom, BlockHeaderHashPredicateAtom, BlockHeaderPredicateAtom,
-        CommittedTransactionPredicateAtom, DomainIdPredicateAtom, DomainPredicateAtom,
-        JsonPredicateAtom, MetadataPredicateAtom, NftIdPredicateAtom, NftPredicateAtom,
-        NumericPredicateAtom, ParameterPredicateAtom, PeerIdPredicateAtom, PermissionPredicateAtom,
-        PublicKeyPredicateAtom, RoleIdPredicateAtom, RolePredicateAtom, SignedBlockPredicateAtom,
-        SignedTransactionPredicateAtom, StringPredicateAtom, TransactionErrorPredicateAtom,
+        CommittedTransactionPredicateAtom, CommittedTriggerReceiptPredicateAtom,
+        ContractIdPredicateAtom, ContractPredicateAtom, DomainIdPredicateAtom, DomainPredicateAtom,
+        EquivocationEvidencePredicateAtom, JsonPredicateAtom, MetadataPredicateAtom,
+        NftIdPredicateAtom, NftPredicateAtom, NumericPredicateAtom, ParameterPredicateAtom,
+        PeerIdPredicateAtom, PermissionPredicateAtom, PublicKeyPredicateAtom, RoleIdPredicateAtom,
+        RolePredicateAtom, SignedBlockPredicateAtom, SignedTransactionPredicateAtom,
+        StorageEntryPredicateAtom, StringPredicateAtom, TransactionErrorPredicateAtom,
         TransactionHashPredicateAtom, TriggerIdPredicateAtom, TriggerPredicateAtom,
+        TriggerReceiptPredicateAtom,
     };
 }
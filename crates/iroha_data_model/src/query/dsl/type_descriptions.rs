@@ -15,7 +15,8 @@ use crate::query::dsl::{
 use crate::{
     account::{Account, AccountId},
     asset::{Asset, AssetDefinition, AssetDefinitionId, AssetId},
-    block::{BlockHeader, SignedBlock},
+    block::{BlockHeader, EquivocationEvidence, SignedBlock, TriggerReceipt},
+    contract::{Contract, ContractId},
     domain::{Domain, DomainId},
     metadata::Metadata,
     name::Name,
@@ -25,9 +26,10 @@ use crate::{
     permission::Permission,
     query::{
         error::{FindError, QueryExecutionFail},
-        CommittedTransaction, QueryOutputBatchBox,
+        CommittedTransaction, CommittedTriggerReceipt, QueryOutputBatchBox,
     },
     role::{Role, RoleId},
+    storage::StorageEntry,
     transaction::{error::TransactionRejectionReason, SignedTransaction},
     trigger::{action, Trigger, TriggerId},
 };
@@ -304,6 +306,17 @@ type_descriptions! {
         value(Value, CommittedTransactionValueProjector): SignedTransaction,
         error(Error, CommittedTransactionErrorProjector): Option<TransactionRejectionReason>,
     }
+    TriggerReceipt[TriggerReceiptProjection, TriggerReceiptPrototype]: TriggerId, Name {
+        trigger_id(TriggerId, TriggerReceiptTriggerIdProjector): TriggerId,
+    }
+    CommittedTriggerReceipt[CommittedTriggerReceiptProjection, CommittedTriggerReceiptPrototype]: HashOf<BlockHeader>, TriggerReceipt, TriggerId, Name {
+        block_hash(BlockHash, CommittedTriggerReceiptBlockHashProjector): HashOf<BlockHeader>,
+        value(Value, CommittedTriggerReceiptValueProjector): TriggerReceipt,
+    }
+    #[custom_evaluate] // EquivocationEvidence is verified on decode, so `peer` is a method
+    EquivocationEvidence[EquivocationEvidenceProjection, EquivocationEvidencePrototype]: PeerId, PublicKey {
+        peer(Peer, EquivocationEvidencePeerProjector): PeerId,
+    }
 
     // domain
     Domain[DomainProjection, DomainPrototype]: DomainId, Name, Metadata, Json {
@@ -347,6 +360,18 @@ type_descriptions! {
         metadata(Metadata, ActionMetadataProjector): Metadata,
     }
 
+    // storage
+    StorageEntry[StorageEntryProjection, StorageEntryPrototype] {}
+
+    // contract
+    ContractId[ContractIdProjection, ContractIdPrototype]: Name {
+        name(Name, ContractIdNameProjector): Name,
+    }
+    Contract[ContractProjection, ContractPrototype]: ContractId, AccountId, DomainId, Name, PublicKey {
+        id(Id, ContractIdProjector): ContractId,
+        owner(Owner, ContractOwnerProjector): AccountId,
+    }
+
     // note: even though `NameProjection` and `StringProjection` are distinct types,
     // their predicates types are the same
     Name[NameProjection, NamePrototype] {}
@@ -578,6 +603,44 @@ impl EvaluateSelector<SignedTransaction> for SignedTransactionProjection<Selecto
     }
 }
 
+impl EvaluatePredicate<EquivocationEvidence> for EquivocationEvidenceProjection<PredicateMarker> {
+    fn applies(&self, input: &EquivocationEvidence) -> bool {
+        match self {
+            EquivocationEvidenceProjection::Atom(atom) => atom.applies(input),
+            EquivocationEvidenceProjection::Peer(peer) => peer.applies(input.peer()),
+        }
+    }
+}
+
+impl EvaluateSelector<EquivocationEvidence> for EquivocationEvidenceProjection<SelectorMarker> {
+    #[expect(single_use_lifetimes)] // FP, the suggested change is not allowed on stable
+    fn project_clone<'a>(
+        &self,
+        batch: impl Iterator<Item = &'a EquivocationEvidence>,
+    ) -> Result<QueryOutputBatchBox, QueryExecutionFail> {
+        match self {
+            EquivocationEvidenceProjection::Atom(()) => {
+                Ok(batch.cloned().collect::<Vec<_>>().into())
+            }
+            EquivocationEvidenceProjection::Peer(peer) => {
+                peer.project_clone(batch.map(EquivocationEvidence::peer))
+            }
+        }
+    }
+
+    fn project(
+        &self,
+        batch: impl Iterator<Item = EquivocationEvidence>,
+    ) -> Result<QueryOutputBatchBox, QueryExecutionFail> {
+        match self {
+            EquivocationEvidenceProjection::Atom(()) => Ok(batch.collect::<Vec<_>>().into()),
+            EquivocationEvidenceProjection::Peer(peer) => {
+                peer.project(batch.map(|item| item.peer().clone()))
+            }
+        }
+    }
+}
+
 // metadata is a special case because we allow projecting on string-typed keys
 /// A projection for the [`Metadata`] type.
 #[derive_where(Debug, Eq, PartialEq, Copy, Clone; <Metadata as Projectable<Marker>>::AtomType, MetadataKeyProjection<Marker>)]
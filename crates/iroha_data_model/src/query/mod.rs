@@ -25,13 +25,14 @@ use serde::{Deserialize, Serialize};
 
 pub use self::model::*;
 use self::{
-    account::*, asset::*, block::*, domain::*, dsl::*, executor::*, nft::*, peer::*, permission::*,
-    role::*, transaction::*, trigger::*,
+    account::*, asset::*, block::*, contract::*, domain::*, dsl::*, executor::*, nft::*, peer::*,
+    permission::*, role::*, storage::*, transaction::*, trigger::*,
 };
 use crate::{
     account::{Account, AccountId},
     asset::{Asset, AssetDefinition, AssetDefinitionId, AssetId},
-    block::{BlockHeader, SignedBlock},
+    block::{BlockHeader, EquivocationEvidence, SignedBlock, TriggerReceipt},
+    contract::{Contract, ContractId},
     domain::{Domain, DomainId},
     metadata::Metadata,
     name::Name,
@@ -41,6 +42,7 @@ use crate::{
     permission::Permission,
     role::{Role, RoleId},
     seal::Sealed,
+    storage::StorageEntry,
     transaction::{error::TransactionRejectionReason, SignedTransaction},
     trigger::{Trigger, TriggerId},
 };
@@ -116,6 +118,7 @@ mod model {
         FindPermissionsByAccountId(QueryWithFilter<FindPermissionsByAccountId>),
         FindRolesByAccountId(QueryWithFilter<FindRolesByAccountId>),
         FindAccountsWithAsset(QueryWithFilter<FindAccountsWithAsset>),
+        FindAliasesByAccountId(QueryWithFilter<FindAliasesByAccountId>),
 
         FindPeers(QueryWithFilter<FindPeers>),
         FindActiveTriggerIds(QueryWithFilter<FindActiveTriggerIds>),
@@ -123,6 +126,10 @@ mod model {
         FindTransactions(QueryWithFilter<FindTransactions>),
         FindBlocks(QueryWithFilter<FindBlocks>),
         FindBlockHeaders(QueryWithFilter<FindBlockHeaders>),
+        FindTriggerReceipts(QueryWithFilter<FindTriggerReceipts>),
+        FindStorageEntries(QueryWithFilter<FindStorageEntries>),
+        FindContracts(QueryWithFilter<FindContracts>),
+        FindEquivocations(QueryWithFilter<FindEquivocations>),
     }
 
     /// An enum of all possible iterable query batches.
@@ -163,6 +170,12 @@ mod model {
         Block(Vec<SignedBlock>),
         BlockHeader(Vec<BlockHeader>),
         BlockHeaderHash(Vec<HashOf<BlockHeader>>),
+        CommittedTriggerReceipt(Vec<CommittedTriggerReceipt>),
+        TriggerReceipt(Vec<TriggerReceipt>),
+        StorageEntry(Vec<StorageEntry>),
+        Contract(Vec<Contract>),
+        ContractId(Vec<ContractId>),
+        EquivocationEvidence(Vec<EquivocationEvidence>),
     }
 
     #[derive(
@@ -179,6 +192,10 @@ mod model {
     pub enum SingularQueryBox {
         FindExecutorDataModel(FindExecutorDataModel),
         FindParameters(FindParameters),
+        FindAccountIdByAlias(FindAccountIdByAlias),
+        FindRandomness(FindRandomness),
+        FindValidatorSets(FindValidatorSets),
+        CustomQuery(CustomQuery),
     }
 
     /// An enum of all possible singular query outputs
@@ -188,6 +205,10 @@ mod model {
     pub enum SingularQueryOutputBox {
         ExecutorDataModel(crate::executor::ExecutorDataModel),
         Parameters(Parameters),
+        AccountId(AccountId),
+        Hash(iroha_crypto::Hash),
+        ValidatorSets(crate::peer::ValidatorSets),
+        Json(Json),
     }
 
     /// The results of a single iterable query request.
@@ -244,7 +265,12 @@ mod model {
 
     /// A signature of [`QueryRequestWithAuthority`] to be used in [`SignedQueryV1`]
     #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
-    pub struct QuerySignature(pub SignatureOf<QueryRequestWithAuthority>);
+    pub struct QuerySignature {
+        /// Key that made the signature, one of the keys of the authority
+        pub public_key: PublicKey,
+        /// Signature itself
+        pub signature: SignatureOf<QueryRequestWithAuthority>,
+    }
 
     declare_versioned!(SignedQuery 1..2, Debug, Clone, FromVariant, IntoSchema);
 
@@ -282,6 +308,30 @@ mod model {
         /// Reason of rejection, if any
         pub error: Option<TransactionRejectionReason>,
     }
+
+    /// Output of [`FindTriggerReceipts`] query
+    #[derive(
+        Debug,
+        Clone,
+        PartialOrd,
+        Ord,
+        PartialEq,
+        Eq,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct CommittedTriggerReceipt {
+        /// The hash of the block after which the trigger was executed
+        pub block_hash: HashOf<BlockHeader>,
+        /// Trigger receipt
+        pub value: TriggerReceipt,
+    }
 }
 
 impl QueryOutputBatchBox {
@@ -325,6 +375,12 @@ impl QueryOutputBatchBox {
             (Self::Block(v1), Self::Block(v2)) => v1.extend(v2),
             (Self::BlockHeader(v1), Self::BlockHeader(v2)) => v1.extend(v2),
             (Self::BlockHeaderHash(v1), Self::BlockHeaderHash(v2)) => v1.extend(v2),
+            (Self::CommittedTriggerReceipt(v1), Self::CommittedTriggerReceipt(v2)) => v1.extend(v2),
+            (Self::TriggerReceipt(v1), Self::TriggerReceipt(v2)) => v1.extend(v2),
+            (Self::StorageEntry(v1), Self::StorageEntry(v2)) => v1.extend(v2),
+            (Self::Contract(v1), Self::Contract(v2)) => v1.extend(v2),
+            (Self::ContractId(v1), Self::ContractId(v2)) => v1.extend(v2),
+            (Self::EquivocationEvidence(v1), Self::EquivocationEvidence(v2)) => v1.extend(v2),
             _ => panic!("Cannot extend different types of IterableQueryOutputBatchBox"),
         }
     }
@@ -364,6 +420,12 @@ impl QueryOutputBatchBox {
             Self::Block(v) => v.len(),
             Self::BlockHeader(v) => v.len(),
             Self::BlockHeaderHash(v) => v.len(),
+            Self::CommittedTriggerReceipt(v) => v.len(),
+            Self::TriggerReceipt(v) => v.len(),
+            Self::StorageEntry(v) => v.len(),
+            Self::Contract(v) => v.len(),
+            Self::ContractId(v) => v.len(),
+            Self::EquivocationEvidence(v) => v.len(),
         }
     }
 }
@@ -466,7 +528,10 @@ impl QueryRequestWithAuthority {
         let signature = SignatureOf::new(key_pair.private_key(), &self);
 
         SignedQueryV1 {
-            signature: QuerySignature(signature),
+            signature: QuerySignature {
+                public_key: key_pair.public_key().clone(),
+                signature,
+            },
             payload: self,
         }
         .into()
@@ -502,9 +567,13 @@ mod candidate {
         fn validate(self) -> Result<SignedQueryV1, &'static str> {
             #[cfg(not(target_family = "wasm"))]
             {
-                let QuerySignature(signature) = &self.signature;
+                // Whether the key belongs to the authority is checked against the state
+                let QuerySignature {
+                    public_key,
+                    signature,
+                } = &self.signature;
                 signature
-                    .verify(&self.payload.authority.signatory, &self.payload)
+                    .verify(public_key, &self.payload)
                     .map_err(|_| "Query request signature is not valid")?;
             }
 
@@ -622,18 +691,19 @@ mod candidate {
         }
 
         #[test]
-        fn mismatching_authority() {
+        fn mismatching_public_key() {
             let SignedQuery::V1(signed_query) = QueryRequest::Singular(
                 SingularQueryBox::FindExecutorDataModel(FindExecutorDataModel),
             )
-            // signing with a wrong key here
             .with_authority(ALICE_ID.clone())
             .sign(&BOB_KEYPAIR);
 
-            let candidate = SignedQueryCandidate {
+            let mut candidate = SignedQueryCandidate {
                 signature: signed_query.signature,
                 payload: signed_query.payload,
             };
+            // claiming a key other than the one which made the signature
+            candidate.signature.public_key = ALICE_KEYPAIR.public_key().clone();
 
             assert_eq!(
                 candidate.validate().unwrap_err(),
@@ -688,13 +758,22 @@ impl_iter_queries! {
     FindTriggers => crate::trigger::Trigger,
     FindTransactions => CommittedTransaction,
     FindAccountsWithAsset => crate::account::Account,
+    FindAliasesByAccountId => crate::name::Name,
     FindBlockHeaders => crate::block::BlockHeader,
     FindBlocks => SignedBlock,
+    FindTriggerReceipts => CommittedTriggerReceipt,
+    FindStorageEntries => StorageEntry,
+    FindContracts => Contract,
+    FindEquivocations => EquivocationEvidence,
 }
 
 impl_singular_queries! {
     FindParameters => crate::parameter::Parameters,
     FindExecutorDataModel => crate::executor::ExecutorDataModel,
+    FindAccountIdByAlias => crate::account::AccountId,
+    FindRandomness => iroha_crypto::Hash,
+    FindValidatorSets => crate::peer::ValidatorSets,
+    CustomQuery => Json,
 }
 
 impl AsRef<SignedTransaction> for CommittedTransaction {
@@ -824,11 +903,36 @@ pub mod account {
             /// `Id` of the definition of the asset which should be stored in founded accounts.
             pub asset_definition: AssetDefinitionId,
         }
+
+        /// [`FindAccountIdByAlias`] Iroha Query resolves an [`AccountAlias`] into the [`AccountId`] it is registered for.
+        #[derive(Display)]
+        #[display(fmt = "Find account with `{alias}` alias")]
+        #[repr(transparent)]
+        // SAFETY: `FindAccountIdByAlias` has no trap representation in `AccountAlias`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindAccountIdByAlias {
+            /// Alias to resolve.
+            pub alias: AccountAlias,
+        }
+
+        /// [`FindAliasesByAccountId`] Iroha Query finds names of all [`AccountAlias`]es
+        /// registered for a specified account within its domain.
+        #[derive(Display)]
+        #[display(fmt = "Find all aliases of `{id}` account")]
+        #[repr(transparent)]
+        // SAFETY: `FindAliasesByAccountId` has no trap representation in `AccountId`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindAliasesByAccountId {
+            /// `Id` of an account to find.
+            pub id: AccountId,
+        }
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::{FindAccounts, FindAccountsWithAsset};
+        pub use super::{
+            FindAccountIdByAlias, FindAccounts, FindAccountsWithAsset, FindAliasesByAccountId,
+        };
     }
 }
 
@@ -923,11 +1027,18 @@ pub mod peer {
         #[display(fmt = "Find all peers")]
         #[ffi_type]
         pub struct FindPeers;
+
+        /// [`FindValidatorSets`] Iroha Query finds the peers committing blocks
+        /// and the changes to them staged for an epoch boundary
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find validator sets")]
+        #[ffi_type]
+        pub struct FindValidatorSets;
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::FindPeers;
+        pub use super::{FindPeers, FindValidatorSets};
     }
 }
 
@@ -938,6 +1049,7 @@ pub mod executor {
     use alloc::{format, string::String, vec::Vec};
 
     use derive_more::Display;
+    use iroha_primitives::json::Json;
 
     queries! {
         /// [`FindExecutorDataModel`] Iroha Query finds the data model of the current executor.
@@ -951,11 +1063,23 @@ pub mod executor {
         #[display(fmt = "Find all peers parameters")]
         #[ffi_type]
         pub struct FindParameters;
+
+        /// Blockchain specific query (defined and resolved by the executor).
+        ///
+        /// Executors that don't resolve custom queries fail them with
+        /// [`QueryExecutionFail::Custom`](crate::query::error::QueryExecutionFail::Custom)
+        #[derive(Display, getset::Getters)]
+        #[display(fmt = "CUSTOM({payload})")]
+        #[getset(get = "pub")]
+        pub struct CustomQuery {
+            /// Custom payload
+            pub payload: Json,
+        }
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::{FindExecutorDataModel, FindParameters};
+        pub use super::{CustomQuery, FindExecutorDataModel, FindParameters};
     }
 }
 
@@ -987,6 +1111,58 @@ pub mod trigger {
     }
 }
 
+pub mod storage {
+    //! Queries related to the persistent key-value storage of smart contracts and triggers.
+
+    #[cfg(not(feature = "std"))]
+    use alloc::{format, string::String, vec::Vec};
+
+    use derive_more::Display;
+
+    use crate::storage::StorageOwner;
+
+    queries! {
+        /// [`FindStorageEntries`] Iroha Query lists entries of the persistent storage
+        /// of a trigger or of the smart contracts submitted by an account sorted by key
+        #[derive(Display)]
+        #[display(fmt = "Find storage entries of {owner}")]
+        #[repr(transparent)]
+        // SAFETY: `FindStorageEntries` has no trap representation in `StorageOwner`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindStorageEntries {
+            /// Owner of the storage.
+            pub owner: StorageOwner,
+        }
+    }
+
+    /// The prelude re-exports most commonly used traits, structs and macros from this crate.
+    pub mod prelude {
+        pub use super::FindStorageEntries;
+    }
+}
+
+pub mod contract {
+    //! Queries related to the contract registry.
+
+    #[cfg(not(feature = "std"))]
+    use alloc::{format, string::String, vec::Vec};
+
+    use derive_more::Display;
+
+    queries! {
+        /// [`FindContracts`] Iroha Query finds all deployed [`Contract`](crate::contract::Contract)s.
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find all contracts")]
+        #[ffi_type]
+        pub struct FindContracts;
+    }
+
+    /// The prelude re-exports most commonly used traits, structs and macros from this crate.
+    pub mod prelude {
+        pub use super::FindContracts;
+    }
+}
+
 pub mod transaction {
     //! Queries related to transactions.
 
@@ -1035,11 +1211,35 @@ pub mod block {
         #[display(fmt = "Find all block headers")]
         #[ffi_type]
         pub struct FindBlockHeaders;
+
+        /// [`FindTriggerReceipts`] Iroha Query lists receipts of the triggers
+        /// executed after every block sorted by height in descending order
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find all trigger receipts")]
+        #[ffi_type]
+        pub struct FindTriggerReceipts;
+
+        /// [`FindRandomness`] Iroha Query finds the latest value of the randomness beacon:
+        /// the randomness of the latest block or the hash of the genesis block
+        /// if no other blocks are committed
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find latest randomness")]
+        #[ffi_type]
+        pub struct FindRandomness;
+
+        /// [`FindEquivocations`] Iroha Query lists evidence of peers' equivocation
+        /// recorded in every block sorted by height in descending order
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find all equivocations")]
+        #[ffi_type]
+        pub struct FindEquivocations;
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::{FindBlockHeaders, FindBlocks};
+        pub use super::{
+            FindBlockHeaders, FindBlocks, FindEquivocations, FindRandomness, FindTriggerReceipts,
+        };
     }
 }
 
@@ -1100,6 +1300,12 @@ pub mod error {
             InvalidSingularParameters,
             /// Reached the limit of parallel queries. Either wait for previous queries to complete, or increase the limit in the config.
             CapacityLimit,
+            /// Executor failed to resolve the custom query: {0}
+            Custom(
+                #[skip_from]
+                #[skip_try_from]
+                String,
+            ),
         }
 
         /// Type assertion error
@@ -1147,6 +1353,10 @@ pub mod error {
             Permission(Permission),
             /// Failed to find public key: `{0}`
             PublicKey(PublicKey),
+            /// Failed to find account alias: `{0}`
+            AccountAlias(AccountAlias),
+            /// Contract with id `{0}` not found
+            Contract(ContractId),
         }
     }
 }
@@ -1156,9 +1366,9 @@ pub mod error {
 pub mod prelude {
     pub use super::{
         account::prelude::*, asset::prelude::*, block::prelude::*, builder::prelude::*,
-        domain::prelude::*, dsl::prelude::*, executor::prelude::*, nft::prelude::*,
-        parameters::prelude::*, peer::prelude::*, permission::prelude::*, role::prelude::*,
-        transaction::prelude::*, trigger::prelude::*, CommittedTransaction, QueryBox, QueryRequest,
-        SingularQueryBox,
+        contract::prelude::*, domain::prelude::*, dsl::prelude::*, executor::prelude::*,
+        nft::prelude::*, parameters::prelude::*, peer::prelude::*, permission::prelude::*,
+        role::prelude::*, storage::prelude::*, transaction::prelude::*, trigger::prelude::*,
+        CommittedTransaction, CommittedTriggerReceipt, QueryBox, QueryRequest, SingularQueryBox,
     };
 }
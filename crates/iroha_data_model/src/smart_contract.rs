@@ -29,6 +29,21 @@ pub mod payloads {
         pub event: EventBox,
     }
 
+    /// Context for contract entrypoint
+    #[derive(Debug, Clone, Encode, Decode)]
+    pub struct ContractContext {
+        /// Id of this contract
+        pub id: ContractId,
+        /// Account that deployed the contract and on behalf of which it is executed
+        pub authority: AccountId,
+        /// Account that called the contract
+        pub caller: AccountId,
+        /// Block currently being processed
+        pub curr_block: BlockHeader,
+        /// Arguments the contract was called with
+        pub args: Json,
+    }
+
     /// Context for migrate entrypoint
     #[derive(Debug, Clone, Encode, Decode)]
     pub struct ExecutorContext {
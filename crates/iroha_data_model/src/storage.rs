@@ -0,0 +1,84 @@
+//! Structures related to the persistent key-value storage of smart contracts and triggers.
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+
+use iroha_data_model_derive::model;
+
+pub use self::model::*;
+use crate::{account::AccountId, contract::ContractId, trigger::TriggerId};
+
+#[model]
+mod model {
+    use derive_more::{Constructor, Display};
+    use getset::Getters;
+    use iroha_macro::FromVariant;
+    use iroha_schema::IntoSchema;
+    use parity_scale_codec::{Decode, Encode};
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    /// Owner of a namespace in the persistent key-value storage.
+    ///
+    /// Every trigger and deployed contract has a namespace of its own, while
+    /// smart contracts share the namespace of the account which submitted them.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        FromVariant,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type(opaque)]
+    pub enum StorageOwner {
+        /// Storage of the trigger.
+        #[display(fmt = "trigger `{_0}`")]
+        Trigger(TriggerId),
+        /// Storage of the smart contracts submitted by the account.
+        #[display(fmt = "account `{_0}`")]
+        Account(AccountId),
+        /// Storage of the deployed contract.
+        #[display(fmt = "contract `{_0}`")]
+        Contract(ContractId),
+    }
+
+    /// Entry of the persistent key-value storage.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct StorageEntry {
+        /// Key of the entry.
+        pub key: Vec<u8>,
+        /// Value of the entry.
+        pub value: Vec<u8>,
+    }
+}
+
+/// The prelude re-exports most commonly used traits, structs and macros from this crate.
+pub mod prelude {
+    pub use super::{StorageEntry, StorageOwner};
+}
@@ -1,6 +1,6 @@
 //! [`Transaction`] structures and related implementations.
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, format, string::String, vec::Vec};
+use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
 use core::{
     fmt::{Display, Formatter, Result as FmtResult},
     iter::IntoIterator,
@@ -9,7 +9,7 @@ use core::{
 };
 
 use derive_more::{DebugCustom, Display};
-use iroha_crypto::{Signature, SignatureOf};
+use iroha_crypto::{PublicKey, Signature, SignatureOf};
 use iroha_data_model_derive::model;
 use iroha_macro::FromVariant;
 use iroha_schema::IntoSchema;
@@ -115,7 +115,7 @@ mod model {
         pub metadata: Metadata,
     }
 
-    /// Signature of transaction
+    /// Signature of transaction along with the key that made it
     #[derive(
         Debug,
         Clone,
@@ -129,14 +129,20 @@ mod model {
         Serialize,
         IntoSchema,
     )]
-    pub struct TransactionSignature(pub SignatureOf<TransactionPayload>);
+    pub struct TransactionSignature {
+        /// Key that made the signature.
+        pub public_key: PublicKey,
+        /// Signature itself.
+        pub signature: SignatureOf<TransactionPayload>,
+    }
 
-    /// Transaction that contains a signature
+    /// Transaction that contains signatures
     ///
     /// `Iroha` and its clients use [`Self`] to send transactions over the network.
     /// After a transaction is signed and before it can be processed any further,
     /// the transaction must be accepted by the `Iroha` peer.
-    /// The peer verifies the signature and checks the limits.
+    /// The peer verifies the signatures and checks the limits.
+    /// Whether the signing keys are authorized for the authority account is checked against the state.
     #[version(version = 1, versioned_alias = "SignedTransaction")]
     #[derive(
         Debug, Display, Clone, PartialEq, Eq, PartialOrd, Ord, Encode, Serialize, IntoSchema,
@@ -144,8 +150,8 @@ mod model {
     #[display(fmt = "{}", "self.hash()")]
     #[ffi_type]
     pub struct SignedTransactionV1 {
-        /// Signature of [`Self::payload`].
-        pub(super) signature: TransactionSignature,
+        /// Signatures of [`Self::payload`].
+        pub(super) signatures: Vec<TransactionSignature>,
         /// Payload of the transaction.
         pub(super) payload: TransactionPayload,
     }
@@ -261,11 +267,35 @@ impl SignedTransaction {
         &tx.payload.chain
     }
 
-    /// Return the transaction signature
+    /// Return the transaction signatures
     #[inline]
-    pub fn signature(&self) -> &TransactionSignature {
+    pub fn signatures(&self) -> &[TransactionSignature] {
         let SignedTransaction::V1(tx) = self;
-        &tx.signature
+        &tx.signatures
+    }
+
+    /// Return keys that signed the transaction
+    #[inline]
+    pub fn signatories(&self) -> impl Iterator<Item = &PublicKey> {
+        self.signatures()
+            .iter()
+            .map(|signature| &signature.public_key)
+    }
+
+    /// Add a signature by a further key, as required by accounts whose
+    /// [`AccountKeys`](crate::account::AccountKeys) threshold is not reached by a single key.
+    #[must_use]
+    pub fn cosign(self, private_key: &iroha_crypto::PrivateKey) -> Self {
+        let SignedTransaction::V1(mut tx) = self;
+        let signature = TransactionSignature::new(private_key, &tx.payload);
+        if !tx
+            .signatures
+            .iter()
+            .any(|existing| existing.public_key == signature.public_key)
+        {
+            tx.signatures.push(signature);
+        }
+        tx.into()
     }
 
     /// Calculate transaction [`Hash`](`iroha_crypto::HashOf`).
@@ -290,9 +320,16 @@ impl SignedTransactionV1 {
 }
 
 impl TransactionSignature {
+    fn new(private_key: &iroha_crypto::PrivateKey, payload: &TransactionPayload) -> Self {
+        Self {
+            public_key: private_key.clone().into(),
+            signature: SignatureOf::new(private_key, payload),
+        }
+    }
+
     /// Signature itself
     pub fn payload(&self) -> &Signature {
-        &self.0
+        &self.signature
     }
 }
 
@@ -410,12 +447,14 @@ impl TransactionBuilder {
     }
 
     /// Sign transaction with provided key pair.
+    ///
+    /// Use [`SignedTransaction::cosign`] to add signatures by further keys.
     #[must_use]
     pub fn sign(self, private_key: &iroha_crypto::PrivateKey) -> SignedTransaction {
-        let signature = TransactionSignature(SignatureOf::new(private_key, &self.payload));
+        let signature = TransactionSignature::new(private_key, &self.payload);
 
         SignedTransactionV1 {
-            signature,
+            signatures: vec![signature],
             payload: self.payload,
         }
         .into()
@@ -429,7 +468,7 @@ mod candidate {
 
     #[derive(Decode, Deserialize)]
     struct SignedTransactionCandidate {
-        signature: TransactionSignature,
+        signatures: Vec<TransactionSignature>,
         payload: TransactionPayload,
     }
 
@@ -441,7 +480,7 @@ mod candidate {
             self.validate_signature()?;
 
             Ok(SignedTransactionV1 {
-                signature: self.signature,
+                signatures: self.signatures,
                 payload: self.payload,
             })
         }
@@ -459,11 +498,29 @@ mod candidate {
 
         #[cfg(not(target_family = "wasm"))]
         fn validate_signature(&self) -> Result<(), &'static str> {
-            let TransactionSignature(signature) = &self.signature;
+            if self.signatures.is_empty() {
+                return Err("Transaction is not signed");
+            }
 
-            signature
-                .verify(&self.payload.authority.signatory, &self.payload)
-                .map_err(|_| "Transaction signature is invalid")?;
+            for (
+                i,
+                TransactionSignature {
+                    public_key,
+                    signature,
+                },
+            ) in self.signatures.iter().enumerate()
+            {
+                if self.signatures[..i]
+                    .iter()
+                    .any(|other| other.public_key == *public_key)
+                {
+                    return Err("Transaction is signed by the same key more than once");
+                }
+
+                signature
+                    .verify(public_key, &self.payload)
+                    .map_err(|_| "Transaction signature is invalid")?;
+            }
 
             Ok(())
         }
@@ -639,6 +696,8 @@ pub mod error {
                 #[cfg_attr(feature = "std", source)]
                 crate::query::error::FindError,
             ),
+            /// Transaction signatures don't reach the signature threshold of the authority account
+            InsufficientSignatures,
             /// Failed to validate transaction limits
             ///
             /// e.g. number of instructions
@@ -672,6 +731,11 @@ pub mod error {
                 SetParameter(_) => "set parameter",
                 Upgrade(_) => "upgrade",
                 Log(_) => "log",
+                AccountKey(_) => "account key",
+                AccountAlias(_) => "account alias",
+                Contract(_) => "contract",
+                EmitEvent(_) => "emit event",
+                UpgradeTrigger(_) => "upgrade trigger",
                 Custom(_) => "custom",
             };
             write!(
@@ -698,10 +762,82 @@ pub mod error {
     }
 }
 
+pub mod simulation {
+    //! Structures related to executing a transaction without committing it
+    pub use self::model::*;
+    use super::*;
+
+    #[model]
+    mod model {
+        use getset::{CopyGetters, Getters};
+
+        use super::*;
+        use crate::{events::data::DataEvent, trigger::TriggerId};
+
+        /// Transaction to simulate
+        #[derive(
+            Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema,
+        )]
+        pub enum TransactionSimulationRequest {
+            /// Transaction whose signatures must meet the signature threshold of the authority
+            Signed(SignedTransaction),
+            /// Transaction signed by any key of the authority, the signature threshold isn't checked
+            PartiallySigned(SignedTransaction),
+        }
+
+        /// Outcome of executing a transaction on top of the latest state without committing it
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            CopyGetters,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        pub struct TransactionSimulation {
+            /// Reason of the rejection, `None` if the transaction would be accepted
+            #[getset(get = "pub")]
+            pub rejection_reason: Option<error::TransactionRejectionReason>,
+            /// Data events produced by the transaction
+            #[getset(get = "pub")]
+            pub events: Vec<DataEvent>,
+            /// Triggers matched by the events of the transaction
+            #[getset(get = "pub")]
+            pub triggers: Vec<TriggerId>,
+            /// Fuel consumed by the executor, smart contract and contracts run by the transaction
+            #[getset(get_copy = "pub")]
+            pub fuel_consumed: u64,
+            /// Peak linear memory in bytes used by any WASM module run by the transaction
+            #[getset(get_copy = "pub")]
+            pub memory_consumed: u64,
+        }
+    }
+
+    impl TransactionSimulation {
+        /// Whether the transaction would be accepted
+        #[inline]
+        pub fn is_accepted(&self) -> bool {
+            self.rejection_reason.is_none()
+        }
+    }
+
+    pub mod prelude {
+        //! The prelude re-exports most commonly used traits, structs and macros from this module.
+
+        pub use super::{TransactionSimulation, TransactionSimulationRequest};
+    }
+}
+
 /// The prelude re-exports most commonly used traits, structs and macros from this module.
 pub mod prelude {
     pub use super::{
-        error::prelude::*, Executable, SignedTransaction, TransactionBuilder, WasmSmartContract,
+        error::prelude::*, simulation::prelude::*, Executable, SignedTransaction,
+        TransactionBuilder, WasmSmartContract,
     };
 }
 
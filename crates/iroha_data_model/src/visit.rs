@@ -45,8 +45,13 @@ pub trait Visit {
         visit_transfer(&TransferBox),
         visit_unregister(&UnregisterBox),
         visit_upgrade(&Upgrade),
+        visit_account_key(&AccountKeyBox),
+        visit_account_alias(&AccountAliasBox),
+        visit_contract(&ContractBox),
 
         visit_execute_trigger(&ExecuteTrigger),
+        visit_upgrade_trigger(&UpgradeTrigger),
+        visit_emit_event(&EmitEvent),
         visit_set_parameter(&SetParameter),
         visit_log(&Log),
         visit_custom_instruction(&CustomInstruction),
@@ -54,6 +59,10 @@ pub trait Visit {
         // Visit SingularQueryBox
         visit_find_executor_data_model(&FindExecutorDataModel),
         visit_find_parameters(&FindParameters),
+        visit_find_account_id_by_alias(&FindAccountIdByAlias),
+        visit_find_randomness(&FindRandomness),
+        visit_find_validator_sets(&FindValidatorSets),
+        visit_custom_query(&CustomQuery),
 
         // Visit IterableQueryBox
         visit_find_domains(&QueryWithFilter<FindDomains>),
@@ -65,6 +74,7 @@ pub trait Visit {
         visit_find_role_ids(&QueryWithFilter<FindRoleIds>),
         visit_find_permissions_by_account_id(&QueryWithFilter<FindPermissionsByAccountId>),
         visit_find_roles_by_account_id(&QueryWithFilter<FindRolesByAccountId>),
+        visit_find_aliases_by_account_id(&QueryWithFilter<FindAliasesByAccountId>),
         visit_find_accounts_with_asset(&QueryWithFilter<FindAccountsWithAsset>),
         visit_find_peers(&QueryWithFilter<FindPeers>),
         visit_find_active_trigger_ids(&QueryWithFilter<FindActiveTriggerIds>),
@@ -72,6 +82,10 @@ pub trait Visit {
         visit_find_transactions(&QueryWithFilter<FindTransactions>),
         visit_find_blocks(&QueryWithFilter<FindBlocks>),
         visit_find_block_headers(&QueryWithFilter<FindBlockHeaders>),
+        visit_find_trigger_receipts(&QueryWithFilter<FindTriggerReceipts>),
+        visit_find_storage_entries(&QueryWithFilter<FindStorageEntries>),
+        visit_find_contracts(&QueryWithFilter<FindContracts>),
+        visit_find_equivocations(&QueryWithFilter<FindEquivocations>),
 
         // Visit RegisterBox
         visit_register_peer(&Register<Peer>),
@@ -129,6 +143,22 @@ pub trait Visit {
         visit_revoke_account_permission(&Revoke<Permission, Account>),
         visit_revoke_account_role(&Revoke<RoleId, Account>),
         visit_revoke_role_permission(&Revoke<Permission, Role>),
+
+        // Visit AccountKeyBox
+        visit_add_account_key(&AddAccountKey),
+        visit_remove_account_key(&RemoveAccountKey),
+        visit_rotate_account_key(&RotateAccountKey),
+        visit_set_account_key_threshold(&SetAccountKeyThreshold),
+
+        // Visit AccountAliasBox
+        visit_register_account_alias(&RegisterAccountAlias),
+        visit_transfer_account_alias(&TransferAccountAlias),
+        visit_unregister_account_alias(&UnregisterAccountAlias),
+
+        // Visit ContractBox
+        visit_deploy_contract(&DeployContract),
+        visit_remove_contract(&RemoveContract),
+        visit_call_contract(&CallContract),
     }
 }
 
@@ -155,6 +185,10 @@ pub fn visit_singular_query<V: Visit + ?Sized>(visitor: &mut V, query: &Singular
     singular_query_visitors! {
         visit_find_executor_data_model(FindExecutorDataModel),
         visit_find_parameters(FindParameters),
+        visit_find_account_id_by_alias(FindAccountIdByAlias),
+        visit_find_randomness(FindRandomness),
+        visit_find_validator_sets(FindValidatorSets),
+        visit_custom_query(CustomQuery),
     }
 }
 
@@ -177,6 +211,7 @@ pub fn visit_iter_query<V: Visit + ?Sized>(visitor: &mut V, query: &QueryWithPar
         visit_find_role_ids(FindRoleIds),
         visit_find_permissions_by_account_id(FindPermissionsByAccountId),
         visit_find_roles_by_account_id(FindRolesByAccountId),
+        visit_find_aliases_by_account_id(FindAliasesByAccountId),
         visit_find_accounts_with_asset(FindAccountsWithAsset),
         visit_find_peers(FindPeers),
         visit_find_active_trigger_ids(FindActiveTriggerIds),
@@ -184,6 +219,10 @@ pub fn visit_iter_query<V: Visit + ?Sized>(visitor: &mut V, query: &QueryWithPar
         visit_find_transactions(FindTransactions),
         visit_find_block_headers(FindBlockHeaders),
         visit_find_blocks(FindBlocks),
+        visit_find_trigger_receipts(FindTriggerReceipts),
+        visit_find_storage_entries(FindStorageEntries),
+        visit_find_contracts(FindContracts),
+        visit_find_equivocations(FindEquivocations),
     }
 }
 
@@ -220,6 +259,13 @@ pub fn visit_instruction<V: Visit + ?Sized>(visitor: &mut V, isi: &InstructionBo
         InstructionBox::Transfer(variant_value) => visitor.visit_transfer(variant_value),
         InstructionBox::Unregister(variant_value) => visitor.visit_unregister(variant_value),
         InstructionBox::Upgrade(variant_value) => visitor.visit_upgrade(variant_value),
+        InstructionBox::AccountKey(variant_value) => visitor.visit_account_key(variant_value),
+        InstructionBox::AccountAlias(variant_value) => visitor.visit_account_alias(variant_value),
+        InstructionBox::Contract(variant_value) => visitor.visit_contract(variant_value),
+        InstructionBox::EmitEvent(variant_value) => visitor.visit_emit_event(variant_value),
+        InstructionBox::UpgradeTrigger(variant_value) => {
+            visitor.visit_upgrade_trigger(variant_value)
+        }
         InstructionBox::Custom(custom) => visitor.visit_custom_instruction(custom),
     }
 }
@@ -309,6 +355,31 @@ pub fn visit_revoke<V: Visit + ?Sized>(visitor: &mut V, isi: &RevokeBox) {
     }
 }
 
+pub fn visit_account_key<V: Visit + ?Sized>(visitor: &mut V, isi: &AccountKeyBox) {
+    match isi {
+        AccountKeyBox::Add(obj) => visitor.visit_add_account_key(obj),
+        AccountKeyBox::Remove(obj) => visitor.visit_remove_account_key(obj),
+        AccountKeyBox::Rotate(obj) => visitor.visit_rotate_account_key(obj),
+        AccountKeyBox::SetThreshold(obj) => visitor.visit_set_account_key_threshold(obj),
+    }
+}
+
+pub fn visit_account_alias<V: Visit + ?Sized>(visitor: &mut V, isi: &AccountAliasBox) {
+    match isi {
+        AccountAliasBox::Register(obj) => visitor.visit_register_account_alias(obj),
+        AccountAliasBox::Transfer(obj) => visitor.visit_transfer_account_alias(obj),
+        AccountAliasBox::Unregister(obj) => visitor.visit_unregister_account_alias(obj),
+    }
+}
+
+pub fn visit_contract<V: Visit + ?Sized>(visitor: &mut V, isi: &ContractBox) {
+    match isi {
+        ContractBox::Deploy(obj) => visitor.visit_deploy_contract(obj),
+        ContractBox::Remove(obj) => visitor.visit_remove_contract(obj),
+        ContractBox::Call(obj) => visitor.visit_call_contract(obj),
+    }
+}
+
 macro_rules! leaf_visitors {
     ( $($visitor:ident($operation:ty)),+ $(,)? ) => { $(
         pub fn $visitor<V: Visit + ?Sized>(_visitor: &mut V, _operation: $operation) {
@@ -323,6 +394,16 @@ leaf_visitors! {
     visit_unregister_account(&Unregister<Account>),
     visit_set_account_key_value(&SetKeyValue<Account>),
     visit_remove_account_key_value(&RemoveKeyValue<Account>),
+    visit_add_account_key(&AddAccountKey),
+    visit_remove_account_key(&RemoveAccountKey),
+    visit_rotate_account_key(&RotateAccountKey),
+    visit_set_account_key_threshold(&SetAccountKeyThreshold),
+    visit_register_account_alias(&RegisterAccountAlias),
+    visit_transfer_account_alias(&TransferAccountAlias),
+    visit_unregister_account_alias(&UnregisterAccountAlias),
+    visit_deploy_contract(&DeployContract),
+    visit_remove_contract(&RemoveContract),
+    visit_call_contract(&CallContract),
     visit_register_nft(&Register<Nft>),
     visit_unregister_nft(&Unregister<Nft>),
     visit_mint_asset_numeric(&Mint<Numeric, Asset>),
@@ -360,12 +441,18 @@ leaf_visitors! {
     visit_upgrade(&Upgrade),
     visit_set_parameter(&SetParameter),
     visit_execute_trigger(&ExecuteTrigger),
+    visit_upgrade_trigger(&UpgradeTrigger),
+    visit_emit_event(&EmitEvent),
     visit_log(&Log),
     visit_custom_instruction(&CustomInstruction),
 
     // Singular Query visitors
     visit_find_executor_data_model(&FindExecutorDataModel),
     visit_find_parameters(&FindParameters),
+    visit_find_account_id_by_alias(&FindAccountIdByAlias),
+    visit_find_randomness(&FindRandomness),
+    visit_find_validator_sets(&FindValidatorSets),
+    visit_custom_query(&CustomQuery),
 
     // Iterable Query visitors
     visit_find_domains(&QueryWithFilter<FindDomains>),
@@ -377,6 +464,7 @@ leaf_visitors! {
     visit_find_role_ids(&QueryWithFilter<FindRoleIds>),
     visit_find_permissions_by_account_id(&QueryWithFilter<FindPermissionsByAccountId>),
     visit_find_roles_by_account_id(&QueryWithFilter<FindRolesByAccountId>),
+    visit_find_aliases_by_account_id(&QueryWithFilter<FindAliasesByAccountId>),
     visit_find_accounts_with_asset(&QueryWithFilter<FindAccountsWithAsset>),
     visit_find_peers(&QueryWithFilter<FindPeers>),
     visit_find_active_trigger_ids(&QueryWithFilter<FindActiveTriggerIds>),
@@ -384,4 +472,8 @@ leaf_visitors! {
     visit_find_transactions(&QueryWithFilter<FindTransactions>),
     visit_find_blocks(&QueryWithFilter<FindBlocks>),
     visit_find_block_headers(&QueryWithFilter<FindBlockHeaders>),
+    visit_find_trigger_receipts(&QueryWithFilter<FindTriggerReceipts>),
+    visit_find_storage_entries(&QueryWithFilter<FindStorageEntries>),
+    visit_find_contracts(&QueryWithFilter<FindContracts>),
+    visit_find_equivocations(&QueryWithFilter<FindEquivocations>),
 }
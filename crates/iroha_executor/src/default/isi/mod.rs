@@ -1,7 +1,12 @@
-use iroha_executor_data_model::isi::multisig::MultisigInstructionBox;
+use iroha_executor_data_model::isi::{
+    multisig::MultisigInstructionBox, role::RoleInstructionBox, vesting::VestingInstructionBox,
+};
 
 use super::*;
-use crate::prelude::{Execute, Vec, Visit};
+use crate::{
+    prelude::{Execute, Vec, Visit},
+    smart_contract::DebugExpectExt as _,
+};
 
 pub fn visit_custom_instruction<V: Execute + Visit + ?Sized>(
     executor: &mut V,
@@ -10,6 +15,12 @@ pub fn visit_custom_instruction<V: Execute + Visit + ?Sized>(
     if let Ok(instruction) = MultisigInstructionBox::try_from(instruction.payload()) {
         return instruction.visit_execute(executor);
     };
+    if let Ok(instruction) = VestingInstructionBox::try_from(instruction.payload()) {
+        return instruction.visit_execute(executor);
+    };
+    if let Ok(instruction) = RoleInstructionBox::try_from(instruction.payload()) {
+        return instruction.visit_execute(executor);
+    };
 
     deny!(executor, "unexpected custom instruction");
 }
@@ -46,4 +57,23 @@ macro_rules! visit_seq {
     };
 }
 
+/// Time in milliseconds at which the current block was created
+fn now_ms<V: Execute + Visit + ?Sized>(executor: &V) -> u64 {
+    executor
+        .context()
+        .curr_block
+        .creation_time()
+        .as_millis()
+        .try_into()
+        .dbg_expect("shouldn't overflow within 584942417 years")
+}
+
 mod multisig;
+mod role;
+mod vesting;
+
+pub(super) use role::{
+    can_administer_role, can_manage_role, ensure_in_scope, remove_from_hierarchy,
+    unregister_scoped_roles,
+};
+pub(super) use vesting::ensure_spendable;
@@ -3,7 +3,11 @@
 use super::*;
 
 impl VisitExecute for MultisigRegister {
-    fn visit<V: Execute + Visit + ?Sized>(&self, _executor: &mut V) {}
+    fn visit<V: Execute + Visit + ?Sized>(&self, executor: &mut V) {
+        if let Err(err) = validate_spec(&self.spec) {
+            deny!(executor, err)
+        };
+    }
 
     fn execute<V: Execute + Visit + ?Sized>(self, executor: &mut V) -> Result<(), ValidationFail> {
         let (multisig_account, spec) = self.into();
@@ -38,6 +42,9 @@ impl VisitExecute for MultisigRegister {
             Role::new(multisig_role.clone(), domain_owner.clone()),
         )));
 
+        // Authorize as the multisig account, which manages the signatories of its own role
+        executor.context_mut().authority = multisig_account;
+
         for signatory in spec.signatories.keys().cloned() {
             visit_seq!(executor
                 .visit_grant_account_role(&Grant::account_role(multisig_role.clone(), signatory)));
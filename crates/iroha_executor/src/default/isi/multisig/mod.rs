@@ -1,41 +1,43 @@
+use alloc::borrow::ToOwned as _;
+
 use iroha_executor_data_model::isi::multisig::*;
 
 use super::*;
-use crate::smart_contract::{DebugExpectExt as _, DebugUnwrapExt};
+use crate::smart_contract::DebugUnwrapExt;
 
 mod account;
 mod transaction;
 
+/// Check that the signatories of a multisig account can reach its quorum
+fn validate_spec(spec: &MultisigSpec) -> Result<(), ValidationFail> {
+    let total_weight = spec
+        .signatories
+        .values()
+        .try_fold(0_u16, |total, weight| total.checked_add(u16::from(*weight)));
+    let Some(total_weight) = total_weight else {
+        return Err(ValidationFail::NotPermitted(
+            "total weight of multisig signatories overflows".to_owned(),
+        ));
+    };
+
+    if total_weight < spec.quorum.get() {
+        return Err(ValidationFail::NotPermitted(
+            "multisig quorum exceeds the total weight of signatories".to_owned(),
+        ));
+    }
+
+    Ok(())
+}
+
 impl VisitExecute for MultisigInstructionBox {
     fn visit_execute<V: Execute + Visit + ?Sized>(self, executor: &mut V) {
         match self {
             MultisigInstructionBox::Register(instruction) => instruction.visit_execute(executor),
             MultisigInstructionBox::Propose(instruction) => instruction.visit_execute(executor),
             MultisigInstructionBox::Approve(instruction) => instruction.visit_execute(executor),
+            MultisigInstructionBox::Reject(instruction) => instruction.visit_execute(executor),
+            MultisigInstructionBox::Cancel(instruction) => instruction.visit_execute(executor),
+            MultisigInstructionBox::UpdateSpec(instruction) => instruction.visit_execute(executor),
         }
     }
 }
-
-const DELIMITER: char = '/';
-const MULTISIG: &str = "multisig";
-const MULTISIG_SIGNATORY: &str = "MULTISIG_SIGNATORY";
-
-fn spec_key() -> Name {
-    format!("{MULTISIG}{DELIMITER}spec").parse().unwrap()
-}
-
-fn proposal_key(hash: &HashOf<Vec<InstructionBox>>) -> Name {
-    format!("{MULTISIG}{DELIMITER}proposals{DELIMITER}{hash}")
-        .parse()
-        .unwrap()
-}
-
-fn multisig_role_for(account: &AccountId) -> RoleId {
-    format!(
-        "{MULTISIG_SIGNATORY}{DELIMITER}{}{DELIMITER}{}",
-        account.domain(),
-        account.signatory(),
-    )
-    .parse()
-    .unwrap()
-}
@@ -66,17 +66,21 @@ impl VisitExecute for MultisigPropose {
         };
         let proposal_value = MultisigProposalValue::new(
             self.instructions,
+            Some(proposer.clone()),
             now_ms,
             expires_at_ms,
             BTreeSet::from([proposer]),
+            BTreeSet::new(),
             None,
         );
         let relay_value = |relay: MultisigApprove| {
             MultisigProposalValue::new(
                 vec![relay.into()],
+                None,
                 now_ms,
                 expires_at_ms,
                 BTreeSet::new(),
+                BTreeSet::new(),
                 Some(false),
             )
         };
@@ -188,16 +192,6 @@ fn proposal_value<V: Execute + Visit + ?Sized>(
         .map_err(metadata_conversion_error)
 }
 
-fn now_ms<V: Execute + Visit + ?Sized>(executor: &V) -> u64 {
-    executor
-        .context()
-        .curr_block
-        .creation_time()
-        .as_millis()
-        .try_into()
-        .dbg_expect("shouldn't overflow within 584942417 years")
-}
-
 impl VisitExecute for MultisigApprove {
     fn visit<V: Execute + Visit + ?Sized>(&self, executor: &mut V) {
         let approver = executor.context().authority.clone();
@@ -242,6 +236,7 @@ impl VisitExecute for MultisigApprove {
             return Ok(());
         }
 
+        proposal_value.rejections.remove(&approver);
         proposal_value.approvals.insert(approver);
         visit_seq!(executor.visit_set_account_key_value(&SetKeyValue::account(
             multisig_account.clone(),
@@ -250,15 +245,8 @@ impl VisitExecute for MultisigApprove {
         )));
 
         let spec = multisig_spec(multisig_account.clone(), executor)?;
-        let is_authenticated = u16::from(spec.quorum)
-            <= spec
-                .signatories
-                .into_iter()
-                .filter(|(id, _)| proposal_value.approvals.contains(id))
-                .map(|(_, weight)| u16::from(weight))
-                .sum();
-
-        if is_authenticated {
+
+        if spec.is_authenticated(&proposal_value.approvals) {
             match proposal_value.is_relayed {
                 None => {
                     // Cleanup the transaction entry
@@ -323,6 +311,11 @@ fn prune_down<V: Execute + Visit + ?Sized>(
     // Authorize as the multisig account
     executor.context_mut().authority = multisig_account.clone();
 
+    if proposal_value(multisig_account.clone(), instructions_hash, executor).is_err() {
+        // The entry is missing if the signatories have been updated since the relayer was deployed
+        return Ok(());
+    }
+
     visit_seq!(
         executor.visit_remove_account_key_value(&RemoveKeyValue::account(
             multisig_account.clone(),
@@ -346,6 +339,196 @@ fn prune_down<V: Execute + Visit + ?Sized>(
     Ok(())
 }
 
+impl VisitExecute for MultisigReject {
+    fn visit<V: Execute + Visit + ?Sized>(&self, executor: &mut V) {
+        let rejecter = executor.context().authority.clone();
+        let multisig_account = self.account.clone();
+        let host = executor.host();
+        let instructions_hash = self.instructions_hash;
+
+        if host
+            .query(FindRolesByAccountId::new(rejecter))
+            .filter_with(|role_id| role_id.eq(multisig_role_for(&multisig_account)))
+            .execute_single()
+            .is_err()
+        {
+            deny!(executor, "not qualified to reject multisig");
+        };
+
+        if let Err(err) = proposal_value(multisig_account, instructions_hash, executor) {
+            deny!(executor, err)
+        };
+    }
+
+    fn execute<V: Execute + Visit + ?Sized>(self, executor: &mut V) -> Result<(), ValidationFail> {
+        let rejecter = executor.context().authority.clone();
+        let multisig_account = self.account;
+        let instructions_hash = self.instructions_hash;
+
+        // Check if the proposal is expired
+        // Authorize as the multisig account
+        prune_expired(multisig_account.clone(), instructions_hash, executor)?;
+
+        let Ok(mut proposal_value) =
+            proposal_value(multisig_account.clone(), instructions_hash, executor)
+        else {
+            // The proposal is pruned
+            // Notify that the proposal has expired, while returning Ok for the entry deletion to take effect
+            let log = Log::new(Level::INFO, format!("multisig proposal expired:\naccount: {multisig_account}\ninstructions hash: {instructions_hash}"));
+            visit_seq!(executor.visit_log(&log));
+            return Ok(());
+        };
+        if let Some(true) = proposal_value.is_relayed {
+            // The relaying approval already has executed
+            return Ok(());
+        }
+
+        proposal_value.approvals.remove(&rejecter);
+        proposal_value.rejections.insert(rejecter);
+        visit_seq!(executor.visit_set_account_key_value(&SetKeyValue::account(
+            multisig_account.clone(),
+            proposal_key(&instructions_hash),
+            Json::new(&proposal_value),
+        )));
+
+        let spec = multisig_spec(multisig_account.clone(), executor)?;
+
+        if spec.is_reachable(&proposal_value.rejections) {
+            return Ok(());
+        }
+
+        match proposal_value.is_relayed {
+            None => {
+                // Cleanup the transaction entry
+                prune_down(multisig_account.clone(), instructions_hash, executor)?;
+                let log = Log::new(Level::INFO, format!("multisig proposal rejected:\naccount: {multisig_account}\ninstructions hash: {instructions_hash}"));
+                visit_seq!(executor.visit_log(&log));
+            }
+            Some(false) => {
+                // This relayer can no longer approve, so reject upstream on behalf of it
+                executor.context_mut().authority = multisig_account.clone();
+
+                for instruction in proposal_value.instructions {
+                    let InstructionBox::Custom(instruction) = instruction else {
+                        continue;
+                    };
+                    if let Ok(MultisigInstructionBox::Approve(approve)) =
+                        instruction.payload().try_into()
+                    {
+                        let reject =
+                            MultisigReject::new(approve.account, approve.instructions_hash);
+                        visit_seq!(executor.visit_instruction(&reject.into()));
+                    }
+                }
+            }
+            _ => unreachable!(),
+        }
+
+        Ok(())
+    }
+}
+
+impl VisitExecute for MultisigCancel {
+    fn visit<V: Execute + Visit + ?Sized>(&self, executor: &mut V) {
+        let canceler = executor.context().authority.clone();
+        let multisig_account = self.account.clone();
+        let instructions_hash = self.instructions_hash;
+
+        let proposal_value = match proposal_value(multisig_account, instructions_hash, executor) {
+            Ok(proposal_value) => proposal_value,
+            Err(err) => deny!(executor, err),
+        };
+
+        if proposal_value.proposer.as_ref() != Some(&canceler) {
+            deny!(executor, "only the proposer can cancel multisig");
+        };
+    }
+
+    fn execute<V: Execute + Visit + ?Sized>(self, executor: &mut V) -> Result<(), ValidationFail> {
+        let multisig_account = self.account;
+        let instructions_hash = self.instructions_hash;
+
+        // Authorize as the multisig account
+        prune_down(multisig_account.clone(), instructions_hash, executor)?;
+
+        let log = Log::new(Level::INFO, format!("multisig proposal canceled:\naccount: {multisig_account}\ninstructions hash: {instructions_hash}"));
+        visit_seq!(executor.visit_log(&log));
+
+        Ok(())
+    }
+}
+
+impl VisitExecute for MultisigUpdateSpec {
+    fn visit<V: Execute + Visit + ?Sized>(&self, executor: &mut V) {
+        let multisig_account = self.account.clone();
+
+        if executor.context().authority != multisig_account {
+            deny!(
+                executor,
+                "multisig spec can only be updated by the multisig account itself through a proposal"
+            );
+        };
+
+        if let Err(err) = multisig_spec(multisig_account.clone(), executor) {
+            deny!(executor, err)
+        };
+
+        if let Err(err) = validate_spec(&self.spec) {
+            deny!(executor, err)
+        };
+    }
+
+    fn execute<V: Execute + Visit + ?Sized>(self, executor: &mut V) -> Result<(), ValidationFail> {
+        let multisig_account = self.account;
+        let new_spec = self.spec;
+        let old_spec = multisig_spec(multisig_account.clone(), executor)?;
+
+        // Discard pending proposals along with their relayers while the previous spec is still in effect.
+        // Relays deployed for upstream proposals are discarded too, as their approvals were collected under the previous spec
+        let account = executor
+            .host()
+            .query(FindAccounts)
+            .filter_with(|account| account.id.eq(multisig_account.clone()))
+            .execute_single()
+            .dbg_expect("multisig account should be found as its spec was found");
+        let pending = proposals_of(&account)
+            .map_err(|err| ValidationFail::QueryFailed(QueryExecutionFail::Conversion(err)))?;
+        for instructions_hash in pending.into_keys() {
+            prune_down(multisig_account.clone(), instructions_hash, executor)?;
+        }
+
+        // Authorize as the multisig account, which manages the signatories of its own role
+        executor.context_mut().authority = multisig_account.clone();
+
+        visit_seq!(executor.visit_set_account_key_value(&SetKeyValue::account(
+            multisig_account.clone(),
+            spec_key(),
+            Json::new(&new_spec),
+        )));
+
+        let multisig_role = multisig_role_for(&multisig_account);
+
+        for signatory in new_spec.signatories.keys() {
+            if !old_spec.signatories.contains_key(signatory) {
+                visit_seq!(executor.visit_grant_account_role(&Grant::account_role(
+                    multisig_role.clone(),
+                    signatory.clone()
+                )));
+            }
+        }
+        for signatory in old_spec.signatories.keys() {
+            if !new_spec.signatories.contains_key(signatory) {
+                visit_seq!(executor.visit_revoke_account_role(&Revoke::account_role(
+                    multisig_role.clone(),
+                    signatory.clone()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[expect(clippy::needless_pass_by_value)]
 fn metadata_conversion_error(err: serde_json::Error) -> ValidationFail {
     ValidationFail::QueryFailed(QueryExecutionFail::Conversion(format!(
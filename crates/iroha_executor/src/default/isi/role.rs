@@ -0,0 +1,325 @@
+//! Validation and execution logic of instructions for role hierarchy
+
+use iroha_executor_data_model::{isi::role::*, permission::role::CanManageRoles};
+use iroha_smart_contract::data_model::{query::error::QueryExecutionFail, role::Role};
+
+use super::*;
+use crate::{
+    data_model::query::{builder::SingleQueryError, error::FindError},
+    default::domain::is_permission_domain_associated,
+    permission::{
+        domain::is_domain_owner,
+        role::{effective_roles, role_hierarchy},
+        AnyPermission, ValidateGrantRevoke as _,
+    },
+};
+
+impl VisitExecute for RoleInstructionBox {
+    fn visit_execute<V: Execute + Visit + ?Sized>(self, executor: &mut V) {
+        match self {
+            RoleInstructionBox::RegisterScoped(instruction) => instruction.visit_execute(executor),
+            RoleInstructionBox::SetParents(instruction) => instruction.visit_execute(executor),
+            RoleInstructionBox::SetAdmins(instruction) => instruction.visit_execute(executor),
+        }
+    }
+}
+
+impl VisitExecute for RoleRegisterScoped {
+    fn visit<V: Execute + Visit + ?Sized>(&self, executor: &mut V) {
+        let authority = executor.context().authority.clone();
+        let is_genesis = executor.context().curr_block.is_genesis();
+
+        if !is_genesis {
+            match is_domain_owner(&self.domain, &authority, executor.host()) {
+                Ok(true) => {}
+                Ok(false) => deny!(
+                    executor,
+                    "only the domain owner can register roles scoped to the domain"
+                ),
+                Err(err) => deny!(executor, err),
+            }
+        }
+
+        for permission in self.role.inner().permissions() {
+            let Ok(any_permission) = AnyPermission::try_from(permission) else {
+                deny!(
+                    executor,
+                    ValidationFail::NotPermitted(format!("{permission:?}: Unknown permission"))
+                );
+            };
+            if let Err(err) = ensure_in_scope(permission, &self.domain) {
+                deny!(executor, err);
+            }
+            if !is_genesis {
+                if let Err(err) =
+                    any_permission.validate_grant(&authority, executor.context(), executor.host())
+                {
+                    deny!(executor, err);
+                }
+            }
+        }
+    }
+
+    fn execute<V: Execute + Visit + ?Sized>(self, executor: &mut V) -> Result<(), ValidationFail> {
+        let role_id = self.role.id().clone();
+        let grant_to = self.role.grant_to().clone();
+
+        let mut new_role = Role::new(role_id.clone(), grant_to.clone());
+        for permission in self.role.inner().permissions() {
+            let any_permission = AnyPermission::try_from(permission)
+                .dbg_expect("permission should be known as it was validated");
+            new_role = new_role.add_permission(any_permission);
+        }
+
+        executor.host().submit(&Register::role(new_role))?;
+        executor
+            .host()
+            .submit(&Grant::account_role(role_id.clone(), grant_to))?;
+
+        let mut hierarchy = role_hierarchy(executor.host());
+        let spec = RoleSpec {
+            domain: Some(self.domain),
+            ..RoleSpec::default()
+        };
+        hierarchy.set_spec(role_id, spec);
+
+        store_hierarchy(hierarchy, executor)
+    }
+}
+
+impl VisitExecute for RoleSetParents {
+    fn visit<V: Execute + Visit + ?Sized>(&self, executor: &mut V) {
+        let hierarchy = role_hierarchy(executor.host());
+
+        match can_manage_role(&self.role, &hierarchy, executor) {
+            Ok(true) => {}
+            Ok(false) => deny!(executor, "not qualified to manage the role"),
+            Err(err) => deny!(executor, err),
+        }
+
+        let spec = hierarchy.spec(&self.role);
+        for parent in &self.parents {
+            if let Err(err) = ensure_role_exists(parent, executor) {
+                deny!(executor, err);
+            }
+
+            // Holders of the parent already have the permissions of the role if it's an ancestor
+            if hierarchy.inherits(parent, &self.role) {
+                deny!(
+                    executor,
+                    ValidationFail::NotPermitted(format!(
+                        "inheritance of {parent} by {} forms a cycle",
+                        self.role
+                    ))
+                );
+            }
+
+            if spec.domain.is_some() && hierarchy.spec(parent).domain != spec.domain {
+                deny!(
+                    executor,
+                    "roles scoped to a domain can only inherit roles scoped to the same domain"
+                );
+            }
+
+            // Inheriting a role is equivalent to granting it to every holder of the role
+            if !spec.parents.contains(parent) {
+                match can_administer_role(parent, &hierarchy, executor) {
+                    Ok(true) => {}
+                    Ok(false) => deny!(
+                        executor,
+                        ValidationFail::NotPermitted(format!(
+                            "not qualified to pass on the permissions of {parent}"
+                        ))
+                    ),
+                    Err(err) => deny!(executor, err),
+                }
+            }
+        }
+    }
+
+    fn execute<V: Execute + Visit + ?Sized>(self, executor: &mut V) -> Result<(), ValidationFail> {
+        let mut hierarchy = role_hierarchy(executor.host());
+        let mut spec = hierarchy.spec(&self.role);
+        spec.parents = self.parents;
+        hierarchy.set_spec(self.role, spec);
+
+        store_hierarchy(hierarchy, executor)
+    }
+}
+
+impl VisitExecute for RoleSetAdmins {
+    fn visit<V: Execute + Visit + ?Sized>(&self, executor: &mut V) {
+        let hierarchy = role_hierarchy(executor.host());
+
+        match can_manage_role(&self.role, &hierarchy, executor) {
+            Ok(true) => {}
+            Ok(false) => deny!(executor, "not qualified to manage the role"),
+            Err(err) => deny!(executor, err),
+        }
+
+        for admin in &self.admins {
+            if let Err(err) = ensure_role_exists(admin, executor) {
+                deny!(executor, err);
+            }
+        }
+    }
+
+    fn execute<V: Execute + Visit + ?Sized>(self, executor: &mut V) -> Result<(), ValidationFail> {
+        let mut hierarchy = role_hierarchy(executor.host());
+        let mut spec = hierarchy.spec(&self.role);
+        spec.admins = self.admins;
+        hierarchy.set_spec(self.role, spec);
+
+        store_hierarchy(hierarchy, executor)
+    }
+}
+
+/// Check if the authority can change the position of the role in the hierarchy or unregister it:
+/// role managers can manage any role, domain owners can manage the roles scoped to their domain
+///
+/// # Errors
+///
+/// Fails if the domain the role is scoped to is not found
+pub fn can_manage_role<V: Execute + ?Sized>(
+    role_id: &RoleId,
+    hierarchy: &RoleHierarchy,
+    executor: &V,
+) -> Result<bool, ValidationFail> {
+    let authority = &executor.context().authority;
+
+    if executor.context().curr_block.is_genesis()
+        || CanManageRoles.is_owned_by(authority, executor.host())
+    {
+        return Ok(true);
+    }
+
+    hierarchy.spec(role_id).domain.map_or(Ok(false), |domain| {
+        is_domain_owner(&domain, authority, executor.host())
+    })
+}
+
+/// Check if the authority can grant and revoke the role and its permissions:
+/// holders of its admin roles can, or holders of the role itself if it has no admins.
+/// Domain owners can administer the roles scoped to their domain
+///
+/// # Errors
+///
+/// Fails if the domain the role is scoped to is not found
+pub fn can_administer_role<V: Execute + ?Sized>(
+    role_id: &RoleId,
+    hierarchy: &RoleHierarchy,
+    executor: &V,
+) -> Result<bool, ValidationFail> {
+    let authority = &executor.context().authority;
+
+    if executor.context().curr_block.is_genesis() {
+        return Ok(true);
+    }
+
+    let spec = hierarchy.spec(role_id);
+    if let Some(domain) = &spec.domain {
+        if is_domain_owner(domain, authority, executor.host())? {
+            return Ok(true);
+        }
+    }
+
+    let held_roles = effective_roles(authority, hierarchy, executor.host());
+    if spec.admins.is_empty() {
+        return Ok(held_roles.contains(role_id));
+    }
+
+    Ok(!spec.admins.is_disjoint(&held_roles))
+}
+
+/// Check that the permission applies inside the domain the role is scoped to, if any
+///
+/// # Errors
+///
+/// Fails if the permission is not associated with the domain
+pub fn ensure_in_scope(permission: &Permission, domain: &DomainId) -> Result<(), ValidationFail> {
+    if is_permission_domain_associated(permission, domain) {
+        return Ok(());
+    }
+
+    Err(ValidationFail::NotPermitted(format!(
+        "{permission:?}: Permission is outside of the role scope {domain}"
+    )))
+}
+
+/// Drop the role from the hierarchy, which is necessary before unregistering it
+///
+/// # Errors
+///
+/// Fails if the hierarchy can't be stored
+pub fn remove_from_hierarchy<V: Execute + ?Sized>(
+    role_id: &RoleId,
+    executor: &V,
+) -> Result<(), ValidationFail> {
+    let mut hierarchy = role_hierarchy(executor.host());
+    let prev_hierarchy = hierarchy.clone();
+    hierarchy.remove(role_id);
+    if hierarchy == prev_hierarchy {
+        return Ok(());
+    }
+
+    store_hierarchy(hierarchy, executor)
+}
+
+/// Unregister the roles scoped to the domain, which is necessary before unregistering it
+///
+/// # Errors
+///
+/// Fails if some role can't be unregistered
+pub fn unregister_scoped_roles<V: Execute + ?Sized>(
+    domain_id: &DomainId,
+    executor: &V,
+) -> Result<(), ValidationFail> {
+    let mut hierarchy = role_hierarchy(executor.host());
+    let scoped_roles: Vec<_> = hierarchy
+        .roles
+        .iter()
+        .filter(|(_, spec)| spec.domain.as_ref() == Some(domain_id))
+        .map(|(role_id, _)| role_id.clone())
+        .collect();
+    if scoped_roles.is_empty() {
+        return Ok(());
+    }
+
+    for role_id in scoped_roles {
+        executor.host().submit(&Unregister::role(role_id.clone()))?;
+        hierarchy.remove(&role_id);
+    }
+
+    store_hierarchy(hierarchy, executor)
+}
+
+/// Store the hierarchy in the executor parameter.
+/// Bypasses the validation of parameter changes,
+/// since the hierarchy is protected from direct changes
+fn store_hierarchy<V: Execute + ?Sized>(
+    hierarchy: RoleHierarchy,
+    executor: &V,
+) -> Result<(), ValidationFail> {
+    executor.host().submit(&SetParameter::new(hierarchy.into()))
+}
+
+fn ensure_role_exists<V: Execute + ?Sized>(
+    role_id: &RoleId,
+    executor: &V,
+) -> Result<(), ValidationFail> {
+    executor
+        .host()
+        .query(FindRoles)
+        .filter_with(|role| role.id.eq(role_id.clone()))
+        .execute_single()
+        .map(|_| ())
+        .map_err(|e| match e {
+            SingleQueryError::QueryError(e) => e,
+            SingleQueryError::ExpectedOneGotNone => ValidationFail::QueryFailed(
+                QueryExecutionFail::Find(FindError::Role(role_id.clone())),
+            ),
+            SingleQueryError::ExpectedOneGotMany | SingleQueryError::ExpectedOneOrZeroGotMany => {
+                unreachable!()
+            }
+        })
+}
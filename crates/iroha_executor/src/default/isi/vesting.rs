@@ -0,0 +1,243 @@
+//! Validation and execution logic of instructions for vesting locks
+
+use iroha_executor_data_model::isi::vesting::*;
+use iroha_smart_contract::data_model::query::error::QueryExecutionFail;
+
+use super::*;
+use crate::data_model::{
+    isi::error::{InstructionExecutionError, MathError},
+    query::{builder::SingleQueryError, error::FindError},
+};
+
+impl VisitExecute for VestingInstructionBox {
+    fn visit_execute<V: Execute + Visit + ?Sized>(self, executor: &mut V) {
+        match self {
+            VestingInstructionBox::Lock(instruction) => instruction.visit_execute(executor),
+            VestingInstructionBox::Claim(instruction) => instruction.visit_execute(executor),
+            VestingInstructionBox::Revoke(instruction) => instruction.visit_execute(executor),
+        }
+    }
+}
+
+impl VisitExecute for VestingLock {
+    fn visit<V: Execute + Visit + ?Sized>(&self, executor: &mut V) {
+        if self.amount.is_zero() {
+            deny!(executor, "vesting amount should be positive");
+        }
+
+        if self.schedule.duration_ms.get() < self.schedule.cliff_ms {
+            deny!(executor, "vesting cliff exceeds the duration");
+        }
+
+        match lock_value(&self.asset, executor) {
+            Ok(Some(value)) if !value.locked().is_zero() => {
+                deny!(executor, "vesting lock duplicates")
+            }
+            Ok(_) => {}
+            Err(err) => deny!(executor, err),
+        }
+    }
+
+    fn execute<V: Execute + Visit + ?Sized>(self, executor: &mut V) -> Result<(), ValidationFail> {
+        let grantor = executor.context().authority.clone();
+        let source = AssetId::new(self.asset.definition().clone(), grantor.clone());
+
+        // The grantor needs to have sufficient permission to transfer the amount
+        visit_seq!(
+            executor.visit_transfer_asset_numeric(&Transfer::asset_numeric(
+                source,
+                self.amount,
+                self.asset.account().clone(),
+            ))
+        );
+
+        let value = VestingLockValue::new(
+            grantor,
+            self.revoker,
+            self.amount,
+            Numeric::ZERO,
+            self.schedule,
+            false,
+        );
+
+        store_lock_value(&self.asset, &value, executor)
+    }
+}
+
+impl VisitExecute for VestingClaim {
+    fn visit<V: Execute + Visit + ?Sized>(&self, executor: &mut V) {
+        if executor.context().authority != *self.asset.account() {
+            deny!(executor, "only the beneficiary can claim vested assets");
+        }
+
+        match lock_value(&self.asset, executor) {
+            Ok(Some(_)) => {}
+            Ok(None) => deny!(executor, "vesting lock not found"),
+            Err(err) => deny!(executor, err),
+        }
+    }
+
+    fn execute<V: Execute + Visit + ?Sized>(self, executor: &mut V) -> Result<(), ValidationFail> {
+        let mut value = lock_value(&self.asset, executor)?
+            .dbg_expect("vesting lock should be found as it was validated");
+        let spec = asset_spec(self.asset.definition(), executor)?;
+
+        value.claimed = value.released(now_ms(executor), spec).map_err(math_error)?;
+
+        store_lock_value(&self.asset, &value, executor)
+    }
+}
+
+impl VisitExecute for VestingRevoke {
+    fn visit<V: Execute + Visit + ?Sized>(&self, executor: &mut V) {
+        let revoker = executor.context().authority.clone();
+
+        let value = match lock_value(&self.asset, executor) {
+            Ok(Some(value)) => value,
+            Ok(None) => deny!(executor, "vesting lock not found"),
+            Err(err) => deny!(executor, err),
+        };
+
+        if value.revoker.as_ref() != Some(&revoker) {
+            deny!(executor, "not qualified to revoke vesting");
+        }
+
+        if value.is_revoked {
+            deny!(executor, "vesting is already revoked");
+        }
+    }
+
+    fn execute<V: Execute + Visit + ?Sized>(self, executor: &mut V) -> Result<(), ValidationFail> {
+        let mut value = lock_value(&self.asset, executor)?
+            .dbg_expect("vesting lock should be found as it was validated");
+        let spec = asset_spec(self.asset.definition(), executor)?;
+
+        let released = value.released(now_ms(executor), spec).map_err(math_error)?;
+        let unvested = value.total.checked_sub(released).unwrap_or(Numeric::ZERO);
+        value.total = released;
+        value.is_revoked = true;
+        let grantor = value.grantor.clone();
+
+        store_lock_value(&self.asset, &value, executor)?;
+
+        if !unvested.is_zero() {
+            // Return the unvested portion on behalf of the vesting, which is not spendable by the beneficiary
+            executor
+                .host()
+                .submit(&Transfer::asset_numeric(self.asset, unvested, grantor))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Check that spending the amount from the asset leaves the amount locked by vesting intact
+///
+/// # Errors
+///
+/// Fails if the amount exceeds the spendable balance or the vesting lock is malformed
+pub fn ensure_spendable<V: Execute + Visit + ?Sized>(
+    asset: &AssetId,
+    amount: &Numeric,
+    executor: &V,
+) -> Result<(), ValidationFail> {
+    let Some(value) = lock_value(asset, executor)? else {
+        return Ok(());
+    };
+    let Some(balance) = executor
+        .host()
+        .query(FindAssets)
+        .filter_with(|entry| entry.id.eq(asset.clone()))
+        .select_with(|entry| entry.value)
+        .execute_single_opt()
+        .map_err(query_error)?
+    else {
+        return Ok(());
+    };
+
+    let spendable = balance.checked_sub(value.locked()).unwrap_or(Numeric::ZERO);
+    if spendable < *amount {
+        return Err(ValidationFail::NotPermitted(format!(
+            "only {spendable} of {asset} is spendable as the rest is locked by vesting"
+        )));
+    }
+
+    Ok(())
+}
+
+fn lock_value<V: Execute + Visit + ?Sized>(
+    asset: &AssetId,
+    executor: &V,
+) -> Result<Option<VestingLockValue>, ValidationFail> {
+    executor
+        .host()
+        .query(FindAccounts)
+        .filter_with(|account| account.id.eq(asset.account().clone()))
+        .select_with(|account| account.metadata.key(lock_key(asset.definition())))
+        .execute_single_opt()
+        .map_err(query_error)?
+        .map(|value| value.try_into_any().map_err(metadata_conversion_error))
+        .transpose()
+}
+
+/// Store the lock in the beneficiary account metadata, removing it once nothing is locked anymore.
+/// Bypasses the validation of metadata changes, since vesting locks are protected from the account owner
+fn store_lock_value<V: Execute + Visit + ?Sized>(
+    asset: &AssetId,
+    value: &VestingLockValue,
+    executor: &V,
+) -> Result<(), ValidationFail> {
+    let account = asset.account().clone();
+    let key = lock_key(asset.definition());
+
+    if value.locked().is_zero() {
+        return executor
+            .host()
+            .submit(&RemoveKeyValue::account(account, key));
+    }
+
+    executor
+        .host()
+        .submit(&SetKeyValue::account(account, key, Json::new(value)))
+}
+
+fn asset_spec<V: Execute + Visit + ?Sized>(
+    asset_definition: &AssetDefinitionId,
+    executor: &V,
+) -> Result<NumericSpec, ValidationFail> {
+    executor
+        .host()
+        .query(FindAssetsDefinitions)
+        .filter_with(|entry| entry.id.eq(asset_definition.clone()))
+        .execute_single()
+        .map(|entry| entry.spec())
+        .map_err(|e| match e {
+            SingleQueryError::QueryError(e) => e,
+            SingleQueryError::ExpectedOneGotNone => ValidationFail::QueryFailed(
+                QueryExecutionFail::Find(FindError::AssetDefinition(asset_definition.clone())),
+            ),
+            SingleQueryError::ExpectedOneGotMany | SingleQueryError::ExpectedOneOrZeroGotMany => {
+                unreachable!()
+            }
+        })
+}
+
+fn query_error(err: SingleQueryError<ValidationFail>) -> ValidationFail {
+    match err {
+        SingleQueryError::QueryError(err) => err,
+        SingleQueryError::ExpectedOneGotNone
+        | SingleQueryError::ExpectedOneGotMany
+        | SingleQueryError::ExpectedOneOrZeroGotMany => unreachable!(),
+    }
+}
+
+fn math_error(err: MathError) -> ValidationFail {
+    ValidationFail::InstructionFailed(InstructionExecutionError::Math(err))
+}
+
+#[expect(clippy::needless_pass_by_value)]
+fn metadata_conversion_error(err: serde_json::Error) -> ValidationFail {
+    ValidationFail::QueryFailed(QueryExecutionFail::Conversion(format!(
+        "vesting lock metadata malformed:\n{err}"
+    )))
+}
@@ -4,8 +4,10 @@
 use alloc::format;
 
 pub use account::{
-    visit_register_account, visit_remove_account_key_value, visit_set_account_key_value,
-    visit_unregister_account,
+    visit_add_account_key, visit_register_account, visit_register_account_alias,
+    visit_remove_account_key, visit_remove_account_key_value, visit_rotate_account_key,
+    visit_set_account_key_threshold, visit_set_account_key_value, visit_transfer_account_alias,
+    visit_unregister_account, visit_unregister_account_alias,
 };
 pub use asset::{visit_burn_asset_numeric, visit_mint_asset_numeric, visit_transfer_asset_numeric};
 pub use asset_definition::{
@@ -13,10 +15,12 @@ pub use asset_definition::{
     visit_set_asset_definition_key_value, visit_transfer_asset_definition,
     visit_unregister_asset_definition,
 };
+pub use contract::{visit_call_contract, visit_deploy_contract, visit_remove_contract};
 pub use domain::{
     visit_register_domain, visit_remove_domain_key_value, visit_set_domain_key_value,
     visit_transfer_domain, visit_unregister_domain,
 };
+pub use event::visit_emit_event;
 pub use executor::visit_upgrade;
 use iroha_smart_contract::data_model::{prelude::*, visit::Visit};
 pub use isi::visit_custom_instruction;
@@ -28,6 +32,7 @@ pub use nft::{
 pub use parameter::visit_set_parameter;
 pub use peer::{visit_register_peer, visit_unregister_peer};
 pub use permission::{visit_grant_account_permission, visit_revoke_account_permission};
+pub use query::execute_custom_query;
 pub use role::{
     visit_grant_account_role, visit_grant_role_permission, visit_register_role,
     visit_revoke_account_role, visit_revoke_role_permission, visit_unregister_role,
@@ -35,7 +40,7 @@ pub use role::{
 pub use trigger::{
     visit_burn_trigger_repetitions, visit_execute_trigger, visit_mint_trigger_repetitions,
     visit_register_trigger, visit_remove_trigger_key_value, visit_set_trigger_key_value,
-    visit_unregister_trigger,
+    visit_unregister_trigger, visit_upgrade_trigger,
 };
 
 use crate::{
@@ -45,6 +50,7 @@ use crate::{
 };
 
 pub mod isi;
+pub mod query;
 
 // NOTE: If any new `visit_..` functions are introduced in this module, one should
 // not forget to update the default executor boilerplate too, specifically the
@@ -118,6 +124,21 @@ pub fn visit_instruction<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &In
         InstructionBox::Upgrade(isi) => {
             executor.visit_upgrade(isi);
         }
+        InstructionBox::AccountKey(isi) => {
+            executor.visit_account_key(isi);
+        }
+        InstructionBox::AccountAlias(isi) => {
+            executor.visit_account_alias(isi);
+        }
+        InstructionBox::Contract(isi) => {
+            executor.visit_contract(isi);
+        }
+        InstructionBox::EmitEvent(isi) => {
+            executor.visit_emit_event(isi);
+        }
+        InstructionBox::UpgradeTrigger(isi) => {
+            executor.visit_upgrade_trigger(isi);
+        }
         InstructionBox::Custom(isi) => {
             executor.visit_custom_instruction(isi);
         }
@@ -164,7 +185,7 @@ pub mod domain {
     };
     use iroha_smart_contract::data_model::domain::DomainId;
 
-    use super::*;
+    use super::{isi::unregister_scoped_roles, *};
     use crate::permission::{
         account::is_account_owner, domain::is_domain_owner, revoke_permissions,
     };
@@ -208,6 +229,9 @@ pub mod domain {
             if let Err(err) = err {
                 deny!(executor, err);
             }
+            if let Err(err) = unregister_scoped_roles(domain_id, executor) {
+                deny!(executor, err);
+            }
 
             execute!(executor, isi);
         }
@@ -291,7 +315,10 @@ pub mod domain {
     }
 
     #[allow(clippy::too_many_lines)]
-    fn is_permission_domain_associated(permission: &Permission, domain_id: &DomainId) -> bool {
+    pub(crate) fn is_permission_domain_associated(
+        permission: &Permission,
+        domain_id: &DomainId,
+    ) -> bool {
         let Ok(permission) = AnyPermission::try_from(permission) else {
             return false;
         };
@@ -356,15 +383,19 @@ pub mod domain {
             | AnyPermission::CanRegisterDomain(_)
             | AnyPermission::CanSetParameters(_)
             | AnyPermission::CanManageRoles(_)
+            | AnyPermission::CanDeployContract(_)
+            | AnyPermission::CanCallContract(_)
             | AnyPermission::CanUpgradeExecutor(_) => false,
         }
     }
 }
 
 pub mod account {
-    use iroha_executor_data_model::permission::account::{
-        CanModifyAccountMetadata, CanRegisterAccount, CanUnregisterAccount,
+    use iroha_executor_data_model::{
+        isi::vesting,
+        permission::account::{CanModifyAccountMetadata, CanRegisterAccount, CanUnregisterAccount},
     };
+    use iroha_smart_contract::Iroha;
 
     use super::*;
     use crate::permission::{account::is_account_owner, revoke_permissions};
@@ -440,6 +471,12 @@ pub mod account {
         if executor.context().curr_block.is_genesis() {
             execute!(executor, isi);
         }
+        if isi.key().as_ref().starts_with(&vesting::lock_key_prefix()) {
+            deny!(
+                executor,
+                "Vesting locks can only be changed by vesting instructions"
+            );
+        }
         match is_account_owner(account_id, &executor.context().authority, executor.host()) {
             Err(err) => deny!(executor, err),
             Ok(true) => execute!(executor, isi),
@@ -469,6 +506,12 @@ pub mod account {
         if executor.context().curr_block.is_genesis() {
             execute!(executor, isi);
         }
+        if isi.key().as_ref().starts_with(&vesting::lock_key_prefix()) {
+            deny!(
+                executor,
+                "Vesting locks can only be changed by vesting instructions"
+            );
+        }
         match is_account_owner(account_id, &executor.context().authority, executor.host()) {
             Err(err) => deny!(executor, err),
             Ok(true) => execute!(executor, isi),
@@ -489,6 +532,121 @@ pub mod account {
         );
     }
 
+    pub fn visit_add_account_key<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &AddAccountKey,
+    ) {
+        if is_own_account(executor, isi.account()) {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't add a key to another account");
+    }
+
+    pub fn visit_remove_account_key<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &RemoveAccountKey,
+    ) {
+        if is_own_account(executor, isi.account()) {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't remove a key from another account");
+    }
+
+    pub fn visit_rotate_account_key<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &RotateAccountKey,
+    ) {
+        if is_own_account(executor, isi.account()) {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't rotate a key of another account");
+    }
+
+    pub fn visit_set_account_key_threshold<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &SetAccountKeyThreshold,
+    ) {
+        if is_own_account(executor, isi.account()) {
+            execute!(executor, isi);
+        }
+
+        deny!(
+            executor,
+            "Can't set the signature threshold of another account"
+        );
+    }
+
+    pub fn visit_register_account_alias<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &RegisterAccountAlias,
+    ) {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match is_account_owner(
+            isi.account(),
+            &executor.context().authority,
+            executor.host(),
+        ) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(executor, "Can't register an alias for another account");
+    }
+
+    pub fn visit_transfer_account_alias<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &TransferAccountAlias,
+    ) {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match is_alias_owner(isi.alias(), &executor.context().authority, executor.host()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(executor, "Can't transfer an alias of another account");
+    }
+
+    pub fn visit_unregister_account_alias<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &UnregisterAccountAlias,
+    ) {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match is_alias_owner(isi.alias(), &executor.context().authority, executor.host()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(executor, "Can't unregister an alias of another account");
+    }
+
+    /// An alias is owned by the owner of the account it currently resolves to.
+    fn is_alias_owner(
+        alias: &AccountAlias,
+        authority: &AccountId,
+        host: &Iroha,
+    ) -> Result<bool, ValidationFail> {
+        let account_id = host.query_single(FindAccountIdByAlias::new(alias.clone()))?;
+        is_account_owner(&account_id, authority, host)
+    }
+
+    /// Keys are managed by the account itself only, since no permission can be safely delegated
+    /// without letting the delegate take over the account.
+    fn is_own_account<V: Execute + Visit + ?Sized>(executor: &V, account_id: &AccountId) -> bool {
+        executor.context().curr_block.is_genesis() || executor.context().authority == *account_id
+    }
+
     fn is_permission_account_associated(permission: &Permission, account_id: &AccountId) -> bool {
         let Ok(permission) = AnyPermission::try_from(permission) else {
             return false;
@@ -525,6 +683,8 @@ pub mod account {
             | AnyPermission::CanModifyNftMetadata(_)
             | AnyPermission::CanSetParameters(_)
             | AnyPermission::CanManageRoles(_)
+            | AnyPermission::CanDeployContract(_)
+            | AnyPermission::CanCallContract(_)
             | AnyPermission::CanUpgradeExecutor(_) => false,
         }
     }
@@ -764,6 +924,8 @@ pub mod asset_definition {
             | AnyPermission::CanModifyNftMetadata(_)
             | AnyPermission::CanSetParameters(_)
             | AnyPermission::CanManageRoles(_)
+            | AnyPermission::CanDeployContract(_)
+            | AnyPermission::CanCallContract(_)
             | AnyPermission::CanUpgradeExecutor(_) => false,
         }
     }
@@ -777,7 +939,7 @@ pub mod asset {
     use iroha_smart_contract::data_model::isi::BuiltInInstruction;
     use iroha_smart_contract_utils::Encode;
 
-    use super::*;
+    use super::{isi::ensure_spendable, *};
     use crate::permission::{asset::is_asset_owner, asset_definition::is_asset_definition_owner};
 
     fn execute_mint_asset<V, Q>(executor: &mut V, isi: &Mint<Q, Asset>)
@@ -873,6 +1035,9 @@ pub mod asset {
         executor: &mut V,
         isi: &Burn<Numeric, Asset>,
     ) {
+        if let Err(err) = ensure_spendable(isi.destination(), isi.object(), executor) {
+            deny!(executor, err);
+        }
         execute_burn_asset(executor, isi);
     }
 
@@ -881,6 +1046,9 @@ pub mod asset {
         isi: &Transfer<Asset, Numeric, Account>,
     ) {
         let asset_id = isi.source();
+        if let Err(err) = ensure_spendable(asset_id, isi.object(), executor) {
+            deny!(executor, err);
+        }
         if executor.context().curr_block.is_genesis() {
             execute!(executor, isi);
         }
@@ -1082,7 +1250,10 @@ pub mod nft {
 }
 
 pub mod parameter {
-    use iroha_executor_data_model::permission::parameter::CanSetParameters;
+    use iroha_executor_data_model::{
+        isi::role::RoleHierarchy, parameter::Parameter as _,
+        permission::parameter::CanSetParameters,
+    };
 
     use super::*;
 
@@ -1090,6 +1261,14 @@ pub mod parameter {
         if executor.context().curr_block.is_genesis() {
             execute!(executor, isi);
         }
+        if let Parameter::Custom(parameter) = isi.parameter() {
+            if parameter.id() == &RoleHierarchy::id() {
+                deny!(
+                    executor,
+                    "Role hierarchy can only be changed by role instructions"
+                );
+            }
+        }
         if CanSetParameters.is_owned_by(&executor.context().authority, executor.host()) {
             execute!(executor, isi);
         }
@@ -1103,19 +1282,33 @@ pub mod parameter {
 
 pub mod role {
     use iroha_executor_data_model::permission::role::CanManageRoles;
-    use iroha_smart_contract::{data_model::role::Role, Iroha};
+    use iroha_smart_contract::data_model::role::Role;
 
-    use super::*;
+    use super::{
+        isi::{can_administer_role, can_manage_role, ensure_in_scope, remove_from_hierarchy},
+        *,
+    };
+    use crate::permission::role::role_hierarchy;
 
     macro_rules! impl_execute_grant_revoke_account_role {
         ($executor:ident, $isi:ident) => {
             let role_id = $isi.object();
 
-            if $executor.context().curr_block.is_genesis()
-                || find_account_roles($executor.context().authority.clone(), $executor.host())
-                    .any(|authority_role_id| authority_role_id == *role_id)
+            // Exception for multisig roles: the multisig account manages its own signatories
             {
-                execute!($executor, $isi)
+                use iroha_executor_data_model::isi::multisig::multisig_account_from;
+
+                if multisig_account_from(role_id).as_ref() == Some(&$executor.context().authority) {
+                    execute!($executor, $isi);
+                }
+            }
+
+            let hierarchy = role_hierarchy($executor.host());
+
+            match can_administer_role(role_id, &hierarchy, $executor) {
+                Ok(true) => execute!($executor, $isi),
+                Ok(false) => {}
+                Err(err) => deny!($executor, err),
             }
 
             deny!($executor, "Can't grant or revoke role to another account");
@@ -1128,11 +1321,19 @@ pub mod role {
             let permission = $isi.object();
 
             if let Ok(any_permission) = AnyPermission::try_from(permission) {
+                let hierarchy = role_hierarchy($executor.host());
+
+                if let Some(domain) = hierarchy.spec(&role_id).domain {
+                    if let Err(err) = ensure_in_scope(permission, &domain) {
+                        deny!($executor, err);
+                    }
+                }
+
                 if !$executor.context().curr_block.is_genesis() {
-                    if !find_account_roles($executor.context().authority.clone(), $executor.host())
-                        .any(|authority_role_id| authority_role_id == role_id)
-                    {
-                        deny!($executor, "Can't modify role");
+                    match can_administer_role(&role_id, &hierarchy, $executor) {
+                        Ok(true) => {}
+                        Ok(false) => deny!($executor, "Can't modify role"),
+                        Err(err) => deny!($executor, err),
                     }
 
                     if let Err(error) = crate::permission::ValidateGrantRevoke::$method(
@@ -1156,15 +1357,6 @@ pub mod role {
         };
     }
 
-    fn find_account_roles(account_id: AccountId, host: &Iroha) -> impl Iterator<Item = RoleId> {
-        use iroha_smart_contract::DebugExpectExt as _;
-
-        host.query(FindRolesByAccountId::new(account_id))
-            .execute()
-            .dbg_expect("INTERNAL BUG: `FindRolesByAccountId` must never fail")
-            .map(|role| role.dbg_expect("Failed to get role from cursor"))
-    }
-
     pub fn visit_register_role<V: Execute + Visit + ?Sized>(
         executor: &mut V,
         isi: &Register<Role>,
@@ -1175,22 +1367,11 @@ pub mod role {
 
         // Exception for multisig roles
         {
-            use crate::permission::domain::is_domain_owner;
+            use iroha_executor_data_model::isi::multisig::{
+                multisig_account_from, MULTISIG_SIGNATORY,
+            };
 
-            const DELIMITER: char = '/';
-            const MULTISIG_SIGNATORY: &str = "MULTISIG_SIGNATORY";
-
-            fn multisig_account_from(role: &RoleId) -> Option<AccountId> {
-                role.name()
-                    .as_ref()
-                    .strip_prefix(MULTISIG_SIGNATORY)?
-                    .rsplit_once(DELIMITER)
-                    .and_then(|(init, last)| {
-                        format!("{last}@{}", init.trim_matches(DELIMITER))
-                            .parse()
-                            .ok()
-                    })
-            }
+            use crate::permission::domain::is_domain_owner;
 
             if role.id().name().as_ref().starts_with(MULTISIG_SIGNATORY) {
                 let Some(multisig_account) = multisig_account_from(role.id()) else {
@@ -1256,11 +1437,18 @@ pub mod role {
         executor: &mut V,
         isi: &Unregister<Role>,
     ) {
-        if executor.context().curr_block.is_genesis() {
-            execute!(executor, isi);
-        }
-        if CanManageRoles.is_owned_by(&executor.context().authority, executor.host()) {
-            execute!(executor, isi);
+        let role_id = isi.object();
+        let hierarchy = role_hierarchy(executor.host());
+
+        match can_manage_role(role_id, &hierarchy, executor) {
+            Ok(true) => {
+                if let Err(err) = remove_from_hierarchy(role_id, executor) {
+                    deny!(executor, err);
+                }
+                execute!(executor, isi);
+            }
+            Ok(false) => {}
+            Err(err) => deny!(executor, err),
         }
 
         deny!(executor, "Can't unregister role");
@@ -1451,6 +1639,30 @@ pub mod trigger {
         deny!(executor, "Can't execute trigger owned by another account");
     }
 
+    pub fn visit_upgrade_trigger<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &UpgradeTrigger,
+    ) {
+        let trigger_id = isi.trigger();
+
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match is_trigger_owner(trigger_id, &executor.context().authority, executor.host()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_modify_trigger_token = CanModifyTrigger {
+            trigger: trigger_id.clone(),
+        };
+        if can_modify_trigger_token.is_owned_by(&executor.context().authority, executor.host()) {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't upgrade trigger owned by another account");
+    }
+
     pub fn visit_set_trigger_key_value<V: Execute + Visit + ?Sized>(
         executor: &mut V,
         isi: &SetKeyValue<Trigger>,
@@ -1545,6 +1757,118 @@ pub mod trigger {
             | AnyPermission::CanUnregisterNft(_)
             | AnyPermission::CanTransferNft(_)
             | AnyPermission::CanModifyNftMetadata(_)
+            | AnyPermission::CanDeployContract(_)
+            | AnyPermission::CanCallContract(_)
+            | AnyPermission::CanUpgradeExecutor(_) => false,
+        }
+    }
+}
+
+pub mod contract {
+    use iroha_executor_data_model::permission::contract::{CanCallContract, CanDeployContract};
+
+    use super::*;
+    use crate::permission::{contract::is_contract_owner, revoke_permissions};
+
+    pub fn visit_deploy_contract<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &DeployContract,
+    ) {
+        if executor.context().curr_block.is_genesis()
+            || CanDeployContract.is_owned_by(&executor.context().authority, executor.host())
+        {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't deploy contract");
+    }
+
+    pub fn visit_remove_contract<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &RemoveContract,
+    ) {
+        let contract_id = isi.contract();
+
+        if executor.context().curr_block.is_genesis()
+            || match is_contract_owner(contract_id, &executor.context().authority, executor.host())
+            {
+                Err(err) => deny!(executor, err),
+                Ok(is_contract_owner) => is_contract_owner,
+            }
+        {
+            let err = revoke_permissions(executor, |permission| {
+                is_permission_contract_associated(permission, contract_id)
+            });
+            if let Err(err) = err {
+                deny!(executor, err);
+            }
+
+            execute!(executor, isi);
+        }
+        deny!(executor, "Can't remove contract owned by another account");
+    }
+
+    pub fn visit_call_contract<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &CallContract) {
+        let contract_id = isi.contract();
+
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        let authority = &executor.context().authority;
+        match is_contract_owner(contract_id, authority, executor.host()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_call_contract_token = CanCallContract {
+            contract: contract_id.clone(),
+        };
+        if can_call_contract_token.is_owned_by(authority, executor.host()) {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't call contract owned by another account");
+    }
+
+    fn is_permission_contract_associated(
+        permission: &Permission,
+        contract_id: &ContractId,
+    ) -> bool {
+        let Ok(permission) = AnyPermission::try_from(permission) else {
+            return false;
+        };
+        match permission {
+            AnyPermission::CanCallContract(permission) => &permission.contract == contract_id,
+            AnyPermission::CanDeployContract(_)
+            | AnyPermission::CanManagePeers(_)
+            | AnyPermission::CanRegisterDomain(_)
+            | AnyPermission::CanUnregisterDomain(_)
+            | AnyPermission::CanModifyDomainMetadata(_)
+            | AnyPermission::CanRegisterAccount(_)
+            | AnyPermission::CanRegisterAssetDefinition(_)
+            | AnyPermission::CanUnregisterAccount(_)
+            | AnyPermission::CanModifyAccountMetadata(_)
+            | AnyPermission::CanUnregisterAssetDefinition(_)
+            | AnyPermission::CanModifyAssetDefinitionMetadata(_)
+            | AnyPermission::CanRegisterAssetWithDefinition(_)
+            | AnyPermission::CanUnregisterAssetWithDefinition(_)
+            | AnyPermission::CanMintAssetWithDefinition(_)
+            | AnyPermission::CanBurnAssetWithDefinition(_)
+            | AnyPermission::CanTransferAssetWithDefinition(_)
+            | AnyPermission::CanMintAsset(_)
+            | AnyPermission::CanBurnAsset(_)
+            | AnyPermission::CanTransferAsset(_)
+            | AnyPermission::CanSetParameters(_)
+            | AnyPermission::CanManageRoles(_)
+            | AnyPermission::CanRegisterTrigger(_)
+            | AnyPermission::CanUnregisterTrigger(_)
+            | AnyPermission::CanExecuteTrigger(_)
+            | AnyPermission::CanModifyTrigger(_)
+            | AnyPermission::CanModifyTriggerMetadata(_)
+            | AnyPermission::CanRegisterNft(_)
+            | AnyPermission::CanUnregisterNft(_)
+            | AnyPermission::CanTransferNft(_)
+            | AnyPermission::CanModifyNftMetadata(_)
             | AnyPermission::CanUpgradeExecutor(_) => false,
         }
     }
@@ -1620,3 +1944,15 @@ pub mod log {
         execute!(executor, isi)
     }
 }
+
+pub mod event {
+    use super::*;
+
+    /// Emit the event as is.
+    ///
+    /// The host checks that the payload matches the schema of the event type
+    /// registered in the executor data model.
+    pub fn visit_emit_event<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &EmitEvent) {
+        execute!(executor, isi)
+    }
+}
@@ -0,0 +1,105 @@
+//! Resolution of custom queries defined by the default executor
+
+use alloc::{borrow::ToOwned as _, string::String};
+
+use iroha_executor_data_model::query::{multisig::MultisigQueryBox, role::RoleQueryBox};
+use iroha_smart_contract::data_model::query::{
+    builder::SingleQueryError,
+    error::{FindError, QueryExecutionFail},
+};
+
+use super::*;
+
+/// Resolve [`CustomQuery`] into its JSON-encoded output.
+///
+/// # Errors
+///
+/// Fails if the query is unknown to the executor or its target is not found
+pub fn execute_custom_query<V: Execute + ?Sized>(
+    executor: &V,
+    query: &CustomQuery,
+) -> Result<Json, ValidationFail> {
+    if let Ok(query) = MultisigQueryBox::try_from(query.payload()) {
+        return query.execute(executor);
+    };
+    if let Ok(query) = RoleQueryBox::try_from(query.payload()) {
+        return query.execute(executor);
+    };
+
+    Err(ValidationFail::NotPermitted(
+        "unexpected custom query".to_owned(),
+    ))
+}
+
+trait ExecuteQuery {
+    fn execute<V: Execute + ?Sized>(self, executor: &V) -> Result<Json, ValidationFail>;
+}
+
+impl ExecuteQuery for MultisigQueryBox {
+    fn execute<V: Execute + ?Sized>(self, executor: &V) -> Result<Json, ValidationFail> {
+        use iroha_executor_data_model::isi::multisig::{proposals_of, spec_of};
+
+        match self {
+            MultisigQueryBox::FindSpec(query) => {
+                let account = find_account(query.account, executor)?;
+                spec_of(&account).map(Json::new).map_err(conversion_error)
+            }
+            MultisigQueryBox::FindProposals(query) => {
+                let account = find_account(query.account, executor)?;
+                proposals_of(&account)
+                    .map(Json::new)
+                    .map_err(conversion_error)
+            }
+        }
+    }
+}
+
+impl ExecuteQuery for RoleQueryBox {
+    fn execute<V: Execute + ?Sized>(self, executor: &V) -> Result<Json, ValidationFail> {
+        use crate::permission::role::role_hierarchy;
+
+        match self {
+            RoleQueryBox::FindEffectivePermissions(query) => {
+                let host = executor.host();
+                let permissions = host
+                    .query(FindPermissionsByAccountId::new(query.account.clone()))
+                    .execute_all()?;
+                let granted_roles = host
+                    .query(FindRolesByAccountId::new(query.account))
+                    .execute_all()?;
+                let roles = host.query(FindRoles).execute_all()?;
+                let hierarchy = role_hierarchy(host);
+
+                Ok(Json::new(hierarchy.effective_permissions(
+                    permissions,
+                    granted_roles,
+                    &roles,
+                )))
+            }
+        }
+    }
+}
+
+fn find_account<V: Execute + ?Sized>(
+    account_id: AccountId,
+    executor: &V,
+) -> Result<Account, ValidationFail> {
+    executor
+        .host()
+        .query(FindAccounts)
+        .filter_with(|account| account.id.eq(account_id.clone()))
+        .execute_single()
+        .map_err(|e| match e {
+            SingleQueryError::QueryError(e) => e,
+            SingleQueryError::ExpectedOneGotNone => ValidationFail::QueryFailed(
+                QueryExecutionFail::Find(FindError::Account(account_id)),
+            ),
+            SingleQueryError::ExpectedOneGotMany | SingleQueryError::ExpectedOneOrZeroGotMany => {
+                unreachable!()
+            }
+        })
+}
+
+fn conversion_error(err: String) -> ValidationFail {
+    ValidationFail::QueryFailed(QueryExecutionFail::Conversion(err))
+}
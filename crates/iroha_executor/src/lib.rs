@@ -79,6 +79,20 @@ pub mod utils {
         decode_with_length_prefix_from_raw(context)
     }
 
+    /// Get context for `execute_custom_query()` entrypoint.
+    ///
+    /// # Safety
+    ///
+    /// It's safe to call this function as long as it's safe to construct, from the given
+    /// pointer, byte array of prefix length and `Box<[u8]>` containing the encoded object
+    #[doc(hidden)]
+    #[cfg(not(test))]
+    pub unsafe fn __decode_execute_custom_query_context(
+        context: *const u8,
+    ) -> payloads::Validate<CustomQuery> {
+        decode_with_length_prefix_from_raw(context)
+    }
+
     /// Get context for `migrate()` entrypoint.
     ///
     /// # Safety
@@ -167,6 +181,7 @@ pub struct DataModelBuilder {
     parameters: BTreeSet<data_model::parameter::CustomParameter>,
     instructions: BTreeSet<Ident>,
     permissions: BTreeSet<Ident>,
+    events: BTreeSet<Ident>,
     schema: MetaMap,
 }
 
@@ -179,6 +194,7 @@ impl DataModelBuilder {
             parameters: <_>::default(),
             instructions: <_>::default(),
             permissions: <_>::default(),
+            events: <_>::default(),
             schema: <_>::default(),
         }
     }
@@ -235,6 +251,15 @@ impl DataModelBuilder {
         self
     }
 
+    /// Define a type of custom event in the data model.
+    /// Corresponds to payload of `EventBox::Custom`.
+    #[must_use]
+    pub fn add_event<T: iroha_schema::IntoSchema>(mut self) -> Self {
+        T::update_schema_map(&mut self.schema);
+        self.events.insert(T::type_name());
+        self
+    }
+
     /// Set the data model of the executor via [`set_data_model`]
     #[cfg(not(test))]
     pub fn build_and_set(self, host: &Iroha) {
@@ -277,6 +302,7 @@ impl DataModelBuilder {
                 .collect(),
             self.instructions,
             self.permissions,
+            self.events,
             serde_json::to_value(&self.schema)
                 .expect("INTERNAL BUG: Failed to serialize Executor data model entity")
                 .into(),
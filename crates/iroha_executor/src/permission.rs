@@ -125,6 +125,9 @@ declare_permissions! {
     iroha_executor_data_model::permission::trigger::{CanExecuteTrigger},
     iroha_executor_data_model::permission::trigger::{CanModifyTriggerMetadata},
 
+    iroha_executor_data_model::permission::contract::{CanDeployContract},
+    iroha_executor_data_model::permission::contract::{CanCallContract},
+
     iroha_executor_data_model::permission::executor::{CanUpgradeExecutor},
 }
 
@@ -146,12 +149,10 @@ pub trait ExecutorPermission: Permission + PartialEq {
             return true;
         }
 
-        // build a big OR predicate over all roles we are interested in
-        let role_predicate = host
-            .query(FindRolesByAccountId::new(authority.clone()))
-            .execute()
-            .expect("INTERNAL BUG: `FindRolesByAccountId` must never fail")
-            .map(|role_id| role_id.dbg_expect("Failed to get role from cursor"))
+        // build a big OR predicate over all roles we are interested in, including inherited ones
+        let hierarchy = role::role_hierarchy(host);
+        let role_predicate = role::effective_roles(authority, &hierarchy, host)
+            .into_iter()
             .fold(CompoundPredicate::Or(Vec::new()), |predicate, role_id| {
                 predicate.or(CompoundPredicate::<Role>::build(|role| role.id.eq(role_id)))
             });
@@ -227,11 +228,40 @@ mod peer {
     }
 }
 
-mod role {
-    use iroha_executor_data_model::permission::role::CanManageRoles;
+pub mod role {
+    //! Module with pass conditions for role related tokens and role hierarchy resolution
+    use alloc::collections::BTreeSet;
+
+    use iroha_executor_data_model::{
+        isi::role::RoleHierarchy, parameter::Parameter as _, permission::role::CanManageRoles,
+    };
 
     use super::*;
 
+    /// Role hierarchy maintained by the executor. Empty if the executor doesn't define one
+    pub fn role_hierarchy(host: &Iroha) -> RoleHierarchy {
+        host.query_single(FindParameters)
+            .dbg_expect("INTERNAL BUG: `FindParameters` must never fail")
+            .custom()
+            .get(&RoleHierarchy::id())
+            .and_then(|parameter| RoleHierarchy::try_from(parameter).ok())
+            .unwrap_or_default()
+    }
+
+    /// Roles granted to the account along with the roles they inherit
+    pub fn effective_roles(
+        account_id: &AccountId,
+        hierarchy: &RoleHierarchy,
+        host: &Iroha,
+    ) -> BTreeSet<RoleId> {
+        host.query(FindRolesByAccountId::new(account_id.clone()))
+            .execute()
+            .dbg_expect("INTERNAL BUG: `FindRolesByAccountId` must never fail")
+            .map(|role_id| role_id.dbg_expect("Failed to get role from cursor"))
+            .flat_map(|role_id| hierarchy.lineage(&role_id).into_keys())
+            .collect()
+    }
+
     impl ValidateGrantRevoke for CanManageRoles {
         fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
             OnlyGenesis::from(self).validate(authority, host, context)
@@ -1009,6 +1039,94 @@ pub mod trigger {
     );
 }
 
+pub mod contract {
+    //! Module with pass conditions for contract related tokens
+    use iroha_executor_data_model::permission::contract::{CanCallContract, CanDeployContract};
+
+    use super::*;
+    use crate::data_model::{
+        isi::error::InstructionExecutionError,
+        query::{builder::SingleQueryError, contract::FindContracts, error::FindError},
+    };
+
+    /// Check if `authority` is the owner of contract, i.e. the account which deployed it.
+    ///
+    /// # Errors
+    /// Fails if query fails
+    pub fn is_contract_owner(
+        contract_id: &ContractId,
+        authority: &AccountId,
+        host: &Iroha,
+    ) -> Result<bool> {
+        host.query(FindContracts)
+            .filter_with(|contract| contract.id.eq(contract_id.clone()))
+            .execute_single()
+            .map(|contract| contract.owner() == authority)
+            .map_err(|e| match e {
+                SingleQueryError::QueryError(e) => e,
+                SingleQueryError::ExpectedOneGotNone => ValidationFail::InstructionFailed(
+                    InstructionExecutionError::Find(FindError::Contract(contract_id.clone())),
+                ),
+                _ => unreachable!(),
+            })
+    }
+
+    /// Pass condition that checks if `authority` is the owner of contract.
+    #[derive(Debug, Clone)]
+    pub struct Owner<'contract> {
+        /// Contract id to check against
+        pub contract: &'contract ContractId,
+    }
+
+    impl PassCondition for Owner<'_> {
+        fn validate(&self, authority: &AccountId, host: &Iroha, _context: &Context) -> Result {
+            if is_contract_owner(self.contract, authority, host)? {
+                return Ok(());
+            }
+
+            Err(ValidationFail::NotPermitted(
+                "Can't give permission to access contract owned by another account".to_owned(),
+            ))
+        }
+    }
+
+    impl ValidateGrantRevoke for CanDeployContract {
+        fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
+            OnlyGenesis::from(self).validate(authority, host, context)
+        }
+        fn validate_revoke(
+            &self,
+            authority: &AccountId,
+            context: &Context,
+            host: &Iroha,
+        ) -> Result {
+            OnlyGenesis::from(self).validate(authority, host, context)
+        }
+    }
+
+    impl ValidateGrantRevoke for CanCallContract {
+        fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+        fn validate_revoke(
+            &self,
+            authority: &AccountId,
+            context: &Context,
+            host: &Iroha,
+        ) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+    }
+
+    impl<'t> From<&'t CanCallContract> for Owner<'t> {
+        fn from(value: &'t CanCallContract) -> Self {
+            Self {
+                contract: &value.contract,
+            }
+        }
+    }
+}
+
 pub mod domain {
     //! Module with pass conditions for domain related tokens
     use iroha_executor_data_model::permission::{
@@ -65,6 +65,12 @@ pub mod multisig {
         Propose(MultisigPropose),
         /// Approve a certain multisig transaction
         Approve(MultisigApprove),
+        /// Reject a certain multisig transaction, discarding it once the quorum becomes unreachable
+        Reject(MultisigReject),
+        /// Cancel a multisig transaction by its proposer
+        Cancel(MultisigCancel),
+        /// Replace the specification of a multisig account. Must be executed as the multisig account itself i.e. proposed and approved
+        UpdateSpec(MultisigUpdateSpec),
     }
 
     /// Register a multisig account, which is a prerequisite of multisig transactions
@@ -110,9 +116,44 @@ pub mod multisig {
         pub instructions_hash: HashOf<Vec<InstructionBox>>,
     }
 
+    /// Reject a certain multisig transaction, discarding it once the quorum becomes unreachable
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, Constructor)]
+    pub struct MultisigReject {
+        /// Multisig account to reject
+        pub account: AccountId,
+        /// Proposal to reject
+        pub instructions_hash: HashOf<Vec<InstructionBox>>,
+    }
+
+    /// Cancel a multisig transaction by its proposer
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, Constructor)]
+    pub struct MultisigCancel {
+        /// Multisig account to cancel
+        pub account: AccountId,
+        /// Proposal to cancel
+        pub instructions_hash: HashOf<Vec<InstructionBox>>,
+    }
+
+    /// Replace the specification of a multisig account.
+    ///
+    /// Must be executed as the multisig account itself i.e. proposed and approved.
+    /// Pending proposals, including relays of upstream proposals, are discarded since they were approved under the previous specification
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, Constructor)]
+    pub struct MultisigUpdateSpec {
+        /// Multisig account to update
+        pub account: AccountId,
+        /// New specification of the multisig account
+        pub spec: MultisigSpec,
+    }
+
     impl_custom_instruction!(
         MultisigInstructionBox,
-        MultisigRegister | MultisigPropose | MultisigApprove
+        MultisigRegister
+            | MultisigPropose
+            | MultisigApprove
+            | MultisigReject
+            | MultisigCancel
+            | MultisigUpdateSpec
     );
 
     /// Metadata value for a multisig account specification
@@ -126,17 +167,57 @@ pub mod multisig {
         pub transaction_ttl_ms: NonZeroU64,
     }
 
+    impl MultisigSpec {
+        /// Total weight of the given accounts, ignoring non-signatories
+        #[expect(single_use_lifetimes)] // FP, the suggested change is not allowed on stable
+        pub fn weight_of<'a>(&self, accounts: impl IntoIterator<Item = &'a AccountId>) -> u16 {
+            accounts
+                .into_iter()
+                .filter_map(|id| self.signatories.get(id))
+                .map(|weight| u16::from(*weight))
+                .sum()
+        }
+
+        /// Total weight of all the signatories
+        pub fn total_weight(&self) -> u16 {
+            self.signatories
+                .values()
+                .map(|weight| u16::from(*weight))
+                .sum()
+        }
+
+        /// Check if the given approvals meet the quorum
+        pub fn is_authenticated(&self, approvals: &BTreeSet<AccountId>) -> bool {
+            u16::from(self.quorum) <= self.weight_of(approvals)
+        }
+
+        /// Check if the quorum can still be met despite the given rejections
+        pub fn is_reachable(&self, rejections: &BTreeSet<AccountId>) -> bool {
+            u16::from(self.quorum)
+                <= self
+                    .total_weight()
+                    .saturating_sub(self.weight_of(rejections))
+        }
+    }
+
     /// Metadata value for a multisig transaction proposal
     #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, Constructor)]
     pub struct MultisigProposalValue {
         /// Proposal contents
         pub instructions: Vec<InstructionBox>,
+        /// Account that made the proposal. `None` for relaying approvals
+        /// and proposals made before the proposer was recorded
+        #[serde(default)]
+        pub proposer: Option<AccountId>,
         /// Time in milliseconds at which the proposal was made
         pub proposed_at_ms: u64,
         /// Time in milliseconds at which the proposal will expire
         pub expires_at_ms: u64,
         /// List of approvers of the proposal so far
         pub approvals: BTreeSet<AccountId>,
+        /// List of rejecters of the proposal so far
+        #[serde(default)]
+        pub rejections: BTreeSet<AccountId>,
         /// In case this proposal is some relaying approval, indicates if it has executed or not
         pub is_relayed: Option<bool>,
     }
@@ -169,9 +250,515 @@ pub mod multisig {
         }
     }
 
+    const DELIMITER: char = '/';
+    const MULTISIG: &str = "multisig";
+    /// Prefix of the roles that multisig signatories are granted
+    pub const MULTISIG_SIGNATORY: &str = "MULTISIG_SIGNATORY";
+
+    /// Key of the multisig account metadata entry holding [`MultisigSpec`]
+    pub fn spec_key() -> Name {
+        format!("{MULTISIG}{DELIMITER}spec").parse().unwrap()
+    }
+
+    /// Prefix of the multisig account metadata keys holding [`MultisigProposalValue`]s
+    pub fn proposal_key_prefix() -> String {
+        format!("{MULTISIG}{DELIMITER}proposals{DELIMITER}")
+    }
+
+    /// Key of the multisig account metadata entry holding the [`MultisigProposalValue`] for the given instructions
+    pub fn proposal_key(hash: &HashOf<Vec<InstructionBox>>) -> Name {
+        format!("{}{hash}", proposal_key_prefix()).parse().unwrap()
+    }
+
+    /// Role granted to the signatories of the given multisig account
+    pub fn multisig_role_for(account: &AccountId) -> RoleId {
+        format!(
+            "{MULTISIG_SIGNATORY}{DELIMITER}{}{DELIMITER}{}",
+            account.domain(),
+            account.signatory(),
+        )
+        .parse()
+        .unwrap()
+    }
+
+    /// Multisig account whose signatories are granted the given role, if any
+    pub fn multisig_account_from(role: &RoleId) -> Option<AccountId> {
+        role.name()
+            .as_ref()
+            .strip_prefix(MULTISIG_SIGNATORY)?
+            .rsplit_once(DELIMITER)
+            .and_then(|(init, last)| {
+                format!("{last}@{}", init.trim_matches(DELIMITER))
+                    .parse()
+                    .ok()
+            })
+    }
+
+    /// Decode the specification of a multisig account from its metadata
+    ///
+    /// # Errors
+    ///
+    /// Fails if the account is not a multisig account or the specification is malformed
+    pub fn spec_of(account: &Account) -> Result<MultisigSpec, String> {
+        account
+            .metadata()
+            .get(&spec_key())
+            .ok_or_else(|| format!("{} is not a multisig account", account.id()))?
+            .try_into_any()
+            .map_err(|err| format!("multisig account metadata malformed:\n{err}"))
+    }
+
+    /// Decode pending proposals of a multisig account from its metadata, keyed by instructions hash
+    ///
+    /// # Errors
+    ///
+    /// Fails if some proposal entry is malformed
+    pub fn proposals_of(
+        account: &Account,
+    ) -> Result<BTreeMap<HashOf<Vec<InstructionBox>>, MultisigProposalValue>, String> {
+        let prefix = proposal_key_prefix();
+        account
+            .metadata()
+            .iter()
+            .filter_map(|(key, value)| key.as_ref().strip_prefix(&prefix).map(|hash| (hash, value)))
+            .map(|(hash, value)| {
+                let hash = hash
+                    .parse()
+                    .map_err(|err| format!("multisig proposal key malformed:\n{err}"))?;
+                let value = value
+                    .try_into_any()
+                    .map_err(|err| format!("multisig account metadata malformed:\n{err}"))?;
+                Ok((hash, value))
+            })
+            .collect()
+    }
+
     impl From<MultisigRegister> for (AccountId, MultisigSpec) {
         fn from(value: MultisigRegister) -> Self {
             (value.account, value.spec)
         }
     }
 }
+
+/// Types for vesting instructions
+pub mod vesting {
+    use core::num::NonZeroU64;
+
+    use iroha_data_model::isi::error::MathError;
+
+    use super::*;
+
+    /// Vesting-related instructions
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, From)]
+    pub enum VestingInstructionBox {
+        /// Transfer an amount of a numeric asset to the beneficiary, locking it until released by the schedule
+        Lock(VestingLock),
+        /// Unlock the portion released so far, making it spendable by the beneficiary
+        Claim(VestingClaim),
+        /// Return the unvested portion to the grantor, releasing the rest immediately
+        Revoke(VestingRevoke),
+    }
+
+    /// Transfer an amount of a numeric asset from the authority to the beneficiary, locking it until released by the schedule
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, Constructor)]
+    pub struct VestingLock {
+        /// Asset of the beneficiary to lock the amount in
+        pub asset: AssetId,
+        /// Amount to be transferred from the authority and locked
+        pub amount: Numeric,
+        /// Schedule by which the amount is released
+        pub schedule: VestingSchedule,
+        /// Account allowed to revoke the unvested portion. `None` for irrevocable vesting
+        pub revoker: Option<AccountId>,
+    }
+
+    /// Unlock the portion released so far, making it spendable by the beneficiary
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, Constructor)]
+    pub struct VestingClaim {
+        /// Asset of the beneficiary holding the locked amount
+        pub asset: AssetId,
+    }
+
+    /// Return the unvested portion to the grantor, releasing the rest immediately
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, Constructor)]
+    pub struct VestingRevoke {
+        /// Asset of the beneficiary holding the locked amount
+        pub asset: AssetId,
+    }
+
+    impl_custom_instruction!(
+        VestingInstructionBox,
+        VestingLock | VestingClaim | VestingRevoke
+    );
+
+    /// Schedule by which a locked amount is released.
+    ///
+    /// Nothing is released before the cliff; afterwards the amount is released linearly,
+    /// or in equal steps if the step is specified, until the end of the duration
+    #[derive(
+        Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, IntoSchema, Constructor,
+    )]
+    #[allow(clippy::struct_field_names)] // Units are spelled out like in `TimeSchedule`
+    pub struct VestingSchedule {
+        /// Time in milliseconds at which the vesting starts
+        pub start_ms: u64,
+        /// Time in milliseconds after the start before which nothing is released
+        pub cliff_ms: u64,
+        /// Time in milliseconds after the start at which the whole amount is released
+        pub duration_ms: NonZeroU64,
+        /// Interval in milliseconds between releases. `None` for linear release
+        pub step_ms: Option<NonZeroU64>,
+    }
+
+    impl VestingSchedule {
+        /// Portion of the total amount released at the given time, truncated toward zero according to the spec
+        /// so that no more than has vested is ever released
+        ///
+        /// # Errors
+        ///
+        /// Fails if computing the portion overflows
+        pub fn released(
+            &self,
+            total: Numeric,
+            now_ms: u64,
+            spec: NumericSpec,
+        ) -> Result<Numeric, MathError> {
+            let elapsed_ms = now_ms.saturating_sub(self.start_ms);
+            if elapsed_ms < self.cliff_ms {
+                return Ok(Numeric::ZERO);
+            }
+            if self.duration_ms.get() <= elapsed_ms {
+                return Ok(total);
+            }
+            let elapsed_ms = self
+                .step_ms
+                .map_or(elapsed_ms, |step_ms| elapsed_ms - elapsed_ms % step_ms);
+
+            let released = total
+                .checked_mul(Numeric::from(elapsed_ms), NumericSpec::unconstrained())
+                .and_then(|scaled| {
+                    scaled.checked_div(
+                        Numeric::from(self.duration_ms.get()),
+                        NumericSpec::unconstrained(),
+                    )
+                })
+                .ok_or(MathError::Overflow)?
+                .truncate(spec);
+
+            Ok(released.min(total))
+        }
+    }
+
+    /// Metadata value for an amount locked in the asset of the beneficiary
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, IntoSchema, Constructor)]
+    pub struct VestingLockValue {
+        /// Account that transferred the locked amount and receives the unvested portion on revocation
+        pub grantor: AccountId,
+        /// Account allowed to revoke the unvested portion. `None` for irrevocable vesting
+        pub revoker: Option<AccountId>,
+        /// Total amount under vesting
+        pub total: Numeric,
+        /// Amount claimed by the beneficiary so far
+        pub claimed: Numeric,
+        /// Schedule by which the total amount is released
+        pub schedule: VestingSchedule,
+        /// Whether the unvested portion has been revoked, which releases the remaining total
+        pub is_revoked: bool,
+    }
+
+    impl VestingLockValue {
+        /// Amount released at the given time, whether claimed or not
+        ///
+        /// # Errors
+        ///
+        /// Fails if computing the amount overflows
+        pub fn released(&self, now_ms: u64, spec: NumericSpec) -> Result<Numeric, MathError> {
+            if self.is_revoked {
+                return Ok(self.total);
+            }
+            self.schedule.released(self.total, now_ms, spec)
+        }
+
+        /// Amount released at the given time but not claimed yet
+        ///
+        /// # Errors
+        ///
+        /// Fails if computing the amount overflows
+        pub fn claimable(&self, now_ms: u64, spec: NumericSpec) -> Result<Numeric, MathError> {
+            Ok(self
+                .released(now_ms, spec)?
+                .checked_sub(self.claimed)
+                .unwrap_or(Numeric::ZERO))
+        }
+
+        /// Amount not spendable by the beneficiary until claimed
+        pub fn locked(&self) -> Numeric {
+            self.total
+                .checked_sub(self.claimed)
+                .unwrap_or(Numeric::ZERO)
+        }
+    }
+
+    impl From<VestingLockValue> for Json {
+        fn from(details: VestingLockValue) -> Self {
+            Json::new(details)
+        }
+    }
+
+    impl TryFrom<&Json> for VestingLockValue {
+        type Error = serde_json::Error;
+
+        fn try_from(payload: &Json) -> serde_json::Result<Self> {
+            serde_json::from_str::<Self>(payload.as_ref())
+        }
+    }
+
+    const DELIMITER: char = '/';
+    const VESTING: &str = "vesting";
+
+    /// Prefix of the beneficiary account metadata keys holding [`VestingLockValue`]s
+    pub fn lock_key_prefix() -> String {
+        format!("{VESTING}{DELIMITER}")
+    }
+
+    /// Key of the beneficiary account metadata entry holding the [`VestingLockValue`] for assets of the given definition
+    pub fn lock_key(asset_definition: &AssetDefinitionId) -> Name {
+        format!(
+            "{}{}{DELIMITER}{}",
+            lock_key_prefix(),
+            asset_definition.domain(),
+            asset_definition.name(),
+        )
+        .parse()
+        .unwrap()
+    }
+
+    /// Decode amounts locked in the assets of an account from its metadata, keyed by asset definition
+    ///
+    /// # Errors
+    ///
+    /// Fails if some lock entry is malformed
+    pub fn locks_of(
+        account: &Account,
+    ) -> Result<BTreeMap<AssetDefinitionId, VestingLockValue>, String> {
+        let prefix = lock_key_prefix();
+        account
+            .metadata()
+            .iter()
+            .filter_map(|(key, value)| {
+                key.as_ref()
+                    .strip_prefix(&prefix)
+                    .map(|definition| (definition, value))
+            })
+            .map(|(definition, value)| {
+                let definition = definition
+                    .split_once(DELIMITER)
+                    .and_then(|(domain, name)| format!("{name}#{domain}").parse().ok())
+                    .ok_or_else(|| format!("vesting lock key malformed: {definition}"))?;
+                let value = value
+                    .try_into_any()
+                    .map_err(|err| format!("vesting lock metadata malformed:\n{err}"))?;
+                Ok((definition, value))
+            })
+            .collect()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn released_is_truncated_toward_zero() {
+            let schedule = VestingSchedule::new(0, 0, NonZeroU64::new(2_000).unwrap(), None);
+
+            // A half of 3 is 1.5, which banker's rounding would round up to 2
+            let released = schedule
+                .released(Numeric::from(3_u32), 1_000, NumericSpec::integer())
+                .unwrap();
+            assert_eq!(released, Numeric::from(1_u32));
+
+            let released = schedule
+                .released(Numeric::new(3, 0), 1_000, NumericSpec::fractional(1))
+                .unwrap();
+            assert_eq!(released, Numeric::new(15, 1));
+        }
+
+        #[test]
+        fn released_fails_on_overflow() {
+            let schedule = VestingSchedule::new(0, 0, NonZeroU64::new(u64::MAX).unwrap(), None);
+
+            let released = schedule.released(Numeric::MAX, u64::MAX - 1, NumericSpec::integer());
+            assert_eq!(released, Err(MathError::Overflow));
+        }
+    }
+}
+
+/// Types for role hierarchy instructions
+pub mod role {
+    use alloc::collections::{btree_set::BTreeSet, vec_deque::VecDeque};
+
+    use super::*;
+    use crate::parameter::Parameter;
+
+    /// Role-related instructions
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, From)]
+    pub enum RoleInstructionBox {
+        /// Register a role whose permissions only apply inside a domain, and grant it to the first holder
+        RegisterScoped(RoleRegisterScoped),
+        /// Replace the roles whose permissions the role inherits
+        SetParents(RoleSetParents),
+        /// Replace the roles whose holders administer the role
+        SetAdmins(RoleSetAdmins),
+    }
+
+    /// Register a role whose permissions only apply inside a domain, and grant it to the first holder
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, Constructor)]
+    pub struct RoleRegisterScoped {
+        /// Role to register along with its first holder
+        pub role: NewRole,
+        /// Domain outside of which the role grants no permissions
+        pub domain: DomainId,
+    }
+
+    /// Replace the roles whose permissions the role inherits
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, Constructor)]
+    pub struct RoleSetParents {
+        /// Role to inherit permissions
+        pub role: RoleId,
+        /// Roles whose permissions are inherited
+        pub parents: BTreeSet<RoleId>,
+    }
+
+    /// Replace the roles whose holders can grant and revoke the role and its permissions
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, Constructor)]
+    pub struct RoleSetAdmins {
+        /// Role to be administered
+        pub role: RoleId,
+        /// Roles whose holders administer the role
+        pub admins: BTreeSet<RoleId>,
+    }
+
+    impl_custom_instruction!(
+        RoleInstructionBox,
+        RoleRegisterScoped | RoleSetParents | RoleSetAdmins
+    );
+
+    /// Position of a role in the hierarchy
+    #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, IntoSchema)]
+    pub struct RoleSpec {
+        /// Domain outside of which the role grants no permissions. `None` for a global role
+        pub domain: Option<DomainId>,
+        /// Roles whose permissions the role inherits
+        pub parents: BTreeSet<RoleId>,
+        /// Roles whose holders can grant and revoke the role and its permissions.
+        /// If empty, the holders of the role itself can
+        pub admins: BTreeSet<RoleId>,
+    }
+
+    /// Executor parameter holding the specs of roles that are domain-scoped, inherit or are administered by other roles
+    #[derive(
+        Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, IntoSchema, Parameter,
+    )]
+    pub struct RoleHierarchy {
+        /// Specs of roles, except for the plain ones
+        pub roles: BTreeMap<RoleId, RoleSpec>,
+    }
+
+    impl RoleHierarchy {
+        /// Spec of the role, which is the default one for a plain role
+        pub fn spec(&self, role: &RoleId) -> RoleSpec {
+            self.roles.get(role).cloned().unwrap_or_default()
+        }
+
+        /// Replace the spec of the role
+        pub fn set_spec(&mut self, role: RoleId, spec: RoleSpec) {
+            if spec == RoleSpec::default() {
+                self.roles.remove(&role);
+            } else {
+                self.roles.insert(role, spec);
+            }
+        }
+
+        /// Drop the role along with its mentions as a parent or an admin of other roles
+        pub fn remove(&mut self, role: &RoleId) {
+            self.roles.remove(role);
+            for spec in self.roles.values_mut() {
+                spec.parents.remove(role);
+                spec.admins.remove(role);
+            }
+            self.roles.retain(|_, spec| *spec != RoleSpec::default());
+        }
+
+        /// Roles whose permissions the holders of the role have, the role itself included.
+        /// Each one comes with the shortest chain of inheritance leading from the role to it
+        pub fn lineage(&self, role: &RoleId) -> BTreeMap<RoleId, Vec<RoleId>> {
+            let mut lineage = BTreeMap::new();
+            let mut queue = VecDeque::new();
+            queue.push_back(Vec::from([role.clone()]));
+
+            while let Some(chain) = queue.pop_front() {
+                let Some(last) = chain.last().cloned() else {
+                    continue;
+                };
+                if lineage.contains_key(&last) {
+                    continue;
+                }
+                if let Some(spec) = self.roles.get(&last) {
+                    for parent in &spec.parents {
+                        let mut next = chain.clone();
+                        next.push(parent.clone());
+                        queue.push_back(next);
+                    }
+                }
+                lineage.insert(last, chain);
+            }
+
+            lineage
+        }
+
+        /// Whether the holders of the role have the permissions of the ancestor
+        pub fn inherits(&self, role: &RoleId, ancestor: &RoleId) -> bool {
+            self.lineage(role).contains_key(ancestor)
+        }
+
+        /// Resolve the permissions in effect for an account
+        /// given the permissions and the roles granted to it, and all the registered roles
+        pub fn effective_permissions(
+            &self,
+            permissions: impl IntoIterator<Item = Permission>,
+            granted_roles: impl IntoIterator<Item = RoleId>,
+            roles: &[Role],
+        ) -> Vec<EffectivePermission> {
+            let mut effective: Vec<_> = permissions
+                .into_iter()
+                .map(|permission| EffectivePermission::new(permission, Vec::new()))
+                .collect();
+
+            for granted_role in granted_roles {
+                for (role_id, via) in self.lineage(&granted_role) {
+                    let Some(role) = roles.iter().find(|role| role.id() == &role_id) else {
+                        continue;
+                    };
+                    effective.extend(
+                        role.permissions()
+                            .cloned()
+                            .map(|permission| EffectivePermission::new(permission, via.clone())),
+                    );
+                }
+            }
+
+            effective
+        }
+    }
+
+    /// Permission in effect for an account along with its provenance
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Constructor)]
+    pub struct EffectivePermission {
+        /// Permission in effect
+        pub permission: Permission,
+        /// Roles through which the account has the permission: the one granted to the account,
+        /// followed by the inherited ones up to the role holding the permission.
+        /// Empty for a permission granted to the account directly
+        pub via: Vec<RoleId>,
+    }
+}
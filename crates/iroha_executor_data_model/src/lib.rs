@@ -7,6 +7,7 @@ extern crate self as iroha_executor_data_model;
 pub mod isi;
 pub mod parameter;
 pub mod permission;
+pub mod query;
 
 /// An error that might occur while converting a data model object into a native executor type.
 ///
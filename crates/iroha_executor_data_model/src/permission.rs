@@ -219,6 +219,21 @@ pub mod trigger {
     }
 }
 
+pub mod contract {
+    use super::*;
+
+    permission! {
+        #[derive(Copy)]
+        pub struct CanDeployContract;
+    }
+
+    permission! {
+        pub struct CanCallContract {
+            pub contract: ContractId,
+        }
+    }
+}
+
 pub mod parameter {
     use super::*;
 
@@ -0,0 +1,110 @@
+//! Types for custom queries
+
+use alloc::{collections::btree_map::BTreeMap, format, string::String, vec::Vec};
+
+use derive_more::{Constructor, From};
+use iroha_data_model::{
+    isi::InstructionBox,
+    prelude::{Json, *},
+};
+use iroha_schema::IntoSchema;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// Query resolved by the executor, carried by [`CustomQuery`]
+pub trait ExecutorQuery: Into<CustomQuery> {
+    /// The type of the output of the query
+    type Output: Serialize + DeserializeOwned;
+}
+
+macro_rules! impl_custom_query {
+    ($box:ty, $($query:ty => $output:ty),+ $(,)?) => {
+        impl From<$box> for CustomQuery {
+            fn from(value: $box) -> Self {
+                let payload = serde_json::to_value(&value)
+                    .expect(concat!("INTERNAL BUG: Couldn't serialize ", stringify!($box)));
+
+                Self::new(payload.into())
+            }
+        }
+
+        impl TryFrom<&Json> for $box {
+            type Error = serde_json::Error;
+
+            fn try_from(payload: &Json) -> serde_json::Result<Self> {
+                serde_json::from_str::<Self>(payload.as_ref())
+            }
+        } $(
+
+        impl ExecutorQuery for $query {
+            type Output = $output;
+        }
+
+        impl From<$query> for CustomQuery {
+            fn from(value: $query) -> Self {
+                <$box>::from(value).into()
+            }
+        })+
+    };
+}
+
+/// Types for multisig queries
+pub mod multisig {
+    use super::*;
+    use crate::isi::multisig::{MultisigProposalValue, MultisigSpec};
+
+    /// Multisig-related queries
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, From)]
+    pub enum MultisigQueryBox {
+        /// Find the specification of a multisig account
+        FindSpec(FindMultisigSpec),
+        /// Find pending proposals of a multisig account
+        FindProposals(FindMultisigProposals),
+    }
+
+    /// Find the specification of a multisig account
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, Constructor)]
+    pub struct FindMultisigSpec {
+        /// Multisig account to find
+        pub account: AccountId,
+    }
+
+    /// Find pending proposals of a multisig account keyed by instructions hash,
+    /// including their approvals, rejections and expiry
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, Constructor)]
+    pub struct FindMultisigProposals {
+        /// Multisig account to find
+        pub account: AccountId,
+    }
+
+    impl_custom_query!(
+        MultisigQueryBox,
+        FindMultisigSpec => MultisigSpec,
+        FindMultisigProposals => BTreeMap<HashOf<Vec<InstructionBox>>, MultisigProposalValue>,
+    );
+}
+
+/// Types for role queries
+pub mod role {
+    use super::*;
+    use crate::isi::role::EffectivePermission;
+
+    /// Role-related queries
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, From)]
+    pub enum RoleQueryBox {
+        /// Find the permissions in effect for an account
+        FindEffectivePermissions(FindEffectivePermissions),
+    }
+
+    /// Find the permissions in effect for an account along with their provenance,
+    /// including the ones of the roles inherited by the roles granted to it
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, Constructor)]
+    pub struct FindEffectivePermissions {
+        /// Account to find the permissions of
+        pub account: AccountId,
+    }
+
+    impl_custom_query!(
+        RoleQueryBox,
+        FindEffectivePermissions => Vec<EffectivePermission>,
+    );
+}
@@ -89,6 +89,20 @@ pub fn impl_derive_entrypoints(emitter: &mut Emitter, input: &syn::DeriveInput)
                 executor.verdict
             }
         },
+        parse_quote! {
+            #[::iroha_executor::entrypoint]
+            pub fn execute_custom_query(
+                query: ::iroha_executor::data_model::query::executor::CustomQuery,
+                host: ::iroha_executor::prelude::Iroha,
+                context: ::iroha_executor::prelude::Context,
+            ) -> ::core::result::Result<
+                ::iroha_executor::data_model::prelude::Json,
+                ::iroha_executor::data_model::ValidationFail,
+            > {
+                let executor = #ident {host, context, verdict: Ok(()), #(#custom_idents),*};
+                ::iroha_executor::default::execute_custom_query(&executor, &query)
+            }
+        },
     ];
     if let Some(custom) = custom {
         entrypoint_fns.retain(|entrypoint| {
@@ -124,6 +138,13 @@ pub fn impl_derive_visit(emitter: &mut Emitter, input: &syn::DeriveInput) -> Tok
         "fn visit_unregister_account(operation: &Unregister<Account>)",
         "fn visit_set_account_key_value(operation: &SetKeyValue<Account>)",
         "fn visit_remove_account_key_value(operation: &RemoveKeyValue<Account>)",
+        "fn visit_add_account_key(operation: &AddAccountKey)",
+        "fn visit_remove_account_key(operation: &RemoveAccountKey)",
+        "fn visit_rotate_account_key(operation: &RotateAccountKey)",
+        "fn visit_set_account_key_threshold(operation: &SetAccountKeyThreshold)",
+        "fn visit_register_account_alias(operation: &RegisterAccountAlias)",
+        "fn visit_transfer_account_alias(operation: &TransferAccountAlias)",
+        "fn visit_unregister_account_alias(operation: &UnregisterAccountAlias)",
         "fn visit_register_nft(operation: &Register<Nft>)",
         "fn visit_unregister_nft(operation: &Unregister<Nft>)",
         "fn visit_mint_asset_numeric(operation: &Mint<Numeric, Asset>)",
@@ -152,6 +173,11 @@ pub fn impl_derive_visit(emitter: &mut Emitter, input: &syn::DeriveInput) -> Tok
         "fn visit_mint_trigger_repetitions(operation: &Mint<u32, Trigger>)",
         "fn visit_burn_trigger_repetitions(operation: &Burn<u32, Trigger>)",
         "fn visit_execute_trigger(operation: &ExecuteTrigger)",
+        "fn visit_upgrade_trigger(operation: &UpgradeTrigger)",
+        "fn visit_emit_event(operation: &EmitEvent)",
+        "fn visit_deploy_contract(operation: &DeployContract)",
+        "fn visit_remove_contract(operation: &RemoveContract)",
+        "fn visit_call_contract(operation: &CallContract)",
         "fn visit_set_parameter(operation: &SetParameter)",
         "fn visit_upgrade(operation: &Upgrade)",
         "fn visit_log(operation: &Log)",
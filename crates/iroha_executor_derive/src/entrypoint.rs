@@ -9,6 +9,7 @@ mod export {
     pub const EXECUTOR_EXECUTE_TRANSACTION: &str = "_iroha_executor_execute_transaction";
     pub const EXECUTOR_EXECUTE_INSTRUCTION: &str = "_iroha_executor_execute_instruction";
     pub const EXECUTOR_VALIDATE_QUERY: &str = "_iroha_executor_validate_query";
+    pub const EXECUTOR_EXECUTE_CUSTOM_QUERY: &str = "_iroha_executor_execute_custom_query";
     pub const EXECUTOR_MIGRATE_CONTEXT: &str = "_iroha_executor_migrate";
 }
 
@@ -16,6 +17,7 @@ mod import {
     pub const DECODE_EXECUTE_TRANSACTION_CONTEXT: &str = "__decode_execute_transaction_context";
     pub const DECODE_EXECUTE_INSTRUCTION_CONTEXT: &str = "__decode_execute_instruction_context";
     pub const DECODE_VALIDATE_QUERY_CONTEXT: &str = "__decode_validate_query_context";
+    pub const DECODE_EXECUTE_CUSTOM_QUERY_CONTEXT: &str = "__decode_execute_custom_query_context";
 }
 
 /// [`executor_entrypoint`](crate::executor_entrypoint()) macro implementation
@@ -52,6 +54,7 @@ pub fn impl_validate_entrypoint(emitter: &mut Emitter, item: syn::ItemFn) -> Tok
             execute_transaction => EXECUTOR_EXECUTE_TRANSACTION(DECODE_EXECUTE_TRANSACTION_CONTEXT),
             execute_instruction => EXECUTOR_EXECUTE_INSTRUCTION(DECODE_EXECUTE_INSTRUCTION_CONTEXT),
             validate_query => EXECUTOR_VALIDATE_QUERY(DECODE_VALIDATE_QUERY_CONTEXT),
+            execute_custom_query => EXECUTOR_EXECUTE_CUSTOM_QUERY(DECODE_EXECUTE_CUSTOM_QUERY_CONTEXT),
         }
     }
 }
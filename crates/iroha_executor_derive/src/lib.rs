@@ -65,6 +65,11 @@ pub fn migrate(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// fn validate_query(query: QueryBox, host: Iroha, context: Context) -> Result {
 ///     todo!()
 /// }
+///
+/// #[entrypoint]
+/// fn execute_custom_query(query: CustomQuery, host: Iroha, context: Context) -> Result<Json, ValidationFail> {
+///     todo!()
+/// }
 /// ```
 #[manyhow]
 #[proc_macro_attribute]
@@ -147,9 +152,9 @@ pub fn derive_visit(input: TokenStream) -> TokenStream {
     emitter.finish_token_stream_with(result)
 }
 
-/// Implements three default entrypoints on a given `Executor` struct: `execute_transaction`,
-/// `validate_query` and `execute_instruction`. The `migrate` entrypoint is implied to be
-/// implemented manually by the user at all times.
+/// Implements four default entrypoints on a given `Executor` struct: `execute_transaction`,
+/// `validate_query`, `execute_instruction` and `execute_custom_query`. The `migrate` entrypoint
+/// is implied to be implemented manually by the user at all times.
 ///
 /// Users can supply custom overrides for any of the entrypoint functions as freestanding functions
 /// in the same module via the `#[entrypoints(custom(...))]` attribute by
@@ -11,7 +11,7 @@ use core::str::FromStr;
 
 use derive_more::{Display, From};
 use parity_scale_codec::{Decode, Encode};
-use rust_decimal::{prelude::ToPrimitive, Decimal};
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
 use serde::{Deserialize, Serialize};
 
 /// Decimal number with arbitrary precision and scale.
@@ -244,6 +244,20 @@ impl Numeric {
         Self { inner: self.inner }
     }
 
+    /// Returns a new `Decimal` number truncated toward zero to the given spec. e.g. 6.9 -> 6, 7.5 -> 7
+    #[must_use]
+    pub fn truncate(&self, spec: NumericSpec) -> Self {
+        if let Some(scale) = spec.scale {
+            return Self {
+                inner: self
+                    .inner
+                    .round_dp_with_strategy(scale, RoundingStrategy::ToZero),
+            };
+        }
+
+        Self { inner: self.inner }
+    }
+
     /// Convert [`Numeric`] to [`f64`] with possible loss in precision
     pub fn to_f64(self) -> f64 {
         self.inner.to_f64().expect("never fails")
@@ -511,6 +525,18 @@ mod tests {
         assert_eq!(a.checked_add(b), Some(Numeric::new(1000, 2)));
     }
 
+    #[test]
+    fn check_truncate() {
+        let num = Numeric::new(1999, 3);
+
+        assert_eq!(num.truncate(NumericSpec::integer()), Numeric::new(1, 0));
+        assert_eq!(
+            num.truncate(NumericSpec::fractional(2)),
+            Numeric::new(199, 2)
+        );
+        assert_eq!(num.truncate(NumericSpec::unconstrained()), num);
+    }
+
     #[test]
     fn check_serde() {
         let num1 = Numeric::new(1002, 2);
@@ -0,0 +1,158 @@
+//! Compression of the messages sent to peers.
+//!
+//! Compression is negotiated without changing the handshake, so that peers with compression
+//! enabled stay compatible with the peers of earlier versions. A peer with compression enabled
+//! offers it by sending a pong before it has pinged the other peer, which peers of earlier versions
+//! ignore, and compresses messages only to the peers which offered compression in the same way.
+//!
+//! A compressed message starts with a marker, which the encoding of an uncompressed message never
+//! starts with, followed by the identifier of the algorithm. Small messages, which aren't worth
+//! compressing, are sent as-is. Messages are compressed before the encryption,
+//! as encrypted data doesn't compress.
+use std::{
+    borrow::Cow,
+    io::{Read, Write},
+    sync::Arc,
+};
+
+use crate::{network::TrafficStatistics, Error};
+
+/// Marker of the compressed message.
+///
+/// Encoded messages start with the index of the variant of a small enum and never with this byte
+const COMPRESSED: u8 = u8::MAX;
+
+/// Compression settings of the connections
+#[derive(Debug, Clone, Copy)]
+pub struct CompressionConfig {
+    /// Offer compression to peers once connected
+    pub enabled: bool,
+    /// Messages smaller than this number of bytes are sent uncompressed
+    pub threshold: usize,
+    /// Max number of bytes of a decompressed message
+    pub max_decompressed_len: usize,
+}
+
+/// Compression algorithm
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    /// [Zstandard](https://facebook.github.io/zstd/)
+    Zstd,
+}
+
+impl Algorithm {
+    /// Algorithms supported by this implementation
+    pub const SUPPORTED: [Self; 1] = [Self::Zstd];
+
+    /// Identifier of the algorithm sent along with the compressed message
+    pub const fn id(self) -> u8 {
+        match self {
+            Self::Zstd => 1,
+        }
+    }
+
+    /// Algorithm with the given identifier, [`None`] if it isn't supported
+    pub fn from_id(id: u8) -> Option<Self> {
+        Self::SUPPORTED
+            .into_iter()
+            .find(|algorithm| algorithm.id() == id)
+    }
+
+    fn compress(self, data: &[u8], output: Vec<u8>) -> std::io::Result<Vec<u8>> {
+        match self {
+            Self::Zstd => {
+                let mut encoder =
+                    zstd::stream::Encoder::new(output, zstd::DEFAULT_COMPRESSION_LEVEL)?;
+                encoder.write_all(data)?;
+                encoder.finish()
+            }
+        }
+    }
+
+    fn decompress(self, data: &[u8], limit: u64, output: &mut Vec<u8>) -> std::io::Result<()> {
+        match self {
+            Self::Zstd => {
+                let decoder = zstd::stream::read::Decoder::with_buffer(data)?;
+                decoder.take(limit).read_to_end(output)?;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Compression of the messages of a single connection
+#[derive(Clone)]
+pub(crate) struct Compressor {
+    /// Algorithm compressing the sent messages, [`None`] until the peer offers compression
+    algorithm: Option<Algorithm>,
+    config: CompressionConfig,
+    statistics: Arc<TrafficStatistics>,
+}
+
+impl Compressor {
+    pub fn new(config: CompressionConfig, statistics: Arc<TrafficStatistics>) -> Self {
+        Self {
+            algorithm: None,
+            config,
+            statistics,
+        }
+    }
+
+    /// Start compressing the sent messages once the peer offered compression.
+    /// Does nothing unless compression is enabled.
+    pub fn peer_offered(&mut self) {
+        if self.config.enabled && self.algorithm.is_none() {
+            iroha_logger::trace!("Peer offered compression");
+            self.algorithm = Some(Algorithm::Zstd);
+        }
+    }
+
+    /// Turn the encoded message into the data to be encrypted
+    pub fn compress<'data>(&self, encoded: &'data [u8]) -> Cow<'data, [u8]> {
+        let Some(algorithm) = self.algorithm else {
+            return Cow::Borrowed(encoded);
+        };
+        if encoded.len() < self.config.threshold {
+            return Cow::Borrowed(encoded);
+        }
+
+        let output = match algorithm.compress(encoded, vec![COMPRESSED, algorithm.id()]) {
+            Ok(compressed) if compressed.len() < encoded.len() => Cow::Owned(compressed),
+            // Incompressible data is sent as-is
+            Ok(_) => Cow::Borrowed(encoded),
+            Err(error) => {
+                iroha_logger::warn!(%error, "Failed to compress message, sending it as-is");
+                Cow::Borrowed(encoded)
+            }
+        };
+        self.statistics
+            .record_compressed(encoded.len(), output.len());
+        output
+    }
+
+    /// Turn the decrypted data into the encoded message
+    ///
+    /// # Errors
+    /// - If the data is malformed
+    /// - If the decompressed message exceeds the size limit
+    pub fn decompress<'data>(&self, decrypted: &'data [u8]) -> Result<Cow<'data, [u8]>, Error> {
+        // Compressed messages are received only if compression was offered
+        let ([COMPRESSED, id, compressed @ ..], true) = (decrypted, self.config.enabled) else {
+            return Ok(Cow::Borrowed(decrypted));
+        };
+        let algorithm = Algorithm::from_id(*id).ok_or(Error::Decompression)?;
+
+        let max_len = self.config.max_decompressed_len;
+        // Read one byte more than allowed to tell if the limit is exceeded
+        // without ever holding more than that in memory
+        let limit = u64::try_from(max_len).map_or(u64::MAX, |len| len.saturating_add(1));
+        let mut output = Vec::new();
+        algorithm
+            .decompress(compressed, limit, &mut output)
+            .map_err(|_| Error::Decompression)?;
+        if output.len() > max_len {
+            return Err(Error::DecompressedTooLarge);
+        }
+        Ok(Cow::Owned(output))
+    }
+}
@@ -0,0 +1,144 @@
+//! Priority lanes of the outgoing messages.
+//!
+//! Every connection keeps a separate queue of messages for each [`Lane`] and picks the next
+//! message to send with deficit round robin, so that a lane gets a share of the bandwidth
+//! proportional to its [`Lane::weight`] while there are messages waiting in other lanes.
+//! This keeps small consensus messages from waiting behind large batches of gossiped
+//! transactions or synced blocks.
+//!
+//! Messages are never split, so a message which started being sent is sent completely
+//! before the next one is picked.
+use std::{collections::VecDeque, sync::Arc};
+
+use bytes::Bytes;
+
+use crate::network::TrafficStatistics;
+
+/// Number of bytes a lane of weight `1` may send in one round of the scheduling
+const QUANTUM: usize = 16 * 1024;
+/// Number of lanes
+pub(crate) const LANES: usize = 4;
+
+/// Lane of the outgoing message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lane {
+    /// Consensus messages: votes, commits and view changes
+    Consensus,
+    /// Blocks requested and shared by the block synchronization
+    BlockSync,
+    /// Gossiped transactions
+    TransactionGossip,
+    /// Gossiped peer addresses and other network maintenance messages
+    PeerGossip,
+}
+
+impl Lane {
+    /// All lanes, in the order of their scheduling
+    pub const ALL: [Self; LANES] = [
+        Self::Consensus,
+        Self::BlockSync,
+        Self::TransactionGossip,
+        Self::PeerGossip,
+    ];
+
+    /// Relative share of the bandwidth the lane gets while other lanes have messages waiting
+    pub const fn weight(self) -> usize {
+        match self {
+            Self::Consensus => 8,
+            Self::BlockSync => 4,
+            Self::TransactionGossip => 2,
+            Self::PeerGossip => 1,
+        }
+    }
+
+    /// Name of the lane, e.g. to be used as a metric label
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Consensus => "consensus",
+            Self::BlockSync => "block_sync",
+            Self::TransactionGossip => "transaction_gossip",
+            Self::PeerGossip => "peer_gossip",
+        }
+    }
+
+    pub(crate) const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Payload which declares the [`Lane`] it is sent in
+pub trait Prioritized {
+    /// Lane of the message
+    fn lane(&self) -> Lane;
+}
+
+/// Queues of encoded messages of a single connection, one for each lane
+pub(crate) struct LaneScheduler {
+    queues: [VecDeque<Bytes>; LANES],
+    /// Number of bytes each lane may send before the turn passes to the next lane
+    deficits: [usize; LANES],
+    /// Index of the lane whose turn it is
+    current: usize,
+    /// Queue depth of every lane is reported to the statistics shared by all connections
+    statistics: Arc<TrafficStatistics>,
+}
+
+impl LaneScheduler {
+    pub fn new(statistics: Arc<TrafficStatistics>) -> Self {
+        Self {
+            queues: std::array::from_fn(|_| VecDeque::new()),
+            deficits: [0; LANES],
+            current: 0,
+            statistics,
+        }
+    }
+
+    /// Put the encoded message at the end of the queue of the `lane`
+    pub fn push(&mut self, lane: Lane, message: Bytes) {
+        self.queues[lane.index()].push_back(message);
+        self.statistics.lane_pushed(lane);
+    }
+
+    /// Take the message which should be sent next, if any
+    pub fn pop(&mut self) -> Option<Bytes> {
+        if self.is_empty() {
+            return None;
+        }
+
+        loop {
+            let queue = &mut self.queues[self.current];
+            if let Some(message) = queue.front() {
+                if message.len() <= self.deficits[self.current] {
+                    self.deficits[self.current] -= message.len();
+                    let message = queue.pop_front();
+                    // Lane can't save up its turns while it has nothing to send
+                    if queue.is_empty() {
+                        self.deficits[self.current] = 0;
+                    }
+                    self.statistics.lane_popped(Lane::ALL[self.current], 1);
+                    return message;
+                }
+            }
+
+            self.current = (self.current + 1) % LANES;
+            if !self.queues[self.current].is_empty() {
+                self.deficits[self.current] += Lane::ALL[self.current].weight() * QUANTUM;
+            }
+        }
+    }
+
+    /// Check if there are no messages waiting in any lane
+    pub fn is_empty(&self) -> bool {
+        self.queues.iter().all(VecDeque::is_empty)
+    }
+}
+
+impl Drop for LaneScheduler {
+    fn drop(&mut self) {
+        // Messages left in the queues are dropped along with the connection
+        for lane in Lane::ALL {
+            let len = self.queues[lane.index()].len();
+            self.statistics.lane_popped(lane, len);
+        }
+    }
+}
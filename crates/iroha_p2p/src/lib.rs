@@ -15,8 +15,12 @@ pub use network::message::*;
 use parity_scale_codec::{Decode, Encode};
 use thiserror::Error;
 
+pub mod compression;
+pub mod lane;
 pub mod network;
 pub mod peer;
+pub(crate) mod rate_limit;
+pub mod scoring;
 
 /// The main type to use for secure communication.
 pub type NetworkHandle<T> = network::NetworkBaseHandle<T, X25519Sha256, ChaCha20Poly1305>;
@@ -28,10 +32,11 @@ pub mod boilerplate {
     use iroha_crypto::{encryption::Encryptor, kex::KeyExchangeScheme};
 
     use super::*;
+    use crate::lane::Prioritized;
 
     /// Shorthand for traits required for payload
-    pub trait Pload: Encode + Decode + Send + Clone + 'static {}
-    impl<T> Pload for T where T: Encode + Decode + Send + Clone + 'static {}
+    pub trait Pload: Encode + Decode + Prioritized + Send + Clone + 'static {}
+    impl<T> Pload for T where T: Encode + Decode + Prioritized + Send + Clone + 'static {}
 
     /// Shorthand for traits required for key exchange
     pub trait Kex: KeyExchangeScheme + Send + 'static {}
@@ -61,6 +66,10 @@ pub enum Error {
     Addr(#[from] AddrParseError),
     /// Connection reset by peer in the middle of message transfer
     ConnectionResetByPeer,
+    /// Failed to decompress message
+    Decompression,
+    /// Decompressed message exceeds the size limit
+    DecompressedTooLarge,
 }
 
 impl From<io::Error> for Error {
@@ -120,6 +129,13 @@ pub(crate) mod unbounded_with_len {
             Some(message)
         }
 
+        #[cfg(any(test, feature = "simulation"))]
+        pub fn try_recv(&mut self) -> Option<T> {
+            let message = self.receiver.try_recv().ok()?;
+            self.len.fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
+            Some(message)
+        }
+
         pub fn len(&self) -> usize {
             self.len
                 .load(std::sync::atomic::Ordering::SeqCst)
@@ -3,6 +3,10 @@ use std::{
     collections::{HashMap, HashSet},
     fmt::Debug,
     net::ToSocketAddrs,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
     time::Duration,
 };
 
@@ -19,15 +23,19 @@ use iroha_primitives::addr::SocketAddr;
 use tokio::{
     net::{TcpListener, TcpStream},
     sync::{mpsc, watch},
+    time::Instant,
 };
 
 use crate::{
     boilerplate::*,
+    compression::CompressionConfig,
+    lane::{Lane, LANES},
     peer::{
         handles::{connected_from, connecting, PeerHandle},
         message::*,
-        Connection, ConnectionId,
+        Connection, ConnectionId, TrafficControl,
     },
+    scoring::{Misbehavior, PeerScores},
     unbounded_with_len, Broadcast, Error, NetworkMessage, OnlinePeers, Post, UpdatePeers,
     UpdateTopology,
 };
@@ -49,6 +57,10 @@ pub struct NetworkBaseHandle<T: Pload, K: Kex, E: Enc> {
     update_peers_sender: mpsc::UnboundedSender<UpdatePeers>,
     /// Sender of [`NetworkMessage`] message
     network_message_sender: unbounded_with_len::Sender<NetworkMessage<T>>,
+    /// Sender of penalties for misbehaving peers
+    penalize_sender: mpsc::UnboundedSender<(PeerId, Misbehavior)>,
+    /// Statistics of the traffic dropped by the network
+    statistics: Arc<TrafficStatistics>,
     /// Key exchange used by network
     _key_exchange: core::marker::PhantomData<K>,
     /// Encryptor used by the network
@@ -63,6 +75,8 @@ impl<T: Pload, K: Kex, E: Enc> Clone for NetworkBaseHandle<T, K, E> {
             update_topology_sender: self.update_topology_sender.clone(),
             update_peers_sender: self.update_peers_sender.clone(),
             network_message_sender: self.network_message_sender.clone(),
+            penalize_sender: self.penalize_sender.clone(),
+            statistics: Arc::clone(&self.statistics),
             _key_exchange: core::marker::PhantomData::<K>,
             _encryptor: core::marker::PhantomData::<E>,
         }
@@ -81,6 +95,13 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
             address: listen_addr,
             public_address,
             idle_timeout,
+            replicas,
+            inbound_bytes_per_sec,
+            outbound_bytes_per_sec,
+            ban_duration,
+            compression,
+            compression_threshold,
+            max_decompressed_len,
         }: Config,
         shutdown_signal: ShutdownSignal,
     ) -> Result<(Self, Child), Error> {
@@ -94,8 +115,10 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
         let (update_peers_sender, update_peers_receiver) = mpsc::unbounded_channel();
         let (network_message_sender, network_message_receiver) =
             unbounded_with_len::unbounded_channel();
+        let (penalize_sender, penalize_receiver) = mpsc::unbounded_channel();
         let (peer_message_sender, peer_message_receiver) = mpsc::channel(1);
         let (service_message_sender, service_message_receiver) = mpsc::channel(1);
+        let statistics = Arc::new(TrafficStatistics::default());
         let network = NetworkBase {
             listen_addr: listen_addr.into_value(),
             public_address: public_address.into_value(),
@@ -109,14 +132,28 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
             update_topology_receiver,
             update_peers_receiver,
             network_message_receiver,
+            penalize_receiver,
             peer_message_receiver,
             peer_message_sender,
             service_message_receiver,
             service_message_sender,
             current_conn_id: 0,
             current_topology: HashSet::new(),
+            replicas: replicas.into_iter().collect(),
             current_peers_addresses: Vec::new(),
             idle_timeout,
+            traffic_control: TrafficControl {
+                inbound_bytes_per_sec,
+                outbound_bytes_per_sec,
+                compression: CompressionConfig {
+                    enabled: compression,
+                    threshold: usize::try_from(compression_threshold.get()).unwrap_or(usize::MAX),
+                    max_decompressed_len: usize::try_from(max_decompressed_len.get())
+                        .unwrap_or(usize::MAX),
+                },
+                statistics: Arc::clone(&statistics),
+            },
+            scores: PeerScores::new(ban_duration),
             _key_exchange: core::marker::PhantomData::<K>,
             _encryptor: core::marker::PhantomData::<E>,
         };
@@ -131,6 +168,8 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
                 update_topology_sender,
                 update_peers_sender,
                 network_message_sender,
+                penalize_sender,
+                statistics,
                 _key_exchange: core::marker::PhantomData,
                 _encryptor: core::marker::PhantomData,
             },
@@ -175,6 +214,19 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
             .expect("NetworkBase must accept messages until there is at least one handle to it")
     }
 
+    /// Penalize the peer for the `misbehavior`, see [`crate::scoring`].
+    pub fn penalize(&self, peer_id: PeerId, misbehavior: Misbehavior) {
+        self.penalize_sender
+            .send((peer_id, misbehavior))
+            .expect("NetworkBase must accept messages until there is at least one handle to it")
+    }
+
+    /// Statistics of the traffic dropped by the network, of the penalized peers,
+    /// of the messages waiting to be sent and of the compression
+    pub fn statistics(&self) -> &TrafficStatistics {
+        &self.statistics
+    }
+
     /// Receive latest update of [`OnlinePeers`]
     pub fn online_peers<P>(&self, f: impl FnOnce(&OnlinePeers) -> P) -> P {
         f(&self.online_peers_receiver.borrow())
@@ -193,6 +245,77 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
     }
 }
 
+#[cfg(any(test, feature = "simulation"))]
+impl<T: Pload, K: Kex, E: Enc> NetworkBaseHandle<T, K, E> {
+    /// Create handle which isn't connected to any network.
+    ///
+    /// Messages sent through the handle are collected by the returned [`SimulatedNetwork`]
+    /// so that the caller can deliver them to other peers at the time of its choosing.
+    pub fn simulated() -> (Self, SimulatedNetwork<T>) {
+        let (online_peers_sender, online_peers_receiver) = watch::channel(HashSet::new());
+        let (subscribe_to_peers_messages_sender, subscribe_to_peers_messages_receiver) =
+            mpsc::unbounded_channel();
+        let (update_topology_sender, update_topology_receiver) = mpsc::unbounded_channel();
+        let (update_peers_sender, update_peers_receiver) = mpsc::unbounded_channel();
+        let (network_message_sender, network_message_receiver) =
+            unbounded_with_len::unbounded_channel();
+        let (penalize_sender, penalize_receiver) = mpsc::unbounded_channel();
+
+        (
+            Self {
+                subscribe_to_peers_messages_sender,
+                online_peers_receiver,
+                update_topology_sender,
+                update_peers_sender,
+                network_message_sender,
+                penalize_sender,
+                statistics: Arc::default(),
+                _key_exchange: core::marker::PhantomData,
+                _encryptor: core::marker::PhantomData,
+            },
+            SimulatedNetwork {
+                network_message_receiver,
+                _online_peers_sender: online_peers_sender,
+                _subscribe_to_peers_messages_receiver: subscribe_to_peers_messages_receiver,
+                update_topology_receiver,
+                update_peers_receiver,
+                penalize_receiver,
+            },
+        )
+    }
+}
+
+/// Counterpart of the handle created with [`NetworkBaseHandle::simulated`].
+///
+/// Keeps the channels of the handle open and collects the messages sent through it.
+#[cfg(any(test, feature = "simulation"))]
+pub struct SimulatedNetwork<T: Pload> {
+    network_message_receiver: unbounded_with_len::Receiver<NetworkMessage<T>>,
+    _online_peers_sender: watch::Sender<OnlinePeers>,
+    _subscribe_to_peers_messages_receiver: mpsc::UnboundedReceiver<mpsc::Sender<PeerMessage<T>>>,
+    update_topology_receiver: mpsc::UnboundedReceiver<UpdateTopology>,
+    update_peers_receiver: mpsc::UnboundedReceiver<UpdatePeers>,
+    penalize_receiver: mpsc::UnboundedReceiver<(PeerId, Misbehavior)>,
+}
+
+#[cfg(any(test, feature = "simulation"))]
+impl<T: Pload> SimulatedNetwork<T> {
+    /// Take the next message sent through the handle, if any,
+    /// along with its recipient which is [`None`] for a broadcast
+    pub fn try_recv(&mut self) -> Option<(Option<PeerId>, T)> {
+        // Connections aren't simulated, updates of the topology and penalties are of no interest
+        while self.update_topology_receiver.try_recv().is_ok() {}
+        while self.update_peers_receiver.try_recv().is_ok() {}
+        while self.penalize_receiver.try_recv().is_ok() {}
+
+        let message = match self.network_message_receiver.try_recv()? {
+            NetworkMessage::Post(Post { data, peer_id }) => (Some(peer_id), data),
+            NetworkMessage::Broadcast(Broadcast { data }) => (None, data),
+        };
+        Some(message)
+    }
+}
+
 /// Base network layer structure, holding connections interacting with peers.
 struct NetworkBase<T: Pload, K: Kex, E: Enc> {
     /// Listening address for incoming connections. Must parse into [`std::net::SocketAddr`]
@@ -219,6 +342,8 @@ struct NetworkBase<T: Pload, K: Kex, E: Enc> {
     update_peers_receiver: mpsc::UnboundedReceiver<UpdatePeers>,
     /// Receiver of [`Post`] message
     network_message_receiver: unbounded_with_len::Receiver<NetworkMessage<T>>,
+    /// Receiver of penalties for misbehaving peers
+    penalize_receiver: mpsc::UnboundedReceiver<(PeerId, Misbehavior)>,
     /// Channel to gather messages from all peers
     peer_message_receiver: mpsc::Receiver<PeerMessage<T>>,
     /// Sender for peer messages to provide clone of sender inside peer
@@ -231,6 +356,10 @@ struct NetworkBase<T: Pload, K: Kex, E: Enc> {
     current_conn_id: ConnectionId,
     /// Current topology
     current_topology: HashSet<PeerId>,
+    /// Replicas which are allowed to connect while not being part of the topology.
+    ///
+    /// Connections to replicas are only accepted, never initiated.
+    replicas: HashSet<PeerId>,
     /// Can have two addresses for same `PeerId`.
     /// * One initially provided via config
     /// * Second received from other peers via gossiping
@@ -239,6 +368,10 @@ struct NetworkBase<T: Pload, K: Kex, E: Enc> {
     current_peers_addresses: Vec<(PeerId, SocketAddr)>,
     /// Duration after which terminate connection with idle peer
     idle_timeout: Duration,
+    /// Rate limits of the connections and the shared traffic statistics
+    traffic_control: TrafficControl,
+    /// Scores and bans of misbehaving peers
+    scores: PeerScores,
     /// Key exchange used by network
     _key_exchange: core::marker::PhantomData<K>,
     /// Encryptor used by the network
@@ -266,6 +399,10 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
                 Some(update_peers) = self.update_peers_receiver.recv() => {
                     self.set_current_peers_addresses(update_peers);
                 }
+                // Penalties are given for rare events like invalid blocks
+                Some((peer_id, misbehavior)) = self.penalize_receiver.recv() => {
+                    self.penalize(&peer_id, misbehavior);
+                }
                 // Frequency of update is relatively low, so it won't block other tasks from execution
                 _ = update_topology_interval.tick() => {
                     self.update_topology()
@@ -279,6 +416,9 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
                         ServiceMessage::Connected(connected) => {
                             self.peer_connected(connected);
                         }
+                        ServiceMessage::Misbehaved(Misbehaved { peer_id, misbehavior }) => {
+                            self.penalize(&peer_id, misbehavior);
+                        }
                     }
                 }
                 // Because network messages is responses to incoming messages or relatively low rate messages
@@ -336,6 +476,7 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
             Connection::new(conn_id, stream),
             service_message_sender,
             self.idle_timeout,
+            self.traffic_control.clone(),
         );
     }
 
@@ -355,12 +496,20 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
     }
 
     fn update_topology(&mut self) {
+        let now = Instant::now();
+        self.scores.prune(now);
+        self.traffic_control
+            .statistics
+            .banned_peers
+            .store(self.scores.banned_count(now) as u64, Ordering::Relaxed);
+
         let to_connect = self
             .current_peers_addresses
             .iter()
             // Peer is not connected but should
             .filter(|(id, address)| {
                 self.current_topology.contains(id)
+                    && !self.scores.is_banned(id, now)
                     && !self.peers.contains_key(id)
                     && !self
                         .connecting_peers
@@ -374,7 +523,7 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
             .peers
             .keys()
             // Peer is connected but shouldn't
-            .filter(|&peer_id| !self.current_topology.contains(peer_id))
+            .filter(|&peer_id| !self.is_allowed(peer_id) || self.scores.is_banned(peer_id, now))
             .cloned()
             .collect::<Vec<_>>();
 
@@ -387,6 +536,11 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
         }
     }
 
+    /// Whether the peer is allowed to stay connected: it's either part of the topology or a replica.
+    fn is_allowed(&self, peer_id: &PeerId) -> bool {
+        self.current_topology.contains(peer_id) || self.replicas.contains(peer_id)
+    }
+
     fn connect_peer(&mut self, peer: &Peer) {
         iroha_logger::trace!(
             listen_addr = %self.listen_addr, peer.id.address = %peer.address(),
@@ -404,6 +558,7 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
             conn_id,
             service_message_sender,
             self.idle_timeout,
+            self.traffic_control.clone(),
         );
     }
 
@@ -430,10 +585,17 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
     ) {
         self.connecting_peers.remove(&connection_id);
 
-        if !self.current_topology.contains(peer.id()) {
+        if !self.is_allowed(peer.id()) {
             iroha_logger::warn!(peer=%peer.id(), topology=?self.current_topology, "Peer not present in topology is trying to connect");
             return;
         }
+        if self.scores.is_banned(peer.id(), Instant::now()) {
+            iroha_logger::debug!("Banned peer is trying to connect");
+            return;
+        }
+        if !self.current_topology.contains(peer.id()) {
+            iroha_logger::debug!("Replica connected");
+        }
 
         //  Insert peer if peer not in peers yet or replace peer if it's disambiguator value is smaller than new one (simultaneous connections resolution rule)
         match self.peers.get(peer.id()) {
@@ -475,6 +637,27 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
         }
     }
 
+    fn penalize(&mut self, peer_id: &PeerId, misbehavior: Misbehavior) {
+        let now = Instant::now();
+        self.traffic_control
+            .statistics
+            .penalties
+            .fetch_add(1, Ordering::Relaxed);
+        let Some(ban_duration) = self.scores.penalize(peer_id, misbehavior, now) else {
+            let score = self.scores.score(peer_id, now);
+            return iroha_logger::warn!(peer=%peer_id, ?misbehavior, score, "Peer penalized");
+        };
+
+        iroha_logger::warn!(peer=%peer_id, ?misbehavior, ?ban_duration, "Peer banned");
+        self.traffic_control
+            .statistics
+            .banned_peers
+            .store(self.scores.banned_count(now) as u64, Ordering::Relaxed);
+        if self.peers.contains_key(peer_id) {
+            self.disconnect_peer(peer_id);
+        }
+    }
+
     fn post(&mut self, Post { data, peer_id }: Post<T>) {
         iroha_logger::trace!(peer=%peer_id, "Post message");
         match self.peers.get(&peer_id) {
@@ -599,6 +782,77 @@ pub mod message {
     }
 }
 
+/// Statistics of the traffic dropped by the network, of the penalized peers,
+/// of the messages waiting to be sent and of the compression
+#[derive(Debug, Default)]
+pub struct TrafficStatistics {
+    dropped_messages: AtomicU64,
+    dropped_bytes: AtomicU64,
+    penalties: AtomicU64,
+    banned_peers: AtomicU64,
+    queued_messages: [AtomicU64; LANES],
+    compression_input_bytes: AtomicU64,
+    compression_output_bytes: AtomicU64,
+}
+
+impl TrafficStatistics {
+    /// Number of messages dropped because the inbound rate limit of the connection was exceeded
+    pub fn dropped_messages(&self) -> u64 {
+        self.dropped_messages.load(Ordering::Relaxed)
+    }
+
+    /// Number of bytes dropped because the inbound rate limit of the connection was exceeded
+    pub fn dropped_bytes(&self) -> u64 {
+        self.dropped_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Number of penalties given to misbehaving peers
+    pub fn penalties(&self) -> u64 {
+        self.penalties.load(Ordering::Relaxed)
+    }
+
+    /// Number of currently banned peers
+    pub fn banned_peers(&self) -> u64 {
+        self.banned_peers.load(Ordering::Relaxed)
+    }
+
+    /// Number of messages waiting to be sent in the `lane` of all connections
+    pub fn queue_depth(&self, lane: Lane) -> u64 {
+        self.queued_messages[lane.index()].load(Ordering::Relaxed)
+    }
+
+    /// Number of bytes of the messages which were large enough to be compressed, before compression
+    pub fn compression_input_bytes(&self) -> u64 {
+        self.compression_input_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Number of bytes of the messages which were large enough to be compressed, after compression
+    pub fn compression_output_bytes(&self) -> u64 {
+        self.compression_output_bytes.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn lane_pushed(&self, lane: Lane) {
+        self.queued_messages[lane.index()].fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn lane_popped(&self, lane: Lane, count: usize) {
+        self.queued_messages[lane.index()].fetch_sub(count as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_compressed(&self, input: usize, output: usize) {
+        self.compression_input_bytes
+            .fetch_add(input as u64, Ordering::Relaxed);
+        self.compression_output_bytes
+            .fetch_add(output as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_dropped(&self, bytes: usize) {
+        self.dropped_messages.fetch_add(1, Ordering::Relaxed);
+        self.dropped_bytes
+            .fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+}
+
 /// Reference as a means of communication with a [`Peer`]
 struct RefPeer<T: Pload> {
     handle: PeerHandle<T>,
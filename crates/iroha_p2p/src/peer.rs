@@ -1,6 +1,8 @@
 //! Tokio actor Peer
 
-use bytes::{Buf, BufMut, BytesMut};
+use std::{num::NonZeroU64, sync::Arc};
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
 use message::*;
 use parity_scale_codec::{DecodeAll, Encode};
 use tokio::{
@@ -13,7 +15,7 @@ use tokio::{
     time::Duration,
 };
 
-use crate::{boilerplate::*, Error};
+use crate::{boilerplate::*, compression::CompressionConfig, network::TrafficStatistics, Error};
 
 /// Max length of message handshake in bytes excluding first message length byte.
 pub const MAX_HANDSHAKE_LENGTH: u8 = 255;
@@ -39,6 +41,7 @@ pub mod handles {
         connection_id: ConnectionId,
         service_message_sender: mpsc::Sender<ServiceMessage<T>>,
         idle_timeout: Duration,
+        traffic_control: TrafficControl,
     ) {
         let peer = state::Connecting {
             peer_addr,
@@ -50,6 +53,7 @@ pub mod handles {
             peer,
             service_message_sender,
             idle_timeout,
+            traffic_control,
         };
         tokio::task::spawn(run::run::<T, K, E, _>(peer).in_current_span());
     }
@@ -61,6 +65,7 @@ pub mod handles {
         connection: Connection,
         service_message_sender: mpsc::Sender<ServiceMessage<T>>,
         idle_timeout: Duration,
+        traffic_control: TrafficControl,
     ) {
         let peer = state::ConnectedFrom {
             our_public_address,
@@ -71,6 +76,7 @@ pub mod handles {
             peer,
             service_message_sender,
             idle_timeout,
+            traffic_control,
         };
         tokio::task::spawn(run::run::<T, K, E, _>(peer).in_current_span());
     }
@@ -96,6 +102,7 @@ pub mod handles {
 mod run {
     //! Module with peer [`run`] function.
 
+    use iroha_data_model::Identifiable;
     use iroha_logger::prelude::*;
     use parity_scale_codec::Decode;
     use tokio::time::Instant;
@@ -106,7 +113,13 @@ mod run {
         state::{ConnectedFrom, Connecting, Ready},
         *,
     };
-    use crate::unbounded_with_len;
+    use crate::{
+        compression::Compressor,
+        lane::{Lane, LaneScheduler, Prioritized},
+        rate_limit::RateLimiter,
+        scoring::Misbehavior,
+        unbounded_with_len,
+    };
 
     /// Peer task.
     #[allow(clippy::too_many_lines)]
@@ -116,6 +129,13 @@ mod run {
             peer,
             service_message_sender,
             idle_timeout,
+            traffic_control:
+                TrafficControl {
+                    inbound_bytes_per_sec,
+                    outbound_bytes_per_sec,
+                    compression,
+                    statistics,
+                },
         }: RunPeerArgs<T, P>,
     ) {
         let conn_id = peer.connection_id();
@@ -184,19 +204,33 @@ mod run {
 
             iroha_logger::trace!("Peer connected");
 
-            let mut message_reader = MessageReader::new(read, cryptographer.clone());
-            let mut message_sender = MessageSender::new(write, cryptographer);
+            let compressor = Compressor::new(compression, Arc::clone(&statistics));
+            let mut message_reader = MessageReader::new(read, cryptographer.clone(), compressor.clone());
+            let mut message_sender = MessageSender::new(write, cryptographer, compressor, Arc::clone(&statistics));
+            // Peers reply to pings with pongs, so a pong received before pinging the peer is its offer
+            // of compression, which is ignored by the peers not supporting it
+            let mut pinged = false;
+            if compression.enabled {
+                if let Err(error) = message_sender.prepare_message(&Message::<T>::Pong) {
+                    iroha_logger::error!(%error, "Failed to encrypt message.");
+                    return;
+                }
+            }
+            let mut inbound_limiter = inbound_bytes_per_sec.map(|limit| RateLimiter::new(limit, Instant::now()));
+            let mut outbound_limiter = outbound_bytes_per_sec.map(|limit| RateLimiter::new(limit, Instant::now()));
 
             let mut idle_interval = tokio::time::interval_at(Instant::now() + idle_timeout, idle_timeout);
             let mut ping_interval = tokio::time::interval_at(Instant::now() + idle_timeout / 2, idle_timeout / 2);
 
             loop {
+                let throttled_until = outbound_limiter.as_mut().and_then(|limiter| limiter.exceeded_until(Instant::now()));
                 tokio::select! {
                     _ = ping_interval.tick() => {
                         iroha_logger::trace!(
                             ping_period=?ping_interval.period(),
                             "The connection has been idle, pinging to check if it's alive"
                         );
+                        pinged = true;
                         if let Err(error) = message_sender.prepare_message(&Message::<T>::Ping) {
                             iroha_logger::error!(%error, "Failed to encrypt message.");
                             break;
@@ -225,7 +259,7 @@ mod run {
                         }
                     }
                     msg = message_reader.read_message() => {
-                        let msg = match msg {
+                        let (msg, size) = match msg {
                             Ok(Some(msg)) => {
                                 msg
                             },
@@ -233,6 +267,20 @@ mod run {
                                 iroha_logger::debug!("Peer send whole message and close connection");
                                 break;
                             }
+                            Err(error @ (Error::SymmetricEncryption(_) | Error::ParityScale(_) | Error::Decompression | Error::DecompressedTooLarge)) => {
+                                iroha_logger::warn!(?error, "Peer sent malformed message.");
+                                let misbehavior = if matches!(error, Error::DecompressedTooLarge) {
+                                    Misbehavior::OversizedMessage
+                                } else {
+                                    Misbehavior::MalformedMessage
+                                };
+                                let misbehaved = Misbehaved {
+                                    peer_id: peer_id.id().clone(),
+                                    misbehavior,
+                                };
+                                let _ = service_message_sender.send(ServiceMessage::Misbehaved(misbehaved)).await;
+                                break;
+                            }
                             Err(error) => {
                                 iroha_logger::error!(?error, "Error while reading message from peer.");
                                 break;
@@ -248,6 +296,21 @@ mod run {
                             },
                             Message::Pong => {
                                 iroha_logger::trace!("Received peer pong");
+                                if !pinged {
+                                    message_sender.compressor.peer_offered();
+                                }
+                            }
+                            Message::Data(_) if inbound_limiter.as_mut().is_some_and(|limiter| !limiter.try_consume(size, Instant::now())) => {
+                                iroha_logger::debug!(size, "Inbound rate limit exceeded, dropping peer message");
+                                statistics.record_dropped(size);
+                                let misbehaved = Misbehaved {
+                                    peer_id: peer_id.id().clone(),
+                                    misbehavior: Misbehavior::RateLimitExceeded,
+                                };
+                                if service_message_sender.send(ServiceMessage::Misbehaved(misbehaved)).await.is_err() {
+                                    iroha_logger::error!("Network dropped service message channel.");
+                                    break;
+                                }
                             }
                             Message::Data(msg) => {
                                 iroha_logger::trace!("Received peer message");
@@ -267,12 +330,23 @@ mod run {
                     // We need to conditionally disable it in case there is no data is to be sent, otherwise `message_sender.send()` will complete immediately
                     //
                     // The only source of data to be sent is other branches of this loop, so we do not need any async waiting mechanism for waiting for readiness.
-                    result = message_sender.send(), if message_sender.ready() => {
-                        if let Err(error) = result {
-                            iroha_logger::error!(%error, "Failed to send message to peer.");
-                            break;
+                    //
+                    // Sending is also disabled while the outbound rate limit is exceeded, see the branch below.
+                    result = message_sender.send(), if message_sender.ready() && throttled_until.is_none() => {
+                        match result {
+                            Ok(sent) => {
+                                if let Some(limiter) = &mut outbound_limiter {
+                                    limiter.consume(sent, Instant::now());
+                                }
+                            }
+                            Err(error) => {
+                                iroha_logger::error!(%error, "Failed to send message to peer.");
+                                break;
+                            }
                         }
                     }
+                    // Wake up to continue sending once the outbound rate limit isn't exceeded anymore
+                    () = tokio::time::sleep_until(throttled_until.unwrap_or_else(Instant::now)), if message_sender.ready() && throttled_until.is_some() => {}
                     else => break,
                 }
                 tokio::task::yield_now().await;
@@ -293,6 +367,7 @@ mod run {
         pub peer: P,
         pub service_message_sender: mpsc::Sender<ServiceMessage<T>>,
         pub idle_timeout: Duration,
+        pub traffic_control: TrafficControl,
     }
 
     /// Trait for peer stages that might be used as starting point for peer's [`run`] function.
@@ -317,27 +392,34 @@ mod run {
         read: OwnedReadHalf,
         buffer: bytes::BytesMut,
         cryptographer: Cryptographer<E>,
+        compressor: Compressor,
     }
 
     impl<E: Enc> MessageReader<E> {
         const U32_SIZE: usize = core::mem::size_of::<u32>();
 
-        fn new(read: OwnedReadHalf, cryptographer: Cryptographer<E>) -> Self {
+        fn new(
+            read: OwnedReadHalf,
+            cryptographer: Cryptographer<E>,
+            compressor: Compressor,
+        ) -> Self {
             Self {
                 read,
                 cryptographer,
+                compressor,
                 // TODO: eyeball decision of default buffer size of 1 KB, should be benchmarked and optimized
                 buffer: BytesMut::with_capacity(1024),
             }
         }
 
-        /// Read message by first reading it's size as u32 and then rest of the message
+        /// Read message by first reading it's size as u32 and then rest of the message.
+        /// Returns the message along with its size in bytes.
         ///
         /// # Errors
         /// - Fail in case reading from stream fails
         /// - Connection is closed by there is still unfinished message in buffer
         /// - Forward errors from [`Self::parse_message`]
-        async fn read_message<T: Pload>(&mut self) -> Result<Option<T>, Error> {
+        async fn read_message<T: Pload>(&mut self) -> Result<Option<(T, usize)>, Error> {
             loop {
                 // Try to get full message
                 if let Some(msg) = self.parse_message()? {
@@ -357,8 +439,9 @@ mod run {
         ///
         /// # Errors
         /// - Fail to decrypt message
+        /// - Fail to decompress message
         /// - Fail to decode message
-        fn parse_message<T: Pload>(&mut self) -> Result<Option<T>, Error> {
+        fn parse_message<T: Pload>(&mut self) -> Result<Option<(T, usize)>, Error> {
             let mut buf = &self.buffer[..];
             if buf.remaining() < Self::U32_SIZE {
                 // Not enough data to read u32
@@ -372,37 +455,48 @@ mod run {
 
             let data = &buf[..size];
             let decrypted = self.cryptographer.decrypt(data)?;
-            let decoded = DecodeAll::decode_all(&mut decrypted.as_slice())?;
+            let encoded = self.compressor.decompress(&decrypted)?;
+            let decoded = DecodeAll::decode_all(&mut &*encoded)?;
 
             self.buffer.advance(size + Self::U32_SIZE);
 
-            Ok(Some(decoded))
+            Ok(Some((decoded, size)))
         }
     }
 
     struct MessageSender<E: Enc> {
         write: OwnedWriteHalf,
         cryptographer: Cryptographer<E>,
+        compressor: Compressor,
         /// Reusable buffer to encode messages
         buffer: Vec<u8>,
-        /// Queue of encrypted messages waiting to be sent
-        queue: BytesMut,
+        /// Encrypted messages waiting to be sent, queued by their lane
+        lanes: LaneScheduler,
+        /// Remaining bytes of the encrypted message which is being sent
+        sending: Bytes,
     }
 
     impl<E: Enc> MessageSender<E> {
         const U32_SIZE: usize = core::mem::size_of::<u32>();
 
-        fn new(write: OwnedWriteHalf, cryptographer: Cryptographer<E>) -> Self {
+        fn new(
+            write: OwnedWriteHalf,
+            cryptographer: Cryptographer<E>,
+            compressor: Compressor,
+            statistics: Arc<TrafficStatistics>,
+        ) -> Self {
             Self {
                 write,
                 cryptographer,
+                compressor,
                 // TODO: eyeball decision of default buffer size of 1 KB, should be benchmarked and optimized
                 buffer: Vec::with_capacity(1024),
-                queue: BytesMut::with_capacity(1024),
+                lanes: LaneScheduler::new(statistics),
+                sending: Bytes::new(),
             }
         }
 
-        /// Prepare message for the delivery and put it into the queue to be sent later
+        /// Prepare message for the delivery and put it into the queue of its lane to be sent later
         ///
         /// # Errors
         /// - If encryption fail.
@@ -410,36 +504,46 @@ mod run {
             // Start with fresh buffer
             self.buffer.clear();
             msg.encode_to(&mut self.buffer);
-            let encrypted = self.cryptographer.encrypt(&self.buffer)?;
+            let compressed = self.compressor.compress(&self.buffer);
+            let encrypted = self.cryptographer.encrypt(&compressed)?;
 
             let size = encrypted.len();
-            self.queue.reserve(size + Self::U32_SIZE);
+            let mut message = BytesMut::with_capacity(size + Self::U32_SIZE);
             #[allow(clippy::cast_possible_truncation)]
-            self.queue.put_u32(size as u32);
-            self.queue.put_slice(encrypted.as_slice());
+            message.put_u32(size as u32);
+            message.put_slice(encrypted.as_slice());
+            self.lanes.push(msg.lane(), message.freeze());
             Ok(())
         }
 
-        /// Send bytes of byte-encoded messages piled up in the message queue so far.
+        /// Send bytes of byte-encoded messages piled up in the lanes so far.
         /// On the other side peer will collect bytes and recreate original messages from them.
         ///
         /// Sends only as much data as the underlying writer will accept in one `.write` call,
         /// so must be called in a loop to ensure everything will get sent.
+        /// The next message is picked from the lanes only once the previous one is sent completely.
+        /// Returns the number of bytes sent.
         ///
         /// # Errors
         /// - If write to `stream` fail.
-        async fn send(&mut self) -> Result<(), Error> {
-            let chunk = self.queue.chunk();
-            if !chunk.is_empty() {
-                let n = self.write.write(chunk).await?;
-                self.queue.advance(n);
+        async fn send(&mut self) -> Result<usize, Error> {
+            if self.sending.is_empty() {
+                if let Some(message) = self.lanes.pop() {
+                    self.sending = message;
+                }
             }
-            Ok(())
+            let chunk = self.sending.chunk();
+            if chunk.is_empty() {
+                return Ok(0);
+            }
+            let n = self.write.write(chunk).await?;
+            self.sending.advance(n);
+            Ok(n)
         }
 
         /// Check if message sender has data ready to be sent.
         fn ready(&self) -> bool {
-            !self.queue.is_empty()
+            !self.sending.is_empty() || !self.lanes.is_empty()
         }
     }
 
@@ -450,6 +554,16 @@ mod run {
         Ping,
         Pong,
     }
+
+    impl<T: Prioritized> Prioritized for Message<T> {
+        fn lane(&self) -> Lane {
+            match self {
+                Self::Data(data) => data.lane(),
+                // Pings are tiny and must not be delayed, otherwise the connection looks idle
+                Self::Ping | Self::Pong => Lane::Consensus,
+            }
+        }
+    }
 }
 
 mod state {
@@ -759,9 +873,10 @@ mod handshake {
 pub mod message {
     //! Module for peer messages
 
-    use iroha_data_model::peer::Peer;
+    use iroha_data_model::peer::{Peer, PeerId};
 
     use super::*;
+    use crate::scoring::Misbehavior;
 
     /// Connection and Handshake was successful
     pub struct Connected<T: Pload> {
@@ -789,12 +904,22 @@ pub mod message {
         pub conn_id: ConnectionId,
     }
 
+    /// Peer misbehaved on the connection, send to penalize it
+    pub struct Misbehaved {
+        /// Id of the misbehaving peer
+        pub peer_id: PeerId,
+        /// What the peer did
+        pub misbehavior: Misbehavior,
+    }
+
     /// Messages sent by peer during connection process
     pub enum ServiceMessage<T: Pload> {
         /// Connection and Handshake was successful
         Connected(Connected<T>),
         /// Peer faced error or `Terminate` message, send to indicate that it is terminated
         Terminated(Terminated),
+        /// Peer misbehaved on the connection
+        Misbehaved(Misbehaved),
     }
 }
 
@@ -847,6 +972,19 @@ mod cryptographer {
     }
 }
 
+/// Limits and compression of the traffic over a single connection
+#[derive(Debug, Clone)]
+pub struct TrafficControl {
+    /// Max number of bytes per second received from the peer, unlimited if [`None`]
+    pub inbound_bytes_per_sec: Option<NonZeroU64>,
+    /// Max number of bytes per second sent to the peer, unlimited if [`None`]
+    pub outbound_bytes_per_sec: Option<NonZeroU64>,
+    /// Compression of the messages, used only if the peer supports it as well
+    pub compression: CompressionConfig,
+    /// Statistics of the traffic shared by all connections
+    pub statistics: Arc<TrafficStatistics>,
+}
+
 /// An identification for [`Peer`] connections.
 pub type ConnectionId = u64;
 
@@ -0,0 +1,85 @@
+//! Limit of the number of bytes transferred over a connection per second.
+use std::num::NonZeroU64;
+
+use tokio::time::{Duration, Instant};
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Token bucket holding up to one second worth of bytes.
+///
+/// Messages are never split, so a message is let through as long as there are any bytes
+/// available, even if it is larger: the bucket goes into debt which has to be paid off
+/// before anything else is let through.
+#[derive(Debug)]
+pub struct RateLimiter {
+    bytes_per_sec: u64,
+    /// Number of bytes which can be transferred right now, negative in case of debt
+    available: i64,
+    /// Moment up to which the bucket has been refilled
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    /// Construct [`Self`] with the full bucket.
+    pub fn new(bytes_per_sec: NonZeroU64, now: Instant) -> Self {
+        let bytes_per_sec = bytes_per_sec.get();
+        Self {
+            bytes_per_sec,
+            available: i64::try_from(bytes_per_sec).unwrap_or(i64::MAX),
+            refilled_at: now,
+        }
+    }
+
+    /// Take `bytes` from the bucket unless the limit is exceeded.
+    ///
+    /// Returns `false` if the limit is exceeded and nothing has been taken.
+    pub fn try_consume(&mut self, bytes: usize, now: Instant) -> bool {
+        self.refill(now);
+        if self.available <= 0 {
+            return false;
+        }
+        self.take(bytes);
+        true
+    }
+
+    /// Take `bytes` from the bucket regardless of the limit, e.g. when they are already sent.
+    pub fn consume(&mut self, bytes: usize, now: Instant) {
+        self.refill(now);
+        self.take(bytes);
+    }
+
+    /// Moment at which the limit stops being exceeded, [`None`] if it isn't exceeded `now`.
+    pub fn exceeded_until(&mut self, now: Instant) -> Option<Instant> {
+        self.refill(now);
+        if self.available > 0 {
+            return None;
+        }
+        // Debt has to be paid off and at least one byte has to become available
+        let missing = u128::from(self.available.unsigned_abs()) + 1;
+        let nanos = (missing * NANOS_PER_SEC).div_ceil(u128::from(self.bytes_per_sec));
+        Some(self.refilled_at + Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX)))
+    }
+
+    fn take(&mut self, bytes: usize) {
+        let bytes = i64::try_from(bytes).unwrap_or(i64::MAX);
+        self.available = self.available.saturating_sub(bytes);
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let capacity = i64::try_from(self.bytes_per_sec).unwrap_or(i64::MAX);
+        let elapsed = now.saturating_duration_since(self.refilled_at);
+        let refill = u128::from(self.bytes_per_sec) * elapsed.as_nanos() / NANOS_PER_SEC;
+        let refill = i64::try_from(refill).unwrap_or(i64::MAX);
+
+        if self.available.saturating_add(refill) >= capacity {
+            self.available = capacity;
+            self.refilled_at = now;
+        } else if refill > 0 {
+            self.available += refill;
+            // Only advance by the time it took to refill whole bytes to not lose the remainder
+            let nanos =
+                u128::from(refill.unsigned_abs()) * NANOS_PER_SEC / u128::from(self.bytes_per_sec);
+            self.refilled_at += Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX));
+        }
+    }
+}
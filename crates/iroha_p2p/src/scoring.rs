@@ -0,0 +1,157 @@
+//! Reputation of the peers.
+//!
+//! Every misbehavior of a peer lowers its score, which slowly recovers over time.
+//! Once the score drops to [`BAN_THRESHOLD`] the peer is banned: it's disconnected
+//! and connections with it are refused until the ban expires.
+//! Repeated bans of the same peer last exponentially longer, up to [`MAX_BAN_DURATION`].
+use std::collections::HashMap;
+
+use iroha_data_model::peer::PeerId;
+use tokio::time::{Duration, Instant};
+
+/// Score at which the peer gets banned
+pub const BAN_THRESHOLD: i64 = -100;
+/// Number of points of the score restored for every second without misbehavior
+pub const RECOVERY_PER_SECOND: i64 = 1;
+/// Upper limit of the duration of a ban, no matter how many times the peer was banned before.
+///
+/// The peer which wasn't banned for this long after its last ban is treated as a first offender.
+pub const MAX_BAN_DURATION: Duration = Duration::from_secs(60 * 60);
+
+/// Misbehavior of the peer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Misbehavior {
+    /// Message couldn't be decrypted or decoded
+    MalformedMessage,
+    /// Message is larger than allowed, e.g. shares more blocks than requested
+    OversizedMessage,
+    /// Block shared by the peer failed validation
+    InvalidBlock,
+    /// Message is dropped because the inbound rate limit of the connection is exceeded
+    RateLimitExceeded,
+}
+
+impl Misbehavior {
+    /// Number of points subtracted from the score of the peer
+    pub const fn penalty(self) -> i64 {
+        match self {
+            Self::MalformedMessage | Self::InvalidBlock => 50,
+            Self::OversizedMessage => 25,
+            Self::RateLimitExceeded => 10,
+        }
+    }
+}
+
+/// Scores and bans of the peers
+#[derive(Debug)]
+pub struct PeerScores {
+    /// Scores of the peers which misbehaved recently, other peers have a score of `0`
+    scores: HashMap<PeerId, Score>,
+    /// Current and recent bans
+    bans: HashMap<PeerId, Ban>,
+    /// Duration of the first ban of the peer
+    ban_duration: Duration,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Score {
+    value: i64,
+    updated_at: Instant,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Ban {
+    until: Instant,
+    /// Number of consecutive bans of the peer
+    count: u32,
+}
+
+impl Score {
+    fn at(self, now: Instant) -> i64 {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs();
+        let recovered = i64::try_from(elapsed)
+            .unwrap_or(i64::MAX)
+            .saturating_mul(RECOVERY_PER_SECOND);
+        self.value.saturating_add(recovered).min(0)
+    }
+}
+
+impl PeerScores {
+    /// Construct [`Self`] where the first ban of a peer lasts for `ban_duration`.
+    pub fn new(ban_duration: Duration) -> Self {
+        Self {
+            scores: HashMap::new(),
+            bans: HashMap::new(),
+            ban_duration,
+        }
+    }
+
+    /// Current score of the peer
+    pub fn score(&self, peer_id: &PeerId, now: Instant) -> i64 {
+        self.scores.get(peer_id).map_or(0, |score| score.at(now))
+    }
+
+    /// Penalize the peer for the `misbehavior`.
+    ///
+    /// Returns the duration of the ban if the peer got banned as a result.
+    /// Peers which are already banned aren't penalized any further.
+    pub fn penalize(
+        &mut self,
+        peer_id: &PeerId,
+        misbehavior: Misbehavior,
+        now: Instant,
+    ) -> Option<Duration> {
+        if self.is_banned(peer_id, now) {
+            return None;
+        }
+
+        let value = self
+            .score(peer_id, now)
+            .saturating_sub(misbehavior.penalty());
+        if value > BAN_THRESHOLD {
+            let score = Score {
+                value,
+                updated_at: now,
+            };
+            self.scores.insert(peer_id.clone(), score);
+            return None;
+        }
+
+        // The peer starts over with a clean score once the ban expires
+        self.scores.remove(peer_id);
+        let count = match self.bans.get(peer_id) {
+            Some(ban) if now.saturating_duration_since(ban.until) < MAX_BAN_DURATION => {
+                ban.count.saturating_add(1)
+            }
+            _ => 1,
+        };
+        let duration = self
+            .ban_duration
+            .saturating_mul(2_u32.saturating_pow(count - 1))
+            .min(MAX_BAN_DURATION);
+        let ban = Ban {
+            until: now + duration,
+            count,
+        };
+        self.bans.insert(peer_id.clone(), ban);
+
+        Some(duration)
+    }
+
+    /// Check if the peer is currently banned
+    pub fn is_banned(&self, peer_id: &PeerId, now: Instant) -> bool {
+        self.bans.get(peer_id).is_some_and(|ban| now < ban.until)
+    }
+
+    /// Number of currently banned peers
+    pub fn banned_count(&self, now: Instant) -> usize {
+        self.bans.values().filter(|ban| now < ban.until).count()
+    }
+
+    /// Forget scores which are fully recovered and bans which no longer affect the backoff
+    pub fn prune(&mut self, now: Instant) {
+        self.scores.retain(|_, score| score.at(now) < 0);
+        self.bans
+            .retain(|_, ban| now.saturating_duration_since(ban.until) < MAX_BAN_DURATION);
+    }
+}
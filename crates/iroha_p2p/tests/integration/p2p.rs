@@ -1,6 +1,7 @@
 use std::{
     collections::HashSet,
     fmt::Debug,
+    num::NonZeroU64,
     sync::{
         atomic::{AtomicU32, Ordering},
         Arc,
@@ -9,13 +10,26 @@ use std::{
 
 use futures::{prelude::*, stream::FuturesUnordered, task::AtomicWaker};
 use iroha_config::parameters::actual::Network as Config;
-use iroha_config_base::WithOrigin;
+use iroha_config_base::{util::Bytes, WithOrigin};
 use iroha_crypto::KeyPair;
-use iroha_data_model::{prelude::Peer, Identifiable};
+use iroha_data_model::{
+    prelude::{Peer, PeerId},
+    Identifiable,
+};
 use iroha_futures::supervisor::ShutdownSignal;
 use iroha_logger::{prelude::*, test_logger};
-use iroha_p2p::{network::message::*, peer::message::PeerMessage, NetworkHandle};
-use iroha_primitives::addr::socket_addr;
+use iroha_p2p::{
+    boilerplate::Pload,
+    lane::{Lane, Prioritized},
+    network::message::*,
+    peer::message::PeerMessage,
+    scoring::Misbehavior,
+    NetworkHandle,
+};
+use iroha_primitives::{
+    addr::{socket_addr, SocketAddr},
+    unique_vec::UniqueVec,
+};
 use parity_scale_codec::{Decode, Encode};
 use tokio::{
     sync::{mpsc, Barrier},
@@ -25,6 +39,12 @@ use tokio::{
 #[derive(Clone, Debug, Decode, Encode)]
 struct TestMessage(String);
 
+impl Prioritized for TestMessage {
+    fn lane(&self) -> Lane {
+        Lane::Consensus
+    }
+}
+
 fn setup_logger() {
     test_logger();
 }
@@ -46,6 +66,13 @@ async fn network_create() {
         address: WithOrigin::inline(address.clone()),
         public_address: WithOrigin::inline(address.clone()),
         idle_timeout,
+        inbound_bytes_per_sec: None,
+        outbound_bytes_per_sec: None,
+        ban_duration: Duration::from_secs(60),
+        compression: false,
+        compression_threshold: Bytes(1024),
+        max_decompressed_len: Bytes(2_u64.pow(20)),
+        replicas: UniqueVec::new(),
     };
     let (network, _) = NetworkHandle::start(key_pair, config, ShutdownSignal::new())
         .await
@@ -159,6 +186,13 @@ async fn two_networks() {
         address: WithOrigin::inline(address1.clone()),
         public_address: WithOrigin::inline(address1.clone()),
         idle_timeout,
+        inbound_bytes_per_sec: None,
+        outbound_bytes_per_sec: None,
+        ban_duration: Duration::from_secs(60),
+        compression: false,
+        compression_threshold: Bytes(1024),
+        max_decompressed_len: Bytes(2_u64.pow(20)),
+        replicas: UniqueVec::new(),
     };
     let (mut network1, _) = NetworkHandle::start(key_pair1, config1, ShutdownSignal::new())
         .await
@@ -170,6 +204,13 @@ async fn two_networks() {
         address: WithOrigin::inline(address2.clone()),
         public_address: WithOrigin::inline(address2.clone()),
         idle_timeout,
+        inbound_bytes_per_sec: None,
+        outbound_bytes_per_sec: None,
+        ban_duration: Duration::from_secs(60),
+        compression: false,
+        compression_threshold: Bytes(1024),
+        max_decompressed_len: Bytes(2_u64.pow(20)),
+        replicas: UniqueVec::new(),
     };
     let (network2, _) = NetworkHandle::start(key_pair2, config2, ShutdownSignal::new())
         .await
@@ -217,6 +258,364 @@ async fn two_networks() {
     assert_eq!(connected_peers2, 1);
 }
 
+/// This test creates a validator network and a replica which isn't part of its topology.
+/// The validator must accept the connection of the replica and be able to send messages to it.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn replica_connects_to_validator() {
+    let delay = Duration::from_millis(300);
+    let idle_timeout = Duration::from_secs(60);
+    setup_logger();
+    let validator_key_pair = KeyPair::random();
+    let validator_public_key = validator_key_pair.public_key().clone();
+    let replica_key_pair = KeyPair::random();
+    let replica_public_key = replica_key_pair.public_key().clone();
+
+    info!("Starting validator network...");
+    let validator_address = socket_addr!(127.0.0.1:12_100);
+    let validator_config = Config {
+        address: WithOrigin::inline(validator_address.clone()),
+        public_address: WithOrigin::inline(validator_address.clone()),
+        idle_timeout,
+        inbound_bytes_per_sec: None,
+        outbound_bytes_per_sec: None,
+        ban_duration: Duration::from_secs(60),
+        compression: false,
+        compression_threshold: Bytes(1024),
+        max_decompressed_len: Bytes(2_u64.pow(20)),
+        replicas: [PeerId::new(replica_public_key.clone())]
+            .into_iter()
+            .collect(),
+    };
+    let (mut validator, _) =
+        NetworkHandle::start(validator_key_pair, validator_config, ShutdownSignal::new())
+            .await
+            .unwrap();
+    // Validator is the only peer in its topology
+    update_topology_and_peers_addresses(&validator, &[]);
+
+    info!("Starting replica network...");
+    let replica_address = socket_addr!(127.0.0.1:12_105);
+    let replica_config = Config {
+        address: WithOrigin::inline(replica_address.clone()),
+        public_address: WithOrigin::inline(replica_address.clone()),
+        idle_timeout,
+        inbound_bytes_per_sec: None,
+        outbound_bytes_per_sec: None,
+        ban_duration: Duration::from_secs(60),
+        compression: false,
+        compression_threshold: Bytes(1024),
+        max_decompressed_len: Bytes(2_u64.pow(20)),
+        replicas: UniqueVec::new(),
+    };
+    let (replica, _) =
+        NetworkHandle::start(replica_key_pair, replica_config, ShutdownSignal::new())
+            .await
+            .unwrap();
+
+    let mut messages = WaitForN::new(1);
+    let actor = TestActor::start(messages.clone());
+    replica.subscribe_to_peers_messages(actor);
+
+    info!("Connecting replica to validator...");
+    let validator_peer = Peer::new(validator_address, validator_public_key);
+    update_topology_and_peers_addresses(&replica, &[validator_peer]);
+
+    tokio::time::timeout(Duration::from_millis(2000), async {
+        let mut connections = validator.wait_online_peers_update(HashSet::len).await;
+        while connections != 1 {
+            connections = validator.wait_online_peers_update(HashSet::len).await;
+        }
+    })
+    .await
+    .expect("Replica failed to connect");
+
+    info!("Posting message...");
+    validator.post(Post {
+        data: TestMessage("Some data to send to replica".to_owned()),
+        peer_id: PeerId::new(replica_public_key),
+    });
+
+    tokio::time::timeout(delay, &mut messages)
+        .await
+        .expect("Failed to receive message in given time");
+}
+
+/// This test connects two networks and bans the second peer on the first network.
+/// The banned peer must be disconnected and must not be able to connect back while the ban lasts.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn banned_peer_is_disconnected() {
+    setup_logger();
+    let (mut network1, _network2, peer2, _messages2) = start_two_networks(
+        socket_addr!(127.0.0.1:12_110),
+        socket_addr!(127.0.0.1:12_115),
+        |_| {},
+        |_| {},
+    )
+    .await;
+
+    info!("Banning peer...");
+    network1.penalize(peer2.id().clone(), Misbehavior::InvalidBlock);
+    network1.penalize(peer2.id().clone(), Misbehavior::InvalidBlock);
+
+    tokio::time::timeout(Duration::from_millis(2000), async {
+        let mut connections = network1.online_peers(HashSet::len);
+        while connections != 0 {
+            connections = network1.wait_online_peers_update(HashSet::len).await;
+        }
+    })
+    .await
+    .expect("Banned peer wasn't disconnected");
+    assert_eq!(network1.statistics().penalties(), 2);
+    assert_eq!(network1.statistics().banned_peers(), 1);
+
+    // The second peer keeps trying to reconnect on every topology update
+    tokio::time::sleep(Duration::from_secs(3)).await;
+    assert_eq!(network1.online_peers(HashSet::len), 0);
+}
+
+/// This test limits inbound traffic of the second network so that only the first message fits.
+/// Following messages must be dropped and the sender must be penalized for them.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn inbound_rate_limit_drops_messages() {
+    setup_logger();
+    let (network1, network2, peer2, mut messages2) = start_two_networks(
+        socket_addr!(127.0.0.1:12_120),
+        socket_addr!(127.0.0.1:12_125),
+        |_| {},
+        |config2| config2.inbound_bytes_per_sec = Some(NonZeroU64::MIN),
+    )
+    .await;
+
+    info!("Posting messages...");
+    for _ in 0..3 {
+        network1.post(Post {
+            data: TestMessage("Some data to send to peer".to_owned()),
+            peer_id: peer2.id().clone(),
+        });
+    }
+
+    tokio::time::timeout(Duration::from_millis(2000), async {
+        while network2.statistics().dropped_messages() < 2 {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("Messages weren't dropped");
+    tokio::time::timeout(Duration::from_millis(300), &mut messages2)
+        .await
+        .expect("Failed to receive the first message in given time");
+    assert_eq!(messages2.current(), 1);
+    assert_eq!(network2.statistics().penalties(), 2);
+    assert!(network2.statistics().dropped_bytes() > 0);
+}
+
+/// This test connects two networks offering compression and sends a large compressible message.
+/// The message must be delivered and must be compressed on the way.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn compressed_message_is_delivered() {
+    setup_logger();
+    let (network1, _network2, peer2, mut messages2) = start_two_networks(
+        socket_addr!(127.0.0.1:12_140),
+        socket_addr!(127.0.0.1:12_145),
+        |config1| config1.compression = true,
+        |config2| config2.compression = true,
+    )
+    .await;
+    // Give the peers time to offer compression to each other
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    info!("Posting message...");
+    network1.post(Post {
+        data: TestMessage("a".repeat(64 * 1024)),
+        peer_id: peer2.id().clone(),
+    });
+
+    tokio::time::timeout(Duration::from_millis(2000), &mut messages2)
+        .await
+        .expect("Failed to receive message in given time");
+    let statistics = network1.statistics();
+    assert!(statistics.compression_input_bytes() > 64 * 1024);
+    assert!(statistics.compression_output_bytes() * 10 < statistics.compression_input_bytes());
+}
+
+/// This test connects a network offering compression to a network which doesn't offer it.
+/// The second network sends and accepts the same traffic as a peer of an earlier version,
+/// which doesn't support compression: it rejects compressed messages as malformed.
+/// Messages must be delivered uncompressed and the offer mustn't be taken for misbehavior.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn compression_is_not_used_unless_both_peers_offer_it() {
+    setup_logger();
+    let (network1, network2, peer2, mut messages2) = start_two_networks(
+        socket_addr!(127.0.0.1:12_150),
+        socket_addr!(127.0.0.1:12_155),
+        |config1| config1.compression = true,
+        |_| {},
+    )
+    .await;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    info!("Posting message...");
+    network1.post(Post {
+        data: TestMessage("a".repeat(64 * 1024)),
+        peer_id: peer2.id().clone(),
+    });
+
+    tokio::time::timeout(Duration::from_millis(2000), &mut messages2)
+        .await
+        .expect("Failed to receive message in given time");
+    assert_eq!(network1.statistics().compression_input_bytes(), 0);
+    assert_eq!(network2.statistics().penalties(), 0);
+}
+
+/// This test sends a message which decompresses into more than the second network allows.
+/// The message must be rejected and the sender must be penalized for it.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn oversized_decompressed_message_is_rejected() {
+    setup_logger();
+    let (network1, network2, peer2, messages2) = start_two_networks(
+        socket_addr!(127.0.0.1:12_160),
+        socket_addr!(127.0.0.1:12_165),
+        |config1| config1.compression = true,
+        |config2| {
+            config2.compression = true;
+            config2.max_decompressed_len = Bytes(1024);
+        },
+    )
+    .await;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    info!("Posting message...");
+    network1.post(Post {
+        data: TestMessage("a".repeat(64 * 1024)),
+        peer_id: peer2.id().clone(),
+    });
+
+    tokio::time::timeout(Duration::from_millis(2000), async {
+        while network2.statistics().penalties() == 0 {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("Sender wasn't penalized");
+    assert_eq!(network2.statistics().penalties(), 1);
+    assert_eq!(messages2.current(), 0);
+}
+
+#[derive(Clone, Debug, Decode, Encode)]
+struct LaneMessage {
+    consensus: bool,
+    data: Vec<u8>,
+}
+
+impl Prioritized for LaneMessage {
+    fn lane(&self) -> Lane {
+        if self.consensus {
+            Lane::Consensus
+        } else {
+            Lane::TransactionGossip
+        }
+    }
+}
+
+/// This test throttles outbound traffic of the first network and posts a consensus message
+/// after a batch of large gossip messages.
+/// The consensus message must overtake the gossip messages waiting in their lane.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn consensus_lane_overtakes_gossip() {
+    const GOSSIP_MESSAGES: usize = 8;
+    setup_logger();
+    let idle_timeout = Duration::from_secs(60);
+    let key_pair1 = KeyPair::random();
+    let key_pair2 = KeyPair::random();
+    let address1 = socket_addr!(127.0.0.1:12_130);
+    let address2 = socket_addr!(127.0.0.1:12_135);
+    let peer1 = Peer::new(address1.clone(), key_pair1.public_key().clone());
+    let peer2 = Peer::new(address2.clone(), key_pair2.public_key().clone());
+
+    let config1 = Config {
+        address: WithOrigin::inline(address1.clone()),
+        public_address: WithOrigin::inline(address1),
+        idle_timeout,
+        inbound_bytes_per_sec: None,
+        outbound_bytes_per_sec: NonZeroU64::new(64 * 1024),
+        ban_duration: Duration::from_secs(60),
+        compression: false,
+        compression_threshold: Bytes(1024),
+        max_decompressed_len: Bytes(2_u64.pow(20)),
+        replicas: UniqueVec::new(),
+    };
+    let (mut network1, _) =
+        NetworkHandle::<LaneMessage>::start(key_pair1, config1, ShutdownSignal::new())
+            .await
+            .unwrap();
+    let config2 = Config {
+        address: WithOrigin::inline(address2.clone()),
+        public_address: WithOrigin::inline(address2),
+        idle_timeout,
+        inbound_bytes_per_sec: None,
+        outbound_bytes_per_sec: None,
+        ban_duration: Duration::from_secs(60),
+        compression: false,
+        compression_threshold: Bytes(1024),
+        max_decompressed_len: Bytes(2_u64.pow(20)),
+        replicas: UniqueVec::new(),
+    };
+    let (network2, _) =
+        NetworkHandle::<LaneMessage>::start(key_pair2, config2, ShutdownSignal::new())
+            .await
+            .unwrap();
+    let (sender, mut messages) = mpsc::channel(GOSSIP_MESSAGES + 1);
+    network2.subscribe_to_peers_messages(sender);
+
+    update_topology_and_peers_addresses(&network1, &[peer2.clone()]);
+    update_topology_and_peers_addresses(&network2, &[peer1]);
+
+    tokio::time::timeout(Duration::from_millis(2000), async {
+        let mut connections = network1.wait_online_peers_update(HashSet::len).await;
+        while connections != 1 {
+            connections = network1.wait_online_peers_update(HashSet::len).await;
+        }
+    })
+    .await
+    .expect("Failed to get all connections");
+
+    info!("Posting messages...");
+    for consensus in core::iter::repeat(false)
+        .take(GOSSIP_MESSAGES)
+        .chain([true])
+    {
+        network1.post(Post {
+            data: LaneMessage {
+                consensus,
+                data: vec![0; 16 * 1024],
+            },
+            peer_id: peer2.id().clone(),
+        });
+    }
+
+    let received = tokio::time::timeout(Duration::from_secs(10), async {
+        let mut received = Vec::new();
+        while received.len() <= GOSSIP_MESSAGES {
+            let PeerMessage(_, message) = messages.recv().await.unwrap();
+            received.push(message.consensus);
+        }
+        received
+    })
+    .await
+    .expect("Failed to receive all messages in given time");
+
+    let position = received.iter().position(|consensus| *consensus).unwrap();
+    assert!(
+        position < GOSSIP_MESSAGES - 1,
+        "Consensus message didn't overtake gossip: {received:?}"
+    );
+    assert_eq!(
+        network1.statistics().queue_depth(Lane::TransactionGossip),
+        0
+    );
+    assert_eq!(network1.statistics().queue_depth(Lane::Consensus), 0);
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 8)]
 async fn multiple_networks() {
     setup_logger();
@@ -305,6 +704,13 @@ async fn start_network(
         address: WithOrigin::inline(address.clone()),
         public_address: WithOrigin::inline(address.clone()),
         idle_timeout,
+        inbound_bytes_per_sec: None,
+        outbound_bytes_per_sec: None,
+        ban_duration: Duration::from_secs(60),
+        compression: false,
+        compression_threshold: Bytes(1024),
+        max_decompressed_len: Bytes(2_u64.pow(20)),
+        replicas: UniqueVec::new(),
     };
     let (mut network, _) = NetworkHandle::start(key_pair, config, shutdown_signal)
         .await
@@ -339,7 +745,82 @@ async fn start_network(
     (peer, network)
 }
 
-fn update_topology_and_peers_addresses(network: &NetworkHandle<TestMessage>, peers: &[Peer]) {
+/// Start two networks connected to each other, adjusting their configs with the closures.
+/// Returns the handles of both networks, the second peer and the counter of its received messages.
+async fn start_two_networks(
+    address1: SocketAddr,
+    address2: SocketAddr,
+    configure1: impl FnOnce(&mut Config) + Send,
+    configure2: impl FnOnce(&mut Config) + Send,
+) -> (
+    NetworkHandle<TestMessage>,
+    NetworkHandle<TestMessage>,
+    Peer,
+    WaitForN,
+) {
+    let idle_timeout = Duration::from_secs(60);
+    let key_pair1 = KeyPair::random();
+    let key_pair2 = KeyPair::random();
+    let peer1 = Peer::new(address1.clone(), key_pair1.public_key().clone());
+    let peer2 = Peer::new(address2.clone(), key_pair2.public_key().clone());
+
+    info!("Starting first network...");
+    let mut config1 = Config {
+        address: WithOrigin::inline(address1.clone()),
+        public_address: WithOrigin::inline(address1),
+        idle_timeout,
+        inbound_bytes_per_sec: None,
+        outbound_bytes_per_sec: None,
+        ban_duration: Duration::from_secs(60),
+        compression: false,
+        compression_threshold: Bytes(1024),
+        max_decompressed_len: Bytes(2_u64.pow(20)),
+        replicas: UniqueVec::new(),
+    };
+    configure1(&mut config1);
+    let (mut network1, _) = NetworkHandle::start(key_pair1, config1, ShutdownSignal::new())
+        .await
+        .unwrap();
+
+    info!("Starting second network...");
+    let mut config2 = Config {
+        address: WithOrigin::inline(address2.clone()),
+        public_address: WithOrigin::inline(address2),
+        idle_timeout,
+        inbound_bytes_per_sec: None,
+        outbound_bytes_per_sec: None,
+        ban_duration: Duration::from_secs(60),
+        compression: false,
+        compression_threshold: Bytes(1024),
+        max_decompressed_len: Bytes(2_u64.pow(20)),
+        replicas: UniqueVec::new(),
+    };
+    configure2(&mut config2);
+    let (network2, _) = NetworkHandle::start(key_pair2, config2, ShutdownSignal::new())
+        .await
+        .unwrap();
+
+    let messages2 = WaitForN::new(1);
+    let actor2 = TestActor::start(messages2.clone());
+    network2.subscribe_to_peers_messages(actor2);
+
+    info!("Connecting peers...");
+    update_topology_and_peers_addresses(&network1, &[peer2.clone()]);
+    update_topology_and_peers_addresses(&network2, &[peer1]);
+
+    tokio::time::timeout(Duration::from_millis(2000), async {
+        let mut connections = network1.wait_online_peers_update(HashSet::len).await;
+        while connections != 1 {
+            connections = network1.wait_online_peers_update(HashSet::len).await;
+        }
+    })
+    .await
+    .expect("Failed to get all connections");
+
+    (network1, network2, peer2, messages2)
+}
+
+fn update_topology_and_peers_addresses<T: Pload>(network: &NetworkHandle<T>, peers: &[Peer]) {
     let topology = peers.iter().map(|peer| peer.id().clone()).collect();
     network.update_topology(UpdateTopology(topology));
 
@@ -34,7 +34,10 @@ macro_rules! types {
 /// shall be included recursively.
 pub fn build_schemas() -> MetaMap {
     use iroha_data_model::prelude::*;
-    use iroha_executor_data_model::{isi::multisig, permission};
+    use iroha_executor_data_model::{
+        isi::{multisig, role, vesting},
+        permission, query,
+    };
 
     macro_rules! schemas {
         ($($t:ty),* $(,)?) => {{
@@ -50,6 +53,8 @@ pub fn build_schemas() -> MetaMap {
         SignedTransaction,
         SignedQuery,
         QueryResponse,
+        TransactionSimulationRequest,
+        TransactionSimulation,
 
         // Event stream
         EventMessage,
@@ -61,6 +66,7 @@ pub fn build_schemas() -> MetaMap {
 
         // Never referenced, but present in type signature. Like `PhantomData<X>`
         MerkleTree<SignedTransaction>,
+        MerkleTree<iroha_data_model::block::TriggerReceipt>,
 
         // Default permissions
         permission::peer::CanManagePeers,
@@ -100,6 +106,9 @@ pub fn build_schemas() -> MetaMap {
         permission::trigger::CanModifyTrigger,
         permission::trigger::CanModifyTriggerMetadata,
 
+        permission::contract::CanDeployContract,
+        permission::contract::CanCallContract,
+
         permission::executor::CanUpgradeExecutor,
 
         // Multi-signature operations
@@ -107,6 +116,20 @@ pub fn build_schemas() -> MetaMap {
         // Multi-signature account metadata
         multisig::MultisigSpec,
         multisig::MultisigProposalValue,
+        // Multi-signature queries
+        query::multisig::MultisigQueryBox,
+
+        // Vesting operations
+        vesting::VestingInstructionBox,
+        // Vesting account metadata
+        vesting::VestingLockValue,
+
+        // Role hierarchy operations
+        role::RoleInstructionBox,
+        // Role hierarchy executor parameter
+        role::RoleHierarchy,
+        // Role hierarchy queries
+        query::role::RoleQueryBox,
 
         // Genesis file - used by SDKs to generate the genesis block
         // TODO: IMO it could/should be removed from the schema
@@ -116,6 +139,9 @@ pub fn build_schemas() -> MetaMap {
 
 types!(
     Account,
+    AccountAlias,
+    AccountAliasBox,
+    AccountAliasChanged,
     AccountEvent,
     AccountEventFilter,
     AccountEventSet,
@@ -123,6 +149,9 @@ types!(
     AccountIdPredicateAtom,
     AccountIdProjection<PredicateMarker>,
     AccountIdProjection<SelectorMarker>,
+    AccountKeyBox,
+    AccountKeys,
+    AccountKeysChanged,
     AccountPermissionChanged,
     AccountPredicateAtom,
     AccountProjection<PredicateMarker>,
@@ -132,6 +161,8 @@ types!(
     ActionPredicateAtom,
     ActionProjection<PredicateMarker>,
     ActionProjection<SelectorMarker>,
+    AddAccountKey,
+    AggregateBlockSignature,
     Algorithm,
     Asset,
     AssetChanged,
@@ -161,9 +192,11 @@ types!(
     BTreeMap<AccountId, u8>,
     BTreeMap<CustomParameterId, CustomParameter>,
     BTreeMap<Name, Json>,
+    BTreeMap<PublicKey, u8>,
     BTreeMap<u64, TransactionRejectionReason>,
     BTreeSet<AccountId>,
     BTreeSet<Permission>,
+    BTreeSet<RoleId>,
     BTreeSet<String>,
     BlockEvent,
     BlockEventFilter,
@@ -187,36 +220,52 @@ types!(
     Box<CompoundPredicate<Asset>>,
     Box<CompoundPredicate<BlockHeader>>,
     Box<CompoundPredicate<CommittedTransaction>>,
+    Box<CompoundPredicate<CommittedTriggerReceipt>>,
+    Box<CompoundPredicate<Contract>>,
     Box<CompoundPredicate<Domain>>,
+    Box<CompoundPredicate<EquivocationEvidence>>,
+    Box<CompoundPredicate<Name>>,
     Box<CompoundPredicate<Nft>>,
     Box<CompoundPredicate<PeerId>>,
     Box<CompoundPredicate<Permission>>,
     Box<CompoundPredicate<RoleId>>,
     Box<CompoundPredicate<Role>>,
     Box<CompoundPredicate<SignedBlock>>,
+    Box<CompoundPredicate<StorageEntry>>,
     Box<CompoundPredicate<TriggerId>>,
     Box<CompoundPredicate<Trigger>>,
+    Box<EquivocationEvidence>,
     Box<TransactionRejectionReason>,
     Burn<Numeric, Asset>,
     Burn<u32, Trigger>,
     BurnBox,
+    CallContract,
     ChainId,
     CommittedTransaction,
     CommittedTransactionPredicateAtom,
     CommittedTransactionProjection<PredicateMarker>,
     CommittedTransactionProjection<SelectorMarker>,
+    CommittedTriggerReceipt,
+    CommittedTriggerReceiptPredicateAtom,
+    CommittedTriggerReceiptProjection<PredicateMarker>,
+    CommittedTriggerReceiptProjection<SelectorMarker>,
     CompoundPredicate<Account>,
     CompoundPredicate<AssetDefinition>,
     CompoundPredicate<Asset>,
     CompoundPredicate<BlockHeader>,
     CompoundPredicate<CommittedTransaction>,
+    CompoundPredicate<CommittedTriggerReceipt>,
+    CompoundPredicate<Contract>,
     CompoundPredicate<Domain>,
+    CompoundPredicate<EquivocationEvidence>,
+    CompoundPredicate<Name>,
     CompoundPredicate<Nft>,
     CompoundPredicate<PeerId>,
     CompoundPredicate<Permission>,
     CompoundPredicate<RoleId>,
     CompoundPredicate<Role>,
     CompoundPredicate<SignedBlock>,
+    CompoundPredicate<StorageEntry>,
     CompoundPredicate<TriggerId>,
     CompoundPredicate<Trigger>,
     ConfigurationEvent,
@@ -225,11 +274,31 @@ types!(
     ConstString,
     ConstVec<InstructionBox>,
     ConstVec<u8>,
+    Contract,
+    ContractBox,
+    ContractCalled,
+    ContractEvent,
+    ContractEventFilter,
+    ContractEventSet,
+    ContractId,
+    ContractIdPredicateAtom,
+    ContractIdProjection<PredicateMarker>,
+    ContractIdProjection<SelectorMarker>,
+    ContractParameter,
+    ContractParameters,
+    ContractPredicateAtom,
+    ContractProjection<PredicateMarker>,
+    ContractProjection<SelectorMarker>,
+    CronExpression,
+    CustomEvent,
+    CustomEventFilter,
     CustomInstruction,
     CustomParameter,
     CustomParameterId,
+    CustomQuery,
     DataEvent,
     DataEventFilter,
+    DeployContract,
     Domain,
     DomainEvent,
     DomainEventFilter,
@@ -242,6 +311,11 @@ types!(
     DomainPredicateAtom,
     DomainProjection<PredicateMarker>,
     DomainProjection<SelectorMarker>,
+    EmitEvent,
+    EquivocationEvidence,
+    EquivocationEvidencePredicateAtom,
+    EquivocationEvidenceProjection<PredicateMarker>,
+    EquivocationEvidenceProjection<SelectorMarker>,
     EventBox,
     EventFilterBox,
     EventMessage,
@@ -259,25 +333,33 @@ types!(
     WasmPath,
     ExecutorUpgrade,
     FetchSize,
+    FindAccountIdByAlias,
     FindAccounts,
     FindAccountsWithAsset,
+    FindAliasesByAccountId,
     FindActiveTriggerIds,
     FindAssets,
     FindAssetsDefinitions,
     FindBlockHeaders,
     FindBlocks,
+    FindContracts,
     FindDomains,
+    FindEquivocations,
     FindError,
     FindExecutorDataModel,
     FindNfts,
     FindParameters,
     FindPeers,
     FindPermissionsByAccountId,
+    FindRandomness,
     FindRoleIds,
     FindRoles,
     FindRolesByAccountId,
+    FindStorageEntries,
     FindTransactions,
     FindTriggers,
+    FindTriggerReceipts,
+    FindValidatorSets,
     ForwardCursor,
     GenesisWasmAction,
     GenesisWasmTrigger,
@@ -288,8 +370,13 @@ types!(
     Hash,
     HashOf<BlockHeader>,
     HashOf<MerkleTree<SignedTransaction>>,
+    HashOf<MerkleTree<TriggerReceipt>>,
+    HashOf<TriggerReceipt>,
+    HashOf<Vec<EquivocationEvidence>>,
     HashOf<SignedTransaction>,
     HashOf<Vec<InstructionBox>>,
+    HashOf<Vec<PeerId>>,
+    HashOf<WasmSmartContract>,
     IdBox,
     InstructionBox,
     InstructionEvaluationError,
@@ -308,6 +395,7 @@ types!(
     Log,
     MathError,
     MerkleTree<SignedTransaction>,
+    MerkleTree<TriggerReceipt>,
     Metadata,
     MetadataChanged<AccountId>,
     MetadataChanged<AssetDefinitionId>,
@@ -354,14 +442,22 @@ types!(
     NumericProjection<SelectorMarker>,
     NumericSpec,
     Option<AccountId>,
+    Option<AggregateBlockSignature>,
     Option<AssetDefinitionId>,
     Option<AssetId>,
     Option<BlockStatus>,
+    Option<ContractId>,
+    Option<CronExpression>,
     Option<DomainId>,
     Option<ForwardCursor>,
+    Option<Hash>,
     Option<HashOf<BlockHeader>>,
     Option<HashOf<MerkleTree<SignedTransaction>>>,
+    Option<HashOf<MerkleTree<TriggerReceipt>>>,
     Option<HashOf<SignedTransaction>>,
+    Option<HashOf<Vec<EquivocationEvidence>>>,
+    Option<HashOf<Vec<PeerId>>>,
+    Option<HashOf<WasmSmartContract>>,
     Option<IpfsPath>,
     Option<Name>,
     Option<NftId>,
@@ -370,7 +466,9 @@ types!(
     Option<Option<NonZeroU64>>,
     Option<Parameters>,
     Option<PeerId>,
+    Option<PendingValidatorSet>,
     Option<RoleId>,
+    Option<SignatureOf<Hash>>,
     Option<TransactionRejectionReason>,
     Option<TransactionStatus>,
     Option<TriggerCompletedOutcomeType>,
@@ -390,6 +488,7 @@ types!(
     PeerIdPredicateAtom,
     PeerIdProjection<PredicateMarker>,
     PeerIdProjection<SelectorMarker>,
+    PendingValidatorSet,
     Permission,
     PermissionPredicateAtom,
     PermissionProjection<PredicateMarker>,
@@ -413,19 +512,24 @@ types!(
     QueryWithFilter<FindAccounts>,
     QueryWithFilter<FindAccountsWithAsset>,
     QueryWithFilter<FindActiveTriggerIds>,
+    QueryWithFilter<FindAliasesByAccountId>,
     QueryWithFilter<FindAssets>,
     QueryWithFilter<FindAssetsDefinitions>,
     QueryWithFilter<FindBlockHeaders>,
     QueryWithFilter<FindBlocks>,
+    QueryWithFilter<FindContracts>,
     QueryWithFilter<FindDomains>,
+    QueryWithFilter<FindEquivocations>,
     QueryWithFilter<FindNfts>,
     QueryWithFilter<FindPeers>,
     QueryWithFilter<FindPermissionsByAccountId>,
     QueryWithFilter<FindRoleIds>,
     QueryWithFilter<FindRoles>,
     QueryWithFilter<FindRolesByAccountId>,
+    QueryWithFilter<FindStorageEntries>,
     QueryWithFilter<FindTransactions>,
     QueryWithFilter<FindTriggers>,
+    QueryWithFilter<FindTriggerReceipts>,
     QueryWithParams,
     Register<Account>,
     Register<AssetDefinition>,
@@ -434,7 +538,10 @@ types!(
     Register<Peer>,
     Register<Role>,
     Register<Trigger>,
+    RegisterAccountAlias,
     RegisterBox,
+    RemoveAccountKey,
+    RemoveContract,
     RemoveKeyValue<Account>,
     RemoveKeyValue<AssetDefinition>,
     RemoveKeyValue<Domain>,
@@ -459,20 +566,27 @@ types!(
     RolePredicateAtom,
     RoleProjection<PredicateMarker>,
     RoleProjection<SelectorMarker>,
+    RotateAccountKey,
     SelectorTuple<Account>,
     SelectorTuple<AssetDefinition>,
     SelectorTuple<Asset>,
     SelectorTuple<BlockHeader>,
     SelectorTuple<CommittedTransaction>,
+    SelectorTuple<CommittedTriggerReceipt>,
+    SelectorTuple<Contract>,
     SelectorTuple<Domain>,
+    SelectorTuple<EquivocationEvidence>,
+    SelectorTuple<Name>,
     SelectorTuple<Nft>,
     SelectorTuple<PeerId>,
     SelectorTuple<Permission>,
     SelectorTuple<RoleId>,
     SelectorTuple<Role>,
     SelectorTuple<SignedBlock>,
+    SelectorTuple<StorageEntry>,
     SelectorTuple<TriggerId>,
     SelectorTuple<Trigger>,
+    SetAccountKeyThreshold,
     SetKeyValue<Account>,
     SetKeyValue<AssetDefinition>,
     SetKeyValue<Domain>,
@@ -482,9 +596,11 @@ types!(
     SetParameter,
     Signature,
     SignatureOf<BlockHeader>,
+    SignatureOf<Hash>,
     SignatureOf<QueryRequestWithAuthority>,
     SignatureOf<TransactionPayload>,
     SignedBlock,
+    SignedBlockHeader,
     SignedBlockPredicateAtom,
     SignedBlockProjection<PredicateMarker>,
     SignedBlockProjection<SelectorMarker>,
@@ -505,6 +621,13 @@ types!(
     SocketAddrV4,
     SocketAddrV6,
     Sorting,
+    StorageEntry,
+    StorageEntryPredicateAtom,
+    StorageEntryProjection<PredicateMarker>,
+    StorageEntryProjection<SelectorMarker>,
+    StorageOwner,
+    StorageParameter,
+    StorageParameters,
     String,
     StringPredicateAtom,
     SumeragiParameter,
@@ -527,11 +650,14 @@ types!(
     TransactionPayload,
     TransactionRejectionReason,
     TransactionSignature,
+    TransactionSimulation,
+    TransactionSimulationRequest,
     TransactionStatus,
     Transfer<Account, AssetDefinitionId, Account>,
     Transfer<Account, DomainId, Account>,
     Transfer<Account, NftId, Account>,
     Transfer<Asset, Numeric, Account>,
+    TransferAccountAlias,
     TransferBox,
     Trigger,
     TriggerCompletedEvent,
@@ -546,9 +672,16 @@ types!(
     TriggerIdProjection<PredicateMarker>,
     TriggerIdProjection<SelectorMarker>,
     TriggerNumberOfExecutionsChanged,
+    TriggerParameter,
+    TriggerParameters,
     TriggerPredicateAtom,
     TriggerProjection<PredicateMarker>,
     TriggerProjection<SelectorMarker>,
+    TriggerReceipt,
+    TriggerReceiptPredicateAtom,
+    TriggerReceiptProjection<PredicateMarker>,
+    TriggerReceiptProjection<SelectorMarker>,
+    TriggerUpgraded,
     TypeError,
     Unregister<Account>,
     Unregister<AssetDefinition>,
@@ -557,9 +690,12 @@ types!(
     Unregister<Peer>,
     Unregister<Role>,
     Unregister<Trigger>,
+    UnregisterAccountAlias,
     UnregisterBox,
     Upgrade,
+    UpgradeTrigger,
     ValidationFail,
+    ValidatorSets,
     Vec<Account>,
     Vec<AccountId>,
     Vec<Action>,
@@ -570,22 +706,32 @@ types!(
     Vec<BlockHeader>,
     Vec<BlockSignature>,
     Vec<CommittedTransaction>,
+    Vec<CommittedTriggerReceipt>,
     Vec<CompoundPredicate<Account>>,
     Vec<CompoundPredicate<AssetDefinition>>,
     Vec<CompoundPredicate<Asset>>,
     Vec<CompoundPredicate<BlockHeader>>,
     Vec<CompoundPredicate<CommittedTransaction>>,
+    Vec<CompoundPredicate<CommittedTriggerReceipt>>,
+    Vec<CompoundPredicate<Contract>>,
     Vec<CompoundPredicate<Domain>>,
+    Vec<CompoundPredicate<EquivocationEvidence>>,
+    Vec<CompoundPredicate<Name>>,
     Vec<CompoundPredicate<Nft>>,
     Vec<CompoundPredicate<PeerId>>,
     Vec<CompoundPredicate<Permission>>,
     Vec<CompoundPredicate<RoleId>>,
     Vec<CompoundPredicate<Role>>,
     Vec<CompoundPredicate<SignedBlock>>,
+    Vec<CompoundPredicate<StorageEntry>>,
     Vec<CompoundPredicate<TriggerId>>,
     Vec<CompoundPredicate<Trigger>>,
+    Vec<Contract>,
+    Vec<ContractId>,
+    Vec<DataEvent>,
     Vec<Domain>,
     Vec<DomainId>,
+    Vec<EquivocationEvidence>,
     Vec<EventFilterBox>,
     Vec<GenesisWasmTrigger>,
     Vec<InstructionBox>,
@@ -601,16 +747,21 @@ types!(
     Vec<RoleId>,
     Vec<SignedBlock>,
     Vec<SignedTransaction>,
+    Vec<StorageEntry>,
     Vec<AccountProjection<SelectorMarker>>,
     Vec<AssetDefinitionProjection<SelectorMarker>>,
     Vec<AssetProjection<SelectorMarker>>,
     Vec<BlockHeaderProjection<SelectorMarker>>,
     Vec<CommittedTransactionProjection<SelectorMarker>>,
+    Vec<CommittedTriggerReceiptProjection<SelectorMarker>>,
+    Vec<ContractProjection<SelectorMarker>>,
     Vec<DomainProjection<SelectorMarker>>,
+    Vec<EquivocationEvidenceProjection<SelectorMarker>>,
     Vec<HashOf<BlockHeader>>,
     Vec<HashOf<SignedTransaction>>,
     Vec<Metadata>,
     Vec<Name>,
+    Vec<NameProjection<SelectorMarker>>,
     Vec<Numeric>,
     Vec<Option<TransactionRejectionReason>>,
     Vec<PeerIdProjection<SelectorMarker>>,
@@ -619,11 +770,14 @@ types!(
     Vec<RoleIdProjection<SelectorMarker>>,
     Vec<RoleProjection<SelectorMarker>>,
     Vec<SignedBlockProjection<SelectorMarker>>,
+    Vec<StorageEntryProjection<SelectorMarker>>,
     Vec<String>,
     Vec<TriggerIdProjection<SelectorMarker>>,
     Vec<TriggerProjection<SelectorMarker>>,
     Vec<Trigger>,
     Vec<TriggerId>,
+    Vec<TriggerReceipt>,
+    Vec<TransactionSignature>,
     Vec<u8>,
     WasmExecutionFail,
     WasmSmartContract,
@@ -654,7 +808,8 @@ pub mod complete_data_model {
         block::{
             error::BlockRejectionReason,
             stream::{BlockMessage, BlockSubscriptionRequest},
-            BlockHeader, BlockPayload, BlockSignature, SignedBlock, SignedBlockV1,
+            AggregateBlockSignature, BlockHeader, BlockPayload, BlockSignature,
+            EquivocationEvidence, SignedBlock, SignedBlockHeader, SignedBlockV1, TriggerReceipt,
         },
         domain::NewDomain,
         events::pipeline::{BlockEventFilter, TransactionEventFilter},
@@ -668,18 +823,20 @@ pub mod complete_data_model {
             InstructionType,
         },
         parameter::{
-            BlockParameter, BlockParameters, CustomParameter, CustomParameterId, Parameter,
-            Parameters, SmartContractParameter, SmartContractParameters, SumeragiParameter,
-            SumeragiParameters, TransactionParameter, TransactionParameters,
+            BlockParameter, BlockParameters, ContractParameter, ContractParameters,
+            CustomParameter, CustomParameterId, Parameter, Parameters, SmartContractParameter,
+            SmartContractParameters, StorageParameter, StorageParameters, SumeragiParameter,
+            SumeragiParameters, TransactionParameter, TransactionParameters, TriggerParameter,
+            TriggerParameters,
         },
         prelude::*,
         query::{
             dsl::{CompoundPredicate, PredicateMarker, SelectorMarker},
             error::{FindError, QueryExecutionFail},
             parameters::{ForwardCursor, QueryParams},
-            CommittedTransaction, QueryOutput, QueryOutputBatchBox, QueryOutputBatchBoxTuple,
-            QueryRequestWithAuthority, QueryResponse, QuerySignature, QueryWithFilter,
-            QueryWithParams, SignedQuery, SignedQueryV1, SingularQueryOutputBox,
+            CommittedTransaction, CommittedTriggerReceipt, QueryOutput, QueryOutputBatchBox,
+            QueryOutputBatchBoxTuple, QueryRequestWithAuthority, QueryResponse, QuerySignature,
+            QueryWithFilter, QueryWithParams, SignedQuery, SignedQueryV1, SingularQueryOutputBox,
         },
         transaction::{
             error::TransactionLimitError, SignedTransactionV1, TransactionPayload,
@@ -780,14 +937,39 @@ mod tests {
         insert_into_test_map!(
             iroha_executor_data_model::permission::trigger::CanModifyTriggerMetadata
         );
+        insert_into_test_map!(iroha_executor_data_model::permission::contract::CanDeployContract);
+        insert_into_test_map!(iroha_executor_data_model::permission::contract::CanCallContract);
         insert_into_test_map!(iroha_executor_data_model::permission::executor::CanUpgradeExecutor);
 
         insert_into_test_map!(iroha_executor_data_model::isi::multisig::MultisigInstructionBox);
         insert_into_test_map!(iroha_executor_data_model::isi::multisig::MultisigRegister);
         insert_into_test_map!(iroha_executor_data_model::isi::multisig::MultisigPropose);
         insert_into_test_map!(iroha_executor_data_model::isi::multisig::MultisigApprove);
+        insert_into_test_map!(iroha_executor_data_model::isi::multisig::MultisigReject);
+        insert_into_test_map!(iroha_executor_data_model::isi::multisig::MultisigCancel);
+        insert_into_test_map!(iroha_executor_data_model::isi::multisig::MultisigUpdateSpec);
         insert_into_test_map!(iroha_executor_data_model::isi::multisig::MultisigSpec);
         insert_into_test_map!(iroha_executor_data_model::isi::multisig::MultisigProposalValue);
+        insert_into_test_map!(iroha_executor_data_model::query::multisig::MultisigQueryBox);
+        insert_into_test_map!(iroha_executor_data_model::query::multisig::FindMultisigSpec);
+        insert_into_test_map!(iroha_executor_data_model::query::multisig::FindMultisigProposals);
+
+        insert_into_test_map!(iroha_executor_data_model::isi::vesting::VestingInstructionBox);
+        insert_into_test_map!(iroha_executor_data_model::isi::vesting::VestingLock);
+        insert_into_test_map!(iroha_executor_data_model::isi::vesting::VestingClaim);
+        insert_into_test_map!(iroha_executor_data_model::isi::vesting::VestingRevoke);
+        insert_into_test_map!(iroha_executor_data_model::isi::vesting::VestingSchedule);
+        insert_into_test_map!(iroha_executor_data_model::isi::vesting::VestingLockValue);
+
+        insert_into_test_map!(iroha_executor_data_model::isi::role::RoleInstructionBox);
+        insert_into_test_map!(iroha_executor_data_model::isi::role::RoleRegisterScoped);
+        insert_into_test_map!(iroha_executor_data_model::isi::role::RoleSetParents);
+        insert_into_test_map!(iroha_executor_data_model::isi::role::RoleSetAdmins);
+        insert_into_test_map!(iroha_executor_data_model::isi::role::RoleSpec);
+        insert_into_test_map!(iroha_executor_data_model::isi::role::RoleHierarchy);
+        insert_into_test_map!(BTreeMap<RoleId, iroha_executor_data_model::isi::role::RoleSpec>);
+        insert_into_test_map!(iroha_executor_data_model::query::role::RoleQueryBox);
+        insert_into_test_map!(iroha_executor_data_model::query::role::FindEffectivePermissions);
 
         map
     }
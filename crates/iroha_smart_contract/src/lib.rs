@@ -51,6 +51,156 @@ pub mod log {
     pub use iroha_smart_contract_utils::{debug, error, event, info, trace, warn};
 }
 
+pub mod crypto {
+    //! Cryptographic primitives computed by the host
+    //!
+    //! Every call consumes a base amount of fuel specific to the function
+    //! plus an amount proportional to the length of its encoded arguments.
+
+    use iroha_crypto::PublicKey;
+    pub use iroha_crypto::{Hash, HashAlgorithm, MerkleProof, Signature};
+    use iroha_smart_contract_utils::{decode_with_length_prefix_from_raw, encode_and_execute};
+
+    /// Check that `payload` is signed with the private key corresponding to `public_key`
+    ///
+    /// The cost grows with the length of the `payload` on top of the base cost of the verification.
+    pub fn verify_signature(public_key: &PublicKey, signature: &Signature, payload: &[u8]) -> bool {
+        #[cfg(not(test))]
+        use crate::host::verify_signature as host_verify_signature;
+        #[cfg(test)]
+        use crate::tests::_iroha_smart_contract_verify_signature_mock as host_verify_signature;
+
+        // Safety: - `host_verify_signature` doesn't take ownership of it's pointer parameter
+        //         - ownership of the returned result is transferred into `_decode_from_raw`
+        unsafe {
+            decode_with_length_prefix_from_raw(encode_and_execute(
+                &(public_key, signature, payload),
+                host_verify_signature,
+            ))
+        }
+    }
+
+    /// Compute the digest of `bytes` with the given `algorithm`
+    pub fn hash(algorithm: HashAlgorithm, bytes: &[u8]) -> [u8; Hash::LENGTH] {
+        #[cfg(not(test))]
+        use crate::host::hash as host_hash;
+        #[cfg(test)]
+        use crate::tests::_iroha_smart_contract_hash_mock as host_hash;
+
+        // Safety: - `host_hash` doesn't take ownership of it's pointer parameter
+        //         - ownership of the returned result is transferred into `_decode_from_raw`
+        unsafe {
+            decode_with_length_prefix_from_raw(encode_and_execute(&(algorithm, bytes), host_hash))
+        }
+    }
+
+    /// Check that `leaf` is included into the Merkle tree with the `root` hash.
+    /// See [`MerkleProof`] for how the tree is hashed
+    pub fn verify_merkle_proof(leaf: &[u8], proof: &MerkleProof, root: &Hash) -> bool {
+        #[cfg(not(test))]
+        use crate::host::verify_merkle_proof as host_verify_proof;
+        #[cfg(test)]
+        use crate::tests::_iroha_smart_contract_verify_merkle_proof_mock as host_verify_proof;
+
+        // Safety: - `host_verify_proof` doesn't take ownership of it's pointer parameter
+        //         - ownership of the returned result is transferred into `_decode_from_raw`
+        unsafe {
+            decode_with_length_prefix_from_raw(encode_and_execute(
+                &(leaf, proof, root),
+                host_verify_proof,
+            ))
+        }
+    }
+}
+
+pub mod storage {
+    //! Persistent key-value storage of the smart contract or the trigger
+    //!
+    //! Smart contracts share the storage of the account which submitted them,
+    //! while every trigger has a storage of its own. The total number of bytes of keys
+    //! and values is limited by the `storage.max_bytes` parameter.
+
+    use alloc::vec::Vec;
+
+    pub use iroha_data_model::storage::StorageEntry;
+    use iroha_data_model::ValidationFail;
+    use iroha_smart_contract_utils::{
+        decode_with_length_prefix_from_raw, encode_and_execute, DebugExpectExt as _,
+    };
+    use parity_scale_codec::{DecodeAll, Encode};
+
+    /// Get the value stored under the `key`
+    pub fn get<V: DecodeAll>(key: &[u8]) -> Option<V> {
+        get_raw(key).map(|value| {
+            V::decode_all(&mut value.as_slice()).dbg_expect("Unexpected type of stored value")
+        })
+    }
+
+    /// Get the encoded value stored under the `key`
+    pub fn get_raw(key: &[u8]) -> Option<Vec<u8>> {
+        #[cfg(not(test))]
+        use crate::host::storage_get as host_storage_get;
+        #[cfg(test)]
+        use crate::tests::_iroha_smart_contract_storage_get_mock as host_storage_get;
+
+        // Safety: - `host_storage_get` doesn't take ownership of it's pointer parameter
+        //         - ownership of the returned result is transferred into `_decode_from_raw`
+        unsafe { decode_with_length_prefix_from_raw(encode_and_execute(&key, host_storage_get)) }
+    }
+
+    /// Store the `value` under the `key` replacing the previous one
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`ValidationFail::TooComplex`] if the storage quota is exceeded
+    pub fn set<V: Encode>(key: &[u8], value: &V) -> Result<(), ValidationFail> {
+        set_raw(key, value.encode())
+    }
+
+    /// Store the encoded `value` under the `key` replacing the previous one
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`ValidationFail::TooComplex`] if the storage quota is exceeded
+    pub fn set_raw(key: &[u8], value: Vec<u8>) -> Result<(), ValidationFail> {
+        #[cfg(not(test))]
+        use crate::host::storage_set as host_storage_set;
+        #[cfg(test)]
+        use crate::tests::_iroha_smart_contract_storage_set_mock as host_storage_set;
+
+        let entry = StorageEntry::new(key.to_vec(), value);
+        // Safety: - `host_storage_set` doesn't take ownership of it's pointer parameter
+        //         - ownership of the returned result is transferred into `_decode_from_raw`
+        unsafe { decode_with_length_prefix_from_raw(encode_and_execute(&entry, host_storage_set)) }
+    }
+
+    /// Remove the `key` from the storage returning its encoded value
+    pub fn remove(key: &[u8]) -> Option<Vec<u8>> {
+        #[cfg(not(test))]
+        use crate::host::storage_remove as host_storage_remove;
+        #[cfg(test)]
+        use crate::tests::_iroha_smart_contract_storage_remove_mock as host_storage_remove;
+
+        // Safety: - `host_storage_remove` doesn't take ownership of it's pointer parameter
+        //         - ownership of the returned result is transferred into `_decode_from_raw`
+        unsafe { decode_with_length_prefix_from_raw(encode_and_execute(&key, host_storage_remove)) }
+    }
+
+    /// Get the entries which keys start with the `prefix` sorted by key
+    pub fn iter(prefix: &[u8]) -> Vec<StorageEntry> {
+        #[cfg(not(test))]
+        use crate::host::storage_iter as host_storage_iter;
+        #[cfg(test)]
+        use crate::tests::_iroha_smart_contract_storage_iter_mock as host_storage_iter;
+
+        // Safety: - `host_storage_iter` doesn't take ownership of it's pointer parameter
+        //         - ownership of the returned result is transferred into `_decode_from_raw`
+        unsafe {
+            decode_with_length_prefix_from_raw(encode_and_execute(&prefix, host_storage_iter))
+        }
+    }
+}
+
 /// An iterable query cursor for use in smart contracts.
 #[derive(Debug, Clone, Encode, Decode)]
 pub struct QueryCursor {
@@ -98,6 +248,42 @@ impl Iroha {
         Ok(())
     }
 
+    /// Calls the deployed `contract` with `args` and returns its output
+    ///
+    /// # Errors
+    /// Fails if the call isn't permitted, the contract doesn't exist or its execution fails
+    #[expect(clippy::unused_self)]
+    pub fn call_contract(&self, contract: &ContractId, args: Json) -> Result<Json, ValidationFail> {
+        #[cfg(not(test))]
+        use host::call_contract as host_call_contract;
+        #[cfg(test)]
+        use tests::_iroha_smart_contract_call_contract_mock as host_call_contract;
+
+        // Safety: - `host_call_contract` doesn't take ownership of it's pointer parameter
+        //         - ownership of the returned result is transferred into `_decode_from_raw`
+        unsafe {
+            decode_with_length_prefix_from_raw(encode_and_execute(
+                &(contract, args),
+                host_call_contract,
+            ))
+        }
+    }
+
+    /// Verifiable randomness of the block the executable is run in.
+    /// It can't be predicted before the block is created, nor chosen by the leader,
+    /// as long as the leader has a BLS key. Otherwise it repeats the randomness
+    /// of the previous block. None in the genesis block
+    #[expect(clippy::unused_self)]
+    pub fn randomness(&self) -> Option<iroha_crypto::Hash> {
+        #[cfg(not(test))]
+        use host::get_randomness as host_get_randomness;
+        #[cfg(test)]
+        use tests::_iroha_smart_contract_get_randomness_mock as host_get_randomness;
+
+        // Safety: ownership of the returned result is transferred into `_decode_from_raw`
+        unsafe { decode_with_length_prefix_from_raw(host_get_randomness()) }
+    }
+
     /// Build an iterable query for execution in a smart contract.
     pub fn query<Q>(&self, query: Q) -> QueryBuilder<Self, Q, Q::Item>
     where
@@ -233,6 +419,85 @@ mod host {
         /// This function doesn't take ownership of the provided allocation
         /// but it does transfer ownership of the result to the caller
         pub(super) fn execute_instruction(ptr: *const u8, len: usize) -> *const u8;
+
+        /// Verify encoded public key, signature and payload by providing offset and length
+        /// into WebAssembly's linear memory where they are stored
+        ///
+        /// # Warning
+        ///
+        /// This function doesn't take ownership of the provided allocation
+        /// but it does transfer ownership of the result to the caller
+        pub(super) fn verify_signature(ptr: *const u8, len: usize) -> *const u8;
+
+        /// Hash encoded algorithm and bytes by providing offset and length
+        /// into WebAssembly's linear memory where they are stored
+        ///
+        /// # Warning
+        ///
+        /// This function doesn't take ownership of the provided allocation
+        /// but it does transfer ownership of the result to the caller
+        pub(super) fn hash(ptr: *const u8, len: usize) -> *const u8;
+
+        /// Verify encoded leaf, Merkle proof and root by providing offset and length
+        /// into WebAssembly's linear memory where they are stored
+        ///
+        /// # Warning
+        ///
+        /// This function doesn't take ownership of the provided allocation
+        /// but it does transfer ownership of the result to the caller
+        pub(super) fn verify_merkle_proof(ptr: *const u8, len: usize) -> *const u8;
+
+        /// Get the value of the encoded key of the persistent storage by providing
+        /// offset and length into WebAssembly's linear memory where the key is stored
+        ///
+        /// # Warning
+        ///
+        /// This function doesn't take ownership of the provided allocation
+        /// but it does transfer ownership of the result to the caller
+        pub(super) fn storage_get(ptr: *const u8, len: usize) -> *const u8;
+
+        /// Set the encoded entry of the persistent storage by providing offset and length
+        /// into WebAssembly's linear memory where the entry is stored
+        ///
+        /// # Warning
+        ///
+        /// This function doesn't take ownership of the provided allocation
+        /// but it does transfer ownership of the result to the caller
+        pub(super) fn storage_set(ptr: *const u8, len: usize) -> *const u8;
+
+        /// Remove the encoded key from the persistent storage by providing offset and length
+        /// into WebAssembly's linear memory where the key is stored
+        ///
+        /// # Warning
+        ///
+        /// This function doesn't take ownership of the provided allocation
+        /// but it does transfer ownership of the result to the caller
+        pub(super) fn storage_remove(ptr: *const u8, len: usize) -> *const u8;
+
+        /// Get the entries of the persistent storage by providing offset and length
+        /// into WebAssembly's linear memory where the encoded key prefix is stored
+        ///
+        /// # Warning
+        ///
+        /// This function doesn't take ownership of the provided allocation
+        /// but it does transfer ownership of the result to the caller
+        pub(super) fn storage_iter(ptr: *const u8, len: usize) -> *const u8;
+
+        /// Call the contract with encoded id and arguments by providing offset and length
+        /// into WebAssembly's linear memory where they are stored
+        ///
+        /// # Warning
+        ///
+        /// This function doesn't take ownership of the provided allocation
+        /// but it does transfer ownership of the result to the caller
+        pub(super) fn call_contract(ptr: *const u8, len: usize) -> *const u8;
+
+        /// Get the encoded randomness of the current block
+        ///
+        /// # Warning
+        ///
+        /// This function does transfer ownership of the result to the caller
+        pub(super) fn get_randomness() -> *const u8;
     }
 }
 
@@ -246,7 +511,7 @@ pub mod prelude {
 
 #[cfg(test)]
 mod tests {
-    use alloc::vec;
+    use alloc::{vec, vec::Vec};
     use core::{mem::ManuallyDrop, slice};
 
     use iroha_data_model::query::{
@@ -311,6 +576,124 @@ mod tests {
         ManuallyDrop::new(encode_with_length_prefix(&response)).as_ptr()
     }
 
+    #[no_mangle]
+    pub unsafe extern "C" fn _iroha_smart_contract_verify_signature_mock(
+        ptr: *const u8,
+        len: usize,
+    ) -> *const u8 {
+        let bytes = slice::from_raw_parts(ptr, len);
+        let (public_key, signature, payload) =
+            <(PublicKey, crypto::Signature, Vec<u8>)>::decode_all(&mut &*bytes).unwrap();
+
+        let verified = signature.verify(&public_key, &payload).is_ok();
+        ManuallyDrop::new(encode_with_length_prefix(&verified)).as_ptr()
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn _iroha_smart_contract_hash_mock(
+        ptr: *const u8,
+        len: usize,
+    ) -> *const u8 {
+        let bytes = slice::from_raw_parts(ptr, len);
+        let (algorithm, bytes) =
+            <(crypto::HashAlgorithm, Vec<u8>)>::decode_all(&mut &*bytes).unwrap();
+
+        ManuallyDrop::new(encode_with_length_prefix(&algorithm.digest(bytes))).as_ptr()
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn _iroha_smart_contract_verify_merkle_proof_mock(
+        ptr: *const u8,
+        len: usize,
+    ) -> *const u8 {
+        let bytes = slice::from_raw_parts(ptr, len);
+        let (leaf, proof, root) =
+            <(Vec<u8>, crypto::MerkleProof, crypto::Hash)>::decode_all(&mut &*bytes).unwrap();
+
+        ManuallyDrop::new(encode_with_length_prefix(&proof.verify(leaf, &root))).as_ptr()
+    }
+
+    fn get_test_storage_entry() -> storage::StorageEntry {
+        storage::StorageEntry::new(b"counter".to_vec(), 42_u32.encode())
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn _iroha_smart_contract_storage_get_mock(
+        ptr: *const u8,
+        len: usize,
+    ) -> *const u8 {
+        let bytes = slice::from_raw_parts(ptr, len);
+        let key = Vec::<u8>::decode_all(&mut &*bytes).unwrap();
+        assert_eq!(key, get_test_storage_entry().key);
+
+        let value = Some(get_test_storage_entry().value);
+        ManuallyDrop::new(encode_with_length_prefix(&value)).as_ptr()
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn _iroha_smart_contract_storage_set_mock(
+        ptr: *const u8,
+        len: usize,
+    ) -> *const u8 {
+        let bytes = slice::from_raw_parts(ptr, len);
+        let entry = storage::StorageEntry::decode_all(&mut &*bytes).unwrap();
+        assert_eq!(entry, get_test_storage_entry());
+
+        ManuallyDrop::new(encode_with_length_prefix(&ISI_RESULT)).as_ptr()
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn _iroha_smart_contract_storage_remove_mock(
+        ptr: *const u8,
+        len: usize,
+    ) -> *const u8 {
+        let bytes = slice::from_raw_parts(ptr, len);
+        let key = Vec::<u8>::decode_all(&mut &*bytes).unwrap();
+        assert_eq!(key, get_test_storage_entry().key);
+
+        let value = Some(get_test_storage_entry().value);
+        ManuallyDrop::new(encode_with_length_prefix(&value)).as_ptr()
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn _iroha_smart_contract_storage_iter_mock(
+        ptr: *const u8,
+        len: usize,
+    ) -> *const u8 {
+        let bytes = slice::from_raw_parts(ptr, len);
+        let prefix = Vec::<u8>::decode_all(&mut &*bytes).unwrap();
+        assert_eq!(prefix, b"count");
+
+        let entries = vec![get_test_storage_entry()];
+        ManuallyDrop::new(encode_with_length_prefix(&entries)).as_ptr()
+    }
+
+    fn get_test_contract_call() -> (ContractId, Json) {
+        ("echo".parse().unwrap(), Json::new(42_u32))
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn _iroha_smart_contract_call_contract_mock(
+        ptr: *const u8,
+        len: usize,
+    ) -> *const u8 {
+        let bytes = slice::from_raw_parts(ptr, len);
+        let call = <(ContractId, Json)>::decode_all(&mut &*bytes).unwrap();
+        assert_eq!(call, get_test_contract_call());
+
+        let output: Result<Json, ValidationFail> = Ok(call.1);
+        ManuallyDrop::new(encode_with_length_prefix(&output)).as_ptr()
+    }
+
+    fn get_test_randomness() -> iroha_crypto::Hash {
+        iroha_crypto::Hash::new(b"randomness")
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn _iroha_smart_contract_get_randomness_mock() -> *const u8 {
+        ManuallyDrop::new(encode_with_length_prefix(&Some(get_test_randomness()))).as_ptr()
+    }
+
     #[webassembly_test]
     fn execute_instruction() {
         let host = Iroha;
@@ -328,4 +711,56 @@ mod tests {
             "Expected no cursor, but got {next_cursor:?}",
         );
     }
+
+    #[webassembly_test]
+    fn crypto() {
+        use iroha_crypto::{Algorithm, KeyPair};
+
+        let key_pair = KeyPair::from_seed(vec![7; 32], Algorithm::Ed25519);
+        let signature = crypto::Signature::new(key_pair.private_key(), b"voucher");
+        assert!(crypto::verify_signature(
+            key_pair.public_key(),
+            &signature,
+            b"voucher"
+        ));
+        assert!(!crypto::verify_signature(
+            key_pair.public_key(),
+            &signature,
+            b"forged voucher"
+        ));
+
+        assert_eq!(
+            crypto::hash(crypto::HashAlgorithm::Sha256, b"voucher"),
+            crypto::HashAlgorithm::Sha256.digest(b"voucher")
+        );
+
+        let leaves: [&[u8]; 3] = [b"first", b"second", b"third"];
+        let proof = crypto::MerkleProof::build(&leaves, 2).unwrap();
+        let root = crypto::MerkleProof::root(&leaves);
+        assert!(crypto::verify_merkle_proof(leaves[2], &proof, &root));
+        assert!(!crypto::verify_merkle_proof(leaves[1], &proof, &root));
+    }
+
+    #[webassembly_test]
+    fn storage() {
+        let entry = get_test_storage_entry();
+
+        assert_eq!(storage::get::<u32>(&entry.key), Some(42));
+        storage::set(&entry.key, &42_u32).unwrap();
+        assert_eq!(storage::remove(&entry.key), Some(entry.value.clone()));
+        assert_eq!(storage::iter(b"count"), [entry]);
+    }
+
+    #[webassembly_test]
+    fn call_contract() {
+        let host = Iroha;
+        let (contract, args) = get_test_contract_call();
+        assert_eq!(host.call_contract(&contract, args.clone()), Ok(args));
+    }
+
+    #[webassembly_test]
+    fn randomness() {
+        let host = Iroha;
+        assert_eq!(host.randomness(), Some(get_test_randomness()));
+    }
 }
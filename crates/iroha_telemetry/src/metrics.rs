@@ -5,7 +5,8 @@ use std::{ops::Deref, time::Duration};
 use parity_scale_codec::{Compact, Decode, Encode};
 use prometheus::{
     core::{AtomicU64, GenericGauge, GenericGaugeVec},
-    Encoder, Histogram, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, Opts, Registry,
+    Encoder, Gauge, Histogram, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, Opts,
+    Registry,
 };
 use serde::{Deserialize, Serialize};
 
@@ -122,11 +123,28 @@ pub struct Metrics {
     pub queue_size: GenericGauge<AtomicU64>,
     /// Number of sumeragi dropped messages
     pub dropped_messages: DroppedMessagesCounter,
+    /// Number of peer messages dropped because the inbound rate limit was exceeded
+    pub p2p_dropped_messages: GenericGauge<AtomicU64>,
+    /// Number of bytes of the peer messages dropped because the inbound rate limit was exceeded
+    pub p2p_dropped_bytes: GenericGauge<AtomicU64>,
+    /// Number of penalties given to misbehaving peers
+    pub p2p_penalties: GenericGauge<AtomicU64>,
+    /// Number of currently banned peers
+    pub p2p_banned_peers: GenericGauge<AtomicU64>,
+    /// Number of peer messages waiting to be sent, per lane
+    pub p2p_queue_depth: GenericGaugeVec<AtomicU64>,
+    /// Number of bytes of the peer messages large enough to be compressed, before compression
+    pub p2p_compression_input_bytes: GenericGauge<AtomicU64>,
+    /// Number of bytes of the peer messages large enough to be compressed, after compression
+    pub p2p_compression_output_bytes: GenericGauge<AtomicU64>,
+    /// Ratio of the size of the compressed peer messages to their size before compression
+    pub p2p_compression_ratio: Gauge,
     /// Internal use only. Needed for generating the response.
     registry: Registry,
 }
 
 impl Default for Metrics {
+    #[allow(clippy::too_many_lines)]
     fn default() -> Self {
         let txs = IntCounterVec::new(Opts::new("txs", "Transactions committed"), &["type"])
             .expect("Infallible");
@@ -177,6 +195,42 @@ impl Default for Metrics {
             .expect("Infallible");
         let dropped_messages =
             IntCounter::new("dropped_messages", "Sumeragi dropped messages").expect("Infallible");
+        let p2p_dropped_messages = GenericGauge::new(
+            "p2p_dropped_messages",
+            "Peer messages dropped because the inbound rate limit was exceeded",
+        )
+        .expect("Infallible");
+        let p2p_dropped_bytes = GenericGauge::new(
+            "p2p_dropped_bytes",
+            "Bytes of the peer messages dropped because the inbound rate limit was exceeded",
+        )
+        .expect("Infallible");
+        let p2p_penalties =
+            GenericGauge::new("p2p_penalties", "Penalties given to misbehaving peers")
+                .expect("Infallible");
+        let p2p_banned_peers =
+            GenericGauge::new("p2p_banned_peers", "Number of currently banned peers")
+                .expect("Infallible");
+        let p2p_queue_depth = GenericGaugeVec::new(
+            Opts::new("p2p_queue_depth", "Peer messages waiting to be sent"),
+            &["lane"],
+        )
+        .expect("Infallible");
+        let p2p_compression_input_bytes = GenericGauge::new(
+            "p2p_compression_input_bytes",
+            "Bytes of the peer messages large enough to be compressed, before compression",
+        )
+        .expect("Infallible");
+        let p2p_compression_output_bytes = GenericGauge::new(
+            "p2p_compression_output_bytes",
+            "Bytes of the peer messages large enough to be compressed, after compression",
+        )
+        .expect("Infallible");
+        let p2p_compression_ratio = Gauge::new(
+            "p2p_compression_ratio",
+            "Ratio of the size of the compressed peer messages to their size before compression",
+        )
+        .expect("Infallible");
         let registry = Registry::new();
 
         macro_rules! register {
@@ -202,7 +256,15 @@ impl Default for Metrics {
             isi_times,
             view_changes,
             queue_size,
-            dropped_messages
+            dropped_messages,
+            p2p_dropped_messages,
+            p2p_dropped_bytes,
+            p2p_penalties,
+            p2p_banned_peers,
+            p2p_queue_depth,
+            p2p_compression_input_bytes,
+            p2p_compression_output_bytes,
+            p2p_compression_ratio
         );
 
         Self {
@@ -219,6 +281,14 @@ impl Default for Metrics {
             view_changes,
             queue_size,
             dropped_messages,
+            p2p_dropped_messages,
+            p2p_dropped_bytes,
+            p2p_penalties,
+            p2p_banned_peers,
+            p2p_queue_depth,
+            p2p_compression_input_bytes,
+            p2p_compression_output_bytes,
+            p2p_compression_ratio,
             registry,
         }
     }
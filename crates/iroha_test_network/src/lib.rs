@@ -23,7 +23,7 @@ use iroha_config::base::{
     read::ConfigReader,
     toml::{TomlSource, WriteExt as _, Writer as TomlWriter},
 };
-use iroha_crypto::{ExposedPrivateKey, KeyPair, PrivateKey};
+use iroha_crypto::{Algorithm, ExposedPrivateKey, KeyPair, PrivateKey};
 use iroha_data_model::{
     events::pipeline::BlockEventFilter,
     isi::InstructionBox,
@@ -473,9 +473,11 @@ pub struct NetworkPeer {
 }
 
 impl NetworkPeer {
-    /// Generate a random peer
+    /// Generate a random peer.
+    ///
+    /// Peers have BLS keys, so that they contribute to the randomness beacon as leaders.
     pub fn generate() -> Self {
-        let key_pair = KeyPair::random();
+        let key_pair = KeyPair::random_with_algorithm(Algorithm::BlsNormal);
         let port_p2p = AllocatedPort::new();
         let port_api = AllocatedPort::new();
         let id = Peer::new(
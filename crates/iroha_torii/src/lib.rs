@@ -46,6 +46,7 @@ pub(crate) mod utils;
 mod block;
 mod event;
 mod routing;
+mod simulation;
 mod stream;
 
 const SERVER_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(60);
@@ -59,6 +60,8 @@ pub struct Torii {
     query_service: LiveQueryStoreHandle,
     kura: Arc<Kura>,
     transaction_max_content_len: Bytes<u64>,
+    /// [`None`] if transaction simulation is disabled
+    simulation_limits: Option<Arc<simulation::SimulationLimits>>,
     address: WithOrigin<SocketAddr>,
     state: Arc<State>,
     #[cfg(feature = "telemetry")]
@@ -91,6 +94,13 @@ impl Torii {
             metrics_reporter,
             address: config.address,
             transaction_max_content_len: config.max_content_len,
+            simulation_limits: config.simulation_enabled.then(|| {
+                Arc::new(simulation::SimulationLimits::new(
+                    config.simulation_max_concurrent,
+                    config.simulation_max_per_sec,
+                    config.simulation_max_fuel,
+                ))
+            }),
         }
     }
 
@@ -186,6 +196,23 @@ impl Torii {
                         .expect("should't exceed usize"),
                 )),
             )
+            .route(
+                uri::TRANSACTION_SIMULATION,
+                post({
+                    let chain_id = self.chain_id.clone();
+                    let state = self.state.clone();
+                    let limits = self.simulation_limits.clone();
+                    move |Scale(request): Scale<_>| {
+                        routing::handle_simulate_transaction(chain_id, state, limits, request)
+                    }
+                })
+                .layer(DefaultBodyLimit::max(
+                    self.transaction_max_content_len
+                        .get()
+                        .try_into()
+                        .expect("should't exceed usize"),
+                )),
+            )
             .route(
                 uri::QUERY,
                 post({
@@ -280,6 +307,12 @@ pub enum Error {
     Config(#[source] eyre::Report),
     /// Failed to push into queue
     PushIntoQueue(#[from] Box<queue::Error>),
+    /// Transaction simulation is disabled
+    SimulationDisabled,
+    /// Too many transactions are being simulated
+    SimulationLimitExceeded,
+    /// Transaction simulation failed to complete
+    SimulationFailed(#[source] tokio::task::JoinError),
     #[cfg(feature = "telemetry")]
     /// Failed to get Prometheus metrics
     Prometheus(#[source] eyre::Report),
@@ -320,6 +353,9 @@ impl Error {
                 queue::Error::Full => StatusCode::INTERNAL_SERVER_ERROR,
                 _ => StatusCode::BAD_REQUEST,
             },
+            SimulationDisabled => StatusCode::NOT_IMPLEMENTED,
+            SimulationLimitExceeded => StatusCode::TOO_MANY_REQUESTS,
+            SimulationFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
             #[cfg(feature = "telemetry")]
             Prometheus(_) | StatusFailure(_) => StatusCode::INTERNAL_SERVER_ERROR,
             #[cfg(feature = "profiling")]
@@ -345,7 +381,8 @@ impl Error {
                 | CursorDone
                 | NotFound
                 | FetchSizeTooBig
-                | InvalidSingularParameters => StatusCode::BAD_REQUEST,
+                | InvalidSingularParameters
+                | Custom(_) => StatusCode::BAD_REQUEST,
                 Find(_) => StatusCode::NOT_FOUND,
                 CapacityLimit => StatusCode::TOO_MANY_REQUESTS,
             },
@@ -10,13 +10,14 @@ use iroha_core::{query::store::LiveQueryStoreHandle, smartcontracts::query::Vali
 use iroha_data_model::{
     self,
     prelude::*,
-    query::{QueryRequestWithAuthority, QueryResponse, SignedQuery},
+    query::{QueryResponse, SignedQuery},
 };
 #[cfg(feature = "telemetry")]
 use iroha_telemetry::metrics::Status;
 use tokio::task;
 
 use super::*;
+use crate::simulation::SimulationLimits;
 
 #[iroha_futures::telemetry_future]
 pub async fn handle_transaction(
@@ -47,6 +48,39 @@ pub async fn handle_transaction(
         .map_err(Error::PushIntoQueue)
 }
 
+#[iroha_futures::telemetry_future]
+pub async fn handle_simulate_transaction(
+    chain_id: Arc<ChainId>,
+    state: Arc<State>,
+    limits: Option<Arc<SimulationLimits>>,
+    request: TransactionSimulationRequest,
+) -> Result<Scale<TransactionSimulation>> {
+    let limits = limits.ok_or(Error::SimulationDisabled)?;
+    let permit = limits.try_start().ok_or(Error::SimulationLimitExceeded)?;
+
+    let (max_clock_drift, tx_limits) = {
+        let state_view = state.world.view();
+        let params = state_view.parameters();
+        (params.sumeragi.max_clock_drift(), params.transaction)
+    };
+
+    let (tx, check_threshold) = match request {
+        TransactionSimulationRequest::Signed(tx) => (tx, true),
+        TransactionSimulationRequest::PartiallySigned(tx) => (tx, false),
+    };
+    let accepted_tx = AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits)
+        .map_err(Error::AcceptTransaction)?;
+
+    let handle = task::spawn_blocking(move || {
+        // The slot is held until the simulation finishes even if the request is dropped
+        let _permit = permit;
+        state.simulate_transaction(accepted_tx, check_threshold, limits.max_fuel)
+    });
+    let simulation = handle.await.map_err(Error::SimulationFailed)?;
+
+    Ok(Scale(simulation))
+}
+
 #[iroha_futures::telemetry_future]
 pub async fn handle_queries(
     live_query_store: LiveQueryStoreHandle,
@@ -56,9 +90,7 @@ pub async fn handle_queries(
     let handle = task::spawn_blocking(move || {
         let state_view = state.view();
 
-        let SignedQuery::V1(query) = query;
-        let query: QueryRequestWithAuthority = query.payload;
-        let authority = query.authority.clone();
+        let authority = query.authority().clone();
 
         let valid_query = ValidQueryRequest::validate_for_client(query, &state_view)?;
         let response = valid_query.execute(&live_query_store, &state_view, &authority)?;
@@ -0,0 +1,92 @@
+//! Limits of transaction simulations, which cost the peer as much as executing transactions.
+use std::{
+    num::{NonZeroU32, NonZeroU64, NonZeroUsize},
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use tokio::{
+    sync::{OwnedSemaphorePermit, Semaphore},
+    time::{Duration, Instant},
+};
+
+/// Limits of simultaneous simulations, simulations started per second
+/// and fuel available to a simulated transaction.
+#[derive(Debug)]
+pub struct SimulationLimits {
+    permits: Arc<Semaphore>,
+    max_per_sec: u32,
+    /// Start of the current one second window and the number of simulations started within it
+    window: Mutex<(Instant, u32)>,
+    /// Fuel available to the executor and the smart contract of a simulated transaction
+    pub max_fuel: NonZeroU64,
+}
+
+impl SimulationLimits {
+    /// Construct [`Self`] with no simulations started yet.
+    pub fn new(
+        max_concurrent: NonZeroUsize,
+        max_per_sec: NonZeroU32,
+        max_fuel: NonZeroU64,
+    ) -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(max_concurrent.get())),
+            max_per_sec: max_per_sec.get(),
+            window: Mutex::new((Instant::now(), 0)),
+            max_fuel,
+        }
+    }
+
+    /// Reserve a slot for a simulation unless any of the limits is exceeded.
+    ///
+    /// The slot is freed once the returned permit is dropped.
+    pub fn try_start(&self) -> Option<OwnedSemaphorePermit> {
+        self.try_start_at(Instant::now())
+    }
+
+    fn try_start_at(&self, now: Instant) -> Option<OwnedSemaphorePermit> {
+        let permit = Arc::clone(&self.permits).try_acquire_owned().ok()?;
+
+        let mut window = self.window.lock().unwrap_or_else(PoisonError::into_inner);
+        let (started_at, started) = &mut *window;
+        if now.saturating_duration_since(*started_at) >= Duration::from_secs(1) {
+            *started_at = now;
+            *started = 0;
+        }
+        if *started >= self.max_per_sec {
+            return None;
+        }
+        *started += 1;
+
+        Some(permit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nonzero_ext::nonzero;
+
+    use super::*;
+
+    #[test]
+    fn simulations_are_limited_in_concurrency_and_rate() {
+        let limits = SimulationLimits::new(nonzero!(2_usize), nonzero!(3_u32), nonzero!(1_u64));
+        let now = Instant::now();
+
+        let first = limits.try_start_at(now).expect("Within limits");
+        let second = limits.try_start_at(now).expect("Within limits");
+        assert!(
+            limits.try_start_at(now).is_none(),
+            "Too many simultaneous simulations"
+        );
+
+        drop(first);
+        let _third = limits.try_start_at(now).expect("Within limits");
+        drop(second);
+        assert!(
+            limits.try_start_at(now).is_none(),
+            "Too many simulations per second"
+        );
+
+        assert!(limits.try_start_at(now + Duration::from_secs(1)).is_some());
+    }
+}
@@ -30,9 +30,35 @@ pub mod extractors {
         extract::{FromRequest, FromRequestParts, Request},
         http::StatusCode,
     };
+    use parity_scale_codec::DecodeAll;
 
     use super::*;
 
+    /// Extractor of scale encoded data from body
+    #[async_trait]
+    impl<S, T> FromRequest<S> for Scale<T>
+    where
+        Bytes: FromRequest<S>,
+        S: Send + Sync,
+        T: DecodeAll,
+    {
+        type Rejection = Response;
+
+        async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+            let body = Bytes::from_request(req, state)
+                .await
+                .map_err(IntoResponse::into_response)?;
+
+            T::decode_all(&mut body.as_ref()).map(Scale).map_err(|err| {
+                (
+                    axum::http::StatusCode::BAD_REQUEST,
+                    format!("Could not decode request: {err}"),
+                )
+                    .into_response()
+            })
+        }
+    }
+
     /// Extractor of scale encoded versioned data from body
     #[derive(Clone, Copy, Debug)]
     pub struct ScaleVersioned<T>(pub T);
@@ -7,6 +7,8 @@ pub mod uri {
     pub const QUERY: &str = "/query";
     /// Transaction URI is used to handle incoming ISI requests.
     pub const TRANSACTION: &str = "/transaction";
+    /// Transaction simulation URI is used to execute transactions without committing them.
+    pub const TRANSACTION_SIMULATION: &str = "/transaction/simulate";
     /// Health URI is used to handle incoming Healthcheck requests.
     pub const HEALTH: &str = "/health";
     /// Peers URI is used to find all peers in the network
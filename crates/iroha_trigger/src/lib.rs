@@ -4,7 +4,7 @@
 
 pub use iroha_smart_contract as smart_contract;
 pub use iroha_smart_contract_utils::{dbg, dbg_panic, DebugExpectExt, DebugUnwrapExt};
-pub use iroha_trigger_derive::main;
+pub use iroha_trigger_derive::{main, migrate};
 pub use smart_contract::{data_model, Iroha};
 
 #[doc(hidden)]
@@ -1,4 +1,4 @@
-//! Module wht [`main`](super::main) macro implementation
+//! Module wht [`main`](super::main) and [`migrate`](super::migrate) macros implementation
 
 use iroha_macro_utils::Emitter;
 use manyhow::emit;
@@ -7,6 +7,7 @@ use quote::quote;
 
 mod export {
     pub const TRIGGER_MAIN: &str = "_iroha_trigger_main";
+    pub const TRIGGER_MIGRATE: &str = "_iroha_trigger_migrate";
 }
 
 /// [`main`](super::main()) macro implementation
@@ -50,3 +51,44 @@ pub fn impl_entrypoint(emitter: &mut Emitter, item: syn::ItemFn) -> TokenStream
         #block
     }
 }
+
+/// [`migrate`](super::migrate()) macro implementation
+#[allow(clippy::needless_pass_by_value)]
+pub fn impl_migrate_entrypoint(emitter: &mut Emitter, item: syn::ItemFn) -> TokenStream {
+    let syn::ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = item;
+
+    if sig.output != syn::ReturnType::Default {
+        emit!(
+            emitter,
+            sig.output,
+            "Trigger `migrate()` function must not have a return type"
+        )
+    }
+
+    let fn_name = &sig.ident;
+    let migrate_fn_name = syn::Ident::new(export::TRIGGER_MIGRATE, proc_macro2::Span::call_site());
+
+    // NOTE: `getrandom` callback is registered by the `main` entrypoint
+    quote! {
+        /// Trigger storage migration entrypoint
+        #[no_mangle]
+        #[doc(hidden)]
+        unsafe extern "C" fn #migrate_fn_name(context: *const u8) {
+            let host = ::iroha_trigger::Iroha;
+            let context = ::iroha_trigger::utils::__decode_trigger_context(context);
+            #fn_name(host, context)
+        }
+
+        // NOTE: Host objects are always passed by value to wasm
+        #[allow(clippy::needless_pass_by_value)]
+        #(#attrs)*
+        #[inline]
+        #vis #sig
+        #block
+    }
+}
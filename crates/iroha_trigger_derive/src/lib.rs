@@ -43,3 +43,42 @@ pub fn main(attr: TokenStream, item: TokenStream) -> TokenStream {
 
     emitter.finish_token_stream_with(result)
 }
+
+/// Annotate the user-defined function that migrates the storage of the trigger after upgrade.
+///
+/// Called once when the executable of the trigger is replaced
+/// with the `UpgradeTrigger` instruction.
+/// Accepts the same arguments as [`main`](macro@main),
+/// the triggering event of the context is the upgrade event of the trigger.
+///
+/// # Panics
+///
+/// - If function has a return type
+///
+/// # Examples
+///
+/// ```ignore
+/// use iroha_trigger::prelude::*;
+///
+/// #[migrate]
+/// fn migrate(host: Iroha, context: Context) {
+///     todo!()
+/// }
+/// ```
+#[manyhow]
+#[proc_macro_attribute]
+pub fn migrate(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut emitter = Emitter::new();
+
+    if !attr.is_empty() {
+        emit!(emitter, "#[migrate] attribute does not accept arguments");
+    }
+
+    let Some(item) = emitter.handle(syn::parse2(item)) else {
+        return emitter.finish_token_stream();
+    };
+
+    let result = entrypoint::impl_migrate_entrypoint(&mut emitter, item);
+
+    emitter.finish_token_stream_with(result)
+}
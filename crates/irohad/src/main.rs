@@ -157,7 +157,7 @@ impl NetworkRelay {
             SumeragiControlFlow(data) => {
                 self.sumeragi.incoming_control_flow_message(*data);
             }
-            BlockSync(data) => self.block_sync.message(*data).await,
+            BlockSync(data) => self.block_sync.message(*data, peer.id).await,
             TransactionGossiper(data) => self.tx_gossiper.gossip(*data).await,
             PeersGossiper(data) => self.peers_gossiper.gossip(*data, peer).await,
             Health => {}
@@ -233,7 +233,7 @@ impl Iroha {
                 live_query_store.clone(),
             )
         });
-        let state = Arc::new(state);
+        let state = Arc::new(state.with_execution_workers(config.sumeragi.execution_workers));
 
         let (events_sender, _) = broadcast::channel(EVENTS_BUFFER_CAPACITY);
         let queue = Arc::new(Queue::from_config(config.queue, events_sender.clone()));
@@ -479,6 +479,8 @@ pub enum ConfigError {
     ReadGenesis,
     #[error("The network consists from this one peer only")]
     LonePeer,
+    #[error("Replica can't submit the genesis block")]
+    ReplicaWithGenesis,
     #[cfg(feature = "dev-telemetry")]
     #[error("Telemetry output file path is root or empty")]
     TelemetryOutFileIsRootOrEmpty,
@@ -567,6 +569,13 @@ fn validate_config(config: &Config) -> Result<(), ConfigError> {
         ").attach_printable(config.common.trusted_peers.clone().into_attachment().display_as_debug()));
     }
 
+    if config.sumeragi.replica && config.genesis.file.is_some() {
+        emitter.emit(Report::new(ConfigError::ReplicaWithGenesis).attach_printable("\
+            Reason: replica doesn't take part in consensus and receives the genesis block from validators.\n\
+            Either unset `genesis.file` or disable `sumeragi.replica`.\
+        "));
+    }
+
     if config.network.address.value() == config.torii.address.value() {
         emitter.emit(
             Report::new(ConfigError::SameNetworkAndToriiAddrs)
@@ -0,0 +1,33 @@
+//! Example of a custom event.
+//! See `wasm/samples/executor_with_custom_event`.
+
+use alloc::{format, string::String, vec::Vec};
+
+use iroha_data_model::{isi::EmitEvent, prelude::Json};
+use iroha_schema::IntoSchema;
+use serde::{Deserialize, Serialize};
+
+/// Event announcing a message to off-chain subscribers
+#[derive(Debug, PartialEq, Eq, Deserialize, Serialize, IntoSchema)]
+pub struct Announcement {
+    /// Text of the announcement
+    pub message: String,
+}
+
+impl From<Announcement> for EmitEvent {
+    fn from(event: Announcement) -> Self {
+        let name = Announcement::type_name()
+            .parse()
+            .expect("INTERNAL BUG: Type name of the event should be a valid name");
+
+        Self::new(name, Json::new(&event))
+    }
+}
+
+impl TryFrom<&Json> for Announcement {
+    type Error = serde_json::Error;
+
+    fn try_from(payload: &Json) -> serde_json::Result<Self> {
+        serde_json::from_str::<Self>(payload.as_ref())
+    }
+}
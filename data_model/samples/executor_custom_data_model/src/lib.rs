@@ -5,6 +5,7 @@
 extern crate alloc;
 
 pub mod complex_isi;
+pub mod events;
 pub mod parameters;
 pub mod permissions;
 pub mod simple_isi;
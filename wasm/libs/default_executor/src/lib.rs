@@ -7,6 +7,7 @@ extern crate panic_halt;
 
 use dlmalloc::GlobalDlmalloc;
 use iroha_executor::{data_model::block::BlockHeader, prelude::*};
+use iroha_executor_data_model::isi::role::RoleHierarchy;
 
 #[global_allocator]
 static ALLOC: GlobalDlmalloc = GlobalDlmalloc;
@@ -46,5 +47,7 @@ impl Executor {
 #[iroha_executor::migrate]
 fn migrate(host: Iroha, context: Context) {
     Executor::ensure_genesis(context.curr_block);
-    DataModelBuilder::with_default_permissions().build_and_set(&host);
+    DataModelBuilder::with_default_permissions()
+        .add_parameter(RoleHierarchy::default())
+        .build_and_set(&host);
 }
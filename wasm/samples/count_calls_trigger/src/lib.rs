@@ -0,0 +1,22 @@
+//! Trigger which counts its executions in the persistent storage.
+
+#![no_std]
+
+#[cfg(not(test))]
+extern crate panic_halt;
+
+use dlmalloc::GlobalDlmalloc;
+use iroha_trigger::{prelude::*, smart_contract::storage};
+
+#[global_allocator]
+static ALLOC: GlobalDlmalloc = GlobalDlmalloc;
+
+const COUNTER_KEY: &[u8] = b"calls";
+
+/// Increment the number of calls kept in the storage of the trigger
+#[iroha_trigger::main]
+fn main(_host: Iroha, _context: Context) {
+    let calls = storage::get::<u32>(COUNTER_KEY).unwrap_or(0);
+
+    storage::set(COUNTER_KEY, &(calls + 1)).dbg_expect("Failed to store the number of calls");
+}
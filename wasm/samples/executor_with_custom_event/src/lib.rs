@@ -0,0 +1,27 @@
+//! Runtime Executor which defines a custom event that can be emitted by anyone
+
+#![no_std]
+
+#[cfg(not(test))]
+extern crate panic_halt;
+
+use dlmalloc::GlobalDlmalloc;
+use executor_custom_data_model::events::Announcement;
+use iroha_executor::{prelude::*, DataModelBuilder};
+
+#[global_allocator]
+static ALLOC: GlobalDlmalloc = GlobalDlmalloc;
+
+#[derive(Visit, Execute, Entrypoints)]
+struct Executor {
+    host: Iroha,
+    context: Context,
+    verdict: Result,
+}
+
+#[iroha_executor::migrate]
+fn migrate(host: Iroha, _context: Context) {
+    DataModelBuilder::with_default_permissions()
+        .add_event::<Announcement>()
+        .build_and_set(&host);
+}
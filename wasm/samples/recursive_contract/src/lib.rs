@@ -0,0 +1,31 @@
+//! Contract which calls itself the number of times passed in the arguments.
+
+#![no_std]
+
+#[cfg(not(test))]
+extern crate panic_halt;
+
+use dlmalloc::GlobalDlmalloc;
+use iroha_contract::prelude::*;
+
+#[global_allocator]
+static ALLOC: GlobalDlmalloc = GlobalDlmalloc;
+
+/// Call this contract recursively `depth` times and return the number of nested calls made
+#[iroha_contract::main]
+fn main(host: Iroha, context: Context) -> Result<Json, ValidationFail> {
+    let depth: u32 = context
+        .args
+        .try_into_any()
+        .dbg_expect("Contract arguments must be the call depth");
+    if depth == 0 {
+        return Ok(Json::new(0_u32));
+    }
+
+    let nested: u32 = host
+        .call_contract(&context.id, Json::new(depth - 1))?
+        .try_into_any()
+        .dbg_expect("Contract output must be the number of nested calls");
+
+    Ok(Json::new(nested + 1))
+}